@@ -28,7 +28,7 @@ use c2pa_font_handler::{
     chunks::ChunkReader,
     sfnt::{
         directory::{SfntDirectory, SfntDirectoryEntry},
-        font::{stub_dsig_stream, SfntFont},
+        font::{stub_dsig_stream, SfntFont, SfntReadOptions},
         header::SfntHeader,
         table::TableC2PA,
     },
@@ -48,6 +48,18 @@ fn sfnt_font_benchmarks(c: &mut Criterion) {
         });
     });
 
+    // Benchmark for reading the font data into a single shared buffer, versus
+    // an allocation per table as above
+    c.bench_function("parse_sfnt_from_reader_shared_buffer", |b| {
+        let options = SfntReadOptions::default().with_shared_buffer(true);
+        b.iter(|| {
+            let mut font_stream = std::io::Cursor::new(get_sfnt_font_data());
+            let _ =
+                SfntFont::from_reader_with_options(&mut font_stream, &options)
+                    .expect("Failed to read font data");
+        });
+    });
+
     // Benchmark the process of getting chunk positions
     c.bench_function("get_chunk_positions", |b| {
         b.iter(|| {
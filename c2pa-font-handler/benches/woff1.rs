@@ -18,6 +18,7 @@
 #[path = "utils/profiler.rs"]
 mod profiler_utils;
 use c2pa_font_handler::{
+    c2pa::{C2PASupport, ContentCredentialRecord},
     chunks::ChunkReader,
     sfnt::table::TableC2PA,
     woff1::{
@@ -25,9 +26,11 @@ use c2pa_font_handler::{
         font::Woff1Font,
         header::Woff1Header,
     },
-    FontDataExactRead, FontDataRead, FontDataWrite,
+    FontDataExactRead, FontDataRead, FontDataWrite, MutFontDataWrite,
+};
+use criterion::{
+    criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion,
 };
-use criterion::{criterion_group, criterion_main, Criterion};
 use profiler_utils::DhatProfiler;
 
 #[path = "utils/c2pa.rs"]
@@ -176,9 +179,65 @@ fn woff1_table_benchmarks(c: &mut Criterion) {
     });
 }
 
+/// Creates a manifest store of the given size, with content that compresses
+/// roughly as well as a typical manifest store.
+fn create_manifest_store(size: usize) -> Vec<u8> {
+    let mut state = 0x2545_f491_u32;
+    (0..size)
+        .map(|index| {
+            // Mix runs of repeated bytes with pseudo-random ones
+            if index % 4 == 0 {
+                state ^= state << 13;
+                state ^= state >> 17;
+                state ^= state << 5;
+                state as u8
+            } else {
+                (index / 64) as u8
+            }
+        })
+        .collect()
+}
+
+/// Collection of benchmarks for writing WOFF1 fonts with large C2PA tables,
+/// which exercises the compression of the C2PA table.
+fn woff1_c2pa_write_benchmarks(c: &mut Criterion) {
+    let mut group = c.benchmark_group("woff1_write_with_c2pa");
+    for size in [64 * 1024, 1024 * 1024, 4 * 1024 * 1024] {
+        let manifest_store = create_manifest_store(size);
+        group.bench_with_input(
+            BenchmarkId::from_parameter(size),
+            &manifest_store,
+            |b, manifest_store| {
+                b.iter_batched(
+                    || {
+                        let mut font_stream =
+                            std::io::Cursor::new(get_woff1_font_data());
+                        let mut font = Woff1Font::from_reader(&mut font_stream)
+                            .expect("Failed to read font data");
+                        let record = ContentCredentialRecord::builder()
+                            .with_content_credential(manifest_store.clone())
+                            .build()
+                            .expect("Failed to build C2PA record");
+                        font.add_c2pa_record(record)
+                            .expect("Failed to add C2PA record");
+                        font
+                    },
+                    |mut font| {
+                        let mut dest_stream = std::io::Cursor::new(Vec::new());
+                        font.write(&mut dest_stream)
+                            .expect("Failed to write font data");
+                    },
+                    BatchSize::LargeInput,
+                );
+            },
+        );
+    }
+    group.finish();
+}
+
 criterion_group!(
     name = benches;
     config = Criterion::default().with_profiler(DhatProfiler::new());
-    targets =  woff1_table_benchmarks, woff1_directory_benchmarks, woff1_header_benchmarks, woff1_font_benchmarks,
+    targets =  woff1_table_benchmarks, woff1_directory_benchmarks, woff1_header_benchmarks, woff1_font_benchmarks, woff1_c2pa_write_benchmarks,
 );
 criterion_main!(benches);
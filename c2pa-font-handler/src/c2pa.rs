@@ -40,10 +40,49 @@ pub trait C2PASupport {
     /// Gets the C2PA record
     fn get_c2pa(self) -> Result<Option<ContentCredentialRecord>, Self::Error>;
 
+    /// Gets the C2PA record, applying the additional checks requested in the
+    /// given options.
+    ///
+    /// # Errors
+    /// Returns [`FontIoError::InvalidManifestUri`] if the options request the
+    /// active manifest URI be validated and it is not a valid http/https or
+    /// relative URI.
+    fn get_c2pa_with_options(
+        self,
+        options: &C2PAReadOptions,
+    ) -> Result<Option<ContentCredentialRecord>, Self::Error>
+    where
+        Self: Sized,
+        Self::Error: From<FontIoError>,
+    {
+        let record = self.get_c2pa()?;
+        if options.validate_manifest_uri {
+            if let Some(record) = &record {
+                record.validate_active_manifest_uri()?;
+            }
+        }
+        Ok(record)
+    }
+
     /// Removes a C2PA record
     fn remove_c2pa_record(&mut self) -> Result<(), Self::Error>;
 }
 
+/// Options controlling how a C2PA record is read from a font.
+#[derive(Clone, Debug, Default)]
+pub struct C2PAReadOptions {
+    validate_manifest_uri: bool,
+}
+
+impl C2PAReadOptions {
+    /// Validates the active manifest URI is a well-formed http/https or
+    /// relative URI when reading the record; disabled by default.
+    pub fn with_validate_manifest_uri(mut self, validate: bool) -> Self {
+        self.validate_manifest_uri = validate;
+        self
+    }
+}
+
 /// Support for updating content credential records.
 pub trait UpdatableC2PA {
     /// Error type returned from updating C2PA records
@@ -57,7 +96,11 @@ pub trait UpdatableC2PA {
 }
 
 /// A Content Credential record for supporting C2PA.
-#[derive(Clone, Debug)]
+///
+/// # Remarks
+/// Records compare (and hash) equal when all their fields are equal, which
+/// can be used to detect when an update would not change the font.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct ContentCredentialRecord {
     major_version: u16,
     minor_version: u16,
@@ -108,6 +151,70 @@ impl ContentCredentialRecord {
     pub fn content_credential(&self) -> Option<&[u8]> {
         self.content_credential.as_deref()
     }
+
+    /// Validates the active manifest URI, if present, is a well-formed
+    /// http/https or relative URI.
+    ///
+    /// # Errors
+    /// Returns [`FontIoError::InvalidManifestUri`] if the URI is not valid.
+    pub fn validate_active_manifest_uri(&self) -> Result<(), FontIoError> {
+        match &self.active_manifest_uri {
+            Some(uri) if !is_valid_manifest_uri(uri) => {
+                Err(FontIoError::InvalidManifestUri(uri.to_string()))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Checks the URI is either an absolute http/https URI with a host, or a
+/// relative reference, made up only of the characters allowed by RFC 3986.
+fn is_valid_manifest_uri(uri: &str) -> bool {
+    if uri.is_empty() {
+        return false;
+    }
+    // Only the characters allowed by RFC 3986, with well-formed
+    // percent-encoding
+    let bytes = uri.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'%' => {
+                if !bytes
+                    .get(i + 1..i + 3)
+                    .is_some_and(|hex| hex.iter().all(u8::is_ascii_hexdigit))
+                {
+                    return false;
+                }
+                i += 3;
+                continue;
+            }
+            b if b.is_ascii_alphanumeric()
+                || b"-._~:/?#[]@!$&'()*+,;=".contains(&b) => {}
+            _ => return false,
+        }
+        i += 1;
+    }
+    // A scheme is only present if a ':' comes before any of '/', '?' or '#'
+    let scheme = uri
+        .find([':', '/', '?', '#'])
+        .filter(|&index| uri[index..].starts_with(':'))
+        .map(|index| &uri[..index]);
+    match scheme {
+        // A relative reference
+        None => true,
+        Some(scheme)
+            if scheme.eq_ignore_ascii_case("http")
+                || scheme.eq_ignore_ascii_case("https") =>
+        {
+            // Must have a non-empty authority
+            uri[scheme.len() + 1..]
+                .strip_prefix("//")
+                .and_then(|rest| rest.split(['/', '?', '#']).next())
+                .is_some_and(|authority| !authority.is_empty())
+        }
+        Some(_) => false,
+    }
 }
 
 impl Default for ContentCredentialRecord {
@@ -122,7 +229,7 @@ impl Default for ContentCredentialRecord {
 }
 
 /// Update Type
-#[derive(Debug)]
+#[derive(Debug, Eq, PartialEq)]
 pub enum UpdateType<T: std::fmt::Debug> {
     /// Remove the value
     Remove,
@@ -130,7 +237,7 @@ pub enum UpdateType<T: std::fmt::Debug> {
     Update(T),
 }
 /// Update Content Credential Record
-#[derive(Default, Debug)]
+#[derive(Default, Debug, Eq, PartialEq)]
 pub struct UpdateContentCredentialRecord {
     active_manifest_uri: Option<UpdateType<String>>,
     content_credential: Option<UpdateType<Vec<u8>>>,
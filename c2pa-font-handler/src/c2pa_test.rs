@@ -129,3 +129,71 @@ fn test_update_record_updated_items() {
     ));
     assert!(update_record.take_content_credential().is_none());
 }
+
+#[test]
+fn test_validate_active_manifest_uri() {
+    for uri in [
+        "http://example.com/manifest",
+        "HTTPS://example.com:8080/path?query=1#fragment",
+        "self#jumbf=/c2pa/urn:uuid:1234",
+        "manifests/font%20manifest.c2pa",
+        "/absolute/path",
+    ] {
+        let record = ContentCredentialRecord::builder()
+            .with_active_manifest_uri(uri.to_string())
+            .build()
+            .unwrap();
+        assert!(
+            record.validate_active_manifest_uri().is_ok(),
+            "Expected {uri} to be valid"
+        );
+    }
+    for uri in [
+        "",
+        "ftp://example.com/manifest",
+        "https:///no-host",
+        "https:example.com",
+        "has space",
+        "bad%2",
+        "bad%zz",
+        "\u{1}\u{2}\u{3}",
+        "caf\u{e9}",
+    ] {
+        let record = ContentCredentialRecord::builder()
+            .with_active_manifest_uri(uri.to_string())
+            .build()
+            .unwrap();
+        assert!(
+            matches!(
+                record.validate_active_manifest_uri(),
+                Err(FontIoError::InvalidManifestUri(_))
+            ),
+            "Expected {uri:?} to be invalid"
+        );
+    }
+    // No URI at all is fine
+    assert!(ContentCredentialRecord::default()
+        .validate_active_manifest_uri()
+        .is_ok());
+}
+
+#[test]
+fn test_record_equality_and_hashing() {
+    let build = |store: Vec<u8>| {
+        ContentCredentialRecord::builder()
+            .with_active_manifest_uri("http://example.com/manifest".to_owned())
+            .with_content_credential(store)
+            .build()
+            .unwrap()
+    };
+    let record = build(vec![1, 2, 3]);
+    assert_eq!(record, build(vec![1, 2, 3]));
+    assert_ne!(record, build(vec![1, 2, 4]));
+    assert_ne!(record, ContentCredentialRecord::default());
+
+    let records = [record.clone(), build(vec![1, 2, 3]), build(vec![4])]
+        .into_iter()
+        .collect::<std::collections::HashSet<_>>();
+    assert_eq!(records.len(), 2);
+    assert!(records.contains(&record));
+}
@@ -19,6 +19,12 @@ use std::{
     io::{Read, Seek},
 };
 
+#[cfg(feature = "serde")]
+use byteorder::{BigEndian, ReadBytesExt};
+
+#[cfg(feature = "serde")]
+use crate::{error::FontIoError, magic::Magic};
+
 /// A trait for reading data chunks.
 pub trait ChunkReader {
     /// The error type for reading data chunks.
@@ -113,6 +119,56 @@ impl<T: ChunkTypeTrait> std::fmt::Display for ChunkPosition<T> {
     }
 }
 
+/// Serializes the chunk position, including whether the chunk should be
+/// hashed, so external tools can determine the byte ranges to exclude.
+#[cfg(feature = "serde")]
+impl<T: ChunkTypeTrait + serde::Serialize> serde::Serialize
+    for ChunkPosition<T>
+{
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("ChunkPosition", 5)?;
+        state.serialize_field("offset", &self.offset)?;
+        state.serialize_field("length", &self.length)?;
+        state.serialize_field("name", &String::from_utf8_lossy(&self.name))?;
+        state.serialize_field("chunk_type", &self.chunk_type)?;
+        state.serialize_field("should_hash", &self.chunk_type.should_hash())?;
+        state.end()
+    }
+}
+
+/// Gets the chunk positions of the font in the stream as a JSON array.
+///
+/// # Remarks
+/// The format of the font is detected from its magic number; SFNT fonts are
+/// always supported and WOFF 1.0 fonts are supported when the `woff` feature
+/// is enabled.
+#[cfg(feature = "serde")]
+pub fn chunk_positions_to_json(
+    reader: &mut (impl Read + Seek + ?Sized),
+) -> Result<String, FontIoError> {
+    let start = reader.stream_position()?;
+    let magic = reader.read_u32::<BigEndian>()?;
+    reader.seek(std::io::SeekFrom::Start(start))?;
+    match Magic::try_from(magic)? {
+        Magic::OpenType | Magic::TrueType | Magic::AppleTrue => {
+            let positions =
+                crate::sfnt::font::SfntFont::get_chunk_positions(reader)?;
+            Ok(serde_json::to_string(&positions)?)
+        }
+        #[cfg(feature = "woff")]
+        Magic::Woff => {
+            let positions =
+                crate::woff1::font::Woff1Font::get_chunk_positions(reader)?;
+            Ok(serde_json::to_string(&positions)?)
+        }
+        _ => Err(FontIoError::UnsupportedFontFormat(magic)),
+    }
+}
+
 #[cfg(test)]
 #[path = "chunks_test.rs"]
 mod tests;
@@ -74,3 +74,51 @@ fn test_chunk_type_display() {
     assert_eq!(ChunkType::DirectoryEntry.to_string(), "Directory Entry");
     assert_eq!(ChunkType::TableData.to_string(), "Table Data");
 }
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_chunk_positions_to_json_sfnt() {
+    let font_data = include_bytes!("../../.devtools/font.otf");
+    let mut reader = std::io::Cursor::new(font_data);
+    let json = chunk_positions_to_json(&mut reader).unwrap();
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    let chunks = value.as_array().unwrap();
+    assert_eq!(
+        chunks[0],
+        serde_json::json!({
+            "offset": 0,
+            "length": 188,
+            "name": " HDR",
+            "chunk_type": "HeaderDirectory",
+            "should_hash": false,
+        })
+    );
+    assert!(chunks.iter().any(|chunk| {
+        chunk["chunk_type"] == "ChecksumAdjustment"
+            && chunk["should_hash"] == false
+    }));
+}
+
+#[cfg(all(feature = "serde", feature = "woff"))]
+#[test]
+fn test_chunk_positions_to_json_woff() {
+    let font_data = include_bytes!("../../.devtools/font.woff");
+    let mut reader = std::io::Cursor::new(font_data);
+    let json = chunk_positions_to_json(&mut reader).unwrap();
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    let chunks = value.as_array().unwrap();
+    assert_eq!(chunks[0]["chunk_type"], "Header");
+    assert_eq!(chunks[0]["offset"], 0);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_chunk_positions_to_json_unsupported_format() {
+    let font_data = include_bytes!("../../.devtools/font.woff2");
+    let mut reader = std::io::Cursor::new(font_data);
+    let result = chunk_positions_to_json(&mut reader);
+    assert!(matches!(
+        result,
+        Err(FontIoError::UnsupportedFontFormat(0x774f4632))
+    ));
+}
@@ -16,8 +16,11 @@
 //! tables).
 
 use std::{
-    io::{Read, Seek, SeekFrom, Write},
+    fmt,
+    io::{ErrorKind, Read, Seek, SeekFrom, Write},
     num::Wrapping,
+    ops::{Deref, DerefMut, Range},
+    sync::Arc,
 };
 
 use crate::{
@@ -30,18 +33,121 @@ use crate::{
 #[derive(Clone, Debug, Default)]
 pub struct Data {
     /// The data
-    pub(crate) data: Vec<u8>,
+    pub(crate) data: DataBytes,
 }
 
 impl Data {
     /// Create a new Data record with the given data
     pub fn new(data: Vec<u8>) -> Self {
-        Data { data }
+        Data { data: data.into() }
     }
 
     /// Set the associated data
     pub fn set_data(&mut self, data: Vec<u8>) {
-        self.data = data;
+        self.data = data.into();
+    }
+
+    /// Creates a Data record sharing the given range of the buffer, without
+    /// copying it.
+    ///
+    /// # Errors
+    /// Returns an [`ErrorKind::UnexpectedEof`] I/O error if the range runs
+    /// past the end of the buffer, as reading it would.
+    pub(crate) fn from_shared(
+        buffer: &Arc<[u8]>,
+        offset: usize,
+        size: usize,
+    ) -> Result<Self, FontIoError> {
+        let range = offset
+            .checked_add(size)
+            .filter(|end| *end <= buffer.len())
+            .map(|end| offset..end)
+            .ok_or_else(|| std::io::Error::from(ErrorKind::UnexpectedEof))?;
+        Ok(Data {
+            data: DataBytes::Shared {
+                buffer: Arc::clone(buffer),
+                range,
+            },
+        })
+    }
+}
+
+/// The bytes of a [`Data`] record, either owned by the record or shared with
+/// other records (e.g., the tables of a font read into a single buffer).
+///
+/// # Remarks
+/// Shared bytes are copied the first time they are modified, so modifying
+/// one record never affects another.
+#[derive(Clone)]
+pub(crate) enum DataBytes {
+    /// Bytes owned by the record.
+    Owned(Vec<u8>),
+    /// A range of a buffer shared with other records.
+    Shared {
+        /// The shared buffer
+        buffer: Arc<[u8]>,
+        /// The range of the buffer holding the bytes
+        range: Range<usize>,
+    },
+}
+
+impl DataBytes {
+    /// Returns the bytes as a slice.
+    pub(crate) fn as_slice(&self) -> &[u8] {
+        match self {
+            DataBytes::Owned(data) => data,
+            DataBytes::Shared { buffer, range } => &buffer[range.clone()],
+        }
+    }
+}
+
+impl Default for DataBytes {
+    fn default() -> Self {
+        DataBytes::Owned(Vec::new())
+    }
+}
+
+impl From<Vec<u8>> for DataBytes {
+    fn from(data: Vec<u8>) -> Self {
+        DataBytes::Owned(data)
+    }
+}
+
+impl Deref for DataBytes {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        self.as_slice()
+    }
+}
+
+impl DerefMut for DataBytes {
+    fn deref_mut(&mut self) -> &mut [u8] {
+        if let DataBytes::Shared { buffer, range } = self {
+            *self = DataBytes::Owned(buffer[range.clone()].to_vec());
+        }
+        match self {
+            DataBytes::Owned(data) => data,
+            DataBytes::Shared { .. } => unreachable!("the bytes were copied"),
+        }
+    }
+}
+
+impl AsRef<[u8]> for DataBytes {
+    fn as_ref(&self) -> &[u8] {
+        self.as_slice()
+    }
+}
+
+impl<T: AsRef<[u8]> + ?Sized> PartialEq<T> for DataBytes {
+    fn eq(&self, other: &T) -> bool {
+        self.as_slice() == other.as_ref()
+    }
+}
+
+impl fmt::Debug for DataBytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_slice(), f)
     }
 }
 
@@ -64,7 +170,7 @@ impl FontDataExactRead for Data {
         reader.seek(SeekFrom::Start(offset))?;
         let mut data = vec![0; size];
         reader.read_exact(&mut data)?;
-        Ok(Data { data })
+        Ok(Data::new(data))
     }
 }
 
@@ -46,25 +46,22 @@ fn test_reader_exact_with_invalid_sized_buffer() {
 
 #[test]
 fn test_table_generic_len() {
-    let generic = Data {
-        data: vec![0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00],
-    };
+    let generic =
+        Data::new(vec![0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00]);
     assert_eq!(generic.len(), 8);
 }
 
 #[test]
 fn test_table_generic_checksum() {
-    let generic = Data {
-        data: vec![0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00],
-    };
+    let generic =
+        Data::new(vec![0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00]);
     assert_eq!(generic.checksum(), Wrapping(0x00000001));
 }
 
 #[test]
 fn test_table_generic_write() {
-    let generic = Data {
-        data: vec![0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00],
-    };
+    let generic =
+        Data::new(vec![0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00]);
     let mut buffer = Vec::new();
     generic.write(&mut buffer).unwrap();
     assert_eq!(buffer, vec![0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00]);
@@ -73,18 +70,15 @@ fn test_table_generic_write() {
 #[test]
 fn test_table_generic_write_with_4_byte_alignment() {
     // Create table with 5 bytes, which should be padded to 8 bytes
-    let generic = Data {
-        data: vec![0x00, 0x00, 0x00, 0x01, 0x00],
-    };
+    let generic = Data::new(vec![0x00, 0x00, 0x00, 0x01, 0x00]);
     let mut buffer = Vec::new();
     generic.write(&mut buffer).unwrap();
     assert_eq!(buffer, vec![0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00]);
 }
 #[test]
 fn test_table_generic_write_with_bad_buffer_size() {
-    let generic = Data {
-        data: vec![0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00],
-    };
+    let generic =
+        Data::new(vec![0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00]);
     let mut buffer = [0; 7];
     let mut cursor = Cursor::new(&mut buffer[..]);
     let result = generic.write(&mut cursor);
@@ -110,3 +104,30 @@ fn test_set_data() {
     data.set_data(vec![0x00, 0x00, 0x00, 0x01]);
     assert_eq!(data.data, vec![0x00, 0x00, 0x00, 0x01]);
 }
+
+#[test]
+fn test_from_shared() {
+    let buffer: Arc<[u8]> = vec![0x00, 0x01, 0x02, 0x03, 0x04, 0x05].into();
+    let mut data = Data::from_shared(&buffer, 2, 3).unwrap();
+    assert!(matches!(data.data, DataBytes::Shared { .. }));
+    assert_eq!(data.data, [0x02, 0x03, 0x04]);
+    assert_eq!(data.len(), 3);
+    let other = data.clone();
+
+    // Modifying the data copies it, leaving the buffer and others unchanged
+    data.data[0] = 0xff;
+    assert!(matches!(data.data, DataBytes::Owned(_)));
+    assert_eq!(data.data, [0xff, 0x03, 0x04]);
+    assert_eq!(other.data, [0x02, 0x03, 0x04]);
+    assert_eq!(buffer[2], 0x02);
+}
+
+#[test]
+fn test_from_shared_out_of_range() {
+    let buffer: Arc<[u8]> = vec![0; 4].into();
+    assert!(Data::from_shared(&buffer, 4, 0).is_ok());
+    for (offset, size) in [(2, 3), (5, 0), (usize::MAX, 2)] {
+        let err = Data::from_shared(&buffer, offset, size).unwrap_err();
+        assert!(matches!(err, FontIoError::IoError(_)));
+    }
+}
@@ -14,6 +14,8 @@
 
 //! Errors related to font I/O.
 
+use alloc::string::{FromUtf8Error, String};
+
 use super::tag::FontTag;
 
 /// Errors related to font I/O.
@@ -29,13 +31,60 @@ pub enum FontIoError {
     /// Content credential record not found
     #[error("A content credential was not found")]
     ContentCredentialNotFound,
+    /// A compressed table declares a decompressed size over the allowed limit.
+    #[cfg(feature = "woff")]
+    #[error("The decompressed size of table {tag} ({size} bytes) exceeds the limit of {limit} bytes")]
+    DecompressedSizeExceeded {
+        /// The tag of the table
+        tag: FontTag,
+        /// The declared decompressed size of the table
+        size: u32,
+        /// The maximum allowed decompressed size
+        limit: u32,
+    },
+    /// The font's directory holds fewer entries than its header declares,
+    /// e.g. because the font data is truncated.
+    #[error("The table directory declares {declared} entries, but only {available} are available")]
+    DirectoryEntryCountMismatch {
+        /// The number of entries declared in the header
+        declared: usize,
+        /// The number of whole entries which could be read
+        available: usize,
+    },
+    /// A table is listed more than once in the font's directory.
+    #[error("The font table is listed more than once: {0}")]
+    DuplicateTable(FontTag),
     /// Failed to write the font data.
+    #[cfg(feature = "std")]
     #[error("Failed to write font data")]
     FailedToWriteFontData(std::io::Error),
     /// Failed to write the font table data.
+    #[cfg(feature = "std")]
     #[error("Failed to write font table data")]
     FailedToWriteTableData(std::io::Error),
+    /// The font is larger than the maximum size allowed for reading it.
+    #[error("The font ({size} bytes) exceeds the limit of {limit} bytes")]
+    FontTooLarge {
+        /// The size of the font
+        size: u64,
+        /// The maximum allowed size
+        limit: u64,
+    },
+    /// The `searchRange`, `entrySelector` and `rangeShift` fields of the
+    /// font's header are not those derived from its number of tables.
+    #[error("The header's search fields {found:?} are inconsistent with its {num_tables} tables; expected {expected:?}")]
+    InconsistentHeaderFields {
+        /// The number of tables in the header
+        num_tables: u16,
+        /// The `searchRange`, `entrySelector` and `rangeShift` fields
+        /// derived from the number of tables
+        expected: (u16, u16, u16),
+        /// The `searchRange`, `entrySelector` and `rangeShift` fields in the
+        /// header
+        found: (u16, u16, u16),
+    },
     /// An error occurred while reading or writing the font data.
+    #[cfg(feature = "std")]
     #[error(transparent)]
     IoError(#[from] std::io::Error),
     /// An invalid (or unsupported) major C2PA version
@@ -47,6 +96,15 @@ pub enum FontIoError {
     /// The magic number in the 'head' table is invalid.
     #[error("Invalid magic number in the 'head' table; expected 0x5f0f3cf5, got {0}")]
     InvalidHeadMagicNumber(u32),
+    /// The offset format of the 'loca' table is invalid.
+    #[error("Invalid indexToLocFormat for the 'loca' table: {0}")]
+    InvalidIndexToLocFormat(i16),
+    /// The version of the 'maxp' table is invalid (or unsupported).
+    #[error("Invalid version for the 'maxp' table: {0:#010x}")]
+    InvalidMaxpVersion(u32),
+    /// The active manifest URI is not a valid http/https or relative URI.
+    #[error("Invalid active manifest URI: {0}")]
+    InvalidManifestUri(String),
     /// The table container is invalid for a C2PA table.
     #[error("Invalid C2PA table container")]
     InvalidC2paTableContainer,
@@ -68,9 +126,51 @@ pub enum FontIoError {
     /// The specified size for reading a tag is invalid.
     #[error("Invalid size for a tag, expected 4 bytes, got {0}")]
     InvalidSizeForTAG(usize),
+    /// The length in the WOFF header does not match the length of the data,
+    /// i.e., it runs past the end of the stream or falls short of the font's
+    /// data.
+    #[cfg(feature = "woff")]
+    #[error(
+        "Invalid length in the WOFF header; expected {actual}, got {header}"
+    )]
+    InvalidWoffLength {
+        /// The length recorded in the header
+        header: u32,
+        /// The actual length of the data; the bytes left in the stream, or
+        /// the end of the font's data
+        actual: u64,
+    },
+    /// The WOFF extension metadata is not valid XML.
+    #[cfg(feature = "woff")]
+    #[error("Invalid WOFF extension metadata: {0}")]
+    InvalidWoffMetadata(String),
+    /// The reserved field of the WOFF header is not zero.
+    #[cfg(feature = "woff")]
+    #[error("Invalid reserved field in the WOFF header; expected 0, got {0}")]
+    InvalidWoffReserved(u16),
+    /// The total SFNT size in the WOFF header does not match the size of the
+    /// SFNT font reconstructed from its tables.
+    #[cfg(feature = "woff")]
+    #[error(
+        "Invalid total SFNT size in the WOFF header; expected {computed}, got {declared}"
+    )]
+    SfntSizeMismatch {
+        /// The total SFNT size recorded in the header
+        declared: u32,
+        /// The size of the reconstructed SFNT font
+        computed: u64,
+    },
+    /// An error occurred while serializing to JSON.
+    #[cfg(feature = "serde")]
+    #[error(transparent)]
+    JsonError(#[from] serde_json::Error),
     /// The font table is truncated.
     #[error("The font table is truncated: {0}")]
     LoadTableTruncated(FontTag),
+    /// The 'C2PA' table can be read, but the layout of its sections does not
+    /// conform to the specification.
+    #[error("Malformed C2PA table: {0}")]
+    MalformedC2paTable(String),
     /// There were no tables found in the font.
     #[error("No tables were found in the font.")]
     NoTablesFound,
@@ -79,21 +179,52 @@ pub enum FontIoError {
     SaveError(#[from] FontSaveError),
     /// An error occurred while generating a string from UTF-8 bytes.
     #[error("Error occurred while generating a string from UTF-8 bytes: {0}")]
-    StringFromUtf8(#[from] std::string::FromUtf8Error),
+    StringFromUtf8(#[from] FromUtf8Error),
     /// The table associated with the tag was not found.
     #[error("The font table was not found for tag: {0}")]
     TableNotFound(FontTag),
+    /// An error occurred while generating a thumbnail.
+    #[cfg(feature = "thumbnails")]
+    #[error(transparent)]
+    ThumbnailError(Box<crate::thumbnail::error::FontThumbnailError>),
     /// When determining the type of font, the magic number was not recognized.
     #[error("An unknown magic number was encountered: {0}")]
     UnknownMagic(u32),
+    /// The font format is recognized, but not supported for the operation.
+    #[error("The font format is not supported: {0:#010x}")]
+    UnsupportedFontFormat(u32),
+}
+
+#[cfg(feature = "thumbnails")]
+impl From<crate::thumbnail::error::FontThumbnailError> for FontIoError {
+    fn from(error: crate::thumbnail::error::FontThumbnailError) -> Self {
+        match error {
+            // Unwrap font I/O errors, rather than nesting them
+            crate::thumbnail::error::FontThumbnailError::FontIoError(error) => {
+                error
+            }
+            error => FontIoError::ThumbnailError(Box::new(error)),
+        }
+    }
 }
 
 /// Errors related to saving a font
 #[derive(Debug, thiserror::Error)]
 pub enum FontSaveError {
+    /// The font is too large for its tables to be addressed with 32-bit
+    /// offsets.
+    #[error(
+        "The font is too large to be written; table offsets would exceed 4GB."
+    )]
+    FontTooLarge,
     /// The font has no tables.
     #[error("No tables were found in the font.")]
     NoTablesFound,
+    /// The font has more tables than can be listed in its header.
+    #[error(
+        "The font has too many tables ({0}); at most 65535 are supported."
+    )]
+    TooManyTables(usize),
     /// The font has too many tables that were added.
     #[error("Too many tables were added to the font, which is currently not supported.")]
     TooManyTablesAdded,
@@ -104,3 +235,7 @@ pub enum FontSaveError {
     #[error("An unexpected table was encountered: {0}")]
     UnexpectedTable(String),
 }
+
+#[cfg(all(test, feature = "thumbnails"))]
+#[path = "error_test.rs"]
+mod tests;
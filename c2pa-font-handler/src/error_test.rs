@@ -0,0 +1,58 @@
+// Copyright 2025 Monotype Imaging Inc.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+//! Tests for the error module
+
+use super::*;
+use crate::thumbnail::error::FontThumbnailError;
+
+#[test]
+fn test_from_thumbnail_error() {
+    let error = FontIoError::from(FontThumbnailError::NoFontFound);
+    assert!(matches!(
+        &error,
+        FontIoError::ThumbnailError(inner)
+            if matches!(**inner, FontThumbnailError::NoFontFound)
+    ));
+    // The detail of the thumbnail error is preserved
+    assert_eq!(error.to_string(), "No font found");
+
+    // Font I/O errors are unwrapped, rather than nested
+    let error = FontIoError::from(FontThumbnailError::FontIoError(
+        FontIoError::TableNotFound(FontTag::HEAD),
+    ));
+    assert!(matches!(error, FontIoError::TableNotFound(FontTag::HEAD)));
+}
+
+#[cfg(feature = "svg-thumbnails")]
+#[test]
+fn test_mixed_pipeline_with_question_mark() {
+    use std::io::Cursor;
+
+    use crate::thumbnail::{
+        CosmicTextThumbnailGenerator, SvgThumbnailRenderer, ThumbnailGenerator,
+    };
+
+    fn pipeline(data: &[u8]) -> Result<usize, FontIoError> {
+        let generator = CosmicTextThumbnailGenerator::new(Box::new(
+            SvgThumbnailRenderer::default(),
+        ));
+        let thumbnail = generator
+            .create_thumbnail_from_stream(&mut Cursor::new(data), None)?;
+        Ok(thumbnail.data().len())
+    }
+    let font_data = include_bytes!("../../.devtools/font.otf");
+    assert!(pipeline(font_data).unwrap() > 0);
+    assert!(pipeline(b"not a font").is_err());
+}
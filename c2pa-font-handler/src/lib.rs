@@ -23,39 +23,62 @@
 //! # Example
 //! ```
 //! use std::io::Cursor;
-//! use c2pa_font_handler::error::FontIoError;
-//! use c2pa_font_handler::sfnt::font::SfntFont;
-//! use c2pa_font_handler::*;
 //!
+//! # #[cfg(feature = "std")]
+//! use c2pa_font_handler::{error::FontIoError, sfnt::font::SfntFont, *};
+//!
+//! # #[cfg(feature = "std")]
 //! fn main() -> Result<(), FontIoError> {
-//!    let font_data = include_bytes!("../../.devtools/font.otf");
-//!    let mut reader = Cursor::new(font_data);
-//!    let mut font = SfntFont::from_reader(&mut reader)?;
-//!    assert_eq!(font.header().num_tables(), 11);
-//!    assert_eq!(font.directory().physical_order().len(), 11);
-//!    // And clear out DSIG
-//!    font.stub_dsig()?;
+//!     let font_data = include_bytes!("../../.devtools/font.otf");
+//!     let mut reader = Cursor::new(font_data);
+//!     let mut font = SfntFont::from_reader(&mut reader)?;
+//!     assert_eq!(font.header().num_tables(), 11);
+//!     assert_eq!(font.directory().physical_order().len(), 11);
+//!     // And clear out DSIG
+//!     font.stub_dsig()?;
 //!
-//!    // Save out the file or do something else with it...
+//!     // Save out the file or do something else with it...
 //!
-//!    Ok(())
+//!     Ok(())
 //! }
+//! # #[cfg(not(feature = "std"))]
+//! # fn main() {}
+//! ```
+//!
+//! # `no_std`
+//! Without the `std` feature (on by default) the crate is `no_std`, only
+//! needing `alloc`, and provides just the core of parsing an SFNT font: the
+//! [`tag::FontTag`], the [`sfnt::header::SfntHeader`] and the
+//! [`sfnt::directory::SfntDirectory`], read from byte slices with their
+//! `from_bytes` functions, along with computing [`checksum`]s.
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 
-use std::{
-    io::{Read, Seek, Write},
-    num::Wrapping,
-};
+extern crate alloc;
 
+use core::num::Wrapping;
+#[cfg(feature = "std")]
+use std::io::{Read, Seek, Write};
+
+#[cfg(feature = "std")]
 use tag::FontTag;
+pub use utils::checksum;
 
+#[cfg(feature = "std")]
 pub mod c2pa;
+#[cfg(feature = "std")]
 pub mod chunks;
 #[cfg(feature = "compression")]
 pub mod compression;
+#[cfg(feature = "std")]
 pub mod data;
 pub mod error;
 pub(crate) mod magic;
+#[cfg(feature = "std")]
+pub mod metrics;
+#[cfg(feature = "std")]
 pub mod mime_type;
+#[cfg(feature = "std")]
+pub mod range_reader;
 pub mod sfnt;
 pub mod tag;
 #[cfg(feature = "thumbnails")]
@@ -71,6 +94,7 @@ pub trait FontDataChecksum {
 }
 
 /// Trait for reading SFNT data from a reader.
+#[cfg(feature = "std")]
 pub trait FontDataRead
 where
     Self: Sized,
@@ -84,6 +108,7 @@ where
 }
 
 /// Trait for reading SFNT data from a reader, with exact size information.
+#[cfg(feature = "std")]
 pub trait FontDataExactRead
 where
     Self: Sized,
@@ -101,6 +126,7 @@ where
 }
 
 /// Trait for writing SFNT data to a writer.
+#[cfg(feature = "std")]
 pub trait FontDataWrite {
     /// The error type for writing the data.
     type Error;
@@ -113,6 +139,7 @@ pub trait FontDataWrite {
 
 /// Trait for writing SFNT data to a writer, with the ability to modify the
 /// object.
+#[cfg(feature = "std")]
 pub trait MutFontDataWrite {
     /// The error type for writing the data.
     type Error;
@@ -124,12 +151,14 @@ pub trait MutFontDataWrite {
 }
 
 /// A font header.
+#[cfg(feature = "std")]
 pub trait FontHeader: FontDataRead + FontDataChecksum + FontDataWrite {
     /// Returns the number of tables in the font.
     fn num_tables(&self) -> u16;
 }
 
 /// A directory in a font.
+#[cfg(feature = "std")]
 pub trait FontDirectory:
     FontDataExactRead + FontDataChecksum + FontDataWrite
 {
@@ -149,6 +178,7 @@ pub trait FontDirectory:
 }
 
 /// A directory entry in a font directory.
+#[cfg(feature = "std")]
 pub trait FontDirectoryEntry:
     FontDataRead + FontDataChecksum + FontDataWrite
 {
@@ -163,6 +193,7 @@ pub trait FontDirectoryEntry:
 }
 
 /// A table in a font.
+#[cfg(feature = "std")]
 #[allow(clippy::len_without_is_empty)] // Doesn't make sense for this trait to have is_empty.
 pub trait FontTable: FontDataChecksum + FontDataWrite {
     /// Returns the length of the table.
@@ -174,6 +205,7 @@ pub trait FontTable: FontDataChecksum + FontDataWrite {
 }
 
 /// A trait for getting a reader for a font table.
+#[cfg(feature = "std")]
 pub trait FontTableReader<'a> {
     /// The error type for reading the table.
     type Error: Into<crate::error::FontIoError>;
@@ -182,6 +214,7 @@ pub trait FontTableReader<'a> {
 }
 
 /// Represents a font.
+#[cfg(feature = "std")]
 pub trait Font: FontDataRead + MutFontDataWrite {
     /// The header type for the font.
     type Header: FontHeader;
@@ -193,23 +226,86 @@ pub trait Font: FontDataRead + MutFontDataWrite {
     fn contains_table(&self, tag: &FontTag) -> bool;
     /// Returns a specific table from the font.
     fn table(&self, tag: &FontTag) -> Option<&Self::Table>;
+    /// Returns an iterator over all of the tables in the font, in tag order.
+    fn tables_iter(&self) -> impl Iterator<Item = (&FontTag, &Self::Table)>;
     /// Returns the font header.
     fn header(&self) -> &Self::Header;
     /// Returns the font directory.
     fn directory(&self) -> &Self::Directory;
+    /// Returns the checksum of a specific table, as it is currently held by
+    /// the font, without serializing it.
+    fn table_checksum(&self, tag: &FontTag) -> Option<u32> {
+        self.table(tag).map(|table| table.checksum().0)
+    }
 }
 
 /// A trait for stubbing the DSIG table in a font. By this, we mean that the
 /// DSIG is stripped and replaced with a very minimal version that is still
 /// valid.
+#[cfg(feature = "std")]
 pub trait FontDSIGStubber {
     /// The error type for stubbing the DSIG table.
     type Error;
-    /// Stub the DSIG table in the font.
-    fn stub_dsig(&mut self) -> Result<(), Self::Error>;
+    /// Stub the DSIG table in the font, using the default stub contents.
+    fn stub_dsig(&mut self) -> Result<(), Self::Error> {
+        self.stub_dsig_with(&DSIGStubConfig::default())
+    }
+    /// Stub the DSIG table in the font, using the given stub contents.
+    fn stub_dsig_with(
+        &mut self,
+        config: &DSIGStubConfig,
+    ) -> Result<(), Self::Error>;
+}
+
+/// The contents of a stub DSIG table.
+///
+/// # Remarks
+/// The default is a version 1 table, with no signatures and the "do not
+/// resign" flag set.
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct DSIGStubConfig {
+    version: u32,
+    flags: u16,
+}
+
+#[cfg(feature = "std")]
+impl DSIGStubConfig {
+    /// Sets the version of the stub DSIG table.
+    pub fn with_version(mut self, version: u32) -> Self {
+        self.version = version;
+        self
+    }
+
+    /// Sets the flags of the stub DSIG table.
+    pub fn with_flags(mut self, flags: u16) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    /// Gets the version of the stub DSIG table.
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// Gets the flags of the stub DSIG table.
+    pub fn flags(&self) -> u16 {
+        self.flags
+    }
+}
+
+#[cfg(feature = "std")]
+impl Default for DSIGStubConfig {
+    fn default() -> Self {
+        Self {
+            version: sfnt::table::TableDSIG::DEFAULT_VERSION,
+            flags: sfnt::table::TableDSIG::DO_NOT_RESIGN,
+        }
+    }
 }
 
 /// Represents the state of the DSIG table in a font.
+#[cfg(feature = "std")]
 pub enum DSIGType {
     /// The DSIG table is not present in the font.
     NotPresent,
@@ -222,6 +318,7 @@ pub enum DSIGType {
 /// A trait for detecting if a font has a DSIG table. This is useful when you
 /// want to check if a font has a DSIG table without actually reading the
 /// entire font data into memory.
+#[cfg(feature = "std")]
 pub trait FontDSIGDetector {
     /// The error type for detecting the DSIG table.
     type Error;
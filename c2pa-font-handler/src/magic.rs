@@ -37,6 +37,21 @@ pub enum Magic {
     Woff2 = 0x774f4632,
 }
 
+impl Magic {
+    /// Returns whether this is the version of an SFNT font (i.e., OpenType,
+    /// TrueType, or PostScript Type 1), rather than of a wrapper format like
+    /// WOFF.
+    pub fn is_sfnt(&self) -> bool {
+        matches!(
+            self,
+            Magic::OpenType
+                | Magic::TrueType
+                | Magic::PostScriptType1
+                | Magic::AppleTrue
+        )
+    }
+}
+
 /// Used to attempt conversion from u32 to a Magic value.
 impl TryFrom<u32> for Magic {
     type Error = FontIoError;
@@ -37,6 +37,16 @@ fn test_magic_from_u32() {
     assert_eq!(magic as u32, Magic::Woff2 as u32);
 }
 
+#[test]
+fn test_magic_is_sfnt() {
+    assert!(Magic::OpenType.is_sfnt());
+    assert!(Magic::TrueType.is_sfnt());
+    assert!(Magic::PostScriptType1.is_sfnt());
+    assert!(Magic::AppleTrue.is_sfnt());
+    assert!(!Magic::Woff.is_sfnt());
+    assert!(!Magic::Woff2.is_sfnt());
+}
+
 #[test]
 fn test_magic_try_from_u32_with_bad_value() {
     let magic = Magic::try_from(0x00000000);
@@ -0,0 +1,94 @@
+// Copyright 2025 Monotype Imaging Inc.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+//! Structured metrics about reading and writing fonts, emitted as `tracing`
+//! events which can be subscribed to for monitoring.
+//!
+//! Each successful read (`from_reader`) or write (`write`) of an SFNT or
+//! WOFF1 font emits a single [`FontMetrics`] event with the
+//! [`METRICS_TARGET`] target at the `DEBUG` level; e.g., enable them with a
+//! `c2pa_font_handler::metrics=debug` filter directive.
+
+use std::time::Duration;
+
+/// The target of the `tracing` events carrying [`FontMetrics`].
+pub const METRICS_TARGET: &str = "c2pa_font_handler::metrics";
+
+/// The operation measured by a [`FontMetrics`] event.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FontOperation {
+    /// The font was read.
+    Parse,
+    /// The font was written.
+    Write,
+}
+
+impl FontOperation {
+    /// Returns the name of the operation, as recorded in the events.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FontOperation::Parse => "parse",
+            FontOperation::Write => "write",
+        }
+    }
+}
+
+/// Metrics about reading or writing a font.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FontMetrics {
+    /// The operation measured.
+    pub operation: FontOperation,
+    /// The format of the font (i.e., `sfnt` or `woff`).
+    pub format: &'static str,
+    /// How long the operation took.
+    pub duration: Duration,
+    /// The number of tables in the font.
+    pub num_tables: usize,
+    /// The size of the font, in bytes.
+    pub size: u64,
+    /// The total length of the tables once decompressed, in bytes.
+    pub tables_original_size: u64,
+    /// The total length of the tables as stored in the font, in bytes; the
+    /// same as the original length for a font without compression.
+    pub tables_stored_size: u64,
+}
+
+impl FontMetrics {
+    /// Returns the number of bytes saved by compressing the tables, if any.
+    pub fn compression_savings(&self) -> u64 {
+        self.tables_original_size
+            .saturating_sub(self.tables_stored_size)
+    }
+
+    /// Emits the metrics as a `tracing` event with the [`METRICS_TARGET`]
+    /// target, one field for each metric.
+    pub(crate) fn emit(&self) {
+        tracing::debug!(
+            target: METRICS_TARGET,
+            operation = self.operation.as_str(),
+            format = self.format,
+            duration_us = self.duration.as_micros() as u64,
+            num_tables = self.num_tables,
+            size = self.size,
+            tables_original_size = self.tables_original_size,
+            tables_stored_size = self.tables_stored_size,
+            compression_savings = self.compression_savings(),
+            "Font metrics"
+        );
+    }
+}
+
+#[cfg(test)]
+#[path = "metrics_test.rs"]
+mod tests;
@@ -0,0 +1,74 @@
+// Copyright 2025 Monotype Imaging Inc.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+//! Tests for the font metrics.
+
+use std::io::Cursor;
+
+use super::*;
+use crate::{sfnt::font::SfntFont, FontDataRead, MutFontDataWrite};
+
+#[test]
+fn test_compression_savings() {
+    let mut metrics = FontMetrics {
+        operation: FontOperation::Write,
+        format: "woff",
+        duration: Duration::from_millis(1),
+        num_tables: 2,
+        size: 100,
+        tables_original_size: 120,
+        tables_stored_size: 80,
+    };
+    assert_eq!(metrics.compression_savings(), 40);
+    // Tables which grew when compressed save nothing
+    metrics.tables_stored_size = 130;
+    assert_eq!(metrics.compression_savings(), 0);
+}
+
+#[test]
+fn test_operation_as_str() {
+    assert_eq!(FontOperation::Parse.as_str(), "parse");
+    assert_eq!(FontOperation::Write.as_str(), "write");
+}
+
+#[test]
+#[tracing_test::traced_test]
+fn test_sfnt_metrics_emitted() {
+    let font_data = include_bytes!("../../.devtools/font.otf");
+    let mut font = SfntFont::from_reader(&mut Cursor::new(font_data)).unwrap();
+    assert!(logs_contain("operation=\"parse\" format=\"sfnt\""));
+    assert!(logs_contain("num_tables=11"));
+    assert!(logs_contain(&format!("size={}", font_data.len())));
+    assert!(!logs_contain("operation=\"write\""));
+
+    font.write(&mut Vec::new()).unwrap();
+    assert!(logs_contain("operation=\"write\" format=\"sfnt\""));
+    assert!(logs_contain("compression_savings=0"));
+}
+
+#[cfg(feature = "woff")]
+#[test]
+#[tracing_test::traced_test]
+fn test_woff_metrics_emitted() {
+    use crate::woff1::font::Woff1Font;
+    let woff_data = include_bytes!("../../.devtools/font.woff");
+    let mut font = Woff1Font::from_reader(&mut Cursor::new(woff_data)).unwrap();
+    assert!(logs_contain("operation=\"parse\" format=\"woff\""));
+    assert!(logs_contain(&format!("size={}", woff_data.len())));
+
+    let mut output = Vec::new();
+    font.write(&mut output).unwrap();
+    assert!(logs_contain("operation=\"write\" format=\"woff\""));
+    assert!(logs_contain(&format!("size={}", output.len())));
+}
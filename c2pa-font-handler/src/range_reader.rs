@@ -0,0 +1,130 @@
+// Copyright 2025 Monotype Imaging Inc.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+//! Reading fonts from sources which fetch ranges of bytes on demand (e.g.,
+//! HTTP range requests against a remote object).
+
+use std::io::{Read, Seek, SeekFrom};
+
+/// A source of bytes which can fetch an arbitrary range of them, such as a
+/// remote object supporting HTTP range requests.
+pub trait RangeReader {
+    /// Returns the total length, in bytes, of the source.
+    fn len(&mut self) -> std::io::Result<u64>;
+
+    /// Returns whether the source is empty.
+    fn is_empty(&mut self) -> std::io::Result<bool> {
+        Ok(self.len()? == 0)
+    }
+
+    /// Fetches up to `length` bytes starting at `offset`; fewer bytes are only
+    /// returned at the end of the source.
+    fn fetch(&mut self, offset: u64, length: usize)
+        -> std::io::Result<Vec<u8>>;
+}
+
+/// An adapter implementing [`Read`] and [`Seek`] over a [`RangeReader`], so a
+/// font can be read with `from_reader` while only fetching the ranges which
+/// are actually read.
+///
+/// # Remarks
+/// Reads are rounded up to at least the block size, and the most recently
+/// fetched block is kept, so the many small reads of parsing a header and
+/// directory are served by a single fetch.
+pub struct RangeReaderAdapter<R: RangeReader> {
+    source: R,
+    position: u64,
+    block_size: usize,
+    /// The offset of the buffered block, and its data.
+    block: Option<(u64, Vec<u8>)>,
+}
+
+impl<R: RangeReader> RangeReaderAdapter<R> {
+    /// The default minimum number of bytes fetched at a time, 4 KiB.
+    pub const DEFAULT_BLOCK_SIZE: usize = 4 * 1024;
+
+    /// Creates a new adapter over the given source, starting at offset 0.
+    pub fn new(source: R) -> Self {
+        Self {
+            source,
+            position: 0,
+            block_size: Self::DEFAULT_BLOCK_SIZE,
+            block: None,
+        }
+    }
+
+    /// Sets the minimum number of bytes fetched at a time; defaults to
+    /// [`Self::DEFAULT_BLOCK_SIZE`].
+    pub fn with_block_size(mut self, block_size: usize) -> Self {
+        self.block_size = block_size.max(1);
+        self
+    }
+
+    /// Consumes the adapter, returning the source.
+    pub fn into_inner(self) -> R {
+        self.source
+    }
+
+    /// Returns the buffered bytes at the current position, if any.
+    fn buffered(&self) -> Option<&[u8]> {
+        let (offset, data) = self.block.as_ref()?;
+        let start = self.position.checked_sub(*offset)?;
+        data.get(start as usize..).filter(|rest| !rest.is_empty())
+    }
+}
+
+impl<R: RangeReader> Read for RangeReaderAdapter<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        if self.buffered().is_none() {
+            let data = self
+                .source
+                .fetch(self.position, buf.len().max(self.block_size))?;
+            if data.is_empty() {
+                return Ok(0);
+            }
+            self.block = Some((self.position, data));
+        }
+        let buffered = self.buffered().unwrap_or_default();
+        let count = buffered.len().min(buf.len());
+        buf[..count].copy_from_slice(&buffered[..count]);
+        self.position += count as u64;
+        Ok(count)
+    }
+}
+
+impl<R: RangeReader> Seek for RangeReaderAdapter<R> {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        let position = match pos {
+            SeekFrom::Start(offset) => Some(offset),
+            SeekFrom::End(delta) => {
+                self.source.len()?.checked_add_signed(delta)
+            }
+            SeekFrom::Current(delta) => self.position.checked_add_signed(delta),
+        };
+        self.position = position.ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            )
+        })?;
+        Ok(self.position)
+    }
+}
+
+#[cfg(test)]
+#[path = "range_reader_test.rs"]
+mod tests;
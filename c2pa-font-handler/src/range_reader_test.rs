@@ -0,0 +1,125 @@
+// Copyright 2025 Monotype Imaging Inc.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+//! Tests for the range reader module
+
+use std::io::Cursor;
+
+use super::*;
+use crate::{
+    c2pa::{C2PASupport, ContentCredentialRecord},
+    sfnt::font::SfntFont,
+    Font, FontDataRead, MutFontDataWrite,
+};
+
+/// A range reader over in-memory data, keeping count of what it fetched.
+struct MemoryRangeReader {
+    data: Vec<u8>,
+    fetches: usize,
+    fetched_bytes: usize,
+}
+
+impl MemoryRangeReader {
+    fn new(data: Vec<u8>) -> Self {
+        Self {
+            data,
+            fetches: 0,
+            fetched_bytes: 0,
+        }
+    }
+}
+
+impl RangeReader for MemoryRangeReader {
+    fn len(&mut self) -> std::io::Result<u64> {
+        Ok(self.data.len() as u64)
+    }
+
+    fn fetch(
+        &mut self,
+        offset: u64,
+        length: usize,
+    ) -> std::io::Result<Vec<u8>> {
+        let start = (offset as usize).min(self.data.len());
+        let end = start.saturating_add(length).min(self.data.len());
+        self.fetches += 1;
+        self.fetched_bytes += end - start;
+        Ok(self.data[start..end].to_vec())
+    }
+}
+
+#[test]
+fn test_read_and_seek() {
+    let source = MemoryRangeReader::new((0..=255).collect());
+    let mut reader = RangeReaderAdapter::new(source).with_block_size(16);
+    let mut buf = [0; 4];
+    reader.read_exact(&mut buf).unwrap();
+    assert_eq!(buf, [0, 1, 2, 3]);
+    // Served from the buffered block
+    reader.seek(SeekFrom::Current(8)).unwrap();
+    reader.read_exact(&mut buf).unwrap();
+    assert_eq!(buf, [12, 13, 14, 15]);
+    // Spanning the end of the block
+    let mut buf = [0; 8];
+    reader.seek(SeekFrom::Start(12)).unwrap();
+    reader.read_exact(&mut buf).unwrap();
+    assert_eq!(buf, [12, 13, 14, 15, 16, 17, 18, 19]);
+    assert_eq!(reader.seek(SeekFrom::End(-2)).unwrap(), 254);
+    let mut rest = Vec::new();
+    reader.read_to_end(&mut rest).unwrap();
+    assert_eq!(rest, vec![254, 255]);
+    assert!(reader.seek(SeekFrom::Current(-300)).is_err());
+    let source = reader.into_inner();
+    assert_eq!(source.fetches, 4);
+    assert!(!source.data.is_empty());
+}
+
+#[test]
+fn test_read_font() {
+    let font_data = include_bytes!("../../.devtools/font.otf");
+    let source = MemoryRangeReader::new(font_data.to_vec());
+    let mut reader = RangeReaderAdapter::new(source);
+    let font = SfntFont::from_reader(&mut reader).unwrap();
+    assert_eq!(font.tables_iter().count(), 11);
+    // The whole (small) font fits in a single block
+    assert_eq!(reader.into_inner().fetches, 1);
+}
+
+#[test]
+fn test_manifest_store_reader_fetches_only_needed_ranges() {
+    let font_data = include_bytes!("../../.devtools/font.otf");
+    let mut font = SfntFont::from_reader(&mut Cursor::new(font_data)).unwrap();
+    let store = vec![0xab; 4096];
+    font.add_c2pa_record(
+        ContentCredentialRecord::builder()
+            .with_content_credential(store.clone())
+            .build()
+            .unwrap(),
+    )
+    .unwrap();
+    let mut output = Vec::new();
+    font.write(&mut output).unwrap();
+    let total_length = output.len();
+
+    let source = MemoryRangeReader::new(output);
+    let mut reader = RangeReaderAdapter::new(source).with_block_size(256);
+    let mut manifest_store = Vec::new();
+    SfntFont::manifest_store_reader(&mut reader, 0)
+        .unwrap()
+        .read_to_end(&mut manifest_store)
+        .unwrap();
+    assert_eq!(manifest_store, store);
+    // The other tables are never fetched
+    let source = reader.into_inner();
+    assert!(source.fetched_bytes < total_length - font_data.len() / 2);
+}
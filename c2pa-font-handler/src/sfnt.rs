@@ -15,6 +15,8 @@
 //! Module for reading and writing SFNT font files.
 
 pub mod directory;
+#[cfg(feature = "std")]
 pub mod font;
 pub mod header;
+#[cfg(feature = "std")]
 pub mod table;
@@ -14,17 +14,20 @@
 
 //! SFNT font file directory and entries.
 
-use std::{
-    io::{Read, Seek, Write},
-    mem::size_of,
-    num::Wrapping,
-};
+use alloc::vec::Vec;
+use core::{mem::size_of, num::Wrapping};
+#[cfg(feature = "std")]
+use std::io::{Read, Seek, Write};
 
-use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
+use byteorder::{BigEndian, ByteOrder};
+#[cfg(feature = "std")]
+use byteorder::{ReadBytesExt, WriteBytesExt};
 
+use crate::{error::FontIoError, tag::FontTag, FontDataChecksum};
+#[cfg(feature = "std")]
 use crate::{
-    error::FontIoError, tag::FontTag, FontDataChecksum, FontDataExactRead,
-    FontDataRead, FontDataWrite, FontDirectory, FontDirectoryEntry,
+    FontDataExactRead, FontDataRead, FontDataWrite, FontDirectory,
+    FontDirectoryEntry,
 };
 
 /// SFNT Table Directory Entry, from the OpenType spec.
@@ -41,8 +44,49 @@ pub struct SfntDirectoryEntry {
 impl SfntDirectoryEntry {
     /// The size of an SFNT directory entry.
     pub const SIZE: usize = size_of::<Self>();
+
+    /// Reads a directory entry from the start of the given bytes.
+    ///
+    /// # Errors
+    /// Returns [`FontIoError::InvalidSizeForDirectoryEntry`] if there are
+    /// fewer bytes than an entry takes.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, FontIoError> {
+        let bytes = bytes.get(..Self::SIZE).ok_or(
+            FontIoError::InvalidSizeForDirectoryEntry {
+                expected: Self::SIZE,
+                got: bytes.len(),
+            },
+        )?;
+        Ok(Self {
+            tag: FontTag::new([bytes[0], bytes[1], bytes[2], bytes[3]]),
+            checksum: BigEndian::read_u32(&bytes[4..8]),
+            offset: BigEndian::read_u32(&bytes[8..12]),
+            length: BigEndian::read_u32(&bytes[12..16]),
+        })
+    }
+
+    /// Returns the tag of the table.
+    pub fn tag(&self) -> FontTag {
+        self.tag
+    }
+
+    /// Returns the checksum of the table data.
+    pub fn data_checksum(&self) -> u32 {
+        self.checksum
+    }
+
+    /// Returns the offset of the table.
+    pub fn offset(&self) -> u32 {
+        self.offset
+    }
+
+    /// Returns the length of the table.
+    pub fn length(&self) -> u32 {
+        self.length
+    }
 }
 
+#[cfg(feature = "std")]
 impl FontDataRead for SfntDirectoryEntry {
     type Error = FontIoError;
 
@@ -62,6 +106,7 @@ impl FontDataRead for SfntDirectoryEntry {
     }
 }
 
+#[cfg(feature = "std")]
 impl FontDataExactRead for SfntDirectoryEntry {
     type Error = FontIoError;
 
@@ -81,6 +126,7 @@ impl FontDataExactRead for SfntDirectoryEntry {
     }
 }
 
+#[cfg(feature = "std")]
 impl FontDataWrite for SfntDirectoryEntry {
     type Error = FontIoError;
 
@@ -97,29 +143,30 @@ impl FontDataWrite for SfntDirectoryEntry {
 }
 
 impl FontDataChecksum for SfntDirectoryEntry {
-    fn checksum(&self) -> std::num::Wrapping<u32> {
-        std::num::Wrapping(u32::from_be_bytes(self.tag.data()))
-            + std::num::Wrapping(self.checksum)
-            + std::num::Wrapping(self.offset)
-            + std::num::Wrapping(self.length)
+    fn checksum(&self) -> Wrapping<u32> {
+        Wrapping(u32::from_be_bytes(self.tag.data()))
+            + Wrapping(self.checksum)
+            + Wrapping(self.offset)
+            + Wrapping(self.length)
     }
 }
 
+#[cfg(feature = "std")]
 impl FontDirectoryEntry for SfntDirectoryEntry {
     fn tag(&self) -> FontTag {
-        self.tag
+        SfntDirectoryEntry::tag(self)
     }
 
     fn data_checksum(&self) -> u32 {
-        self.checksum
+        SfntDirectoryEntry::data_checksum(self)
     }
 
     fn offset(&self) -> u32 {
-        self.offset
+        SfntDirectoryEntry::offset(self)
     }
 
     fn length(&self) -> u32 {
-        self.length
+        SfntDirectoryEntry::length(self)
     }
 }
 
@@ -128,13 +175,14 @@ impl FontDirectoryEntry for SfntDirectoryEntry {
 /// Undoubtedly there exists a more-oxidized way of just using Vec directly for
 /// this... but maybe we don't want to? Note the choice of Vec over BTreeMap
 /// here, which lets us keep non-compliant fonts as-is...
-#[derive(Debug, Default)]
+#[derive(Clone, Debug, Default)]
 pub struct SfntDirectory {
     entries: Vec<SfntDirectoryEntry>,
 }
 
 impl SfntDirectory {
     /// Creates a new, empty `SfntDirectory`.
+    #[cfg(feature = "std")]
     pub(crate) fn new() -> Self {
         Self {
             entries: Vec::new(),
@@ -142,11 +190,13 @@ impl SfntDirectory {
     }
 
     /// Adds an entry to the directory.
+    #[cfg(feature = "std")]
     pub(crate) fn add_entry(&mut self, entry: SfntDirectoryEntry) {
         self.entries.push(entry);
     }
 
     /// Sorts the entries in the directory, based on the provided closure.
+    #[cfg(feature = "std")]
     pub(crate) fn sort_entries<F, K>(&mut self, f: F)
     where
         F: FnMut(&SfntDirectoryEntry) -> K,
@@ -154,8 +204,48 @@ impl SfntDirectory {
     {
         self.entries.sort_by_key(f);
     }
+
+    /// Reads a directory of the given number of entries from the start of the
+    /// given bytes.
+    ///
+    /// # Errors
+    /// Returns [`FontIoError::DirectoryEntryCountMismatch`] if there are fewer
+    /// bytes than the entries take.
+    pub fn from_bytes(
+        bytes: &[u8],
+        entry_count: usize,
+    ) -> Result<Self, FontIoError> {
+        let chunks = bytes.chunks_exact(SfntDirectoryEntry::SIZE);
+        if chunks.len() < entry_count {
+            return Err(FontIoError::DirectoryEntryCountMismatch {
+                declared: entry_count,
+                available: chunks.len(),
+            });
+        }
+        let entries = chunks
+            .take(entry_count)
+            .map(SfntDirectoryEntry::from_bytes)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self { entries })
+    }
+
+    /// Returns a reference to the entries in this directory.
+    pub fn entries(&self) -> &[SfntDirectoryEntry] {
+        &self.entries
+    }
+
+    /// Returns the entries of this directory, arranged in increasing order of
+    /// their `offset` field.
+    pub fn physical_order(&self) -> Vec<&SfntDirectoryEntry> {
+        let mut entries = self.entries.iter().collect::<Vec<_>>();
+        // Empty tables come before a table sharing their offset, so the order
+        // is the same after the font is written and read back
+        entries.sort_by_key(|entry| (entry.offset, entry.length));
+        entries
+    }
 }
 
+#[cfg(feature = "std")]
 impl FontDataExactRead for SfntDirectory {
     type Error = FontIoError;
 
@@ -177,6 +267,7 @@ impl FontDataExactRead for SfntDirectory {
     }
 }
 
+#[cfg(feature = "std")]
 impl FontDataWrite for SfntDirectory {
     type Error = FontIoError;
 
@@ -193,7 +284,7 @@ impl FontDataWrite for SfntDirectory {
 }
 
 impl FontDataChecksum for SfntDirectory {
-    fn checksum(&self) -> std::num::Wrapping<u32> {
+    fn checksum(&self) -> Wrapping<u32> {
         match self.entries.is_empty() {
             true => Wrapping(0_u32),
             false => self
@@ -204,6 +295,7 @@ impl FontDataChecksum for SfntDirectory {
     }
 }
 
+#[cfg(feature = "std")]
 impl FontDirectory for SfntDirectory {
     type Entry = SfntDirectoryEntry;
 
@@ -213,22 +305,32 @@ impl FontDirectory for SfntDirectory {
     ) -> Result<Self, <Self as FontDataExactRead>::Error> {
         let mut entries = Vec::with_capacity(entry_count);
         for _ in 0..entry_count {
-            entries.push(SfntDirectoryEntry::from_reader(reader)?);
+            match SfntDirectoryEntry::from_reader(reader) {
+                Ok(entry) => entries.push(entry),
+                // Running out of data means the directory is truncated
+                Err(FontIoError::IoError(err))
+                    if err.kind() == std::io::ErrorKind::UnexpectedEof =>
+                {
+                    return Err(FontIoError::DirectoryEntryCountMismatch {
+                        declared: entry_count,
+                        available: entries.len(),
+                    });
+                }
+                Err(err) => return Err(err),
+            }
         }
         Ok(Self { entries })
     }
 
     fn entries(&self) -> &[Self::Entry] {
-        &self.entries
+        SfntDirectory::entries(self)
     }
 
     fn physical_order(&self) -> Vec<&Self::Entry> {
-        let mut entries = self.entries.iter().collect::<Vec<_>>();
-        entries.sort_by_key(|entry| entry.offset);
-        entries
+        SfntDirectory::physical_order(self)
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 #[path = "directory_test.rs"]
 mod tests;
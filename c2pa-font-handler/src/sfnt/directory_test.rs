@@ -363,3 +363,58 @@ fn test_sfnt_directory_with_table_count() {
     let length2 = entry2.length;
     assert_eq!(length2, 0x13579bdf);
 }
+
+#[test]
+fn test_sfnt_directory_with_table_count_truncated() {
+    // Two whole entries and part of a third, of the four declared
+    let mut data = vec![0u8; SfntDirectoryEntry::SIZE * 2 + 6];
+    data[..4].copy_from_slice(b"test");
+    let result =
+        SfntDirectory::from_reader_with_count(&mut Cursor::new(data), 4);
+    assert!(matches!(
+        result,
+        Err(FontIoError::DirectoryEntryCountMismatch {
+            declared: 4,
+            available: 2
+        })
+    ));
+}
+
+#[test]
+fn test_sfnt_directory_from_bytes() {
+    let font_data = include_bytes!("../../../.devtools/font.otf");
+    let entries = &font_data[12..];
+    let directory = SfntDirectory::from_bytes(entries, 11).unwrap();
+    let expected =
+        SfntDirectory::from_reader_with_count(&mut Cursor::new(entries), 11)
+            .unwrap();
+    assert_eq!(directory.entries().len(), 11);
+    assert_eq!(directory.checksum(), expected.checksum());
+    let first = directory.entries()[0];
+    assert_eq!(first.tag(), FontTag::new(*b"CFF "));
+    assert_eq!(
+        first.checksum().0,
+        FontDataChecksum::checksum(&expected.entries()[0]).0
+    );
+
+    let result = SfntDirectory::from_bytes(&entries[..10 * 16 + 15], 11);
+    assert!(matches!(
+        result,
+        Err(FontIoError::DirectoryEntryCountMismatch {
+            declared: 11,
+            available: 10
+        })
+    ));
+}
+
+#[test]
+fn test_sfnt_directory_entry_from_bytes_with_too_few_bytes() {
+    let result = SfntDirectoryEntry::from_bytes(&[0; 15]);
+    assert!(matches!(
+        result,
+        Err(FontIoError::InvalidSizeForDirectoryEntry {
+            expected: 16,
+            got: 15
+        })
+    ));
+}
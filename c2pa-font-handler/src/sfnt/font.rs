@@ -50,27 +50,50 @@
 use std::{
     collections::{btree_map::Entry, BTreeMap},
     fmt::Display,
-    io::{Read, Seek},
+    io::{Cursor, Read, Seek, SeekFrom, Write},
     num::Wrapping,
+    sync::Arc,
+    time::{Duration, Instant},
 };
 
+use sha2::{Digest, Sha256};
+
 use super::{
     directory::{SfntDirectory, SfntDirectoryEntry},
     header::SfntHeader,
     table::{
-        dsig::TableDSIG, head::SFNT_EXPECTED_CHECKSUM, named_table::NamedTable,
+        c2pa::TableC2PARaw,
+        cmap::TableCmap,
+        dsig::TableDSIG,
+        ebdt::{EmbeddedBitmap, TableEbdt},
+        eblc::TableEblc,
+        fftm::TableFftm,
+        fvar::{FvarAxis, FvarInstance, TableFvar},
+        head::{TableHead, HEAD_TABLE_MAGIC_NUMBER, SFNT_EXPECTED_CHECKSUM},
+        hhea::TableHhea,
+        hmtx::TableHmtx,
+        loca::TableLoca,
+        maxp::TableMaxp,
+        name::{LocalizedName, TableName},
+        named_table::NamedTable,
+        os2::TableOs2,
+        post::TablePost,
+        stat::{StatAxisRecord, StatAxisValue, TableStat},
+        vorg::TableVorg,
     },
 };
 use crate::{
     c2pa::{C2PASupport, UpdatableC2PA},
     chunks::{ChunkPosition, ChunkReader, ChunkTypeTrait},
+    data::Data,
     error::{FontIoError, FontSaveError},
+    metrics::{FontMetrics, FontOperation},
     sfnt::table::TableC2PA,
     tag::FontTag,
-    utils::align_to_four,
-    DSIGType, Font, FontDSIGDetector, FontDSIGStubber, FontDataChecksum,
-    FontDataExactRead, FontDataRead, FontDataWrite, FontDirectory,
-    FontDirectoryEntry, FontHeader, FontTable, MutFontDataWrite,
+    utils::{self, advance_offset, align_to_four},
+    DSIGStubConfig, DSIGType, Font, FontDSIGDetector, FontDSIGStubber,
+    FontDataChecksum, FontDataExactRead, FontDataRead, FontDataWrite,
+    FontDirectory, FontHeader, FontTable, MutFontDataWrite,
 };
 
 /// Implementation of an SFNT font.
@@ -80,39 +103,1441 @@ use crate::{
 /// a Woff1Font using the `TryFrom` trait. This is not intended to be used
 /// to produce production-ready SFNT fonts, but serves as a way to utilize
 /// thumbnails for WOFF fonts.
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct SfntFont {
     header: SfntHeader,
     directory: SfntDirectory,
     tables: BTreeMap<FontTag, NamedTable>,
+    /// The change in the number of tables made by the operations which add or
+    /// remove several tables at once (i.e., preparing for signing, or adding
+    /// both the DSIG and C2PA tables), which the table count checks expect on
+    /// top of the single table otherwise allowed.
+    expected_table_delta: i32,
+    /// The order of the entries in the table directory when written.
+    table_order: TableOrder,
+    /// The order of the tables' data when written, overriding their order as
+    /// read for the listed tables; empty to keep the order as read.
+    physical_table_order: Vec<FontTag>,
+    /// The bytes found between the tables when the font was read with its
+    /// layout preserved, keyed by the tag of the table they precede.
+    layout_gaps: BTreeMap<FontTag, Vec<u8>>,
+    /// The checksums of the raw tables as listed in the directory when the
+    /// font was read, reused on write so unchanged tables need not be summed
+    /// again.
+    table_checksums: BTreeMap<FontTag, u32>,
 }
 
-impl FontDataRead for SfntFont {
-    type Error = FontIoError;
+/// The order of the entries in the table directory of a written font.
+///
+/// # Remarks
+/// This only affects the directory; in either order, the data of the C2PA
+/// table is placed physically last in the written font, after all the other
+/// tables.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum TableOrder {
+    /// Entries are sorted by tag, as required by the OpenType specification.
+    #[default]
+    SortedByTag,
+    /// Entries are kept in the order they were read, even if that order is
+    /// not sorted by tag; any added table (i.e., the C2PA table) is listed
+    /// last.
+    ///
+    /// # Remarks
+    /// Useful when the exact bytes of a (spec-violating) directory must be
+    /// preserved, such as when they were previously hashed.
+    AsRead,
+}
 
-    fn from_reader<T: Read + Seek + ?Sized>(
-        reader: &mut T,
-    ) -> Result<Self, Self::Error> {
+/// A modification made to a font while preparing it for signing.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum SigningModification {
+    /// The DSIG table was replaced with a stub.
+    StubbedDSIG,
+    /// An existing C2PA table was removed.
+    RemovedC2PA,
+    /// A table on the deny-list was removed.
+    RemovedTable(FontTag),
+}
+
+/// The style attributes of a font, from its 'STAT' table, with the names
+/// resolved from its 'name' table.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StyleAttributes {
+    /// The design axes.
+    pub design_axes: Vec<StatAxisRecord>,
+    /// The axis values, each paired with its name; the name is `None` if it
+    /// is not found in the 'name' table.
+    pub axis_values: Vec<(StatAxisValue, Option<String>)>,
+    /// The name to use when all the style's axis value names are elided
+    /// (e.g., "Regular"), if the table has one and it is found in the 'name'
+    /// table.
+    pub elided_fallback_name: Option<String>,
+}
+
+/// The format of a font's glyph outlines.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum OutlineFormat {
+    /// TrueType outlines, in the 'glyf' and 'loca' tables.
+    TrueType,
+    /// Compact Font Format outlines, in the 'CFF ' table.
+    #[allow(clippy::upper_case_acronyms)]
+    CFF,
+    /// Compact Font Format 2 outlines, in the 'CFF2' table.
+    CFF2,
+    /// No outlines (e.g., a bitmap-only font).
+    None,
+}
+
+/// Which of the layout (shaping) tables are present in a font.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct LayoutFeatureReport {
+    /// Whether the OpenType 'GSUB' (glyph substitution) table is present.
+    pub gsub: bool,
+    /// Whether the OpenType 'GPOS' (glyph positioning) table is present.
+    pub gpos: bool,
+    /// Whether the OpenType 'GDEF' (glyph definition) table is present.
+    pub gdef: bool,
+    /// Whether the legacy 'kern' table is present.
+    pub kern: bool,
+    /// Whether the AAT 'morx' (extended glyph metamorphosis) table is present.
+    pub morx: bool,
+    /// Whether the AAT 'kerx' (extended kerning) table is present.
+    pub kerx: bool,
+}
+
+impl LayoutFeatureReport {
+    /// Returns whether the font has kerning, from any of the 'GPOS', 'kern' or
+    /// 'kerx' tables.
+    pub fn has_kerning(&self) -> bool {
+        self.gpos || self.kern || self.kerx
+    }
+
+    /// Returns whether the font has OpenType layout tables.
+    pub fn has_opentype_layout(&self) -> bool {
+        self.gsub || self.gpos || self.gdef
+    }
+
+    /// Returns whether the font has AAT layout tables.
+    pub fn has_aat_layout(&self) -> bool {
+        self.morx || self.kerx
+    }
+}
+
+/// Options for reading an SFNT font.
+#[derive(Clone, Debug, Default)]
+pub struct SfntReadOptions {
+    lenient_duplicate_tables: bool,
+    lenient_head_magic_number: bool,
+    preserve_layout: bool,
+    shared_buffer: bool,
+    strict_c2pa: bool,
+    strict_header_fields: bool,
+    validate_device_tables: bool,
+}
+
+impl SfntReadOptions {
+    /// Sets whether a table listed more than once in the directory is
+    /// tolerated, keeping the first entry and logging a warning, rather than
+    /// failing to read the font; disabled by default.
+    pub fn with_lenient_duplicate_tables(mut self, lenient: bool) -> Self {
+        self.lenient_duplicate_tables = lenient;
+        self
+    }
+
+    /// Sets whether a 'head' table with an invalid magic number is tolerated,
+    /// logging a warning, rather than failing to read the font; disabled by
+    /// default.
+    pub fn with_lenient_head_magic_number(mut self, lenient: bool) -> Self {
+        self.lenient_head_magic_number = lenient;
+        self
+    }
+
+    /// Sets whether a 'C2PA' table whose sections are not laid out as the
+    /// specification requires is rejected, even if it could be read;
+    /// disabled by default.
+    ///
+    /// # Remarks
+    /// See [`TableC2PA::from_reader_strict`] for the checks made.
+    pub fn with_strict_c2pa(mut self, strict: bool) -> Self {
+        self.strict_c2pa = strict;
+        self
+    }
+
+    /// Sets whether a header whose `searchRange`, `entrySelector` and
+    /// `rangeShift` fields are not those derived from its number of tables
+    /// is rejected, rather than the fields being ignored (and recomputed on
+    /// write); disabled by default.
+    ///
+    /// # Remarks
+    /// This is a cheap check that the header was not tampered with, see
+    /// [`SfntHeader::validate_search_params`].
+    pub fn with_strict_header_fields(mut self, strict: bool) -> Self {
+        self.strict_header_fields = strict;
+        self
+    }
+
+    /// Sets whether any bytes found between the tables, beyond their padding,
+    /// are kept and written back out ahead of the same tables, rather than
+    /// the tables being packed tightly; disabled by default.
+    ///
+    /// # Remarks
+    /// This keeps the bytes outside the tables unchanged when signing a font
+    /// whose hash should only exclude the C2PA table. Bytes preceding an
+    /// existing C2PA table are not kept, since that table is always moved to
+    /// the end of the font. A gap which would leave the following table
+    /// unaligned is padded with zeros, so every table (and the C2PA table in
+    /// particular) is written at a 4-byte-aligned offset.
+    pub fn with_preserve_layout(mut self, preserve_layout: bool) -> Self {
+        self.preserve_layout = preserve_layout;
+        self
+    }
+
+    /// Sets whether the whole font is read into a single buffer up front,
+    /// with the raw tables sharing their bytes with it rather than each
+    /// being read into its own allocation; disabled by default.
+    ///
+    /// # Remarks
+    /// This trades one large allocation, kept alive as long as any table
+    /// shares it, for many small ones, and suits fonts with many tables. A
+    /// shared table is copied the first time it is modified. The whole
+    /// stream is read into the buffer, from its start, whatever its current
+    /// position.
+    pub fn with_shared_buffer(mut self, shared_buffer: bool) -> Self {
+        self.shared_buffer = shared_buffer;
+        self
+    }
+
+    /// Sets whether the lengths of the legacy 'hdmx' and 'LTSH' device
+    /// tables are checked against the number of glyphs once the font is
+    /// read, logging a warning for each inconsistent table; disabled by
+    /// default.
+    ///
+    /// # Remarks
+    /// The font is still read either way, see
+    /// [`SfntFont::inconsistent_device_tables`].
+    pub fn with_device_table_validation(mut self, validate: bool) -> Self {
+        self.validate_device_tables = validate;
+        self
+    }
+}
+
+/// Options for preparing a font for signing.
+#[derive(Clone, Debug, Default)]
+pub struct SigningPreparationOptions {
+    deny_list: Vec<FontTag>,
+}
+
+impl SigningPreparationOptions {
+    /// Adds a (vendor-specific) table, such as 'FFTM', to be removed from the
+    /// font when preparing it for signing.
+    pub fn with_denied_table(mut self, tag: FontTag) -> Self {
+        self.deny_list.push(tag);
+        self
+    }
+}
+
+impl SfntFont {
+    /// Sets the order of the entries in the table directory when the font is
+    /// written; defaults to [`TableOrder::SortedByTag`].
+    pub fn set_table_order(&mut self, table_order: TableOrder) {
+        self.table_order = table_order;
+    }
+
+    /// Gets the order of the entries in the table directory when the font is
+    /// written.
+    pub fn table_order(&self) -> TableOrder {
+        self.table_order
+    }
+
+    /// Sets the order in which the tables' data is laid out when the font is
+    /// written, independently of the order of the directory entries (e.g., to
+    /// match the output of another tool).
+    ///
+    /// # Remarks
+    /// The listed tables are laid out first, in the given order, followed by
+    /// any other tables in their order as read; an empty list keeps the
+    /// order as read. The C2PA table is always laid out last, wherever it is
+    /// listed.
+    pub fn set_physical_table_order(
+        &mut self,
+        order: impl IntoIterator<Item = FontTag>,
+    ) {
+        self.physical_table_order = order.into_iter().collect();
+    }
+
+    /// Gets the order in which the tables' data is laid out when the font is
+    /// written, as set by [`SfntFont::set_physical_table_order`].
+    pub fn physical_table_order(&self) -> &[FontTag] {
+        &self.physical_table_order
+    }
+
+    /// Returns the offset and length of each table, keyed by tag, as recorded
+    /// in the current directory.
+    ///
+    /// # Remarks
+    /// After a call to `write`, the directory reflects the layout of the
+    /// written font, so this can be used to find where a table (i.e., the
+    /// C2PA table) landed in the output without re-reading it.
+    pub fn table_offsets(&self) -> BTreeMap<FontTag, (u32, u32)> {
+        self.directory
+            .entries()
+            .iter()
+            .map(|entry| (entry.tag, (entry.offset, entry.length)))
+            .collect()
+    }
+
+    /// Collects the metrics of an operation on the font which took
+    /// `duration`; the size is that of the font as laid out by `write`.
+    fn metrics(
+        &self,
+        operation: FontOperation,
+        duration: Duration,
+    ) -> FontMetrics {
+        let tables_size =
+            self.tables.values().map(|table| table.len() as u64).sum();
+        FontMetrics {
+            operation,
+            format: "sfnt",
+            duration,
+            num_tables: self.tables.len(),
+            size: self.computed_size(),
+            tables_original_size: tables_size,
+            tables_stored_size: tables_size,
+        }
+    }
+
+    /// Computes the size, in bytes, of the font as it would be written by
+    /// `write`, without actually writing it.
+    pub fn computed_size(&self) -> u64 {
+        let mut size = SfntHeader::SIZE as u64
+            + SfntDirectoryEntry::SIZE as u64 * self.tables.len() as u64;
+        // Just as in `write`, only the tables in the directory are written,
+        // with the C2PA table always being added at the end (and, being the
+        // last table, it is written without any padding)
+        size += self
+            .directory
+            .entries()
+            .iter()
+            .filter(|entry| !entry.tag.is_c2pa())
+            .filter_map(|entry| self.tables.get(&entry.tag))
+            .map(|table| align_to_four(table.len()) as u64)
+            .sum::<u64>();
+        // Along with any bytes preserved ahead of those tables
+        size += self
+            .directory
+            .entries()
+            .iter()
+            .filter(|entry| self.tables.contains_key(&entry.tag))
+            .filter_map(|entry| self.layout_gaps.get(&entry.tag))
+            .map(|gap| gap.len() as u64)
+            .sum::<u64>();
+        if let Some(c2pa) = self.tables.get(&FontTag::C2PA) {
+            size += c2pa.len() as u64;
+        }
+        size
+    }
+
+    /// Returns the number of glyphs in the font, as recorded in the 'maxp'
+    /// table, or `None` if the table is missing or fails to parse.
+    pub fn num_glyphs(&self) -> Option<u16> {
+        self.parse_table::<TableMaxp>(&FontTag::MAXP)?
+            .ok()
+            .map(|maxp| maxp.num_glyphs())
+    }
+
+    /// Checks the lengths of the legacy 'hdmx' and 'LTSH' device tables
+    /// against the number of glyphs in the 'maxp' table, logging a warning
+    /// for each table which disagrees, and returns the tags of those tables.
+    ///
+    /// # Remarks
+    /// Both tables hold one byte per glyph (per device record, for 'hdmx'),
+    /// so a mismatch is a sign of a damaged font. The tables are otherwise
+    /// left as raw data, and nothing is checked when the 'maxp' table is
+    /// missing or fails to parse.
+    pub fn inconsistent_device_tables(&self) -> Vec<FontTag> {
+        let mut inconsistent = Vec::new();
+        let Some(num_glyphs) = self.num_glyphs() else {
+            return inconsistent;
+        };
+        let num_glyphs = num_glyphs as usize;
+        let hdmx = FontTag::new(*b"hdmx");
+        if let Some(NamedTable::Generic(data)) = self.tables.get(&hdmx) {
+            // A header (version, numRecords, sizeDeviceRecord) followed by
+            // records of pixelSize, maxWidth and the widths, padded to 4 bytes
+            let consistent = data.data.len() >= 8 && {
+                let num_records =
+                    u16::from_be_bytes([data.data[2], data.data[3]]) as usize;
+                let record_size = u32::from_be_bytes([
+                    data.data[4],
+                    data.data[5],
+                    data.data[6],
+                    data.data[7],
+                ]) as usize;
+                record_size == align_to_four(num_glyphs as u32 + 2) as usize
+                    && data.data.len() >= 8 + num_records * record_size
+            };
+            if !consistent {
+                tracing::warn!(
+                    "The 'hdmx' table ({} bytes) is inconsistent with {num_glyphs} glyphs",
+                    data.data.len()
+                );
+                inconsistent.push(hdmx);
+            }
+        }
+        let ltsh = FontTag::new(*b"LTSH");
+        if let Some(NamedTable::Generic(data)) = self.tables.get(&ltsh) {
+            // A header (version, numGlyphs) followed by one byte per glyph
+            let consistent = data.data.len() >= 4 + num_glyphs
+                && u16::from_be_bytes([data.data[2], data.data[3]]) as usize
+                    == num_glyphs;
+            if !consistent {
+                tracing::warn!(
+                    "The 'LTSH' table ({} bytes) is inconsistent with {num_glyphs} glyphs",
+                    data.data.len()
+                );
+                inconsistent.push(ltsh);
+            }
+        }
+        inconsistent
+    }
+
+    /// Reports which layout tables are present in the font, without parsing
+    /// any of them.
+    pub fn has_layout_features(&self) -> LayoutFeatureReport {
+        let has = |tag: &[u8; 4]| self.contains_table(&FontTag::new(*tag));
+        LayoutFeatureReport {
+            gsub: has(b"GSUB"),
+            gpos: has(b"GPOS"),
+            gdef: has(b"GDEF"),
+            kern: has(b"kern"),
+            morx: has(b"morx"),
+            kerx: has(b"kerx"),
+        }
+    }
+
+    /// Reports the format of the font's glyph outlines, from which outline
+    /// tables are present, without parsing any of them.
+    ///
+    /// # Remarks
+    /// TrueType outlines need both the 'glyf' and 'loca' tables. If a font
+    /// has more than one kind of outline, TrueType is reported first, then
+    /// CFF2, then CFF.
+    pub fn outline_format(&self) -> OutlineFormat {
+        let has = |tag: &[u8; 4]| self.contains_table(&FontTag::new(*tag));
+        if has(b"glyf") && has(b"loca") {
+            OutlineFormat::TrueType
+        } else if has(b"CFF2") {
+            OutlineFormat::CFF2
+        } else if has(b"CFF ") {
+            OutlineFormat::CFF
+        } else {
+            OutlineFormat::None
+        }
+    }
+
+    /// Returns the byte range, as start (inclusive) and end (exclusive)
+    /// offsets within the 'glyf' table, of the given glyph; or `None` if the
+    /// font has no 'loca' table, the table fails to parse, or the glyph is out
+    /// of range.
+    ///
+    /// # Remarks
+    /// The 'loca' table is read using the offset format from the 'head' table
+    /// and the number of glyphs from the 'maxp' table.
+    pub fn glyph_range(&self, gid: u16) -> Option<(u32, u32)> {
+        let NamedTable::Head(head) = self.tables.get(&FontTag::HEAD)? else {
+            return None;
+        };
+        let NamedTable::Generic(loca) = self.tables.get(&FontTag::LOCA)? else {
+            return None;
+        };
+        let loca = TableLoca::from_reader_with_format(
+            &mut Cursor::new(&loca.data),
+            0,
+            loca.data.len(),
+            self.num_glyphs()?,
+            head.indexToLocFormat,
+        )
+        .ok()?;
+        let (start, end) = loca.glyph_range(gid)?;
+        // Make sure the glyph is within the 'glyf' table, when present
+        match self.tables.get(&FontTag::new(*b"glyf")) {
+            Some(glyf) if end > glyf.len() => None,
+            _ => Some((start, end)),
+        }
+    }
+
+    /// Returns the raw 'glyf' table data of the given glyph; or `None` if the
+    /// font has no 'glyf' or 'loca' table, the 'loca' table fails to parse,
+    /// or the glyph is out of range.
+    ///
+    /// # Remarks
+    /// Both the short and long 'loca' formats are handled, as for
+    /// [`SfntFont::glyph_range`]; glyphs without outlines (e.g., a space) have
+    /// empty data.
+    pub fn glyph_data(&self, gid: u16) -> Option<&[u8]> {
+        let NamedTable::Generic(glyf) =
+            self.tables.get(&FontTag::new(*b"glyf"))?
+        else {
+            return None;
+        };
+        let (start, end) = self.glyph_range(gid)?;
+        glyf.data.get(start as usize..end as usize)
+    }
+
+    /// Maps the given text to glyph IDs with the 'cmap' table, pairing each
+    /// glyph with its advance width (in font units) from the 'hmtx' table,
+    /// for a simple preview which bypasses shaping.
+    ///
+    /// # Remarks
+    /// Each character maps to exactly one glyph, so there are no ligatures,
+    /// kerning, or reordering; characters missing from the font map to the
+    /// missing glyph (0). The run is empty if the 'cmap', 'hhea', 'hmtx', or
+    /// 'maxp' table is missing or fails to parse.
+    pub fn simple_glyph_run(&self, text: &str) -> Vec<(u16, u16)> {
+        let Some((cmap, hmtx)) = self.cmap_and_hmtx() else {
+            return Vec::new();
+        };
+        text.chars()
+            .map(|c| {
+                let gid = cmap.glyph_for_char(c).unwrap_or(0);
+                (gid, hmtx.advance_width(gid).unwrap_or(0))
+            })
+            .collect()
+    }
+
+    /// Parses the 'cmap' and 'hmtx' tables, the latter using the counts from
+    /// the 'hhea' and 'maxp' tables.
+    fn cmap_and_hmtx(&self) -> Option<(TableCmap, TableHmtx)> {
+        let cmap = self.parse_table::<TableCmap>(&FontTag::CMAP)?.ok()?;
+        let hhea = self.parse_table::<TableHhea>(&FontTag::HHEA)?.ok()?;
+        let NamedTable::Generic(hmtx) = self.tables.get(&FontTag::HMTX)? else {
+            return None;
+        };
+        let hmtx = TableHmtx::from_reader_with_counts(
+            &mut Cursor::new(&hmtx.data),
+            0,
+            hmtx.data.len(),
+            hhea.numberOfHMetrics,
+            self.num_glyphs()?,
+        )
+        .ok()?;
+        Some((cmap, hmtx))
+    }
+
+    /// Returns the font vendor identification from the 'OS/2' table, or
+    /// `None` if the table is missing, fails to parse, or has a blank vendor
+    /// ID.
+    ///
+    /// # Remarks
+    /// Vendor IDs shorter than four characters are padded with spaces, as
+    /// for any tag; see [`TableOs2::vendor_id`].
+    pub fn vendor_id(&self) -> Option<FontTag> {
+        self.parse_table::<TableOs2>(&FontTag::OS2)?
+            .ok()?
+            .vendor_id()
+    }
+
+    /// Returns whether the font is monospaced, as recorded in the 'post'
+    /// table, or `None` if the table is missing or fails to parse.
+    pub fn is_monospace(&self) -> Option<bool> {
+        self.parse_table::<TablePost>(&FontTag::POST)?
+            .ok()
+            .map(|post| post.is_fixed_pitch())
+    }
+
+    /// Returns the italic angle of the font in counter-clockwise degrees from
+    /// the vertical, as recorded in the 'post' table, or `None` if the table
+    /// is missing or fails to parse.
+    pub fn post_italic_angle(&self) -> Option<f32> {
+        self.parse_table::<TablePost>(&FontTag::POST)?
+            .ok()
+            .map(|post| post.italic_angle())
+    }
+
+    /// Returns the y coordinate of the vertical origin of the given glyph, as
+    /// recorded in the 'VORG' table, or `None` if the table is missing or
+    /// fails to parse.
+    ///
+    /// # Remarks
+    /// Only fonts with CFF outlines have a 'VORG' table; for others, the
+    /// origin must be derived from the glyph's bounding box and vertical
+    /// metrics.
+    pub fn vertical_origin(&self, gid: u16) -> Option<i16> {
+        self.parse_table::<TableVorg>(&FontTag::VORG)?
+            .ok()
+            .map(|vorg| vorg.vertical_origin(gid))
+    }
+
+    /// Returns the FontForge version and timestamps recorded in the 'FFTM'
+    /// table, or `None` if the table is missing (i.e., the font was likely
+    /// not generated by FontForge) or fails to parse.
+    ///
+    /// # Remarks
+    /// The table is kept as is when the font is written; it can be removed
+    /// before signing with [`SigningPreparationOptions::with_denied_table`].
+    pub fn fontforge_timestamps(&self) -> Option<TableFftm> {
+        self.parse_table::<TableFftm>(&FontTag::FFTM)?.ok()
+    }
+
+    /// Returns the variation axes of the font, as recorded in the 'fvar'
+    /// table, or `None` if the table is missing or fails to parse.
+    pub fn variation_axes(&self) -> Option<Vec<FvarAxis>> {
+        self.parse_table::<TableFvar>(&FontTag::FVAR)?
+            .ok()
+            .map(|fvar| fvar.axes)
+    }
+
+    /// Returns the named instances of the font, as recorded in the 'fvar'
+    /// table, or `None` if the table is missing or fails to parse.
+    pub fn named_instances(&self) -> Option<Vec<FvarInstance>> {
+        self.parse_table::<TableFvar>(&FontTag::FVAR)?
+            .ok()
+            .map(|fvar| fvar.instances)
+    }
+
+    /// Returns the style attributes of the font, as recorded in the 'STAT'
+    /// table, or `None` if the table is missing or fails to parse.
+    ///
+    /// # Remarks
+    /// Names are resolved from the 'name' table, preferring the Windows
+    /// platform's English (United States) names; if the 'name' table is
+    /// missing or fails to parse, all names are `None`.
+    pub fn style_attributes(&self) -> Option<StyleAttributes> {
+        let stat = self.parse_table::<TableStat>(&FontTag::STAT)?.ok()?;
+        let name = self
+            .parse_table::<TableName>(&FontTag::NAME)
+            .and_then(Result::ok);
+        let resolve = |name_id: u16| {
+            name.as_ref()
+                .and_then(|name| name.name(name_id))
+                .map(str::to_string)
+        };
+        Some(StyleAttributes {
+            elided_fallback_name: stat.elidedFallbackNameID.and_then(resolve),
+            axis_values: stat
+                .axisValues
+                .into_iter()
+                .map(|value| {
+                    let value_name = resolve(value.value_name_id());
+                    (value, value_name)
+                })
+                .collect(),
+            design_axes: stat.designAxes,
+        })
+    }
+
+    /// Returns every name recorded in the 'name' table, grouped by name ID,
+    /// with all of its localized variants; or an empty map if the table is
+    /// missing or fails to parse.
+    ///
+    /// # Remarks
+    /// Records whose strings cannot be decoded are skipped.
+    pub fn all_names(&self) -> BTreeMap<u16, Vec<LocalizedName>> {
+        self.parse_table::<TableName>(&FontTag::NAME)
+            .and_then(Result::ok)
+            .map(|name| name.localized_names())
+            .unwrap_or_default()
+    }
+
+    /// Returns whether the font's family name, as recorded in the 'name'
+    /// table, matches the expected family name; or `false` if the table is
+    /// missing or fails to parse.
+    ///
+    /// # Remarks
+    /// The typographic family names (name ID 16) are checked when present,
+    /// otherwise the legacy family names (name ID 1). The font matches if any
+    /// of its records, for any platform or language, is exactly equal to the
+    /// expected family name.
+    pub fn verify_name_matches(&self, expected_family: &str) -> bool {
+        self.parse_table::<TableName>(&FontTag::NAME)
+            .and_then(Result::ok)
+            .is_some_and(|name| name.family_names().contains(&expected_family))
+    }
+
+    /// Returns the embedded bitmap of the given glyph from the 'EBLC' and
+    /// 'EBDT' tables, using the strike for the given pixels per em or else the
+    /// strike with the nearest size; or `None` if the font has no embedded
+    /// bitmaps or the strike has no bitmap for the glyph.
+    ///
+    /// # Remarks
+    /// Both monochrome and grayscale bitmaps are decoded; use
+    /// [`EmbeddedBitmap::format`] to tell them apart. Composite bitmaps are not
+    /// supported and are reported as `None`.
+    ///
+    /// # Errors
+    /// Returns [`FontIoError::LoadTableTruncated`] if either table fails to
+    /// parse.
+    pub fn embedded_bitmap(
+        &self,
+        glyph_id: u16,
+        ppem: u8,
+    ) -> Result<Option<EmbeddedBitmap>, FontIoError> {
+        let Some(eblc) = self.parse_table::<TableEblc>(&FontTag::EBLC) else {
+            return Ok(None);
+        };
+        let Some(ebdt) = self.parse_table::<TableEbdt>(&FontTag::EBDT) else {
+            return Ok(None);
+        };
+        let (eblc, ebdt) = (eblc?, ebdt?);
+        Ok(eblc.strike(ppem).and_then(|strike| {
+            let location = strike.glyph_location(glyph_id)?;
+            ebdt.bitmap(&location, strike.bitDepth)
+        }))
+    }
+
+    /// Parses the raw data of the table with the given tag into a typed
+    /// table, returning `None` if there is no such (raw) table.
+    pub(crate) fn parse_table<T>(
+        &self,
+        tag: &FontTag,
+    ) -> Option<Result<T, FontIoError>>
+    where
+        T: FontDataExactRead<Error = FontIoError>,
+    {
+        match self.tables.get(tag)? {
+            NamedTable::Generic(data) => Some(T::from_reader_exact(
+                &mut Cursor::new(&data.data),
+                0,
+                data.data.len(),
+            )),
+            _ => None,
+        }
+    }
+
+    /// Inserts the given table, replacing any table with the same tag and
+    /// forgetting the checksum read for it.
+    pub(crate) fn insert_table(&mut self, tag: FontTag, table: NamedTable) {
+        self.table_checksums.remove(&tag);
+        self.tables.insert(tag, table);
+    }
+
+    /// Returns the checksum of the given table, reusing the one read from the
+    /// directory for a raw table rather than summing its data again.
+    ///
+    /// # Remarks
+    /// Typed tables (e.g., 'head', 'DSIG' and C2PA) may have been modified
+    /// since the font was read, so their checksums are always recomputed. The
+    /// checksums listed in the directory are trusted as is, so a stale one is
+    /// carried over to the written font.
+    fn cached_checksum(&self, tag: &FontTag, table: &NamedTable) -> u32 {
+        match (table, self.table_checksums.get(tag)) {
+            (NamedTable::Generic(_), Some(checksum)) => *checksum,
+            _ => table.checksum().0,
+        }
+    }
+
+    /// Stubs the DSIG table, inserting a stub table if the font has none, e.g.
+    /// to reserve the table for later signing.
+    ///
+    /// # Remarks
+    /// Unlike [`FontDSIGStubber::stub_dsig`], which leaves a font without a
+    /// DSIG table alone, the table is created here. The created table is laid
+    /// out after the existing tables (other than the C2PA table). As only a
+    /// single table may be added to a font, a C2PA table can no longer be
+    /// added once the DSIG table has been.
+    ///
+    /// # Errors
+    /// Returns [`FontSaveError::TooManyTablesAdded`] if a table has already
+    /// been added to the font.
+    pub fn ensure_stub_dsig(&mut self) -> Result<(), FontIoError> {
+        if self.tables.contains_key(&FontTag::DSIG) {
+            return self.stub_dsig();
+        }
+        self.check_table_count(
+            self.tables.len() + 1,
+            self.expected_table_delta,
+        )?;
+        let stub = TableDSIG::stub();
+        // Place the table after all the others, so it is written last
+        let offset = self
+            .directory
+            .entries()
+            .iter()
+            .filter(|entry| !entry.tag.is_c2pa())
+            .map(|entry| entry.offset.saturating_add(entry.length))
+            .max()
+            .unwrap_or_default();
+        self.directory.add_entry(SfntDirectoryEntry {
+            tag: FontTag::DSIG,
+            offset,
+            checksum: stub.checksum().0,
+            length: stub.len(),
+        });
+        self.insert_table(FontTag::DSIG, NamedTable::DSIG(stub));
+        Ok(())
+    }
+
+    /// Adds a C2PA table with the given record and stubs the DSIG table,
+    /// inserting a stub table if the font has none, as when signing a font.
+    ///
+    /// # Remarks
+    /// Any signature in the DSIG table is invalidated by adding the C2PA
+    /// table, so the two are best done together. Unlike calling
+    /// [`SfntFont::ensure_stub_dsig`] and
+    /// [`C2PASupport::add_c2pa_record`] in turn, both tables may be added
+    /// here. Either both changes are made or, on error, neither is.
+    ///
+    /// # Errors
+    /// Returns [`FontIoError::ContentCredentialAlreadyExists`] if the font
+    /// already has a C2PA table, or [`FontSaveError::TooManyTablesAdded`] if
+    /// a table has already been added to the font.
+    pub fn add_c2pa_and_stub_dsig(
+        &mut self,
+        record: crate::c2pa::ContentCredentialRecord,
+    ) -> Result<(), FontIoError> {
+        if self.has_c2pa() {
+            return Err(FontIoError::ContentCredentialAlreadyExists);
+        }
+        if self.tables.contains_key(&FontTag::DSIG) {
+            self.check_table_count(
+                self.tables.len() + 1,
+                self.expected_table_delta,
+            )?;
+            self.stub_dsig()?;
+        } else {
+            // The DSIG table is expected on top of the C2PA table, so check
+            // for both tables before adding either
+            let expected_table_delta = self.expected_table_delta + 1;
+            self.check_table_count(
+                self.tables.len() + 2,
+                expected_table_delta,
+            )?;
+            self.ensure_stub_dsig()?;
+            self.expected_table_delta = expected_table_delta;
+        }
+        self.add_c2pa_record(record)
+    }
+
+    /// Replaces the DSIG table, if present, with a stub table which keeps the
+    /// flags of the original table, rather than using the stub's defaults.
+    ///
+    /// # Remarks
+    /// This is useful for fonts whose licensing depends on the resign
+    /// permission bit.
+    pub fn stub_dsig_preserving_flags(&mut self) -> Result<(), FontIoError> {
+        if let Entry::Occupied(mut entry) = self.tables.entry(FontTag::DSIG) {
+            let stub = match entry.get() {
+                NamedTable::DSIG(dsig) => {
+                    TableDSIG::stub_preserving_flags(dsig.flags)
+                }
+                // No flags could be read from the original table
+                _ => TableDSIG::stub(),
+            };
+            entry.insert(NamedTable::DSIG(stub));
+        }
+        Ok(())
+    }
+
+    /// Gets a reader over the manifest store embedded in the C2PA table of the
+    /// font in the given stream, without reading the manifest store into
+    /// memory.
+    ///
+    /// # Remarks
+    /// `font_offset` is the position of the start of the font in the stream.
+    /// The returned reader is bounded to the bytes of the manifest store.
+    ///
+    /// # Errors
+    /// Returns [`FontIoError::ContentCredentialNotFound`] if the font has no
+    /// C2PA table, or the table has no manifest store, and
+    /// [`FontIoError::LoadTableTruncated`] if the manifest store does not lie
+    /// within the table.
+    pub fn manifest_store_reader<'a, R: Read + Seek + ?Sized>(
+        reader: &'a mut R,
+        font_offset: u64,
+    ) -> Result<impl Read + 'a, FontIoError> {
+        reader.seek(SeekFrom::Start(font_offset))?;
         let header = SfntHeader::from_reader(reader)?;
+        let directory = SfntDirectory::from_reader_with_count(
+            reader,
+            header.numTables as usize,
+        )?;
+        let entry = directory
+            .entries()
+            .iter()
+            .find(|entry| entry.tag.is_c2pa())
+            .ok_or(FontIoError::ContentCredentialNotFound)?;
+        if (entry.length as usize) < TableC2PARaw::MINIMUM_SIZE {
+            return Err(FontIoError::LoadTableTruncated(FontTag::C2PA));
+        }
+        let table_offset = font_offset + entry.offset as u64;
+        reader.seek(SeekFrom::Start(table_offset))?;
+        let raw_table = TableC2PARaw::from_reader(reader)
+            .map_err(|_| FontIoError::LoadTableTruncated(FontTag::C2PA))?;
+        let store_offset = raw_table.manifestStoreOffset;
+        let store_len = raw_table.manifestStoreLength;
+        if store_offset == 0 {
+            return Err(FontIoError::ContentCredentialNotFound);
+        }
+        // The manifest store must lie within the table
+        if store_offset as u64 + store_len as u64 > entry.length as u64 {
+            return Err(FontIoError::LoadTableTruncated(FontTag::C2PA));
+        }
+        reader.seek(SeekFrom::Start(table_offset + store_offset as u64))?;
+        Ok(reader.take(store_len as u64))
+    }
+
+    /// Gets the offset where the font's data ends, ahead of its DSIG table,
+    /// without reading the whole font into memory; i.e., the end of the data
+    /// of the last table other than the DSIG table, or the end of the stream
+    /// if the font has no DSIG table.
+    ///
+    /// # Remarks
+    /// The font is read from the current position of the stream, which is
+    /// restored afterwards, and the offset returned is a position in the
+    /// stream (i.e., it includes the position the font starts at). As with
+    /// [`FontDSIGDetector::check_for_dsig`], the DSIG table is located purely
+    /// by its directory entry, wherever its data is laid out.
+    pub fn data_end_before_dsig<R: Read + Seek + ?Sized>(
+        reader: &mut R,
+    ) -> Result<u64, FontIoError> {
+        let original_position = reader.stream_position()?;
+        let header = SfntHeader::from_reader(reader)?;
+        let directory = SfntDirectory::from_reader_with_count(
+            reader,
+            header.numTables as usize,
+        )?;
+        let data_end = if directory.entries().iter().any(|e| e.tag.is_dsig()) {
+            let tables_end = directory
+                .entries()
+                .iter()
+                .filter(|entry| !entry.tag.is_dsig())
+                .map(|entry| entry.offset as u64 + entry.length as u64)
+                .max()
+                .unwrap_or_default();
+            original_position + tables_end
+        } else {
+            reader.seek(SeekFrom::End(0))?
+        };
+        reader.seek(SeekFrom::Start(original_position))?;
+        Ok(data_end)
+    }
+
+    /// Feeds the bytes of the font at the start of the stream to `hasher`,
+    /// excluding the tables with the given tags, so changes to only some
+    /// tables (e.g., the glyph data) can be detected.
+    ///
+    /// # Remarks
+    /// This generalizes the exclusions of the C2PA hard binding: as with
+    /// [`ChunkReader::get_chunk_positions`], the header and directory, and
+    /// the checksum adjustment of the 'head' table, are never hashed; but
+    /// the C2PA table is only excluded when it is listed. The remaining
+    /// tables are hashed in the order they are laid out in the stream. Any
+    /// writer can be used as the hasher, such as a cryptographic digest
+    /// implementing [`Write`].
+    ///
+    /// # Errors
+    /// Returns [`FontIoError::LoadTableTruncated`] if a hashed table lies
+    /// beyond the end of the stream.
+    pub fn hash_excluding<R: Read + Seek + ?Sized, W: Write + ?Sized>(
+        reader: &mut R,
+        exclude: &[FontTag],
+        hasher: &mut W,
+    ) -> Result<(), FontIoError> {
+        reader.seek(SeekFrom::Start(0))?;
+        let mut buffer = Vec::new();
+        for position in Self::get_chunk_positions(reader)? {
+            if !position.chunk_type().should_hash() {
+                continue;
+            }
+            // The 'head' table is split into chunks around the checksum
+            // adjustment
+            let tag = match position.name() {
+                b"hea0" | b"hea2" => FontTag::HEAD,
+                name => FontTag::new(*name),
+            };
+            if exclude.contains(&tag) {
+                continue;
+            }
+            buffer.resize(position.length(), 0);
+            reader.seek(SeekFrom::Start(position.offset() as u64))?;
+            reader
+                .read_exact(&mut buffer)
+                .map_err(|_| FontIoError::LoadTableTruncated(tag))?;
+            hasher.write_all(&buffer)?;
+        }
+        Ok(())
+    }
+
+    /// Computes a SHA-256 fingerprint of the outline data of the font at the
+    /// start of the stream, to identify the same font across containers and
+    /// metadata changes.
+    ///
+    /// # Remarks
+    /// Only the outline tables ('CFF ', 'CFF2', 'glyf' and 'loca') are
+    /// hashed, in that (tag) order regardless of their layout in the stream;
+    /// each present table contributes its tag, its length (big-endian) and
+    /// its bytes. Metadata, such as the 'name' table, and the C2PA table do
+    /// not affect the fingerprint, so an OTF font and the SFNT font converted
+    /// from its WOFF have the same fingerprint.
+    ///
+    /// # Errors
+    /// Returns [`FontIoError::TableNotFound`] if the font has no outline
+    /// tables, and [`FontIoError::LoadTableTruncated`] if an outline table
+    /// lies beyond the end of the stream.
+    pub fn outline_fingerprint<R: Read + Seek + ?Sized>(
+        reader: &mut R,
+    ) -> Result<[u8; 32], FontIoError> {
+        const OUTLINE_TAGS: [&[u8; 4]; 4] =
+            [b"CFF ", b"CFF2", b"glyf", b"loca"];
+        reader.seek(SeekFrom::Start(0))?;
+        let header = SfntHeader::from_reader(reader)?;
+        let directory = SfntDirectory::from_reader_with_count(
+            reader,
+            header.numTables as usize,
+        )?;
+        let mut hasher = Sha256::new();
+        let mut found = false;
+        for name in OUTLINE_TAGS {
+            let tag = FontTag::new(*name);
+            let Some(entry) =
+                directory.entries().iter().find(|entry| entry.tag == tag)
+            else {
+                continue;
+            };
+            found = true;
+            hasher.update(tag.data);
+            hasher.update(entry.length.to_be_bytes());
+            reader.seek(SeekFrom::Start(entry.offset as u64))?;
+            let copied = std::io::copy(
+                &mut reader.take(entry.length as u64),
+                &mut hasher,
+            )?;
+            if copied != entry.length as u64 {
+                return Err(FontIoError::LoadTableTruncated(tag));
+            }
+        }
+        if !found {
+            return Err(FontIoError::TableNotFound(FontTag::new(*b"glyf")));
+        }
+        Ok(hasher.finalize().into())
+    }
+
+    /// Gets the exact bytes of the font at the start of the stream which are
+    /// hashed, concatenated into a single buffer, e.g. to compare byte for
+    /// byte with a verifier's view when a signature fails to validate.
+    ///
+    /// # Remarks
+    /// As a verifier would, the whole stream is hashed apart from the chunks
+    /// of [`ChunkReader::get_chunk_positions`] which should not be hashed
+    /// (the header and directory, and the checksum adjustment of the 'head'
+    /// table); their ranges are coalesced first, so any bytes between tables
+    /// (e.g., padding) are included.
+    pub fn hashable_bytes<R: Read + Seek + ?Sized>(
+        reader: &mut R,
+    ) -> Result<Vec<u8>, FontIoError> {
+        reader.seek(SeekFrom::Start(0))?;
+        let mut exclusions = Self::get_chunk_positions(reader)?
+            .iter()
+            .filter(|position| !position.chunk_type().should_hash())
+            .map(|position| {
+                let start = position.offset() as u64;
+                (start, start + position.length() as u64)
+            })
+            .collect::<Vec<_>>();
+        exclusions.sort_unstable();
+        let end = reader.seek(SeekFrom::End(0))?;
+        let mut bytes = Vec::new();
+        // Collect the bytes between the (possibly overlapping) exclusions
+        let mut start = 0;
+        for (excluded_start, excluded_end) in
+            exclusions.into_iter().chain([(end, end)])
+        {
+            let segment_end = excluded_start.min(end);
+            if segment_end > start {
+                reader.seek(SeekFrom::Start(start))?;
+                (&mut *reader)
+                    .take(segment_end - start)
+                    .read_to_end(&mut bytes)?;
+            }
+            start = start.max(excluded_end);
+        }
+        Ok(bytes)
+    }
+
+    /// Checks, without modifying the font, whether a C2PA table could be
+    /// added and the font still be written.
+    ///
+    /// # Errors
+    /// Returns [`FontSaveError::UnexpectedTable`] if the font already has a
+    /// C2PA table, or the error `write` would fail with if the added table
+    /// would break the table count constraints.
+    pub fn can_add_c2pa(&self) -> Result<(), FontSaveError> {
+        if self.tables.contains_key(&FontTag::C2PA) {
+            return Err(FontSaveError::UnexpectedTable(
+                FontTag::C2PA.to_string(),
+            ));
+        }
+        self.check_table_count(self.tables.len() + 1, self.expected_table_delta)
+    }
+
+    /// Checks the number of tables which would be written against the number
+    /// of tables the font was read with, given the expected change in the
+    /// number of tables.
+    fn check_table_count(
+        &self,
+        new_table_count: usize,
+        expected_table_delta: i32,
+    ) -> Result<(), FontSaveError> {
+        // The table count must fit in the header
+        if new_table_count > u16::MAX as usize {
+            return Err(FontSaveError::TooManyTables(new_table_count));
+        }
+        // Currently we only allow a single C2PA table to be removed or added.
+        // Table modifications are allowed.  Verify that this is the case.
+        let orig_table_count = self.header.numTables;
+        let table_diff = new_table_count as i32
+            - orig_table_count as i32
+            - expected_table_delta;
+        // Make sure we only removed at most one table, beyond those expected.
+        if table_diff < -1 {
+            return Err(FontSaveError::TooManyTablesRemoved);
+        }
+        // Make sure we only added at most one table, beyond those expected.
+        else if table_diff > 1 {
+            return Err(FontSaveError::TooManyTablesAdded);
+        }
+        Ok(())
+    }
+
+    /// Prepares the font for signing, by stubbing the DSIG table and removing
+    /// any existing C2PA table.
+    ///
+    /// Returns the modifications which were made to the font.
+    pub fn prepare_for_signing(&mut self) -> Vec<SigningModification> {
+        self.prepare_for_signing_with_options(
+            &SigningPreparationOptions::default(),
+        )
+    }
+
+    /// Prepares the font for signing, by stubbing the DSIG table, removing any
+    /// existing C2PA table, and removing the tables on the deny-list of the
+    /// given options.
+    ///
+    /// Returns the modifications which were made to the font.
+    ///
+    /// # Remarks
+    /// Unlike other edits, any number of tables may be removed here and the
+    /// font can still be written; the tables removed here are expected by the
+    /// table count checks, which still apply to any further edits.
+    pub fn prepare_for_signing_with_options(
+        &mut self,
+        options: &SigningPreparationOptions,
+    ) -> Vec<SigningModification> {
+        let mut modifications = Vec::new();
+        let table_count = self.tables.len();
+        if let Some(NamedTable::DSIG(dsig)) = self.tables.get(&FontTag::DSIG) {
+            if !dsig.is_stubbed() {
+                self.tables
+                    .insert(FontTag::DSIG, NamedTable::DSIG(TableDSIG::stub()));
+                modifications.push(SigningModification::StubbedDSIG);
+            }
+        }
+        if self.tables.remove(&FontTag::C2PA).is_some() {
+            modifications.push(SigningModification::RemovedC2PA);
+        }
+        for tag in &options.deny_list {
+            // Never drop the tables we just took care of
+            if tag.is_optional_signing() {
+                continue;
+            }
+            if self.tables.remove(tag).is_some() {
+                modifications.push(SigningModification::RemovedTable(*tag));
+            }
+        }
+        self.expected_table_delta -= (table_count - self.tables.len()) as i32;
+        modifications
+    }
+
+    /// Reads a font from the given reader, provided the font is no larger
+    /// than `max_bytes`, e.g. to enforce an upload limit.
+    ///
+    /// # Remarks
+    /// As the table offsets are relative to the start of the stream, the font
+    /// is read from the start of the stream, as with the shared buffer of
+    /// [`SfntReadOptions::with_shared_buffer`], whatever its current position.
+    /// The size of the font is that of the whole stream, and is checked by
+    /// seeking to the end of the stream before any tables are read.
+    ///
+    /// # Errors
+    /// Returns [`FontIoError::FontTooLarge`] if the font is larger than
+    /// `max_bytes`, or an error if the font cannot be read.
+    pub fn from_reader_with_limit<T: Read + Seek + ?Sized>(
+        reader: &mut T,
+        max_bytes: u64,
+    ) -> Result<Self, FontIoError> {
+        let size = reader.seek(SeekFrom::End(0))?;
+        if size > max_bytes {
+            return Err(FontIoError::FontTooLarge {
+                size,
+                limit: max_bytes,
+            });
+        }
+        reader.rewind()?;
+        Self::from_reader(reader)
+    }
+
+    /// Reads a font from the given reader, using the given options.
+    ///
+    /// # Errors
+    /// Returns [`FontIoError::DuplicateTable`] if a table is listed more than
+    /// once in the directory, or [`FontIoError::InvalidHeadMagicNumber`] if
+    /// the 'head' table's magic number is invalid (unless the options allow
+    /// either), [`FontIoError::MalformedC2paTable`] if the options ask for a
+    /// strict 'C2PA' table and it is not conformant,
+    /// [`FontIoError::InconsistentHeaderFields`] if the options ask for
+    /// strict header fields and they are inconsistent, or an error if the
+    /// font cannot be read.
+    pub fn from_reader_with_options<T: Read + Seek + ?Sized>(
+        reader: &mut T,
+        options: &SfntReadOptions,
+    ) -> Result<Self, FontIoError> {
+        Self::read_measured(reader, options, None)
+    }
+
+    /// Reads a font from the given reader, using the given options, and emits
+    /// the metrics of reading it; the tables which fail to parse are
+    /// collected into `errors` rather than failing, if given.
+    fn read_measured<T: Read + Seek + ?Sized>(
+        reader: &mut T,
+        options: &SfntReadOptions,
+        errors: Option<&mut Vec<(FontTag, FontIoError)>>,
+    ) -> Result<Self, FontIoError> {
+        let start = Instant::now();
+        let font = if options.shared_buffer {
+            let mut buffer = Vec::new();
+            reader.rewind()?;
+            reader.read_to_end(&mut buffer)?;
+            let buffer: Arc<[u8]> = buffer.into();
+            Self::read_with_options(
+                &mut Cursor::new(&buffer[..]),
+                options,
+                Some(&buffer),
+                errors,
+            )?
+        } else {
+            Self::read_with_options(reader, options, None, errors)?
+        };
+        font.metrics(FontOperation::Parse, start.elapsed()).emit();
+        Ok(font)
+    }
+
+    /// Reads a font from the given reader, using the given options; the raw
+    /// tables share their bytes with the given buffer, which must hold the
+    /// same font, if any.
+    ///
+    /// # Remarks
+    /// If `errors` is given, the font is read on a best-effort basis: a
+    /// duplicate table, or a non-required table which fails to parse, is
+    /// recorded there instead of failing, see
+    /// [`SfntFont::from_reader_best_effort`].
+    fn read_with_options<T: Read + Seek + ?Sized>(
+        reader: &mut T,
+        options: &SfntReadOptions,
+        shared: Option<&Arc<[u8]>>,
+        mut errors: Option<&mut Vec<(FontTag, FontIoError)>>,
+    ) -> Result<Self, FontIoError> {
+        let mut header = SfntHeader::from_reader(reader)?;
+        if options.strict_header_fields {
+            header.validate_search_params()?;
+        }
         let directory = SfntDirectory::from_reader_with_count(
             reader,
             header.num_tables() as usize,
         )?;
         let mut tables = BTreeMap::new();
+        let mut table_checksums = BTreeMap::new();
+        let mut neo_directory = SfntDirectory::new();
         for entry in directory.entries() {
-            let table = NamedTable::from_reader_exact(
-                &entry.tag,
-                reader,
-                entry.offset as u64,
-                entry.length as usize,
-            )?;
+            if tables.contains_key(&entry.tag) {
+                // Only the first of any duplicate entries is kept
+                if let Some(errors) = errors.as_deref_mut() {
+                    errors.push((
+                        entry.tag,
+                        FontIoError::DuplicateTable(entry.tag),
+                    ));
+                    continue;
+                }
+                if !options.lenient_duplicate_tables {
+                    return Err(FontIoError::DuplicateTable(entry.tag));
+                }
+                tracing::warn!(
+                    "Ignoring duplicate directory entry for table {}",
+                    entry.tag
+                );
+                continue;
+            }
+            let result = if entry.tag == FontTag::HEAD
+                && options.lenient_head_magic_number
+            {
+                TableHead::from_reader_unchecked(
+                    reader,
+                    entry.offset as u64,
+                    entry.length as usize,
+                )
+                .map(|head| {
+                    let magic_number = head.magicNumber;
+                    if magic_number != HEAD_TABLE_MAGIC_NUMBER {
+                        tracing::warn!(
+                            "Ignoring invalid 'head' magic number {magic_number:#010x}"
+                        );
+                    }
+                    NamedTable::Head(head)
+                })
+            } else if entry.tag.is_c2pa() && options.strict_c2pa {
+                TableC2PA::from_reader_strict(
+                    reader,
+                    entry.offset as u64,
+                    entry.length as usize,
+                )
+                .map(NamedTable::C2PA)
+            } else if let Some(buffer) = shared {
+                NamedTable::from_shared(
+                    &entry.tag,
+                    buffer,
+                    entry.offset as u64,
+                    entry.length as usize,
+                )
+            } else {
+                NamedTable::from_reader_exact(
+                    &entry.tag,
+                    reader,
+                    entry.offset as u64,
+                    entry.length as usize,
+                )
+            };
+            let table = match (result, errors.as_deref_mut()) {
+                (Ok(table), _) => table,
+                (Err(error), Some(errors)) if !entry.tag.is_required() => {
+                    tracing::debug!(
+                        "Failed to parse table {}: {error}",
+                        entry.tag
+                    );
+                    errors.push((entry.tag, error));
+                    // Fall back to the raw bytes of the table, if we can
+                    match Data::from_reader_exact(
+                        reader,
+                        entry.offset as u64,
+                        entry.length as usize,
+                    ) {
+                        Ok(data) => NamedTable::Generic(data),
+                        Err(_) => continue,
+                    }
+                }
+                (Err(error), _) => return Err(error),
+            };
+            // Only raw tables are never modified in place, so only their
+            // checksums can be trusted to stay valid until the font is written
+            if matches!(table, NamedTable::Generic(_)) {
+                table_checksums.insert(entry.tag, entry.checksum);
+            }
             tables.insert(entry.tag, table);
+            neo_directory.add_entry(*entry);
         }
-        Ok(Self {
+        let layout_gaps = if options.preserve_layout {
+            // The tables start after the directory as read, duplicates and all
+            let tables_start = SfntHeader::SIZE as u64
+                + SfntDirectoryEntry::SIZE as u64
+                    * directory.entries().len() as u64;
+            read_layout_gaps(reader, &neo_directory, tables_start)?
+        } else {
+            BTreeMap::new()
+        };
+        // Keep the header in sync with any duplicates which were dropped
+        header.numTables = tables.len() as u16;
+        let font = Self {
             header,
-            directory,
+            directory: neo_directory,
             tables,
-        })
+            expected_table_delta: 0,
+            table_order: TableOrder::default(),
+            physical_table_order: Vec::new(),
+            layout_gaps,
+            table_checksums,
+        };
+        if options.validate_device_tables {
+            font.inconsistent_device_tables();
+        }
+        Ok(font)
+    }
+
+    /// Reads a font from the given reader, skipping over non-essential tables
+    /// which fail to parse instead of aborting.
+    ///
+    /// # Remarks
+    /// A table which fails to parse is kept as raw data (i.e., a
+    /// [`NamedTable::Generic`]) when its bytes can still be read; otherwise it
+    /// is omitted from the font entirely. Either way, the error is returned
+    /// alongside the tag of the table that caused it. Only the first of any
+    /// duplicate tables is kept, with an error for each duplicate.
+    ///
+    /// # Errors
+    /// Returns an error if the header or directory cannot be read, or if one
+    /// of the tables required by the OpenType specification fails to parse.
+    pub fn from_reader_best_effort<T: Read + Seek + ?Sized>(
+        reader: &mut T,
+    ) -> Result<(Self, Vec<(FontTag, FontIoError)>), FontIoError> {
+        Self::from_reader_best_effort_with_options(
+            reader,
+            &SfntReadOptions::default(),
+        )
+    }
+
+    /// Reads a font from the given reader as with
+    /// [`SfntFont::from_reader_best_effort`], using the given options.
+    ///
+    /// # Errors
+    /// Returns an error if the header or directory cannot be read, if one of
+    /// the tables required by the OpenType specification fails to parse, or
+    /// if the options ask for strict header fields and they are inconsistent.
+    pub fn from_reader_best_effort_with_options<T: Read + Seek + ?Sized>(
+        reader: &mut T,
+        options: &SfntReadOptions,
+    ) -> Result<(Self, Vec<(FontTag, FontIoError)>), FontIoError> {
+        let mut errors = Vec::new();
+        let font = Self::read_measured(reader, options, Some(&mut errors))?;
+        Ok((font, errors))
+    }
+}
+
+/// Reads the bytes between the tables in the given directory, beyond their
+/// padding, keyed by the tag of the table they precede; bytes preceding the
+/// C2PA table are skipped.
+fn read_layout_gaps<T: Read + Seek + ?Sized>(
+    reader: &mut T,
+    directory: &SfntDirectory,
+    tables_start: u64,
+) -> Result<BTreeMap<FontTag, Vec<u8>>, FontIoError> {
+    let mut gaps = BTreeMap::new();
+    let mut previous_end = tables_start;
+    for entry in directory.physical_order() {
+        let offset = entry.offset as u64;
+        if offset > previous_end && !entry.tag.is_c2pa() {
+            reader.seek(SeekFrom::Start(previous_end))?;
+            let mut gap = Vec::new();
+            reader.take(offset - previous_end).read_to_end(&mut gap)?;
+            // Pad the gap, so the table still starts on a 4-byte boundary
+            // even if it did not in the font as read
+            gap.resize(gap.len().next_multiple_of(4), 0);
+            tracing::debug!(
+                "Preserving {} bytes ahead of table {}",
+                gap.len(),
+                entry.tag
+            );
+            gaps.insert(entry.tag, gap);
+        }
+        let padded_end = (offset + entry.length as u64 + 3) & !3;
+        previous_end = previous_end.max(padded_end);
+    }
+    Ok(gaps)
+}
+
+impl FontDataRead for SfntFont {
+    type Error = FontIoError;
+
+    fn from_reader<T: Read + Seek + ?Sized>(
+        reader: &mut T,
+    ) -> Result<Self, Self::Error> {
+        Self::from_reader_with_options(reader, &SfntReadOptions::default())
     }
 }
 
@@ -123,62 +1548,84 @@ impl MutFontDataWrite for SfntFont {
         &mut self,
         dest: &mut TDest,
     ) -> Result<(), Self::Error> {
+        let start = Instant::now();
+        self.write_tables(dest)?;
+        self.metrics(FontOperation::Write, start.elapsed()).emit();
+        Ok(())
+    }
+}
+
+impl SfntFont {
+    /// Writes the font to the destination, laying out the tables afresh.
+    fn write_tables<TDest: std::io::Write + ?Sized>(
+        &mut self,
+        dest: &mut TDest,
+    ) -> Result<(), FontIoError> {
+        // Make sure the tables can be written with the header we have
+        self.check_table_count(self.tables.len(), self.expected_table_delta)?;
         let mut neo_header = SfntHeader::default();
         let mut neo_directory = SfntDirectory::new();
         // Re-synthesize the file header based on the actual table count
         neo_header.sfntVersion = self.header.sfntVersion;
         neo_header.numTables = self.tables.len() as u16;
-        neo_header.entrySelector = if neo_header.numTables > 0 {
-            neo_header.numTables.ilog2() as u16
-        } else {
+        if neo_header.numTables == 0 {
             return Err(FontSaveError::NoTablesFound.into());
-        };
-        neo_header.searchRange =
-            2_u16.pow(neo_header.entrySelector as u32) * 16;
-        neo_header.rangeShift =
-            neo_header.numTables * 16 - neo_header.searchRange;
+        }
+        (
+            neo_header.searchRange,
+            neo_header.entrySelector,
+            neo_header.rangeShift,
+        ) = SfntHeader::compute_search_params(neo_header.numTables);
 
-        // Currently we only allow a single C2PA table to be removed or added.
-        // Table modifications are allowed.  Verify that this is the case.
-        let orig_table_count = self.header.numTables;
         let new_table_count = self.tables.len() as u16;
-        let table_diff = new_table_count as i32 - orig_table_count as i32;
-        // Make sure we only removed at most one table.
-        if table_diff < -1 {
-            return Err(FontSaveError::TooManyTablesRemoved.into());
-        }
-        // Make sure we only added at most one table.
-        else if table_diff > 1 {
-            return Err(FontSaveError::TooManyTablesAdded.into());
-        }
 
         // Keep a running offset as we encounter our tables in physical order.
         let mut running_offset = SfntHeader::SIZE as u32
             + SfntDirectoryEntry::SIZE as u32 * new_table_count as u32;
 
         // Walk our old directory in physical order, adding new entries for each
-        // table we still have.
-        self.directory
+        // table we still have; the C2PA table is skipped here, so its data can
+        // be placed after all the others.
+        let mut physical_order = self
+            .directory
             .physical_order()
-            .iter()
-            .filter(|t| t.tag != FontTag::C2PA) // C2PA should always be at the end
-            .for_each(|entry| {
-                // If we have this entry in our current table list, create new
-                // entry
-                if self.tables.contains_key(&entry.tag) {
-                    let neo_entry = SfntDirectoryEntry {
-                        tag: entry.tag,
-                        offset: running_offset,
-                        checksum: self.tables[&entry.tag].checksum().0,
-                        length: self.tables[&entry.tag].len(),
-                    };
-                    neo_directory.add_entry(neo_entry);
-                    // Update our running offset.
-                    running_offset +=
-                        align_to_four(self.tables[&entry.tag].len());
-                }
+            .into_iter()
+            .filter(|t| !t.tag.is_c2pa())
+            .collect::<Vec<_>>();
+        // Move any tables placed by the caller to the front, in their order
+        if !self.physical_table_order.is_empty() {
+            physical_order.sort_by_key(|entry| {
+                self.physical_table_order
+                    .iter()
+                    .position(|tag| *tag == entry.tag)
+                    .unwrap_or(self.physical_table_order.len())
             });
+        }
+        for entry in physical_order {
+            // If we have this entry in our current table list, create new
+            // entry
+            if let Some(table) = self.tables.get(&entry.tag) {
+                // Leave room for any bytes preserved ahead of the table
+                if let Some(gap) = self.layout_gaps.get(&entry.tag) {
+                    running_offset = u32::try_from(gap.len())
+                        .ok()
+                        .and_then(|length| running_offset.checked_add(length))
+                        .ok_or(FontSaveError::FontTooLarge)?;
+                }
+                let neo_entry = SfntDirectoryEntry {
+                    tag: entry.tag,
+                    offset: running_offset,
+                    checksum: self.cached_checksum(&entry.tag, table),
+                    length: table.len(),
+                };
+                neo_directory.add_entry(neo_entry);
+                // Update our running offset, making sure it still fits.
+                running_offset = advance_offset(running_offset, table.len())?;
+            }
+        }
 
+        // The C2PA table is always physically last, whatever the order of the
+        // directory entries, since tables are written in order of offset.
         if let Some(c2pa) = self.tables.get(&FontTag::C2PA) {
             let neo_entry = SfntDirectoryEntry {
                 tag: FontTag::C2PA,
@@ -187,14 +1634,37 @@ impl MutFontDataWrite for SfntFont {
                 length: c2pa.len(),
             };
             neo_directory.add_entry(neo_entry);
+            // Nothing follows the C2PA table, but it must still fit.
+            advance_offset(running_offset, c2pa.len())?;
         }
 
-        // Sort our directory entries by tag.
-        neo_directory.sort_entries(|entry| entry.tag);
+        match self.table_order {
+            // Sort our directory entries by tag.
+            TableOrder::SortedByTag => {
+                neo_directory.sort_entries(|entry| entry.tag)
+            }
+            // Or replay the order of the directory as read, with new tables
+            // following the existing ones.
+            TableOrder::AsRead => {
+                let read_order = self
+                    .directory
+                    .entries()
+                    .iter()
+                    .map(|entry| entry.tag)
+                    .collect::<Vec<_>>();
+                neo_directory.sort_entries(|entry| {
+                    read_order
+                        .iter()
+                        .position(|tag| *tag == entry.tag)
+                        .unwrap_or(read_order.len())
+                });
+            }
+        }
 
         // Figure the checksum for the whole font - the header, the directory,
         // and then all the tables; we can just use the per-table checksums,
         // since the only one we alter is C2PA, and we just refreshed it...
+        // along with any bytes preserved between the tables.
         let font_cksum = neo_header.checksum()
             + neo_directory.checksum()
             + neo_directory
@@ -202,6 +1672,13 @@ impl MutFontDataWrite for SfntFont {
                 .iter()
                 .fold(Wrapping(0_u32), |tables_cksum, entry| {
                     tables_cksum + Wrapping(entry.checksum)
+                })
+            + neo_directory
+                .entries()
+                .iter()
+                .filter_map(|entry| self.layout_gaps.get(&entry.tag))
+                .fold(Wrapping(0_u32), |gaps_cksum, gap| {
+                    gaps_cksum + utils::checksum(gap)
                 });
 
         // Rewrite the head table's checksumAdjustment. (This act does *not*
@@ -217,10 +1694,16 @@ impl MutFontDataWrite for SfntFont {
         // Replace our header & directory with updated editions.
         self.header = neo_header;
         self.directory = neo_directory;
+        // The table count now matches the header, so no change is expected
+        self.expected_table_delta = 0;
         // Write everything out.
         self.header.write(dest)?;
         self.directory.write(dest)?;
         for entry in self.directory.physical_order().iter() {
+            if let Some(gap) = self.layout_gaps.get(&entry.tag) {
+                dest.write_all(gap)
+                    .map_err(FontIoError::FailedToWriteFontData)?;
+            }
             self.tables[&entry.tag].write(dest)?;
         }
         Ok(())
@@ -230,10 +1713,16 @@ impl MutFontDataWrite for SfntFont {
 impl FontDSIGStubber for SfntFont {
     type Error = FontIoError;
 
-    fn stub_dsig(&mut self) -> Result<(), Self::Error> {
+    fn stub_dsig_with(
+        &mut self,
+        config: &DSIGStubConfig,
+    ) -> Result<(), Self::Error> {
         if let Entry::Occupied(mut entry) = self.tables.entry(FontTag::DSIG) {
             // Create the stub DSIG table.
-            let dsig_table = NamedTable::DSIG(TableDSIG::stub());
+            let dsig_table = NamedTable::DSIG(TableDSIG::stub_with(
+                config.version(),
+                config.flags(),
+            ));
             // Replace the DSIG table with a minimal version.
             entry.insert(dsig_table);
         }
@@ -247,22 +1736,11 @@ impl<T: Read + Seek + ?Sized> FontDSIGDetector for T {
     fn check_for_dsig(&mut self) -> Result<crate::DSIGType, Self::Error> {
         // Grab the original position.
         let original_position = self.stream_position()?;
-        // We need to parse the header to be able to read the table directory.
-        let font_header = SfntHeader::from_reader(self)?;
-        // And now we can read the table directory.
-        let font_directory = SfntDirectory::from_reader_with_count(
-            self,
-            font_header.numTables as usize,
-        )?;
-        let dsig_type = match font_directory
-            .entries()
-            .iter()
-            .find(|e| e.tag == FontTag::DSIG)
-        {
+        let dsig_type = match read_dsig_entry(self)? {
             Some(entry) => {
-                // Since DSIG table, according to the spec, must be at the end
-                // of the file we can use the offset to
-                // determine where the end of the font data is.
+                // The DSIG table is located purely by its directory entry, as
+                // non-conformant fonts do not always place it at the end of
+                // the font.
                 let original_dsig_offset = entry.offset();
                 let dsig_table = TableDSIG::from_reader_exact(
                     self,
@@ -288,6 +1766,25 @@ impl<T: Read + Seek + ?Sized> FontDSIGDetector for T {
     }
 }
 
+/// Reads the header and table directory of the font at the current position
+/// of the stream, returning the directory entry of the DSIG table, if any.
+fn read_dsig_entry<T: Read + Seek + ?Sized>(
+    reader: &mut T,
+) -> Result<Option<SfntDirectoryEntry>, FontIoError> {
+    // We need to parse the header to be able to read the table directory.
+    let font_header = SfntHeader::from_reader(reader)?;
+    // And now we can read the table directory.
+    let font_directory = SfntDirectory::from_reader_with_count(
+        reader,
+        font_header.numTables as usize,
+    )?;
+    Ok(font_directory
+        .entries()
+        .iter()
+        .find(|e| e.tag.is_dsig())
+        .copied())
+}
+
 /// A convenience function to stub the DSIG table in a stream. This will
 /// read the stream, check for the DSIG table, and if it is present, stub
 /// it. If the DSIG table is not present or already stubbed, it will simply
@@ -424,6 +1921,10 @@ impl Font for SfntFont {
     fn table(&self, tag: &FontTag) -> Option<&Self::Table> {
         self.tables.get(tag)
     }
+
+    fn tables_iter(&self) -> impl Iterator<Item = (&FontTag, &Self::Table)> {
+        self.tables.iter()
+    }
 }
 
 // Used to indicate the header chunks
@@ -431,6 +1932,7 @@ const SFNT_HEADER_CHUNK_NAME: FontTag = FontTag { data: *b" HDR" };
 
 /// Chunk types for SFNT fonts.
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum SfntChunkType {
     /// Checksum adjustment
     ChecksumAdjustment,
@@ -547,46 +2049,105 @@ impl ChunkReader for SfntFont {
 }
 
 #[cfg(feature = "woff")]
-impl TryFrom<crate::woff1::font::Woff1Font> for SfntFont {
-    type Error = FontIoError;
+impl SfntFont {
+    /// Converts a WOFF font to an SFNT font, carrying over the WOFF font's
+    /// C2PA table (if any) into the SFNT font.
+    ///
+    /// # Remarks
+    /// Unlike the `TryFrom<Woff1Font>` conversion, which drops the C2PA
+    /// table as it belongs to the WOFF file rather than the SFNT font, the
+    /// C2PA table here is decompressed as needed and counted in the SFNT
+    /// font's number of tables.
+    pub fn from_woff_carry_c2pa(
+        woff: crate::woff1::font::Woff1Font,
+    ) -> Result<Self, FontIoError> {
+        Self::from_woff(woff, true)
+    }
 
-    fn try_from(
+    /// Writes the font as an SFNT font to `otf_dest`, and as a WOFF1 font
+    /// converted from the very same bytes to `woff_dest`.
+    ///
+    /// # Remarks
+    /// The font is only laid out once, so the C2PA table (if any) is the same
+    /// in both outputs. The WOFF1 font is converted with the default
+    /// [`SfntToWoffOptions`](crate::woff1::font::SfntToWoffOptions), which
+    /// carry over the C2PA table.
+    ///
+    /// # Errors
+    /// Returns an error if the font fails to be written, or converted to a
+    /// WOFF1 font.
+    pub fn write_both<W1, W2>(
+        &mut self,
+        otf_dest: &mut W1,
+        woff_dest: &mut W2,
+    ) -> Result<(), FontIoError>
+    where
+        W1: Write + ?Sized,
+        W2: Write + ?Sized,
+    {
+        let mut sfnt_data = Cursor::new(Vec::new());
+        self.write(&mut sfnt_data)?;
+        otf_dest.write_all(sfnt_data.get_ref())?;
+        sfnt_data.set_position(0);
+        // The conversion seeks back to write the WOFF1 header last
+        let mut woff_data = Cursor::new(Vec::new());
+        crate::woff1::font::sfnt_to_woff_stream(
+            &mut sfnt_data,
+            &mut woff_data,
+            &crate::woff1::font::SfntToWoffOptions::default(),
+        )?;
+        woff_dest.write_all(woff_data.get_ref())?;
+        Ok(())
+    }
+
+    /// Converts a WOFF font to an SFNT font, including the C2PA table only
+    /// when `carry_c2pa` is set.
+    fn from_woff(
         woff: crate::woff1::font::Woff1Font,
-    ) -> Result<Self, Self::Error> {
+        carry_c2pa: bool,
+    ) -> Result<Self, FontIoError> {
         // These 'use' are done here because of the gated feature for WOFF
         // support.
         use std::collections::BTreeMap;
 
         use crate::{
-            sfnt::table::NamedTable as SfntNamedTable,
+            magic::Magic, sfnt::table::NamedTable as SfntNamedTable,
             woff1::table::NamedTable as WoffNamedTable,
         };
 
         // Number of tables in the WOFF font, excluding C2PA (as the C2PA
-        // belonged to the WOFF file not the SFNT font).
+        // belonged to the WOFF file not the SFNT font) unless it is carried
+        // over.
         let num_tables = woff
             .directory
             .entries()
             .iter()
-            .filter(|e| e.tag != FontTag::C2PA)
+            .filter(|e| carry_c2pa || !e.tag.is_c2pa())
             .count() as u16;
 
         // We must have at least one table to convert to SFNT
         if num_tables == 0 {
             return Err(FontIoError::NoTablesFound);
         }
+        // And the tables must add up to the size the WOFF font declares
+        woff.check_total_sfnt_size()?;
 
         // According to the WOFF spec, these three fields MUST be
         // calculated based on the number of tables.
-        let entry_selector = (num_tables as f64).log2().floor() as u16;
-        let search_range =
-            2u16.pow(entry_selector as u32) * SfntDirectoryEntry::SIZE as u16;
-        let range_shift =
-            num_tables * SfntDirectoryEntry::SIZE as u16 - search_range;
+        let (search_range, entry_selector, range_shift) =
+            SfntHeader::compute_search_params(num_tables);
+
+        // The flavor is the version of the SFNT font, kept as is (e.g., a
+        // TrueType flavor stays 0x00010000); it can't be another wrapper
+        let flavor = woff.header.flavor;
+        let sfnt_version = Magic::try_from(flavor)?;
+        if !sfnt_version.is_sfnt() {
+            return Err(FontIoError::UnknownMagic(flavor));
+        }
 
         // Copy over fields as appropriate
         let sfnt_header = SfntHeader {
-            sfntVersion: woff.header.flavor.try_into()?,
+            sfntVersion: sfnt_version,
             numTables: num_tables,
             entrySelector: entry_selector,
             rangeShift: range_shift,
@@ -596,47 +2157,95 @@ impl TryFrom<crate::woff1::font::Woff1Font> for SfntFont {
         // We will build up the SFNT directory and tables from the WOFF
         let mut sfnt_directory = SfntDirectory::new();
         let mut tables = BTreeMap::new();
+        let mut table_checksums = BTreeMap::new();
 
         // Iterate over the WOFF directory entries and convert them to SFNT
         for entry in woff.directory.entries() {
+            if entry.tag.is_c2pa() && !carry_c2pa {
+                // C2PA table belongs to the WOFF font, so no need to add it
+                // to the SFNT font.
+                tracing::trace!("WOFF C2PA will not be added to SFNT font");
+                continue;
+            }
+            // Get the table from the WOFF font
+            let woff_table = woff.get_decompressed_table(&entry.tag)?;
+
+            // If the table was compressed in WOFF, decompress it
+            let sfnt_table = match woff_table {
+                WoffNamedTable::Generic(table) => {
+                    SfntNamedTable::Generic(table.clone())
+                } // Add other variants as needed
+                WoffNamedTable::C2PA(table) => SfntNamedTable::C2PA(table),
+            };
+
+            // Recompute the checksum from the decompressed data, rather than
+            // trusting the WOFF's origChecksum
+            let mut checksum = sfnt_table.checksum();
+            if let (true, SfntNamedTable::Generic(head)) =
+                (entry.tag == FontTag::HEAD, &sfnt_table)
+            {
+                // The checksum adjustment is treated as zero
+                if let Some(adjustment) = head.data.get(8..12) {
+                    checksum -= u32::from_be_bytes([
+                        adjustment[0],
+                        adjustment[1],
+                        adjustment[2],
+                        adjustment[3],
+                    ]);
+                }
+            }
+            let orig_checksum = entry.origChecksum;
+            if checksum.0 != orig_checksum {
+                tracing::debug!(
+                    "Replacing the WOFF checksum {orig_checksum:#010x} of table {} with {:#010x}",
+                    entry.tag,
+                    checksum.0
+                );
+            }
+            // Only raw tables are never modified in place, so only their
+            // checksums can be reused when the font is written
+            if matches!(sfnt_table, SfntNamedTable::Generic(_)) {
+                table_checksums.insert(entry.tag, checksum.0);
+            }
+
             // Create a new directory entry for the SFNT font
             let sfnt_entry = SfntDirectoryEntry {
                 tag: entry.tag,
                 offset: entry.offset, /* This offset is invalid and but when
                                        * Sfnt is written it will update
                                        * correctly. */
-                checksum: entry.origChecksum, /* This should still be valid,
-                                               * should we we recalculate it? */
+                checksum: checksum.0,
                 length: entry.origLength,
             };
-            // Add the entry to the SFNT directory
+            // Add the entry and table to the SFNT font
             sfnt_directory.add_entry(sfnt_entry);
-
-            // Get the table from the WOFF font
-            let woff_table = woff.get_decompressed_table(&entry.tag)?;
-
-            // If the table was compressed in WOFF, decompress it
-            match woff_table {
-                WoffNamedTable::Generic(table) => {
-                    let sfnt_table = SfntNamedTable::Generic(table.clone());
-                    tables.insert(entry.tag, sfnt_table);
-                } // Add other variants as needed
-                WoffNamedTable::C2PA(_table) => {
-                    // C2PA table belongs to the WOFF font, so no need to add it
-                    // to the SFNT font.
-                    tracing::trace!("WOFF C2PA will not be added to SFNT font");
-                }
-            };
+            tables.insert(entry.tag, sfnt_table);
         }
 
         Ok(Self {
             header: sfnt_header,
             directory: sfnt_directory,
             tables,
+            expected_table_delta: 0,
+            table_order: TableOrder::default(),
+            physical_table_order: Vec::new(),
+            layout_gaps: BTreeMap::new(),
+            table_checksums,
         })
     }
 }
 
+#[cfg(feature = "woff")]
+impl TryFrom<crate::woff1::font::Woff1Font> for SfntFont {
+    type Error = FontIoError;
+
+    fn try_from(
+        woff: crate::woff1::font::Woff1Font,
+    ) -> Result<Self, Self::Error> {
+        Self::from_woff(woff, false)
+    }
+}
+
 #[cfg(test)]
 #[path = "font_test.rs"]
 mod tests;
@@ -18,10 +18,13 @@ use std::io::Cursor;
 
 use super::*;
 use crate::{
-    c2pa::{ContentCredentialRecord, UpdateContentCredentialRecord},
+    c2pa::{
+        C2PAReadOptions, ContentCredentialRecord, UpdateContentCredentialRecord,
+    },
     chunks::ChunkTypeTrait,
-    data::Data,
+    data::{Data, DataBytes},
     error::FontIoError,
+    sfnt::table::EmbeddedBitmapFormat,
 };
 
 #[test]
@@ -40,6 +43,11 @@ fn test_write_font_data_with_zero_tables() {
         header: SfntHeader::default(),
         directory: SfntDirectory::new(),
         tables: std::collections::BTreeMap::new(),
+        expected_table_delta: 0,
+        table_order: TableOrder::default(),
+        physical_table_order: Vec::new(),
+        layout_gaps: std::collections::BTreeMap::new(),
+        table_checksums: std::collections::BTreeMap::new(),
     };
     let mut writer = Cursor::new(Vec::new());
     let result = font.write(&mut writer);
@@ -83,8 +91,17 @@ fn test_load_font_with_wrong_number_of_directory_entries() {
     let result = SfntFont::from_reader(&mut reader);
     assert!(result.is_err());
     let err = result.err().unwrap();
-    println!("{err:?}");
-    assert!(matches!(err, FontIoError::IoError(_)));
+    assert!(matches!(
+        err,
+        FontIoError::DirectoryEntryCountMismatch {
+            declared: 1,
+            available: 0
+        }
+    ));
+    assert_eq!(
+        err.to_string(),
+        "The table directory declares 1 entries, but only 0 are available"
+    );
 }
 
 #[test]
@@ -113,15 +130,13 @@ fn test_font_write_new_table_added() {
     let mut writer = Cursor::new(Vec::new());
 
     // Add a new table to the font
-    let new_table = Data {
-        data: vec![0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00],
-    };
+    let new_table =
+        Data::new(vec![0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00]);
     font.tables
         .insert(FontTag::new(*b"test"), NamedTable::Generic(new_table));
 
-    let new_table = Data {
-        data: vec![0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00],
-    };
+    let new_table =
+        Data::new(vec![0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00]);
     font.tables
         .insert(FontTag::new(*b"te5t"), NamedTable::Generic(new_table));
 
@@ -211,6 +226,152 @@ fn test_font_stub_dsig() {
     assert!(matches!(dsig, NamedTable::DSIG(_)));
 }
 
+#[test]
+fn test_font_ensure_stub_dsig() {
+    // A font without a DSIG table
+    let font_data = include_bytes!("../../../.devtools/font.otf");
+    let mut font = SfntFont::from_reader(&mut Cursor::new(font_data)).unwrap();
+    font.tables.remove(&FontTag::DSIG);
+    let mut writer = Cursor::new(Vec::new());
+    font.write(&mut writer).unwrap();
+    let font_data = writer.into_inner();
+
+    // Stubbing leaves it alone
+    let mut font = SfntFont::from_reader(&mut Cursor::new(&font_data)).unwrap();
+    font.stub_dsig().unwrap();
+    assert!(!font.contains_table(&FontTag::DSIG));
+
+    // While ensuring the stub adds the table
+    font.ensure_stub_dsig().unwrap();
+    // Which uses up the one table which may be added
+    assert!(matches!(
+        font.can_add_c2pa(),
+        Err(FontSaveError::TooManyTablesAdded)
+    ));
+    let mut writer = Cursor::new(Vec::new());
+    font.write(&mut writer).unwrap();
+    let mut reader = Cursor::new(writer.into_inner());
+    assert!(matches!(
+        reader.check_for_dsig().unwrap(),
+        DSIGType::Stubbed
+    ));
+    let mut font = SfntFont::from_reader(&mut reader).unwrap();
+    assert_eq!(font.header.num_tables(), 11);
+    let physical_order = font.directory.physical_order();
+    assert_eq!(physical_order.last().unwrap().tag, FontTag::DSIG);
+
+    // A signed DSIG table is stubbed, without adding anything
+    font.tables.insert(FontTag::DSIG, signed_dsig());
+    font.ensure_stub_dsig().unwrap();
+    assert!(matches!(
+        font.tables.get(&FontTag::DSIG),
+        Some(NamedTable::DSIG(dsig)) if dsig.is_stubbed()
+    ));
+    assert_eq!(font.tables.len(), 11);
+
+    // And a table can't be added once another has been
+    let mut font = SfntFont::from_reader(&mut Cursor::new(&font_data)).unwrap();
+    let record = ContentCredentialRecord::builder()
+        .with_content_credential(vec![0x00])
+        .build()
+        .unwrap();
+    font.add_c2pa_record(record).unwrap();
+    assert!(matches!(
+        font.ensure_stub_dsig(),
+        Err(FontIoError::SaveError(FontSaveError::TooManyTablesAdded))
+    ));
+    assert!(!font.contains_table(&FontTag::DSIG));
+}
+
+#[test]
+fn test_add_c2pa_and_stub_dsig() {
+    let record = ContentCredentialRecord::builder()
+        .with_content_credential(vec![0x00])
+        .build()
+        .unwrap();
+    let read_back = |font: &mut SfntFont| {
+        let mut writer = Cursor::new(Vec::new());
+        font.write(&mut writer).unwrap();
+        SfntFont::from_reader(&mut Cursor::new(writer.into_inner())).unwrap()
+    };
+
+    // A signed DSIG table is stubbed alongside the added C2PA table
+    let font_data = include_bytes!("../../../.devtools/font.otf");
+    let mut font = SfntFont::from_reader(&mut Cursor::new(font_data)).unwrap();
+    font.tables.insert(FontTag::DSIG, signed_dsig());
+    font.add_c2pa_and_stub_dsig(record.clone()).unwrap();
+    let font = read_back(&mut font);
+    assert!(matches!(
+        font.tables.get(&FontTag::DSIG),
+        Some(NamedTable::DSIG(dsig)) if dsig.is_stubbed()
+    ));
+    assert!(font.has_c2pa());
+    assert_eq!(font.header.num_tables(), 12);
+
+    // Without a DSIG table, both tables are added
+    let mut font = SfntFont::from_reader(&mut Cursor::new(font_data)).unwrap();
+    font.tables.remove(&FontTag::DSIG);
+    let without_dsig = read_back(&mut font);
+    assert_eq!(without_dsig.header.num_tables(), 10);
+    let mut font = without_dsig.clone();
+    font.add_c2pa_and_stub_dsig(record.clone()).unwrap();
+    let mut font = read_back(&mut font);
+    assert!(matches!(
+        font.tables.get(&FontTag::DSIG),
+        Some(NamedTable::DSIG(dsig)) if dsig.is_stubbed()
+    ));
+    assert!(font.has_c2pa());
+    assert_eq!(font.header.num_tables(), 12);
+    // The C2PA table is still written last
+    let physical_order = font.directory.physical_order();
+    assert_eq!(physical_order.last().unwrap().tag, FontTag::C2PA);
+
+    // A font with a C2PA table is left unchanged
+    assert!(matches!(
+        font.add_c2pa_and_stub_dsig(record.clone()),
+        Err(FontIoError::ContentCredentialAlreadyExists)
+    ));
+
+    // As is a font which already had a table added
+    let mut font = without_dsig;
+    font.insert_table(
+        FontTag::VORG,
+        NamedTable::Generic(Data::new(vec![0; 8])),
+    );
+    assert!(matches!(
+        font.add_c2pa_and_stub_dsig(record),
+        Err(FontIoError::SaveError(FontSaveError::TooManyTablesAdded))
+    ));
+    assert!(!font.has_c2pa());
+    assert!(!font.contains_table(&FontTag::DSIG));
+    assert!(matches!(
+        font.can_add_c2pa(),
+        Err(FontSaveError::TooManyTablesAdded)
+    ));
+}
+
+#[test]
+fn test_font_stub_dsig_with() {
+    let font_data = include_bytes!("../../../.devtools/font.otf");
+    let mut font = SfntFont::from_reader(&mut Cursor::new(font_data)).unwrap();
+    font.tables.insert(FontTag::DSIG, signed_dsig());
+    let config = DSIGStubConfig::default().with_version(1).with_flags(0);
+    font.stub_dsig_with(&config).unwrap();
+
+    // The stub is written with the requested contents
+    let mut writer = Cursor::new(Vec::new());
+    font.write(&mut writer).unwrap();
+    let font =
+        SfntFont::from_reader(&mut Cursor::new(writer.into_inner())).unwrap();
+    let Some(NamedTable::DSIG(dsig)) = font.tables.get(&FontTag::DSIG) else {
+        panic!("Expected a DSIG table");
+    };
+    assert_eq!(dsig.version, 1);
+    assert_eq!(dsig.numSignatures, 0);
+    assert_eq!(dsig.flags, 0);
+    assert!(dsig.data.is_empty());
+}
+
 #[test]
 #[tracing_test::traced_test]
 fn test_font_stub_dsig_stream_not_present() {
@@ -658,7 +819,7 @@ fn test_try_from_woff_to_sfnt() {
         0x4f, 0x54, 0x54, 0x4f, // Flavor
         0x00, 0x00, 0x00, 0x48, // Length
         0x00, 0x01, 0x00, 0x00, // Number of tables + Reserved
-        0x00, 0x00, 0x00, 0x18, // Total sfnt size
+        0x00, 0x00, 0x00, 0x20, // Total sfnt size
         0x00, 0x00, 0x00, 0x00, // Major version + Minor version
         0x00, 0x00, 0x00, 0x44, // Metadata Offset
         0x00, 0x00, 0x00, 0x04, // Metadata Length
@@ -685,6 +846,20 @@ fn test_try_from_woff_to_sfnt() {
     assert!(table.is_some());
     let table = table.unwrap();
     assert_eq!(table.len(), 4);
+
+    // A total sfnt size which does not match the tables
+    let mut woff_data = woff_data;
+    woff_data[19] = 0x18;
+    let woff_font =
+        Woff1Font::from_reader(&mut Cursor::new(woff_data)).unwrap();
+    let sfnt_font_result: Result<SfntFont, _> = woff_font.try_into();
+    assert!(matches!(
+        sfnt_font_result,
+        Err(FontIoError::SfntSizeMismatch {
+            declared: 0x18,
+            computed: 0x20
+        })
+    ));
 }
 
 #[cfg(feature = "woff")]
@@ -749,6 +924,86 @@ fn test_try_from_woff_to_sfnt_with_c2pa() {
     assert!(logs_contain("WOFF C2PA will not be added to SFNT font"));
 }
 
+#[cfg(feature = "woff")]
+#[test]
+fn test_from_woff_carry_c2pa() {
+    use crate::woff1::font::{Woff1Font, WoffReadOptions};
+    let font_data = include_bytes!("../../../.devtools/font_with_c2pa.woff");
+    // The font's header declares a length short of its data
+    let options = WoffReadOptions::default().with_lenient_header(true);
+    let load_woff = || {
+        Woff1Font::from_reader_with_options(
+            &mut Cursor::new(font_data),
+            &options,
+        )
+        .unwrap()
+    };
+    let woff_tables = load_woff().directory.entries().len();
+    let woff_c2pa = load_woff().get_c2pa().unwrap();
+    assert!(woff_c2pa.is_some());
+
+    // The plain conversion drops the C2PA table
+    let dropped: SfntFont = load_woff().try_into().unwrap();
+    assert_eq!(dropped.header.num_tables() as usize, woff_tables - 1);
+    assert!(!dropped.contains_table(&FontTag::C2PA));
+
+    // While carrying it over keeps it, and counts it
+    let mut sfnt_font = SfntFont::from_woff_carry_c2pa(load_woff()).unwrap();
+    assert_eq!(sfnt_font.header.num_tables() as usize, woff_tables);
+    assert!(sfnt_font.contains_table(&FontTag::C2PA));
+
+    // And the table survives writing the SFNT font
+    let mut writer = Cursor::new(Vec::new());
+    sfnt_font.write(&mut writer).unwrap();
+    let sfnt_font =
+        SfntFont::from_reader(&mut Cursor::new(writer.into_inner())).unwrap();
+    assert_eq!(sfnt_font.header.num_tables() as usize, woff_tables);
+    assert_eq!(sfnt_font.get_c2pa().unwrap(), woff_c2pa);
+}
+
+#[cfg(feature = "woff")]
+#[test]
+fn test_write_both() {
+    use crate::woff1::font::Woff1Font;
+    let font_data = include_bytes!("../../../.devtools/font.otf");
+    let mut font = SfntFont::from_reader(&mut Cursor::new(font_data)).unwrap();
+    let record = ContentCredentialRecord::builder()
+        .with_active_manifest_uri("https://example.com/manifest".to_string())
+        .with_content_credential(vec![0x01, 0x02, 0x03])
+        .build()
+        .unwrap();
+    font.add_c2pa_record(record).unwrap();
+
+    let mut otf = Vec::new();
+    let mut woff = Vec::new();
+    font.write_both(&mut otf, &mut woff).unwrap();
+    let load_sfnt = || SfntFont::from_reader(&mut Cursor::new(&otf)).unwrap();
+    let load_woff = || Woff1Font::from_reader(&mut Cursor::new(&woff)).unwrap();
+    let sfnt_c2pa = load_sfnt().get_c2pa().unwrap();
+    assert!(sfnt_c2pa.is_some());
+    assert_eq!(load_woff().get_c2pa().unwrap(), sfnt_c2pa);
+    assert_eq!(
+        load_woff().directory.entries().len(),
+        load_sfnt().header.num_tables() as usize
+    );
+
+    // The WOFF font converts back to the same tables, other than the 'head'
+    // table's checksum adjustment, which depends on the layout of the font
+    let converted = SfntFont::from_woff_carry_c2pa(load_woff()).unwrap();
+    let sfnt_font = load_sfnt();
+    assert_eq!(converted.tables.len(), sfnt_font.tables.len());
+    for (tag, table) in &sfnt_font.tables {
+        if *tag == FontTag::HEAD {
+            continue;
+        }
+        assert_eq!(
+            converted.table_checksum(tag),
+            Some(table.checksum().0),
+            "Table {tag} differs"
+        );
+    }
+}
+
 #[cfg(feature = "woff")]
 #[test]
 #[tracing_test::traced_test]
@@ -840,3 +1095,2179 @@ fn test_try_from_woff_to_sfnt_with_no_tables() {
     assert!(sfnt_font_result.is_err());
     assert!(matches!(sfnt_font_result, Err(FontIoError::NoTablesFound)));
 }
+
+#[cfg(feature = "woff")]
+#[test]
+fn test_try_from_woff_recomputes_checksums() {
+    use crate::woff1::font::Woff1Font;
+    let mut woff_data = include_bytes!("../../../.devtools/font.woff").to_vec();
+    // Break the (otherwise valid) origChecksum of every table in the WOFF
+    // directory
+    let num_tables = u16::from_be_bytes([woff_data[12], woff_data[13]]);
+    let mut expected = std::collections::BTreeMap::new();
+    for index in 0..num_tables as usize {
+        let entry = &mut woff_data[44 + index * 20..64 + index * 20];
+        let tag = FontTag::new(entry[0..4].try_into().unwrap());
+        let checksum = u32::from_be_bytes(entry[16..20].try_into().unwrap());
+        expected.insert(tag, checksum);
+        entry[16] ^= 0xff;
+    }
+    let woff_font =
+        Woff1Font::from_reader(&mut Cursor::new(woff_data)).unwrap();
+    let font = SfntFont::try_from(woff_font).unwrap();
+    // The checksums are recomputed from the tables' data, 'head' included
+    assert_eq!(font.directory.entries().len(), expected.len());
+    for entry in font.directory.entries() {
+        let checksum = entry.checksum;
+        assert_eq!(
+            Some(&checksum),
+            expected.get(&entry.tag),
+            "Unexpected checksum for table {}",
+            entry.tag
+        );
+    }
+}
+
+#[cfg(feature = "woff")]
+#[test]
+fn test_try_from_woff_to_sfnt_keeps_flavor() {
+    use crate::{magic::Magic, woff1::font::Woff1Font};
+    let woff_data = include_bytes!("../../../.devtools/font.woff");
+    let with_flavor = |flavor: u32| {
+        let mut woff_data = woff_data.to_vec();
+        woff_data[4..8].copy_from_slice(&flavor.to_be_bytes());
+        let woff_font =
+            Woff1Font::from_reader(&mut Cursor::new(woff_data)).unwrap();
+        SfntFont::try_from(woff_font)
+    };
+    for magic in [
+        Magic::TrueType,
+        Magic::OpenType,
+        Magic::AppleTrue,
+        Magic::PostScriptType1,
+    ] {
+        let mut font = with_flavor(magic as u32).unwrap();
+        let sfnt_version = font.header().sfntVersion;
+        assert_eq!(sfnt_version, magic);
+        // And it is written out as is
+        let mut written = Vec::new();
+        font.write(&mut written).unwrap();
+        assert_eq!(written[0..4], (magic as u32).to_be_bytes());
+    }
+    // A WOFF can't wrap another WOFF
+    for flavor in [Magic::Woff as u32, Magic::Woff2 as u32, 0x1234_5678] {
+        let result = with_flavor(flavor);
+        assert!(
+            matches!(result, Err(FontIoError::UnknownMagic(f)) if f == flavor),
+            "Expected an unknown magic error for {flavor:#010x}"
+        );
+    }
+}
+
+#[test]
+fn test_load_font_with_strict_c2pa() {
+    let font_data = include_bytes!("../../../.devtools/font.otf");
+    let mut font = SfntFont::from_reader(&mut Cursor::new(font_data)).unwrap();
+    let record = ContentCredentialRecord::builder()
+        .with_version(0, 1)
+        .with_active_manifest_uri("https://example.com".to_string())
+        .with_content_credential(vec![0x5a; 16])
+        .build()
+        .unwrap();
+    font.add_c2pa_record(record).unwrap();
+    let mut font_data = Vec::new();
+    font.write(&mut font_data).unwrap();
+    let strict = SfntReadOptions::default().with_strict_c2pa(true);
+    let read_with = |font_data: &[u8], options: &SfntReadOptions| {
+        SfntFont::from_reader_with_options(&mut Cursor::new(font_data), options)
+    };
+    // A table as written is conformant
+    assert!(read_with(&font_data, &strict).is_ok());
+
+    // Move the manifest store back over the end of the URI
+    let (c2pa_offset, _) = font.table_offsets()[&FontTag::C2PA];
+    let store_offset = c2pa_offset as usize + 12;
+    let offset = u32::from_be_bytes(
+        font_data[store_offset..store_offset + 4]
+            .try_into()
+            .unwrap(),
+    );
+    font_data[store_offset..store_offset + 4]
+        .copy_from_slice(&(offset - 2).to_be_bytes());
+    assert!(read_with(&font_data, &SfntReadOptions::default()).is_ok());
+    let result = read_with(&font_data, &strict);
+    assert!(
+        matches!(result, Err(FontIoError::MalformedC2paTable(_))),
+        "Expected a malformed table error; found: {:?}",
+        result.err()
+    );
+}
+
+#[test]
+fn test_load_font_with_strict_header_fields() {
+    let mut font_data = include_bytes!("../../../.devtools/font.otf").to_vec();
+    let strict = SfntReadOptions::default().with_strict_header_fields(true);
+    let read_with = |font_data: &[u8], options: &SfntReadOptions| {
+        SfntFont::from_reader_with_options(&mut Cursor::new(font_data), options)
+    };
+    assert!(read_with(&font_data, &strict).is_ok());
+
+    // Tamper with the rangeShift
+    font_data[10..12].copy_from_slice(&0_u16.to_be_bytes());
+    assert!(read_with(&font_data, &SfntReadOptions::default()).is_ok());
+    let result = read_with(&font_data, &strict);
+    assert!(
+        matches!(
+            result,
+            Err(FontIoError::InconsistentHeaderFields {
+                num_tables: 11,
+                expected: (128, 3, 48),
+                found: (128, 3, 0),
+            })
+        ),
+        "Expected an inconsistent header error; found: {:?}",
+        result.err()
+    );
+}
+
+#[test]
+fn test_load_font_with_limit() {
+    let font_data = include_bytes!("../../../.devtools/font.otf");
+    let size = font_data.len() as u64;
+    let font =
+        SfntFont::from_reader_with_limit(&mut Cursor::new(font_data), size)
+            .unwrap();
+    assert_eq!(font.header.num_tables(), 11);
+
+    let result =
+        SfntFont::from_reader_with_limit(&mut Cursor::new(font_data), size - 1);
+    assert!(matches!(
+        result,
+        Err(FontIoError::FontTooLarge { size: s, limit }) if s == size && limit == size - 1
+    ));
+}
+
+#[test]
+fn test_load_font_with_limit_from_current_position() {
+    let font_data = include_bytes!("../../../.devtools/font.otf");
+    let size = font_data.len() as u64;
+    // E.g., the magic number was already read to detect the format
+    let mut reader = Cursor::new(font_data);
+    reader.set_position(4);
+    let result = SfntFont::from_reader_with_limit(&mut reader, size - 1);
+    assert!(matches!(
+        result,
+        Err(FontIoError::FontTooLarge { size: s, .. }) if s == size
+    ));
+    let font = SfntFont::from_reader_with_limit(&mut reader, size).unwrap();
+    assert_eq!(font.header.num_tables(), 11);
+
+    // The shared buffer is read the same way
+    reader.set_position(4);
+    let options = SfntReadOptions::default().with_shared_buffer(true);
+    let shared =
+        SfntFont::from_reader_with_options(&mut reader, &options).unwrap();
+    assert_eq!(shared.header.num_tables(), 11);
+}
+
+#[test]
+fn test_load_font_with_shared_buffer() {
+    let font_data = include_bytes!("../../../.devtools/font.otf");
+    let options = SfntReadOptions::default().with_shared_buffer(true);
+    let mut reader = Cursor::new(font_data);
+    // The buffer holds the whole font, wherever the reader is positioned
+    reader.seek(SeekFrom::End(0)).unwrap();
+    let shared = SfntFont::from_reader_with_options(&mut reader, &options)
+        .expect("Failed to read font");
+    let owned = SfntFont::from_reader(&mut Cursor::new(font_data)).unwrap();
+    for (tag, table) in shared.tables.iter() {
+        match table {
+            NamedTable::Generic(data) => assert!(
+                matches!(data.data, DataBytes::Shared { .. }),
+                "Expected table {tag} to be shared"
+            ),
+            _ => assert!(NamedTable::minimum_size(tag) > 0),
+        }
+    }
+
+    let write = |font: &SfntFont| {
+        let mut output = Vec::new();
+        font.clone().write(&mut output).unwrap();
+        output
+    };
+    assert_eq!(write(&shared), write(&owned));
+
+    // Modifying a shared table leaves the other fonts unchanged
+    let mut modified = shared.clone();
+    if let Some(NamedTable::Generic(os2)) =
+        modified.tables.get_mut(&FontTag::OS2)
+    {
+        os2.data[0] = 0xff;
+    }
+    assert_ne!(write(&modified), write(&shared));
+    assert_eq!(write(&shared), write(&owned));
+}
+
+#[test]
+fn test_table_offsets_after_write() {
+    let font_data = include_bytes!("../../../.devtools/font.otf");
+    let mut reader = Cursor::new(font_data);
+    let mut font = SfntFont::from_reader(&mut reader).unwrap();
+    // Before writing, the offsets should match what was read in
+    let offsets = font.table_offsets();
+    assert_eq!(offsets.len(), 11);
+    assert_eq!(offsets[&FontTag::HEAD], (188, 54));
+    assert_eq!(offsets[&FontTag::DSIG], (1364, 8));
+    // Add a C2PA record and write the font out
+    let record = ContentCredentialRecord::builder()
+        .with_version(0, 1)
+        .with_active_manifest_uri("https://example.com".to_string())
+        .with_content_credential(vec![0x00, 0x01, 0x02, 0x03])
+        .build()
+        .unwrap();
+    font.add_c2pa_record(record).unwrap();
+    let mut writer = Cursor::new(Vec::new());
+    font.write(&mut writer).unwrap();
+    let written_data = writer.into_inner();
+    // The offsets should now reflect where the C2PA table was written
+    let offsets = font.table_offsets();
+    assert_eq!(offsets.len(), 12);
+    let (c2pa_offset, c2pa_length) = offsets[&FontTag::C2PA];
+    assert_eq!(c2pa_offset, 1388);
+    assert_eq!(c2pa_length, 43);
+    // And they should agree with the chunk positions of the written font
+    let positions =
+        SfntFont::get_chunk_positions(&mut Cursor::new(&written_data)).unwrap();
+    let c2pa = positions.iter().find(|p| p.name() == b"C2PA").unwrap();
+    assert_eq!(c2pa.offset(), c2pa_offset as usize);
+    assert_eq!(c2pa.length(), c2pa_length as usize);
+}
+
+#[test]
+fn test_from_reader_best_effort_with_valid_font() {
+    let font_data = include_bytes!("../../../.devtools/font.otf");
+    let mut reader = Cursor::new(font_data);
+    let (font, errors) =
+        SfntFont::from_reader_best_effort(&mut reader).unwrap();
+    assert!(errors.is_empty());
+    assert_eq!(font.tables.len(), 11);
+    assert!(matches!(
+        font.tables.get(&FontTag::DSIG),
+        Some(NamedTable::DSIG(_))
+    ));
+}
+
+#[test]
+fn test_from_reader_best_effort_keeps_raw_data_for_corrupt_table() {
+    let mut font_data = include_bytes!("../../../.devtools/font.otf").to_vec();
+    // Shrink the DSIG table (the second directory entry) below its minimum
+    // size, so it fails to parse
+    font_data[40..44].copy_from_slice(&4u32.to_be_bytes());
+    let mut reader = Cursor::new(&font_data);
+    assert!(SfntFont::from_reader(&mut reader).is_err());
+    reader.set_position(0);
+    let (mut font, errors) =
+        SfntFont::from_reader_best_effort(&mut reader).unwrap();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].0, FontTag::DSIG);
+    assert!(matches!(errors[0].1, FontIoError::LoadTableTruncated(_)));
+    assert_eq!(font.tables.len(), 11);
+    assert!(matches!(
+        font.tables.get(&FontTag::DSIG),
+        Some(NamedTable::Generic(data)) if data.len() == 4
+    ));
+    // The font can still be written out
+    let mut writer = Cursor::new(Vec::new());
+    assert!(font.write(&mut writer).is_ok());
+}
+
+#[test]
+fn test_from_reader_best_effort_omits_unreadable_table() {
+    // Truncate the font in the middle of the (physically last) DSIG table
+    let font_data = &include_bytes!("../../../.devtools/font.otf")[..1366];
+    let mut reader = Cursor::new(font_data);
+    let (mut font, errors) =
+        SfntFont::from_reader_best_effort(&mut reader).unwrap();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].0, FontTag::DSIG);
+    assert_eq!(font.tables.len(), 10);
+    assert!(!font.contains_table(&FontTag::DSIG));
+    assert_eq!(font.header.num_tables(), 10);
+    assert_eq!(font.directory.entries().len(), 10);
+    let mut writer = Cursor::new(Vec::new());
+    assert!(font.write(&mut writer).is_ok());
+}
+
+#[test]
+fn test_from_reader_best_effort_with_corrupt_mandatory_table() {
+    let mut font_data = include_bytes!("../../../.devtools/font.otf").to_vec();
+    // Break the magic number of the 'head' table
+    font_data[188 + 12..188 + 16].copy_from_slice(&[0, 0, 0, 0]);
+    let mut reader = Cursor::new(&font_data);
+    let result = SfntFont::from_reader_best_effort(&mut reader);
+    assert!(matches!(
+        result,
+        Err(FontIoError::InvalidHeadMagicNumber(0))
+    ));
+}
+
+#[test]
+#[tracing_test::traced_test]
+fn test_load_font_with_invalid_head_magic_number_lenient() {
+    let mut font_data = include_bytes!("../../../.devtools/font.otf").to_vec();
+    // Break the magic number of the 'head' table
+    font_data[188 + 12..188 + 16].copy_from_slice(&[0, 0, 0, 0]);
+    let result = SfntFont::from_reader(&mut Cursor::new(&font_data));
+    assert!(matches!(
+        result,
+        Err(FontIoError::InvalidHeadMagicNumber(0))
+    ));
+
+    let options =
+        SfntReadOptions::default().with_lenient_head_magic_number(true);
+    let font = SfntFont::from_reader_with_options(
+        &mut Cursor::new(&font_data),
+        &options,
+    )
+    .unwrap();
+    assert!(logs_contain(
+        "Ignoring invalid 'head' magic number 0x00000000"
+    ));
+    assert!(matches!(
+        font.table(&FontTag::HEAD),
+        Some(NamedTable::Head(head)) if { head.magicNumber } == 0
+    ));
+
+    // A valid magic number is read as usual
+    let font = SfntFont::from_reader_with_options(
+        &mut Cursor::new(include_bytes!("../../../.devtools/font.otf")),
+        &options,
+    )
+    .unwrap();
+    assert!(font.contains_table(&FontTag::HEAD));
+}
+
+#[test]
+fn test_computed_size() {
+    let font_data = include_bytes!("../../../.devtools/font.otf");
+    let mut font = SfntFont::from_reader(&mut Cursor::new(font_data)).unwrap();
+    assert_eq!(font.computed_size(), font_data.len() as u64);
+    // Add a C2PA record with a non 4-byte aligned length
+    let record = ContentCredentialRecord::builder()
+        .with_version(0, 1)
+        .with_active_manifest_uri("https://example.com".to_string())
+        .with_content_credential(vec![0x00, 0x01, 0x02])
+        .build()
+        .unwrap();
+    font.add_c2pa_record(record).unwrap();
+    let computed_size = font.computed_size();
+    let mut writer = Cursor::new(Vec::new());
+    font.write(&mut writer).unwrap();
+    assert_eq!(computed_size, writer.into_inner().len() as u64);
+}
+
+#[test]
+fn test_inconsistent_device_tables() {
+    let font_data = include_bytes!("../../../.devtools/font.otf");
+    let mut font = SfntFont::from_reader(&mut Cursor::new(font_data)).unwrap();
+    let hdmx = FontTag::new(*b"hdmx");
+    let ltsh = FontTag::new(*b"LTSH");
+    // Neither table is present
+    assert!(font.inconsistent_device_tables().is_empty());
+
+    // One 8-byte device record for the 5 glyphs, and a byte per glyph
+    let mut hdmx_data = vec![0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x08];
+    hdmx_data.extend([12, 7, 0, 7, 7, 7, 7, 0]);
+    let mut ltsh_data = vec![0x00, 0x00, 0x00, 0x05];
+    ltsh_data.extend([1, 1, 1, 1, 1]);
+    font.insert_table(hdmx, NamedTable::Generic(Data::new(hdmx_data.clone())));
+    font.insert_table(ltsh, NamedTable::Generic(Data::new(ltsh_data.clone())));
+    assert!(font.inconsistent_device_tables().is_empty());
+
+    // A device record sized for another number of glyphs
+    let mut bad_hdmx = hdmx_data.clone();
+    bad_hdmx[7] = 0x0c;
+    bad_hdmx.extend([0; 4]);
+    font.insert_table(hdmx, NamedTable::Generic(Data::new(bad_hdmx)));
+    assert_eq!(font.inconsistent_device_tables(), vec![hdmx]);
+
+    // Truncated device records
+    font.insert_table(
+        hdmx,
+        NamedTable::Generic(Data::new(hdmx_data[..12].to_vec())),
+    );
+    assert_eq!(font.inconsistent_device_tables(), vec![hdmx]);
+    font.insert_table(hdmx, NamedTable::Generic(Data::new(hdmx_data)));
+
+    // A glyph count disagreeing with the 'maxp' table
+    let mut bad_ltsh = ltsh_data.clone();
+    bad_ltsh[3] = 0x04;
+    font.insert_table(ltsh, NamedTable::Generic(Data::new(bad_ltsh)));
+    assert_eq!(font.inconsistent_device_tables(), vec![ltsh]);
+    font.insert_table(
+        ltsh,
+        NamedTable::Generic(Data::new(ltsh_data[..8].to_vec())),
+    );
+    assert_eq!(font.inconsistent_device_tables(), vec![ltsh]);
+
+    // Nothing is checked without a glyph count
+    font.tables.remove(&FontTag::MAXP);
+    assert!(font.inconsistent_device_tables().is_empty());
+}
+
+#[test]
+#[tracing_test::traced_test]
+fn test_read_with_device_table_validation() {
+    let font_data = include_bytes!("../../../.devtools/font.otf");
+    let mut font = SfntFont::from_reader(&mut Cursor::new(font_data)).unwrap();
+    let ltsh = FontTag::new(*b"LTSH");
+    // An 'LTSH' table for 4 glyphs, rather than 5, laid out after the others
+    let ltsh_data = vec![0x00, 0x00, 0x00, 0x04, 1, 1, 1, 1];
+    let end = font
+        .table_offsets()
+        .values()
+        .map(|(offset, length)| offset + length)
+        .max()
+        .unwrap();
+    font.directory.add_entry(SfntDirectoryEntry {
+        tag: ltsh,
+        offset: align_to_four(end),
+        checksum: 0,
+        length: ltsh_data.len() as u32,
+    });
+    font.insert_table(ltsh, NamedTable::Generic(Data::new(ltsh_data)));
+    let mut output = Vec::new();
+    font.write(&mut output).unwrap();
+
+    // Not checked by default
+    SfntFont::from_reader(&mut Cursor::new(&output)).unwrap();
+    assert!(!logs_contain("'LTSH' table"));
+
+    // The font is still read, but with a warning
+    let options = SfntReadOptions::default().with_device_table_validation(true);
+    let font =
+        SfntFont::from_reader_with_options(&mut Cursor::new(&output), &options)
+            .unwrap();
+    assert!(font.contains_table(&ltsh));
+    assert!(logs_contain(
+        "The 'LTSH' table (8 bytes) is inconsistent with 5 glyphs"
+    ));
+}
+
+#[test]
+fn test_num_glyphs() {
+    let font_data = include_bytes!("../../../.devtools/font.otf");
+    let mut font = SfntFont::from_reader(&mut Cursor::new(font_data)).unwrap();
+    assert_eq!(font.num_glyphs(), Some(5));
+    // Without a 'maxp' table, there is no glyph count
+    font.tables.remove(&FontTag::MAXP);
+    assert_eq!(font.num_glyphs(), None);
+}
+
+#[test]
+fn test_get_c2pa_with_options_validates_manifest_uri() {
+    let font_data = include_bytes!("../../../.devtools/font.otf");
+    let mut font = SfntFont::from_reader(&mut Cursor::new(font_data)).unwrap();
+    let record = ContentCredentialRecord::builder()
+        .with_active_manifest_uri("\u{7f}garbage\u{0}".to_string())
+        .build()
+        .unwrap();
+    font.add_c2pa_record(record).unwrap();
+    let mut writer = Cursor::new(Vec::new());
+    font.write(&mut writer).unwrap();
+    let data = writer.into_inner();
+    // Lenient reading still returns the raw URI
+    let font = SfntFont::from_reader(&mut Cursor::new(&data)).unwrap();
+    let record = font
+        .get_c2pa_with_options(&C2PAReadOptions::default())
+        .unwrap()
+        .unwrap();
+    assert_eq!(record.active_manifest_uri(), Some("\u{7f}garbage\u{0}"));
+    // But asking for validation fails
+    let font = SfntFont::from_reader(&mut Cursor::new(&data)).unwrap();
+    let result = font.get_c2pa_with_options(
+        &C2PAReadOptions::default().with_validate_manifest_uri(true),
+    );
+    assert!(matches!(result, Err(FontIoError::InvalidManifestUri(_))));
+}
+
+/// Creates a DSIG table which looks to hold a signature.
+fn signed_dsig() -> NamedTable {
+    NamedTable::DSIG(TableDSIG {
+        version: 1,
+        numSignatures: 1,
+        flags: 0,
+        data: vec![0x00; 12],
+    })
+}
+
+/// Creates a font with a signed DSIG table physically placed in the middle of
+/// the font, between the 'cmap' and 'CFF ' tables.
+fn font_data_with_dsig_in_middle() -> Vec<u8> {
+    let font_data = include_bytes!("../../../.devtools/font.otf");
+    let mut font = SfntFont::from_reader(&mut Cursor::new(font_data)).unwrap();
+    font.tables.insert(FontTag::DSIG, signed_dsig());
+    let cmap_offset = font
+        .directory
+        .entries()
+        .iter()
+        .find(|entry| entry.tag == FontTag::new(*b"cmap"))
+        .unwrap()
+        .offset;
+    let mut directory = SfntDirectory::new();
+    for entry in font.directory.entries() {
+        let mut entry = *entry;
+        if entry.tag == FontTag::DSIG {
+            entry.offset = cmap_offset + 1;
+        }
+        directory.add_entry(entry);
+    }
+    font.directory = directory;
+    let mut font_data = Vec::new();
+    font.write(&mut font_data).unwrap();
+    font_data
+}
+
+#[test]
+fn test_data_end_before_dsig() {
+    // Without a DSIG table, the data ends at the end of the stream
+    let font_data = include_bytes!("../../../.devtools/font.otf");
+    let mut font = SfntFont::from_reader(&mut Cursor::new(font_data)).unwrap();
+    assert!(font.tables.remove(&FontTag::DSIG).is_some());
+    let mut writer = Cursor::new(Vec::new());
+    font.write(&mut writer).unwrap();
+    let font_data_without_dsig = writer.into_inner();
+    let mut reader = Cursor::new(&font_data_without_dsig);
+    assert_eq!(
+        SfntFont::data_end_before_dsig(&mut reader).unwrap(),
+        font_data_without_dsig.len() as u64
+    );
+    assert_eq!(reader.position(), 0);
+
+    // With one, it ends with the last of the other tables, wherever the
+    // DSIG table is placed
+    let font_data = font_data_with_dsig_in_middle();
+    let font = SfntFont::from_reader(&mut Cursor::new(&font_data)).unwrap();
+    let offsets = font.table_offsets();
+    let dsig_offset = offsets[&FontTag::DSIG].0;
+    let tables_end = offsets
+        .iter()
+        .filter(|(tag, _)| **tag != FontTag::DSIG)
+        .map(|(_, (offset, length))| (offset + length) as u64)
+        .max()
+        .unwrap();
+    assert!(tables_end > dsig_offset as u64);
+    let mut reader = Cursor::new(&font_data);
+    assert_eq!(
+        SfntFont::data_end_before_dsig(&mut reader).unwrap(),
+        tables_end
+    );
+    assert_eq!(reader.position(), 0);
+
+    // Both are positions in the stream, for a font which does not start it
+    let prefix = vec![0xab; 16];
+    let mut reader = Cursor::new([prefix.as_slice(), &font_data].concat());
+    reader.set_position(prefix.len() as u64);
+    assert_eq!(
+        SfntFont::data_end_before_dsig(&mut reader).unwrap(),
+        prefix.len() as u64 + tables_end
+    );
+    assert_eq!(reader.position(), prefix.len() as u64);
+    let mut reader =
+        Cursor::new([prefix.as_slice(), &font_data_without_dsig].concat());
+    reader.set_position(prefix.len() as u64);
+    assert_eq!(
+        SfntFont::data_end_before_dsig(&mut reader).unwrap(),
+        (prefix.len() + font_data_without_dsig.len()) as u64
+    );
+
+    // A stream which is not a font is an error
+    assert!(
+        SfntFont::data_end_before_dsig(&mut Cursor::new(vec![0u8; 4])).is_err()
+    );
+}
+
+#[test]
+fn test_font_dsig_in_middle() {
+    let font_data = font_data_with_dsig_in_middle();
+    let font = SfntFont::from_reader(&mut Cursor::new(&font_data)).unwrap();
+    // The DSIG table is not the last table in the font
+    let physical_order = font.directory.physical_order();
+    let dsig_index = physical_order
+        .iter()
+        .position(|entry| entry.tag == FontTag::DSIG)
+        .unwrap();
+    assert!(dsig_index < physical_order.len() - 1);
+    assert_eq!(physical_order[dsig_index + 1].tag, FontTag::new(*b"CFF "));
+
+    // But it is still detected
+    let mut reader = Cursor::new(&font_data);
+    assert!(matches!(
+        reader.check_for_dsig().unwrap(),
+        DSIGType::Present
+    ));
+    assert_eq!(reader.position(), 0);
+
+    // And stubbed in place, without disturbing the tables following it
+    let mut destination = Cursor::new(Vec::new());
+    stub_dsig_stream(&mut reader, &mut destination).unwrap();
+    destination.set_position(0);
+    assert!(matches!(
+        destination.check_for_dsig().unwrap(),
+        DSIGType::Stubbed
+    ));
+    let stubbed = SfntFont::from_reader(&mut destination).unwrap();
+    for (tag, table) in
+        font.tables.iter().filter(|(tag, _)| **tag != FontTag::DSIG)
+    {
+        let mut expected = Vec::new();
+        table.write(&mut expected).unwrap();
+        let mut actual = Vec::new();
+        stubbed.tables[tag].write(&mut actual).unwrap();
+        if *tag != FontTag::HEAD {
+            assert_eq!(expected, actual, "Table {tag} differs");
+        }
+    }
+}
+
+#[test]
+fn test_font_stub_dsig_preserving_flags() {
+    let font_data = include_bytes!("../../../.devtools/font.otf");
+    let mut font = SfntFont::from_reader(&mut Cursor::new(font_data)).unwrap();
+    // Signed, and resigning is permitted
+    font.tables.insert(FontTag::DSIG, signed_dsig());
+    font.stub_dsig_preserving_flags().unwrap();
+    let Some(NamedTable::DSIG(dsig)) = font.tables.get(&FontTag::DSIG) else {
+        panic!("Expected a DSIG table");
+    };
+    assert_eq!(dsig.numSignatures, 0);
+    assert!(dsig.data.is_empty());
+    assert_eq!(dsig.flags, 0);
+
+    // And it survives a round trip
+    let mut writer = Cursor::new(Vec::new());
+    font.write(&mut writer).unwrap();
+    let font =
+        SfntFont::from_reader(&mut Cursor::new(writer.into_inner())).unwrap();
+    let Some(NamedTable::DSIG(dsig)) = font.tables.get(&FontTag::DSIG) else {
+        panic!("Expected a DSIG table");
+    };
+    assert_eq!(dsig.flags, 0);
+}
+
+#[test]
+fn test_add_c2pa_and_stub_dsig_expects_the_dsig_table() {
+    let record = ContentCredentialRecord::builder()
+        .with_content_credential(vec![0x00])
+        .build()
+        .unwrap();
+    let font_data = include_bytes!("../../../.devtools/font.otf");
+    let mut font = SfntFont::from_reader(&mut Cursor::new(font_data)).unwrap();
+    font.tables.remove(&FontTag::DSIG);
+    font.write(&mut Cursor::new(Vec::new())).unwrap();
+    font.add_c2pa_and_stub_dsig(record).unwrap();
+    assert_eq!(font.expected_table_delta, 1);
+    // No other table may be added on top of the two
+    font.insert_table(
+        FontTag::VORG,
+        NamedTable::Generic(Data::new(vec![0; 8])),
+    );
+    let result = font.write(&mut Cursor::new(Vec::new()));
+    assert!(matches!(
+        result,
+        Err(FontIoError::SaveError(FontSaveError::TooManyTablesAdded))
+    ));
+    font.tables.remove(&FontTag::VORG);
+    font.write(&mut Cursor::new(Vec::new())).unwrap();
+    assert_eq!(font.expected_table_delta, 0);
+}
+
+#[test]
+fn test_prepare_for_signing() {
+    let font_data = include_bytes!("../../../.devtools/font.otf");
+    let mut font = SfntFont::from_reader(&mut Cursor::new(font_data)).unwrap();
+    let record = ContentCredentialRecord::builder()
+        .with_content_credential(vec![0x00, 0x01, 0x02, 0x03])
+        .build()
+        .unwrap();
+    font.add_c2pa_record(record).unwrap();
+    let mut writer = Cursor::new(Vec::new());
+    font.write(&mut writer).unwrap();
+    let mut font =
+        SfntFont::from_reader(&mut Cursor::new(writer.into_inner())).unwrap();
+    assert_eq!(font.tables.len(), 12);
+    // The test font has a stubbed DSIG table, so mimic a signed one
+    font.tables.insert(FontTag::DSIG, signed_dsig());
+    let options = SigningPreparationOptions::default()
+        .with_denied_table(FontTag::new(*b"GDEF"))
+        .with_denied_table(FontTag::FFTM);
+    let modifications = font.prepare_for_signing_with_options(&options);
+    assert_eq!(
+        modifications,
+        vec![
+            SigningModification::StubbedDSIG,
+            SigningModification::RemovedC2PA,
+            SigningModification::RemovedTable(FontTag::new(*b"GDEF")),
+        ]
+    );
+    // Removing two tables at once is allowed here
+    let mut writer = Cursor::new(Vec::new());
+    font.write(&mut writer).unwrap();
+    let font =
+        SfntFont::from_reader(&mut Cursor::new(writer.into_inner())).unwrap();
+    assert_eq!(font.tables.len(), 10);
+    assert!(!font.has_c2pa());
+    assert!(!font.contains_table(&FontTag::new(*b"GDEF")));
+    assert!(matches!(
+        font.tables.get(&FontTag::DSIG),
+        Some(NamedTable::DSIG(dsig)) if dsig.is_stubbed()
+    ));
+}
+
+#[test]
+fn test_prepare_for_signing_only_expects_its_own_removals() {
+    let font_data = include_bytes!("../../../.devtools/font.otf");
+    let mut font = SfntFont::from_reader(&mut Cursor::new(font_data)).unwrap();
+    let options = SigningPreparationOptions::default()
+        .with_denied_table(FontTag::new(*b"GDEF"))
+        .with_denied_table(FontTag::new(*b"post"));
+    font.prepare_for_signing_with_options(&options);
+    assert_eq!(font.expected_table_delta, -2);
+    // A single further removal is allowed, as for any font
+    let mut removed = font.clone();
+    removed.tables.remove(&FontTag::new(*b"name"));
+    assert!(removed.write(&mut Cursor::new(Vec::new())).is_ok());
+    // But no more than that
+    font.tables.remove(&FontTag::new(*b"name"));
+    font.tables.remove(&FontTag::new(*b"hmtx"));
+    let result = font.write(&mut Cursor::new(Vec::new()));
+    assert!(matches!(
+        result,
+        Err(FontIoError::SaveError(FontSaveError::TooManyTablesRemoved))
+    ));
+}
+
+#[test]
+fn test_prepare_for_signing_when_already_prepared() {
+    let font_data = include_bytes!("../../../.devtools/font.otf");
+    let mut font = SfntFont::from_reader(&mut Cursor::new(font_data)).unwrap();
+    font.tables.insert(FontTag::DSIG, signed_dsig());
+    assert_eq!(
+        font.prepare_for_signing(),
+        vec![SigningModification::StubbedDSIG]
+    );
+    assert!(font.prepare_for_signing().is_empty());
+}
+
+#[test]
+fn test_bulk_edit_is_reset_after_write() {
+    let font_data = include_bytes!("../../../.devtools/font.otf");
+    let mut font = SfntFont::from_reader(&mut Cursor::new(font_data)).unwrap();
+    font.prepare_for_signing();
+    font.write(&mut Cursor::new(Vec::new())).unwrap();
+    // Any further removals are subject to the usual checks
+    font.tables.remove(&FontTag::new(*b"GDEF"));
+    font.tables.remove(&FontTag::new(*b"post"));
+    let result = font.write(&mut Cursor::new(Vec::new()));
+    assert!(matches!(
+        result,
+        Err(FontIoError::SaveError(FontSaveError::TooManyTablesRemoved))
+    ));
+}
+
+#[test]
+fn test_table_checksum() {
+    let font_data = include_bytes!("../../../.devtools/font.otf");
+    let font = SfntFont::from_reader(&mut Cursor::new(font_data)).unwrap();
+    // The checksums should match the ones recorded in the directory
+    for entry in font.directory().entries() {
+        assert_eq!(
+            font.table_checksum(&entry.tag),
+            Some(entry.checksum),
+            "Checksum mismatch for table {}",
+            entry.tag
+        );
+    }
+    assert_eq!(font.table_checksum(&FontTag::C2PA), None);
+}
+
+/// Gets the test font, with the 'post' directory entry renamed to a second
+/// 'name' entry.
+fn font_data_with_duplicate_name() -> Vec<u8> {
+    let mut font_data = include_bytes!("../../../.devtools/font.otf").to_vec();
+    // 'post' is the last of the 11 directory entries
+    let entry = 12 + 10 * 16;
+    assert_eq!(&font_data[entry..entry + 4], b"post");
+    font_data[entry..entry + 4].copy_from_slice(b"name");
+    font_data
+}
+
+#[test]
+fn test_load_font_with_duplicate_table() {
+    let font_data = font_data_with_duplicate_name();
+    let result = SfntFont::from_reader(&mut Cursor::new(&font_data));
+    assert!(matches!(
+        result,
+        Err(FontIoError::DuplicateTable(tag)) if tag == FontTag::new(*b"name")
+    ));
+}
+
+#[test]
+#[tracing_test::traced_test]
+fn test_load_font_with_duplicate_table_lenient() {
+    let font_data = font_data_with_duplicate_name();
+    let options =
+        SfntReadOptions::default().with_lenient_duplicate_tables(true);
+    let mut font = SfntFont::from_reader_with_options(
+        &mut Cursor::new(&font_data),
+        &options,
+    )
+    .unwrap();
+    assert!(logs_contain(
+        "Ignoring duplicate directory entry for table name"
+    ));
+    assert_eq!(font.tables.len(), 10);
+    assert_eq!(font.header.num_tables(), 10);
+    assert_eq!(font.directory.entries().len(), 10);
+    // The first entry wins
+    assert_eq!(font.table(&FontTag::new(*b"name")).unwrap().len(), 435);
+    // And the font can be written back out, without the duplicate
+    let mut writer = Cursor::new(Vec::new());
+    font.write(&mut writer).unwrap();
+    let font =
+        SfntFont::from_reader(&mut Cursor::new(writer.into_inner())).unwrap();
+    assert_eq!(font.tables.len(), 10);
+}
+
+#[test]
+fn test_from_reader_best_effort_with_duplicate_table() {
+    let font_data = font_data_with_duplicate_name();
+    let (font, errors) =
+        SfntFont::from_reader_best_effort(&mut Cursor::new(&font_data))
+            .unwrap();
+    assert_eq!(font.tables.len(), 10);
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(errors[0].1, FontIoError::DuplicateTable(_)));
+}
+
+#[test]
+#[tracing_test::traced_test]
+fn test_from_reader_best_effort_with_options() {
+    let (font_data, _) = font_with_gap();
+    let options = SfntReadOptions::default().with_preserve_layout(true);
+    let (font, errors) = SfntFont::from_reader_best_effort_with_options(
+        &mut Cursor::new(&font_data),
+        &options,
+    )
+    .unwrap();
+    assert!(errors.is_empty());
+    assert_eq!(font.layout_gaps.len(), 1);
+    assert!(!font.table_checksums.is_empty());
+    assert!(logs_contain("operation=\"parse\" format=\"sfnt\""));
+}
+
+#[test]
+fn test_variation_axes_and_named_instances() {
+    let font_data = include_bytes!("../../../.devtools/font.otf");
+    let mut font = SfntFont::from_reader(&mut Cursor::new(font_data)).unwrap();
+    // Not a variable font
+    assert!(font.variation_axes().is_none());
+    assert!(font.named_instances().is_none());
+
+    // A single 'wght' axis, with a single "Bold" instance
+    let fvar_data = vec![
+        0x00, 0x01, 0x00, 0x00, 0x00, 0x10, 0x00, 0x02, // version, offset
+        0x00, 0x01, 0x00, 0x14, 0x00, 0x01, 0x00, 0x08, // counts, sizes
+        b'w', b'g', b'h', b't', 0x00, 0x64, 0x00, 0x00, // wght, min 100
+        0x01, 0x90, 0x00, 0x00, 0x03, 0x84, 0x00, 0x00, // default, max
+        0x00, 0x00, 0x01, 0x00, // flags, name ID
+        0x01, 0x01, 0x00, 0x00, 0x02, 0xbc, 0x00, 0x00, // Bold at 700
+    ];
+    font.tables
+        .insert(FontTag::FVAR, NamedTable::Generic(Data::new(fvar_data)));
+    let axes = font.variation_axes().unwrap();
+    assert_eq!(axes.len(), 1);
+    assert_eq!(axes[0].axisTag, FontTag::new(*b"wght"));
+    assert_eq!(axes[0].maxValue, 900.0);
+    let instances = font.named_instances().unwrap();
+    assert_eq!(instances.len(), 1);
+    assert_eq!(instances[0].subfamilyNameID, 257);
+    assert_eq!(instances[0].coordinates, vec![700.0]);
+}
+
+#[test]
+fn test_glyph_range() {
+    let font_data = include_bytes!("../../../.devtools/font.otf");
+    let mut font = SfntFont::from_reader(&mut Cursor::new(font_data)).unwrap();
+    // A CFF font, without a 'loca' table
+    assert!(font.glyph_range(0).is_none());
+
+    // Give the 5 glyphs short offsets, with the 'head' table's format
+    let Some(NamedTable::Head(head)) = font.tables.get_mut(&FontTag::HEAD)
+    else {
+        panic!("Expected a 'head' table");
+    };
+    head.indexToLocFormat = TableLoca::SHORT_FORMAT;
+    let loca = [0_u16, 0, 10, 10, 20, 26]
+        .iter()
+        .flat_map(|offset| offset.to_be_bytes())
+        .collect::<Vec<_>>();
+    font.tables
+        .insert(FontTag::LOCA, NamedTable::Generic(Data::new(loca)));
+    assert_eq!(font.glyph_range(0), Some((0, 0)));
+    assert_eq!(font.glyph_range(1), Some((0, 20)));
+    assert_eq!(font.glyph_range(4), Some((40, 52)));
+    assert_eq!(font.glyph_range(5), None);
+
+    // The glyphs must be within the 'glyf' table
+    font.tables.insert(
+        FontTag::new(*b"glyf"),
+        NamedTable::Generic(Data::new(vec![0; 48])),
+    );
+    assert_eq!(font.glyph_range(3), Some((20, 40)));
+    assert_eq!(font.glyph_range(4), None);
+}
+
+#[test]
+fn test_glyph_data() {
+    let font_data = include_bytes!("../../../.devtools/font.otf");
+    let mut font = SfntFont::from_reader(&mut Cursor::new(font_data)).unwrap();
+    // A CFF font, without 'glyf' and 'loca' tables
+    assert!(font.glyph_data(0).is_none());
+
+    let glyf = (0..52).collect::<Vec<u8>>();
+    font.tables.insert(
+        FontTag::new(*b"glyf"),
+        NamedTable::Generic(Data::new(glyf.clone())),
+    );
+    assert!(font.glyph_data(0).is_none());
+    let set_loca = |font: &mut SfntFont, format: i16, loca: Vec<u8>| {
+        let Some(NamedTable::Head(head)) = font.tables.get_mut(&FontTag::HEAD)
+        else {
+            panic!("Expected a 'head' table");
+        };
+        head.indexToLocFormat = format;
+        font.tables
+            .insert(FontTag::LOCA, NamedTable::Generic(Data::new(loca)));
+    };
+
+    // Short offsets are half the actual offsets
+    let short_loca = [0_u16, 0, 10, 10, 20, 26]
+        .iter()
+        .flat_map(|offset| offset.to_be_bytes())
+        .collect::<Vec<_>>();
+    set_loca(&mut font, TableLoca::SHORT_FORMAT, short_loca);
+    assert_eq!(font.glyph_data(0), Some(&[][..]));
+    assert_eq!(font.glyph_data(1), Some(&glyf[0..20]));
+    assert_eq!(font.glyph_data(4), Some(&glyf[40..52]));
+    assert_eq!(font.glyph_data(5), None);
+
+    // Long offsets are the actual offsets
+    let long_loca = [0_u32, 0, 20, 20, 40, 52]
+        .iter()
+        .flat_map(|offset| offset.to_be_bytes())
+        .collect::<Vec<_>>();
+    set_loca(&mut font, TableLoca::LONG_FORMAT, long_loca);
+    assert_eq!(font.glyph_data(1), Some(&glyf[0..20]));
+    assert_eq!(font.glyph_data(3), Some(&glyf[20..40]));
+    assert_eq!(font.glyph_data(4), Some(&glyf[40..52]));
+}
+
+#[test]
+fn test_has_layout_features() {
+    let font_data = include_bytes!("../../../.devtools/font.otf");
+    let mut font = SfntFont::from_reader(&mut Cursor::new(font_data)).unwrap();
+    // The test font only has a 'GDEF' table
+    let report = font.has_layout_features();
+    assert_eq!(
+        report,
+        LayoutFeatureReport {
+            gdef: true,
+            ..Default::default()
+        }
+    );
+    assert!(report.has_opentype_layout());
+    assert!(!report.has_kerning());
+    assert!(!report.has_aat_layout());
+
+    font.tables.insert(
+        FontTag::new(*b"kern"),
+        NamedTable::Generic(Data::new(vec![0; 4])),
+    );
+    let report = font.has_layout_features();
+    assert!(report.kern);
+    assert!(report.has_kerning());
+}
+
+#[test]
+fn test_outline_format() {
+    let font_data = include_bytes!("../../../.devtools/font.otf");
+    let mut font = SfntFont::from_reader(&mut Cursor::new(font_data)).unwrap();
+    assert_eq!(font.outline_format(), OutlineFormat::CFF);
+
+    let insert = |font: &mut SfntFont, tag: &[u8; 4]| {
+        font.tables.insert(
+            FontTag::new(*tag),
+            NamedTable::Generic(Data::new(vec![0; 4])),
+        );
+    };
+    insert(&mut font, b"CFF2");
+    assert_eq!(font.outline_format(), OutlineFormat::CFF2);
+    // A 'glyf' table alone is not enough for TrueType outlines
+    insert(&mut font, b"glyf");
+    assert_eq!(font.outline_format(), OutlineFormat::CFF2);
+    insert(&mut font, b"loca");
+    assert_eq!(font.outline_format(), OutlineFormat::TrueType);
+
+    for tag in [b"glyf", b"loca", b"CFF2", b"CFF "] {
+        font.tables.remove(&FontTag::new(*tag));
+    }
+    assert_eq!(font.outline_format(), OutlineFormat::None);
+}
+
+/// Swaps the first two directory entries of the test font, so the directory
+/// is no longer sorted by tag.
+fn font_data_with_unsorted_directory() -> Vec<u8> {
+    let mut font_data = include_bytes!("../../../.devtools/font.otf").to_vec();
+    let (first, second) = font_data[12..44].split_at_mut(16);
+    first.swap_with_slice(second);
+    font_data
+}
+
+#[test]
+fn test_unsorted_directory_round_trip() {
+    let font_data = font_data_with_unsorted_directory();
+    let mut font = SfntFont::from_reader(&mut Cursor::new(&font_data)).unwrap();
+    assert_eq!(font.tables.len(), 11);
+    let mut output = Vec::new();
+    font.write(&mut output).unwrap();
+    // By default the directory is written sorted by tag
+    let font = SfntFont::from_reader(&mut Cursor::new(&output)).unwrap();
+    let tags = font
+        .directory
+        .entries()
+        .iter()
+        .map(|entry| entry.tag)
+        .collect::<Vec<_>>();
+    assert!(tags.is_sorted());
+    // With the same tables as the original
+    let original = SfntFont::from_reader(&mut Cursor::new(include_bytes!(
+        "../../../.devtools/font.otf"
+    )))
+    .unwrap();
+    for (tag, table) in original.tables.iter() {
+        let mut expected = Vec::new();
+        table.write(&mut expected).unwrap();
+        let mut actual = Vec::new();
+        font.tables[tag].write(&mut actual).unwrap();
+        assert_eq!(expected, actual, "Table {tag} differs");
+    }
+}
+
+#[test]
+fn test_unsorted_directory_as_read_order() {
+    let font_data = font_data_with_unsorted_directory();
+    let mut font = SfntFont::from_reader(&mut Cursor::new(&font_data)).unwrap();
+    font.set_table_order(TableOrder::AsRead);
+    assert_eq!(font.table_order(), TableOrder::AsRead);
+    let mut output = Vec::new();
+    font.write(&mut output).unwrap();
+    // The exact input is replayed
+    assert_eq!(font_data, output);
+
+    // And an added C2PA table is listed last
+    let record = ContentCredentialRecord::builder()
+        .with_version(0, 1)
+        .with_active_manifest_uri("https://example.com".to_string())
+        .build()
+        .unwrap();
+    font.add_c2pa_record(record).unwrap();
+    let mut output = Vec::new();
+    font.write(&mut output).unwrap();
+    let font = SfntFont::from_reader(&mut Cursor::new(&output)).unwrap();
+    let entries = font.directory.entries();
+    assert_eq!(entries.len(), 12);
+    assert_eq!(entries[11].tag, FontTag::C2PA);
+    assert_eq!(entries[0].tag, FontTag::DSIG);
+    assert_eq!(entries[1].tag, FontTag::new(*b"CFF "));
+}
+
+#[test]
+fn test_write_with_physical_table_order() {
+    let font_data = include_bytes!("../../../.devtools/font.otf");
+    let original = SfntFont::from_reader(&mut Cursor::new(font_data)).unwrap();
+    let mut font = original.clone();
+    let order = [FontTag::POST, FontTag::C2PA, FontTag::OS2, FontTag::HEAD];
+    font.set_physical_table_order(order);
+    assert_eq!(font.physical_table_order(), order);
+    font.add_c2pa_record(ContentCredentialRecord::default())
+        .unwrap();
+    let mut output = Vec::new();
+    font.write(&mut output).unwrap();
+    let font = SfntFont::from_reader(&mut Cursor::new(&output)).unwrap();
+
+    // The directory is still sorted by tag
+    let entries = font.directory.entries();
+    assert!(entries.windows(2).all(|w| w[0].tag < w[1].tag));
+    // While the data of the listed tables comes first, in the given order,
+    // followed by the others as read, and the C2PA table last
+    let physical_order = font
+        .directory
+        .physical_order()
+        .iter()
+        .map(|entry| entry.tag)
+        .collect::<Vec<_>>();
+    let others = original
+        .directory
+        .physical_order()
+        .iter()
+        .map(|entry| entry.tag)
+        .filter(|tag| !order.contains(tag))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        physical_order[..3],
+        [FontTag::POST, FontTag::OS2, FontTag::HEAD]
+    );
+    assert_eq!(physical_order[3..physical_order.len() - 1], others);
+    assert_eq!(physical_order.last(), Some(&FontTag::C2PA));
+    // With the tables themselves unchanged
+    for (tag, table) in original.tables_iter() {
+        if *tag != FontTag::HEAD {
+            assert_eq!(font.table_checksum(tag), Some(table.checksum().0));
+        }
+    }
+    assert_eq!(
+        utils::checksum(&output).0,
+        SFNT_EXPECTED_CHECKSUM,
+        "Expected a valid font checksum"
+    );
+}
+
+#[test]
+fn test_tables_iter() {
+    let font_data = include_bytes!("../../../.devtools/font.otf");
+    let font = SfntFont::from_reader(&mut Cursor::new(font_data)).unwrap();
+    let tags = font.tables_iter().map(|(tag, _)| *tag).collect::<Vec<_>>();
+    assert_eq!(tags.len(), 11);
+    assert!(tags.is_sorted());
+    assert!(tags.contains(&FontTag::HEAD));
+    // Each table is the same as looked up by tag
+    for (tag, table) in font.tables_iter() {
+        assert!(std::ptr::eq(table, font.table(tag).unwrap()));
+    }
+}
+
+#[test]
+fn test_can_add_c2pa() {
+    let font_data = include_bytes!("../../../.devtools/font.otf");
+    let mut font = SfntFont::from_reader(&mut Cursor::new(font_data)).unwrap();
+    assert!(font.can_add_c2pa().is_ok());
+
+    // Not when there is already a C2PA table
+    let mut with_c2pa = font.clone();
+    with_c2pa
+        .add_c2pa_record(ContentCredentialRecord::default())
+        .unwrap();
+    assert!(matches!(
+        with_c2pa.can_add_c2pa(),
+        Err(FontSaveError::UnexpectedTable(tag)) if tag == "C2PA"
+    ));
+
+    // Nor when another table was already added
+    font.tables.insert(
+        FontTag::new(*b"test"),
+        NamedTable::Generic(Data::new(vec![0; 4])),
+    );
+    assert!(matches!(
+        font.can_add_c2pa(),
+        Err(FontSaveError::TooManyTablesAdded)
+    ));
+    // And the font was not modified by the checks
+    assert_eq!(font.tables.len(), 12);
+    assert!(!font.contains_table(&FontTag::C2PA));
+}
+
+#[test]
+fn test_can_add_c2pa_with_too_many_tables() {
+    let mut font = SfntFont::default();
+    for index in 0..u16::MAX {
+        font.tables.insert(
+            FontTag::new(index.to_be_bytes().repeat(2).try_into().unwrap()),
+            NamedTable::Generic(Data::new(vec![0; 4])),
+        );
+    }
+    font.header.numTables = u16::MAX;
+    assert!(matches!(
+        font.can_add_c2pa(),
+        Err(FontSaveError::TooManyTables(65536))
+    ));
+}
+
+#[test]
+fn test_manifest_store_reader() {
+    let font_data = include_bytes!("../../../.devtools/font.otf");
+    let mut font = SfntFont::from_reader(&mut Cursor::new(font_data)).unwrap();
+    let manifest_store = (0..=255).cycle().take(5000).collect::<Vec<u8>>();
+    let record = ContentCredentialRecord::builder()
+        .with_active_manifest_uri("https://example.com".to_string())
+        .with_content_credential(manifest_store.clone())
+        .build()
+        .unwrap();
+    font.add_c2pa_record(record).unwrap();
+    // Place the font after some other data in the stream
+    let mut stream_data = vec![0xff; 7];
+    font.write(&mut stream_data).unwrap();
+
+    let mut reader = Cursor::new(stream_data);
+    let mut store_reader =
+        SfntFont::manifest_store_reader(&mut reader, 7).unwrap();
+    let mut actual = Vec::new();
+    store_reader.read_to_end(&mut actual).unwrap();
+    assert_eq!(manifest_store, actual);
+}
+
+#[test]
+fn test_manifest_store_reader_without_manifest_store() {
+    // No C2PA table at all
+    let font_data = include_bytes!("../../../.devtools/font.otf");
+    let mut reader = Cursor::new(font_data);
+    let result = SfntFont::manifest_store_reader(&mut reader, 0);
+    assert!(matches!(
+        result.err(),
+        Some(FontIoError::ContentCredentialNotFound)
+    ));
+
+    // Or a C2PA table with only a URI
+    let mut font = SfntFont::from_reader(&mut Cursor::new(font_data)).unwrap();
+    let record = ContentCredentialRecord::builder()
+        .with_active_manifest_uri("https://example.com".to_string())
+        .build()
+        .unwrap();
+    font.add_c2pa_record(record).unwrap();
+    let mut stream_data = Vec::new();
+    font.write(&mut stream_data).unwrap();
+    let mut reader = Cursor::new(stream_data);
+    let result = SfntFont::manifest_store_reader(&mut reader, 0);
+    assert!(matches!(
+        result.err(),
+        Some(FontIoError::ContentCredentialNotFound)
+    ));
+}
+
+#[test]
+fn test_embedded_bitmap() {
+    let font_data = include_bytes!("../../../.devtools/font.otf");
+    let mut font = SfntFont::from_reader(&mut Cursor::new(font_data)).unwrap();
+    // No embedded bitmaps
+    assert_eq!(font.embedded_bitmap(1, 12).unwrap(), None);
+
+    // A single 12 ppem, 1-bit strike, with a 3x2 bitmap for glyph 1
+    let mut eblc_data = vec![
+        0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x01, // version, strikes
+        0x00, 0x00, 0x00, 0x38, 0x00, 0x00, 0x00,
+        0x00, // array offset, size
+        0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x00, // subtables, color
+    ];
+    eblc_data.extend_from_slice(&[0; 24]);
+    eblc_data.extend_from_slice(&[
+        0x00, 0x01, 0x00, 0x01, 0x0c, 0x0c, 0x01,
+        0x01, // glyph 1, 12 ppem
+        0x00, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00, 0x08, // subtable array
+        0x00, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00, 0x04, // index subtable
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x07, // offsets
+    ]);
+    let ebdt_data = vec![
+        0x00,
+        0x02,
+        0x00,
+        0x00, // version
+        0x02,
+        0x03,
+        0x00,
+        0x02,
+        0x04,
+        0b1010_0000,
+        0b0100_0000, // glyph 1
+    ];
+    font.tables
+        .insert(FontTag::EBLC, NamedTable::Generic(Data::new(eblc_data)));
+    // Missing the EBDT table
+    assert_eq!(font.embedded_bitmap(1, 12).unwrap(), None);
+    font.tables
+        .insert(FontTag::EBDT, NamedTable::Generic(Data::new(ebdt_data)));
+
+    let bitmap = font.embedded_bitmap(1, 12).unwrap().unwrap();
+    assert_eq!(bitmap.format(), EmbeddedBitmapFormat::Monochrome);
+    assert_eq!(bitmap.pixels, vec![255, 0, 255, 0, 255, 0]);
+    // The nearest strike is used
+    assert_eq!(font.embedded_bitmap(1, 16).unwrap(), Some(bitmap));
+    assert_eq!(font.embedded_bitmap(2, 12).unwrap(), None);
+
+    // A truncated EBDT table
+    font.tables
+        .insert(FontTag::EBDT, NamedTable::Generic(Data::new(vec![0x00])));
+    assert!(matches!(
+        font.embedded_bitmap(1, 12),
+        Err(FontIoError::LoadTableTruncated(FontTag::EBDT))
+    ));
+}
+
+#[test]
+fn test_verify_name_matches() {
+    let font_data = include_bytes!("../../../.devtools/font.otf");
+    let mut font = SfntFont::from_reader(&mut Cursor::new(font_data)).unwrap();
+    assert!(font.verify_name_matches("AnEmptyFont"));
+    assert!(!font.verify_name_matches("AnEmptyFont Regular"));
+    assert!(!font.verify_name_matches("anemptyfont"));
+
+    // A name table which fails to parse never matches
+    font.tables
+        .insert(FontTag::NAME, NamedTable::Generic(Data::new(vec![0x00])));
+    assert!(!font.verify_name_matches("AnEmptyFont"));
+    font.tables.remove(&FontTag::NAME);
+    assert!(!font.verify_name_matches("AnEmptyFont"));
+}
+
+#[test]
+fn test_style_attributes() {
+    let font_data = include_bytes!("../../../.devtools/font.otf");
+    let mut font = SfntFont::from_reader(&mut Cursor::new(font_data)).unwrap();
+    assert!(font.style_attributes().is_none());
+
+    // A 'wght' axis, with a "Regular" value named by name ID 2
+    let stat_data = vec![
+        0x00, 0x01, 0x00, 0x01, 0x00, 0x08, 0x00, 0x01, // version, axes
+        0x00, 0x00, 0x00, 0x14, 0x00, 0x01, 0x00, 0x00, // offsets, values
+        0x00, 0x1c, 0x00, 0x02, // value offsets, elided fallback name ID
+        b'w', b'g', b'h', b't', 0x01, 0x00, 0x00, 0x00, // 'wght' axis
+        0x00, 0x02, // axis value offset
+        0x00, 0x01, 0x00, 0x00, 0x00, 0x02, 0x00, 0x02, // format 1
+        0x01, 0x90, 0x00, 0x00, // 400
+    ];
+    font.tables
+        .insert(FontTag::STAT, NamedTable::Generic(Data::new(stat_data)));
+    let style = font.style_attributes().unwrap();
+    assert_eq!(style.design_axes[0].axisTag, FontTag::new(*b"wght"));
+    assert_eq!(style.elided_fallback_name.as_deref(), Some("Regular"));
+    assert_eq!(style.axis_values.len(), 1);
+    assert_eq!(style.axis_values[0].0.value_name_id(), 2);
+    assert_eq!(style.axis_values[0].1.as_deref(), Some("Regular"));
+
+    // Names are not resolved without a 'name' table
+    font.tables.remove(&FontTag::NAME);
+    let style = font.style_attributes().unwrap();
+    assert_eq!(style.elided_fallback_name, None);
+    assert_eq!(style.axis_values[0].1, None);
+}
+
+#[test]
+fn test_c2pa_table_is_physically_last() {
+    let font_data = include_bytes!("../../../.devtools/font.otf");
+    for table_order in [TableOrder::SortedByTag, TableOrder::AsRead] {
+        let mut font =
+            SfntFont::from_reader(&mut Cursor::new(font_data)).unwrap();
+        font.set_table_order(table_order);
+        font.add_c2pa_record(ContentCredentialRecord::default())
+            .unwrap();
+        let mut output = Vec::new();
+        font.write(&mut output).unwrap();
+        let font = SfntFont::from_reader(&mut Cursor::new(&output)).unwrap();
+        let entries = font.directory.entries();
+        // The directory is in the requested order...
+        let c2pa_index = match table_order {
+            TableOrder::SortedByTag => {
+                assert!(entries.windows(2).all(|w| w[0].tag < w[1].tag));
+                0
+            }
+            TableOrder::AsRead => entries.len() - 1,
+        };
+        assert_eq!(entries[c2pa_index].tag, FontTag::C2PA);
+        // ...but the C2PA table's data always comes last
+        let last = font.directory.physical_order().last().unwrap().tag;
+        assert_eq!(last, FontTag::C2PA);
+        let c2pa = &entries[c2pa_index];
+        assert_eq!(
+            align_to_four(c2pa.offset + c2pa.length) as usize,
+            output.len()
+        );
+    }
+}
+
+#[test]
+fn test_parse_cmap_table() {
+    let font_data = include_bytes!("../../../.devtools/font.otf");
+    let font = SfntFont::from_reader(&mut Cursor::new(font_data)).unwrap();
+    let cmap = font
+        .parse_table::<crate::sfnt::table::TableCmap>(&FontTag::CMAP)
+        .unwrap()
+        .unwrap();
+    let subtables = cmap
+        .subtables()
+        .map(|subtable| {
+            (subtable.platformID, subtable.encodingID, subtable.format)
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(subtables, vec![(0, 3, 4), (1, 0, 6), (3, 1, 4)]);
+    // The format 4 and format 6 subtables agree
+    for (platform_id, encoding_id) in [(0, 3), (1, 0), (3, 1)] {
+        assert_eq!(
+            cmap.glyph_for_char_in(platform_id, encoding_id, ' '),
+            Some(3)
+        );
+        assert_eq!(
+            cmap.glyph_for_char_in(platform_id, encoding_id, 'A'),
+            Some(4)
+        );
+        assert_eq!(cmap.glyph_for_char_in(platform_id, encoding_id, 'B'), None);
+    }
+    assert_eq!(cmap.glyph_for_char('\r'), Some(2));
+}
+
+#[test]
+fn test_simple_glyph_run() {
+    let font_data = include_bytes!("../../../.devtools/font.otf");
+    let mut font = SfntFont::from_reader(&mut Cursor::new(font_data)).unwrap();
+    // 'B' is not in the font, so it maps to the missing glyph
+    assert_eq!(
+        font.simple_glyph_run("A B"),
+        vec![(4, 639), (3, 250), (0, 250)]
+    );
+    assert!(font.simple_glyph_run("").is_empty());
+
+    // Without metrics, there is no run
+    font.tables.remove(&FontTag::HMTX);
+    assert!(font.simple_glyph_run("A").is_empty());
+}
+
+/// Inserts a gap of eight bytes ahead of the second table, in physical order,
+/// of the test font, returning the font data and the offset of the gap.
+fn font_with_gap() -> (Vec<u8>, usize) {
+    font_with_gap_of(8)
+}
+
+/// Inserts a gap of the given length ahead of the second table, in physical
+/// order, of the test font, returning the font data and the offset of the
+/// gap.
+fn font_with_gap_of(length: u32) -> (Vec<u8>, usize) {
+    let mut font_data = include_bytes!("../../../.devtools/font.otf").to_vec();
+    let num_tables = u16::from_be_bytes([font_data[4], font_data[5]]) as usize;
+    let offset_of = |data: &[u8], index: usize| {
+        let start = SfntHeader::SIZE + index * SfntDirectoryEntry::SIZE + 8;
+        u32::from_be_bytes(data[start..start + 4].try_into().unwrap())
+    };
+    let mut offsets = (0..num_tables)
+        .map(|index| offset_of(&font_data, index))
+        .collect::<Vec<_>>();
+    offsets.sort();
+    let gap_offset = offsets[1];
+    for index in 0..num_tables {
+        let offset = offset_of(&font_data, index);
+        if offset >= gap_offset {
+            let start = SfntHeader::SIZE + index * SfntDirectoryEntry::SIZE + 8;
+            font_data[start..start + 4]
+                .copy_from_slice(&(offset + length).to_be_bytes());
+        }
+    }
+    let gap_offset = gap_offset as usize;
+    font_data.splice(gap_offset..gap_offset, vec![0xab; length as usize]);
+    (font_data, gap_offset)
+}
+
+#[test]
+fn test_preserve_layout_keeps_gaps() {
+    let (font_data, gap_offset) = font_with_gap();
+    let options = SfntReadOptions::default().with_preserve_layout(true);
+    let mut font = SfntFont::from_reader_with_options(
+        &mut Cursor::new(&font_data),
+        &options,
+    )
+    .unwrap();
+    assert_eq!(font.layout_gaps.len(), 1);
+    let mut written = Vec::new();
+    font.write(&mut written).unwrap();
+    assert_eq!(written.len(), font_data.len());
+    assert_eq!(&written[gap_offset..gap_offset + 8], &[0xab; 8]);
+    // Only the head table's checksum adjustment changes, to account for the
+    // gap, and the whole font still checksums correctly
+    let head_offset = font.table_offsets()[&FontTag::HEAD].0 as usize;
+    let adjustment = head_offset + 8..head_offset + 12;
+    assert_eq!(&written[..adjustment.start], &font_data[..adjustment.start]);
+    assert_eq!(&written[adjustment.end..], &font_data[adjustment.end..]);
+    assert_eq!(crate::utils::checksum(&written).0, SFNT_EXPECTED_CHECKSUM);
+
+    // Adding a C2PA table moves the tables, but keeps the gap ahead of the
+    // same table
+    let record = ContentCredentialRecord::builder()
+        .with_version(0, 1)
+        .with_active_manifest_uri("https://example.com".to_string())
+        .build()
+        .unwrap();
+    font.add_c2pa_record(record).unwrap();
+    let mut with_c2pa = Vec::new();
+    font.write(&mut with_c2pa).unwrap();
+    let gap_offset = gap_offset + SfntDirectoryEntry::SIZE;
+    assert_eq!(&with_c2pa[gap_offset..gap_offset + 8], &[0xab; 8]);
+    assert_eq!(crate::utils::checksum(&with_c2pa).0, SFNT_EXPECTED_CHECKSUM);
+}
+
+#[test]
+fn test_computed_size_with_preserved_layout() {
+    let options = SfntReadOptions::default().with_preserve_layout(true);
+    let record = ContentCredentialRecord::builder()
+        .with_active_manifest_uri("https://example.com".to_string())
+        .build()
+        .unwrap();
+    for length in [3, 8] {
+        let (font_data, _) = font_with_gap_of(length);
+        let mut font = SfntFont::from_reader_with_options(
+            &mut Cursor::new(&font_data),
+            &options,
+        )
+        .unwrap();
+        assert!(!font.layout_gaps.is_empty());
+        let size = font.computed_size();
+        let mut written = Vec::new();
+        font.write(&mut written).unwrap();
+        assert_eq!(size, written.len() as u64, "gap of {length} bytes");
+
+        // Including with a C2PA table
+        font.add_c2pa_record(record.clone()).unwrap();
+        let size = font.computed_size();
+        let mut written = Vec::new();
+        font.write(&mut written).unwrap();
+        assert_eq!(size, written.len() as u64, "gap of {length} bytes");
+    }
+}
+
+#[test]
+fn test_c2pa_table_offset_is_aligned() {
+    let preserve_layout = SfntReadOptions::default().with_preserve_layout(true);
+    let mut fonts = vec![SfntFont::from_reader(&mut Cursor::new(
+        include_bytes!("../../../.devtools/font.otf"),
+    ))
+    .unwrap()];
+    // Including fonts with gaps between the tables, even unaligned ones
+    for length in [3, 8] {
+        let (font_data, _) = font_with_gap_of(length);
+        fonts.push(
+            SfntFont::from_reader_with_options(
+                &mut Cursor::new(&font_data),
+                &preserve_layout,
+            )
+            .unwrap(),
+        );
+    }
+    let mut rng = TestRng(0x00c2_0a00_0a11);
+    for _ in 0..16 {
+        let font_data = random_sfnt(&mut rng);
+        fonts
+            .push(SfntFont::from_reader(&mut Cursor::new(&font_data)).unwrap());
+    }
+
+    for font in fonts {
+        // Vary the length of the C2PA table, and so the padding it needs
+        for length in 0..8 {
+            let mut font = font.clone();
+            let record = ContentCredentialRecord::builder()
+                .with_version(0, 1)
+                .with_content_credential(vec![0x5a; length])
+                .build()
+                .unwrap();
+            font.add_c2pa_record(record).unwrap();
+            let mut written = Vec::new();
+            font.write(&mut written).unwrap();
+            let offsets = SfntFont::from_reader(&mut Cursor::new(&written))
+                .unwrap()
+                .table_offsets();
+            assert!(offsets.contains_key(&FontTag::C2PA));
+            for (tag, (offset, _)) in offsets {
+                assert_eq!(offset % 4, 0, "Table {tag} is not aligned");
+            }
+            assert_eq!(
+                crate::utils::checksum(&written).0,
+                SFNT_EXPECTED_CHECKSUM
+            );
+        }
+    }
+}
+
+#[test]
+fn test_without_preserve_layout_drops_gaps() {
+    let (font_data, _) = font_with_gap();
+    let mut font = SfntFont::from_reader(&mut Cursor::new(&font_data)).unwrap();
+    let mut written = Vec::new();
+    font.write(&mut written).unwrap();
+    assert_eq!(written.len(), font_data.len() - 8);
+    assert_eq!(crate::utils::checksum(&written).0, SFNT_EXPECTED_CHECKSUM);
+}
+
+#[test]
+fn test_load_font_with_degenerate_head_table() {
+    let mut font_data = include_bytes!("../../../.devtools/font.otf").to_vec();
+    let num_tables = u16::from_be_bytes([font_data[4], font_data[5]]) as usize;
+    let head_entry = (0..num_tables)
+        .map(|index| SfntHeader::SIZE + index * SfntDirectoryEntry::SIZE)
+        .find(|start| font_data[*start..*start + 4] == *b"head")
+        .unwrap();
+    // Claim a 'head' table of only two bytes
+    font_data[head_entry + 12..head_entry + 16]
+        .copy_from_slice(&2_u32.to_be_bytes());
+    let result = SfntFont::from_reader(&mut Cursor::new(&font_data));
+    assert!(matches!(
+        result,
+        Err(FontIoError::LoadTableTruncated(FontTag::HEAD))
+    ));
+}
+
+#[test]
+fn test_vendor_id() {
+    let font_data = include_bytes!("../../../.devtools/font.otf");
+    let mut font = SfntFont::from_reader(&mut Cursor::new(font_data)).unwrap();
+    // The test font's vendor ID is blank
+    assert_eq!(font.vendor_id(), None);
+
+    let Some(NamedTable::Generic(os2)) = font.tables.get_mut(&FontTag::OS2)
+    else {
+        panic!("Expected an 'OS/2' table");
+    };
+    os2.data[58..62].copy_from_slice(b"UKWN");
+    assert_eq!(font.vendor_id(), Some(FontTag::new(*b"UKWN")));
+
+    font.tables.remove(&FontTag::OS2);
+    assert_eq!(font.vendor_id(), None);
+}
+
+#[test]
+fn test_post_table_metrics() {
+    let font_data = include_bytes!("../../../.devtools/font.otf");
+    let mut font = SfntFont::from_reader(&mut Cursor::new(font_data)).unwrap();
+    // The test font is upright and proportional
+    assert_eq!(font.is_monospace(), Some(false));
+    assert_eq!(font.post_italic_angle(), Some(0.0));
+
+    let Some(NamedTable::Generic(post)) = font.tables.get_mut(&FontTag::POST)
+    else {
+        panic!("Expected a 'post' table");
+    };
+    post.data[4..8].copy_from_slice(&(-12_i32 << 16).to_be_bytes());
+    post.data[12..16].copy_from_slice(&1_u32.to_be_bytes());
+    assert_eq!(font.is_monospace(), Some(true));
+    assert_eq!(font.post_italic_angle(), Some(-12.0));
+
+    font.tables.remove(&FontTag::POST);
+    assert_eq!(font.is_monospace(), None);
+    assert_eq!(font.post_italic_angle(), None);
+}
+
+#[test]
+fn test_fontforge_timestamps() {
+    let font_data = include_bytes!("../../../.devtools/font.otf");
+    let mut font = SfntFont::from_reader(&mut Cursor::new(font_data)).unwrap();
+    // The test font was not generated by FontForge
+    assert_eq!(font.fontforge_timestamps(), None);
+
+    let fftm_data = vec![
+        0x00, 0x00, 0x00, 0x01, // version 1
+        0x00, 0x00, 0x00, 0x00, 0xe1, 0x2b, 0x3c, 0x4d, // FFTimeStamp
+        0x00, 0x00, 0x00, 0x00, 0xd5, 0x00, 0x00, 0x00, // sourceCreated
+        0x00, 0x00, 0x00, 0x00, 0xe0, 0x00, 0x00, 0x01, // sourceModified
+    ];
+    // Lay the table out after the others
+    let end = font
+        .table_offsets()
+        .values()
+        .map(|(offset, length)| offset + length)
+        .max()
+        .unwrap();
+    font.directory.add_entry(SfntDirectoryEntry {
+        tag: FontTag::FFTM,
+        offset: align_to_four(end),
+        checksum: 0,
+        length: fftm_data.len() as u32,
+    });
+    font.insert_table(
+        FontTag::FFTM,
+        NamedTable::Generic(Data::new(fftm_data.clone())),
+    );
+    let expected = TableFftm {
+        version: 1,
+        FFTimeStamp: 0xe12b3c4d,
+        sourceCreated: 0xd5000000,
+        sourceModified: 0xe0000001,
+    };
+    assert_eq!(font.fontforge_timestamps(), Some(expected.clone()));
+
+    // The table is written back unchanged
+    let mut output = Vec::new();
+    font.write(&mut output).unwrap();
+    let font = SfntFont::from_reader(&mut Cursor::new(&output)).unwrap();
+    assert_eq!(font.fontforge_timestamps(), Some(expected));
+    assert!(matches!(
+        font.table(&FontTag::FFTM),
+        Some(NamedTable::Generic(fftm)) if fftm.data == fftm_data
+    ));
+}
+
+#[test]
+fn test_vertical_origin() {
+    let font_data = include_bytes!("../../../.devtools/font.otf");
+    let mut font = SfntFont::from_reader(&mut Cursor::new(font_data)).unwrap();
+    // The test font has no 'VORG' table
+    assert_eq!(font.vertical_origin(4), None);
+
+    let vorg_data = vec![
+        0x00, 0x01, 0x00, 0x00, // version 1.0
+        0x03, 0x70, // defaultVertOriginY: 880
+        0x00, 0x01, // numVertOriginYMetrics
+        0x00, 0x04, 0x03, 0x84, // glyph 4: 900
+    ];
+    font.insert_table(FontTag::VORG, NamedTable::Generic(Data::new(vorg_data)));
+    assert_eq!(font.vertical_origin(4), Some(900));
+    assert_eq!(font.vertical_origin(3), Some(880));
+
+    // A truncated table fails to parse
+    font.insert_table(
+        FontTag::VORG,
+        NamedTable::Generic(Data::new(vec![0x00, 0x01])),
+    );
+    assert_eq!(font.vertical_origin(4), None);
+}
+
+#[test]
+fn test_write_reuses_cached_table_checksums() {
+    let font_data = include_bytes!("../../../.devtools/font.otf");
+    let mut font = SfntFont::from_reader(&mut Cursor::new(font_data)).unwrap();
+    // Only the raw tables have cached checksums, and each one matches the
+    // checksum of the table's data.
+    assert!(!font.table_checksums.is_empty());
+    assert!(!font.table_checksums.contains_key(&FontTag::HEAD));
+    for (tag, checksum) in &font.table_checksums {
+        let table = font.tables.get(tag).unwrap();
+        assert!(matches!(table, NamedTable::Generic(_)));
+        assert_eq!(*checksum, table.checksum().0, "checksum of {tag}");
+    }
+
+    // Writing the font with the cached checksums gives the same result as
+    // summing every table again.
+    let mut cached = Cursor::new(Vec::new());
+    font.write(&mut cached).unwrap();
+    font.table_checksums.clear();
+    let mut computed = Cursor::new(Vec::new());
+    font.write(&mut computed).unwrap();
+    assert_eq!(cached.into_inner(), computed.into_inner());
+}
+
+#[test]
+fn test_write_reuses_directory_checksums() {
+    let mut font_data = include_bytes!("../../../.devtools/font.otf").to_vec();
+    let name_index = (0..11)
+        .position(|index| {
+            let start = SfntHeader::SIZE + index * SfntDirectoryEntry::SIZE;
+            font_data[start..start + 4] == *b"name"
+        })
+        .unwrap();
+    // Corrupt the checksum listed for the 'name' table, so any recomputed
+    // checksum would show
+    let checksum_offset =
+        SfntHeader::SIZE + name_index * SfntDirectoryEntry::SIZE + 4;
+    font_data[checksum_offset] ^= 0xff;
+
+    // Reading takes the checksums from the directory, without summing
+    let mut font = SfntFont::from_reader(&mut Cursor::new(&font_data)).unwrap();
+    let listed = font
+        .directory
+        .entries()
+        .iter()
+        .map(|entry| (entry.tag, entry.checksum))
+        .collect::<BTreeMap<_, _>>();
+    assert_ne!(
+        font.table_checksums[&FontTag::NAME],
+        font.tables[&FontTag::NAME].checksum().0
+    );
+    assert_eq!(font.table_checksums[&FontTag::NAME], listed[&FontTag::NAME]);
+
+    // Writing only recomputes the checksums of the changed tables
+    let record = ContentCredentialRecord::builder()
+        .with_content_credential(vec![0x00, 0x01, 0x02, 0x03])
+        .build()
+        .unwrap();
+    font.add_c2pa_record(record).unwrap();
+    let mut output = Vec::new();
+    font.write(&mut output).unwrap();
+    let font = SfntFont::from_reader(&mut Cursor::new(&output)).unwrap();
+    for entry in font.directory.entries() {
+        let checksum = entry.checksum;
+        if entry.tag == FontTag::C2PA || entry.tag == FontTag::HEAD {
+            assert_eq!(checksum, font.tables[&entry.tag].checksum().0);
+        } else {
+            assert_eq!(checksum, listed[&entry.tag], "{}", entry.tag);
+        }
+    }
+    // Including the stale one
+    assert_ne!(
+        font.table_checksums[&FontTag::NAME],
+        font.tables[&FontTag::NAME].checksum().0
+    );
+}
+
+/// A small deterministic pseudo-random number generator (SplitMix64), so the
+/// round-trip tests below explore many table sets while staying reproducible.
+struct TestRng(u64);
+
+impl TestRng {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a value in the range `0..bound`.
+    fn below(&mut self, bound: usize) -> usize {
+        (self.next_u64() % bound as u64) as usize
+    }
+
+    fn bytes(&mut self, length: usize) -> Vec<u8> {
+        (0..length).map(|_| self.next_u64() as u8).collect()
+    }
+}
+
+/// Builds the data of an SFNT font with a valid 'head' table and a random set
+/// of other tables, placed in a random physical order with random gaps.
+fn random_sfnt(rng: &mut TestRng) -> Vec<u8> {
+    let font_data = include_bytes!("../../../.devtools/font.otf");
+    let font = SfntFont::from_reader(&mut Cursor::new(font_data)).unwrap();
+    let NamedTable::Head(head) = &font.tables[&FontTag::HEAD] else {
+        panic!("Expected a 'head' table");
+    };
+    let mut head_data = Vec::new();
+    head.write(&mut head_data).unwrap();
+    // Leave off the padding
+    head_data.truncate(head.len() as usize);
+    let mut tables = BTreeMap::from([(FontTag::HEAD, head_data)]);
+    for _ in 0..rng.below(16) {
+        let tag = FontTag::new([
+            b'a' + rng.below(26) as u8,
+            b'a' + rng.below(26) as u8,
+            b'a' + rng.below(26) as u8,
+            b'a' + rng.below(26) as u8,
+        ]);
+        let length = rng.below(200);
+        tables.insert(tag, rng.bytes(length));
+    }
+    let mut physical = tables.keys().copied().collect::<Vec<_>>();
+    for index in (1..physical.len()).rev() {
+        physical.swap(index, rng.below(index + 1));
+    }
+
+    let header = SfntHeader {
+        numTables: tables.len() as u16,
+        ..Default::default()
+    };
+    let mut font_data = Vec::new();
+    header.write(&mut font_data).unwrap();
+    let mut offset = SfntHeader::SIZE + SfntDirectoryEntry::SIZE * tables.len();
+    let mut offsets = BTreeMap::new();
+    for tag in &physical {
+        offset += rng.below(3) * 4;
+        offsets.insert(*tag, offset);
+        offset += tables[tag].len().div_ceil(4) * 4;
+    }
+    for (tag, table) in &tables {
+        let entry = SfntDirectoryEntry {
+            tag: *tag,
+            checksum: crate::utils::checksum(table).0,
+            offset: offsets[tag] as u32,
+            length: table.len() as u32,
+        };
+        entry.write(&mut font_data).unwrap();
+    }
+    font_data.resize(offset, 0);
+    for (tag, table) in &tables {
+        font_data[offsets[tag]..offsets[tag] + table.len()]
+            .copy_from_slice(table);
+    }
+    font_data
+}
+
+/// Checks the structural invariants of written SFNT font data: a sorted
+/// directory, aligned and non-overlapping tables within the data, correct
+/// table checksums, and a correct whole-font checksum.
+fn assert_sfnt_invariants(font_data: &[u8]) {
+    let mut reader = Cursor::new(font_data);
+    let header = SfntHeader::from_reader(&mut reader).unwrap();
+    let directory = SfntDirectory::from_reader_with_count(
+        &mut reader,
+        header.numTables as usize,
+    )
+    .unwrap();
+    assert_eq!(
+        (header.searchRange, header.entrySelector, header.rangeShift),
+        SfntHeader::compute_search_params(header.numTables)
+    );
+    let tags = directory
+        .entries()
+        .iter()
+        .map(|entry| entry.tag)
+        .collect::<Vec<_>>();
+    assert!(tags.is_sorted(), "Directory is not sorted: {tags:?}");
+    let mut previous_end =
+        SfntHeader::SIZE + SfntDirectoryEntry::SIZE * tags.len();
+    for entry in directory.physical_order() {
+        let (offset, length) = (entry.offset as usize, entry.length as usize);
+        assert_eq!(offset % 4, 0, "Table {} is not aligned", entry.tag);
+        // Empty tables may share their offset with the next table
+        assert!(
+            offset >= previous_end || length == 0,
+            "Table {} overlaps",
+            entry.tag
+        );
+        assert!(offset + length <= font_data.len());
+        let mut table = font_data[offset..offset + length].to_vec();
+        if entry.tag == FontTag::HEAD {
+            // The checksum adjustment is treated as zero
+            table[8..12].fill(0);
+        }
+        let checksum = entry.checksum;
+        assert_eq!(crate::utils::checksum(&table).0, checksum);
+        previous_end = previous_end.max(offset + length.div_ceil(4) * 4);
+    }
+    // The last table (i.e., the C2PA table, which is written unpadded) may
+    // end the data without padding
+    assert!(font_data.len() <= previous_end);
+    assert!(font_data.len() + 4 > previous_end);
+    assert_eq!(crate::utils::checksum(font_data).0, SFNT_EXPECTED_CHECKSUM);
+}
+
+#[test]
+fn test_random_table_sets_round_trip() {
+    let mut rng = TestRng(0x00c2_0a00_f047);
+    for _ in 0..256 {
+        let font_data = random_sfnt(&mut rng);
+        let mut font =
+            SfntFont::from_reader(&mut Cursor::new(&font_data)).unwrap();
+
+        // Mutate a random table, changing its length
+        let generic_tags = font
+            .tables
+            .iter()
+            .filter(|(_, table)| matches!(table, NamedTable::Generic(_)))
+            .map(|(tag, _)| *tag)
+            .collect::<Vec<_>>();
+        if !generic_tags.is_empty() {
+            let tag = generic_tags[rng.below(generic_tags.len())];
+            let length = rng.below(200);
+            font.insert_table(
+                tag,
+                NamedTable::Generic(Data::new(rng.bytes(length))),
+            );
+        }
+        // Maybe add a C2PA table
+        if rng.below(2) == 0 {
+            let length = rng.below(64);
+            let record = ContentCredentialRecord::builder()
+                .with_version(0, 1)
+                .with_content_credential(rng.bytes(length))
+                .build()
+                .unwrap();
+            font.add_c2pa_record(record).unwrap();
+        }
+        let expected = font
+            .tables
+            .iter()
+            .map(|(tag, table)| (*tag, table.len()))
+            .collect::<Vec<_>>();
+
+        let mut written = Vec::new();
+        font.write(&mut written).unwrap();
+        assert_sfnt_invariants(&written);
+
+        // Reading the font back gives the same tables, and writing it again
+        // gives the same data
+        let mut reread =
+            SfntFont::from_reader(&mut Cursor::new(&written)).unwrap();
+        let actual = reread
+            .tables
+            .iter()
+            .map(|(tag, table)| (*tag, table.len()))
+            .collect::<Vec<_>>();
+        assert_eq!(actual, expected);
+        for (tag, table) in &font.tables {
+            if let (
+                NamedTable::Generic(expected),
+                NamedTable::Generic(actual),
+            ) = (table, &reread.tables[tag])
+            {
+                assert_eq!(actual.data, expected.data);
+            }
+        }
+        let mut rewritten = Vec::new();
+        reread.write(&mut rewritten).unwrap();
+        assert_eq!(rewritten, written);
+    }
+}
+
+#[test]
+fn test_all_names() {
+    let font_data = include_bytes!("../../../.devtools/font.otf");
+    let font = SfntFont::from_reader(&mut Cursor::new(font_data)).unwrap();
+    let names = font.all_names();
+    let family = &names[&1];
+    assert_eq!(family.len(), 2);
+    assert_eq!(family[0].platform_id, 1);
+    assert_eq!(family[0].language_tag.as_deref(), Some("en"));
+    assert_eq!(family[1].platform_id, 3);
+    assert_eq!(family[1].language_id, 0x0409);
+    assert_eq!(family[1].language_tag.as_deref(), Some("en-US"));
+    assert!(family.iter().all(|name| name.value == "AnEmptyFont"));
+    // Names only recorded for one platform have a single variant
+    assert_eq!(names[&3].len(), 1);
+
+    // Without a 'name' table, there are no names
+    let mut font = font;
+    font.tables.remove(&FontTag::NAME);
+    assert!(font.all_names().is_empty());
+}
+
+#[test]
+fn test_hash_excluding() {
+    let font_data = include_bytes!("../../../.devtools/font.otf").to_vec();
+    let font = SfntFont::from_reader(&mut Cursor::new(&font_data)).unwrap();
+    let offsets = font.table_offsets();
+    let cff = FontTag::new(*b"CFF ");
+    let hash = |data: &[u8], exclude: &[FontTag]| {
+        let mut hasher = Vec::new();
+        SfntFont::hash_excluding(&mut Cursor::new(data), exclude, &mut hasher)
+            .unwrap();
+        hasher
+    };
+    // Everything but the header, directory, and checksum adjustment
+    let all = hash(&font_data, &[]);
+    let table_bytes: u32 = offsets.values().map(|(_, length)| length).sum();
+    assert_eq!(all.len() as u32, table_bytes - 4);
+    let without_cff = hash(&font_data, &[cff]);
+    assert_eq!(all.len() - without_cff.len(), offsets[&cff].1 as usize);
+    let without_head = hash(&font_data, &[FontTag::HEAD]);
+    assert_eq!(all.len() - without_head.len(), 50);
+
+    // Changing an excluded table only changes the full hash
+    let mut changed = font_data.clone();
+    changed[offsets[&cff].0 as usize] ^= 0xff;
+    assert_ne!(hash(&changed, &[]), all);
+    assert_eq!(hash(&changed, &[cff]), without_cff);
+
+    // While the checksum adjustment is never hashed
+    let mut changed = font_data.clone();
+    changed[offsets[&FontTag::HEAD].0 as usize + 8] ^= 0xff;
+    assert_eq!(hash(&changed, &[]), all);
+
+    // A hashed table beyond the end of the stream is an error
+    let truncated = &font_data[..font_data.len() - 1];
+    let result = SfntFont::hash_excluding(
+        &mut Cursor::new(truncated),
+        &[],
+        &mut Vec::new(),
+    );
+    assert!(matches!(result, Err(FontIoError::LoadTableTruncated(_))));
+}
+
+#[test]
+fn test_outline_fingerprint() {
+    let font_data = include_bytes!("../../../.devtools/font.otf").to_vec();
+    let fingerprint = |data: &[u8]| {
+        SfntFont::outline_fingerprint(&mut Cursor::new(data)).unwrap()
+    };
+    let expected = fingerprint(&font_data);
+
+    // Adding a C2PA table does not change the fingerprint
+    let record = ContentCredentialRecord::builder()
+        .with_content_credential(vec![0x00])
+        .build()
+        .unwrap();
+    let mut font = SfntFont::from_reader(&mut Cursor::new(&font_data)).unwrap();
+    font.add_c2pa_record(record).unwrap();
+    let mut with_c2pa = Vec::new();
+    font.write(&mut with_c2pa).unwrap();
+    assert_ne!(with_c2pa, font_data);
+    assert_eq!(fingerprint(&with_c2pa), expected);
+
+    // Nor does changing the metadata
+    let offsets = SfntFont::from_reader(&mut Cursor::new(&with_c2pa))
+        .unwrap()
+        .table_offsets();
+    let mut changed = with_c2pa.clone();
+    changed[offsets[&FontTag::NAME].0 as usize + 6] ^= 0xff;
+    assert_eq!(fingerprint(&changed), expected);
+
+    // But changing the outlines does
+    let cff = FontTag::new(*b"CFF ");
+    let mut changed = with_c2pa.clone();
+    changed[offsets[&cff].0 as usize] ^= 0xff;
+    assert_ne!(fingerprint(&changed), expected);
+
+    // An outline table beyond the end of the stream is an error
+    let end = (offsets[&cff].0 + offsets[&cff].1) as usize;
+    let result =
+        SfntFont::outline_fingerprint(&mut Cursor::new(&with_c2pa[..end - 1]));
+    assert!(
+        matches!(result, Err(FontIoError::LoadTableTruncated(tag)) if tag == cff)
+    );
+
+    // A font without outline tables has no fingerprint
+    let mut font = SfntFont::from_reader(&mut Cursor::new(&font_data)).unwrap();
+    font.tables.remove(&cff);
+    let mut without_outlines = Vec::new();
+    font.write(&mut without_outlines).unwrap();
+    let result =
+        SfntFont::outline_fingerprint(&mut Cursor::new(&without_outlines));
+    assert!(matches!(result, Err(FontIoError::TableNotFound(_))));
+}
+
+#[cfg(feature = "woff")]
+#[test]
+fn test_outline_fingerprint_matches_woff() {
+    use crate::woff1::font::Woff1Font;
+    let font_data = include_bytes!("../../../.devtools/font.otf");
+    let woff_data = include_bytes!("../../../.devtools/font.woff");
+    let woff_font =
+        Woff1Font::from_reader(&mut Cursor::new(woff_data)).unwrap();
+    let mut font = SfntFont::try_from(woff_font).unwrap();
+    let mut converted = Vec::new();
+    font.write(&mut converted).unwrap();
+    assert_eq!(
+        SfntFont::outline_fingerprint(&mut Cursor::new(&converted)).unwrap(),
+        SfntFont::outline_fingerprint(&mut Cursor::new(font_data)).unwrap()
+    );
+}
+
+#[test]
+fn test_hashable_bytes() {
+    let font_data = include_bytes!("../../../.devtools/font.otf");
+    let font = SfntFont::from_reader(&mut Cursor::new(font_data)).unwrap();
+    let bytes = SfntFont::hashable_bytes(&mut Cursor::new(font_data)).unwrap();
+    // Everything but the header, directory, and checksum adjustment
+    let directory_end = SfntHeader::SIZE + font.directory.entries().len() * 16;
+    let adjustment = font.table_offsets()[&FontTag::HEAD].0 as usize + 8;
+    let mut expected = font_data[directory_end..adjustment].to_vec();
+    expected.extend_from_slice(&font_data[adjustment + 4..]);
+    assert_eq!(bytes, expected);
+
+    // The C2PA table is hashed, along with any padding
+    let mut font = font;
+    let record = ContentCredentialRecord::builder()
+        .with_content_credential(vec![0x00, 0x01, 0x02])
+        .build()
+        .unwrap();
+    font.add_c2pa_record(record).unwrap();
+    let mut writer = Cursor::new(Vec::new());
+    font.write(&mut writer).unwrap();
+    let written = writer.into_inner();
+    let bytes = SfntFont::hashable_bytes(&mut Cursor::new(&written)).unwrap();
+    assert_eq!(bytes.len(), written.len() - (directory_end + 16) - 4);
+
+    // And a header which is not a font is an error
+    assert!(SfntFont::hashable_bytes(&mut Cursor::new(vec![0u8; 4])).is_err());
+}
@@ -14,18 +14,21 @@
 
 //! SFNT font file header.
 
-use std::{
-    io::{Read, Seek, Write},
-    num::Wrapping,
-};
+use core::num::Wrapping;
+#[cfg(feature = "std")]
+use std::io::{Read, Seek, Write};
 
-use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
+use byteorder::{BigEndian, ByteOrder};
+#[cfg(feature = "std")]
+use byteorder::{ReadBytesExt, WriteBytesExt};
 
+use super::directory::SfntDirectoryEntry;
 use crate::{
     error::FontIoError, magic::Magic, utils::u32_from_u16_pair,
-    FontDataChecksum, FontDataExactRead, FontDataRead, FontDataWrite,
-    FontHeader,
+    FontDataChecksum,
 };
+#[cfg(feature = "std")]
+use crate::{FontDataExactRead, FontDataRead, FontDataWrite, FontHeader};
 
 /// All the serialization structures so far have been defined using native
 /// Rust types; should we go all-out in the other direction, and establish a
@@ -51,6 +54,67 @@ pub struct SfntHeader {
 impl SfntHeader {
     /// The size of an SFNT header.
     pub(crate) const SIZE: usize = 12;
+
+    /// Computes the `searchRange`, `entrySelector` and `rangeShift` fields
+    /// for the given number of tables, returned in that order.
+    ///
+    /// # Remarks
+    /// With no tables all three fields are zero. For table counts large
+    /// enough that the fields overflow 16 bits (4096 tables and up), they are
+    /// saturated to `u16::MAX`.
+    pub fn compute_search_params(num_tables: u16) -> (u16, u16, u16) {
+        if num_tables == 0 {
+            return (0, 0, 0);
+        }
+        let entry_size = SfntDirectoryEntry::SIZE as u32;
+        let entry_selector = num_tables.ilog2();
+        let search_range = (1_u32 << entry_selector) * entry_size;
+        let range_shift = num_tables as u32 * entry_size - search_range;
+        (
+            search_range.min(u16::MAX as u32) as u16,
+            entry_selector as u16,
+            range_shift.min(u16::MAX as u32) as u16,
+        )
+    }
+
+    /// Checks that the `searchRange`, `entrySelector` and `rangeShift` fields
+    /// are those derived from the number of tables (see
+    /// [`SfntHeader::compute_search_params`]).
+    ///
+    /// # Errors
+    /// Returns [`FontIoError::InconsistentHeaderFields`] if any of the fields
+    /// differ.
+    pub fn validate_search_params(&self) -> Result<(), FontIoError> {
+        let expected = Self::compute_search_params(self.numTables);
+        let found = (self.searchRange, self.entrySelector, self.rangeShift);
+        if found != expected {
+            return Err(FontIoError::InconsistentHeaderFields {
+                num_tables: self.numTables,
+                expected,
+                found,
+            });
+        }
+        Ok(())
+    }
+
+    /// Reads an SFNT header from the start of the given bytes.
+    ///
+    /// # Errors
+    /// Returns [`FontIoError::InvalidSizeForHeader`] if there are fewer bytes
+    /// than a header takes, or [`FontIoError::UnknownMagic`] if the SFNT
+    /// version isn't recognized.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, FontIoError> {
+        let bytes = bytes
+            .get(..Self::SIZE)
+            .ok_or(FontIoError::InvalidSizeForHeader(bytes.len()))?;
+        Ok(Self {
+            sfntVersion: Magic::try_from(BigEndian::read_u32(&bytes[0..4]))?,
+            numTables: BigEndian::read_u16(&bytes[4..6]),
+            searchRange: BigEndian::read_u16(&bytes[6..8]),
+            entrySelector: BigEndian::read_u16(&bytes[8..10]),
+            rangeShift: BigEndian::read_u16(&bytes[10..12]),
+        })
+    }
 }
 
 impl Default for SfntHeader {
@@ -64,6 +128,7 @@ impl Default for SfntHeader {
         }
     }
 }
+#[cfg(feature = "std")]
 impl FontDataRead for SfntHeader {
     type Error = FontIoError;
 
@@ -80,6 +145,7 @@ impl FontDataRead for SfntHeader {
     }
 }
 
+#[cfg(feature = "std")]
 impl FontDataExactRead for SfntHeader {
     type Error = FontIoError;
 
@@ -96,6 +162,7 @@ impl FontDataExactRead for SfntHeader {
     }
 }
 
+#[cfg(feature = "std")]
 impl FontDataWrite for SfntHeader {
     type Error = FontIoError;
 
@@ -123,12 +190,13 @@ impl FontDataChecksum for SfntHeader {
     }
 }
 
+#[cfg(feature = "std")]
 impl FontHeader for SfntHeader {
     fn num_tables(&self) -> u16 {
         self.numTables
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 #[path = "header_test.rs"]
 mod tests;
@@ -161,3 +161,57 @@ fn test_sfnt_header_num_tables() {
     };
     assert_eq!(header.num_tables(), 3);
 }
+
+#[test]
+fn test_sfnt_header_compute_search_params() {
+    assert_eq!(SfntHeader::compute_search_params(0), (0, 0, 0));
+    assert_eq!(SfntHeader::compute_search_params(1), (16, 0, 0));
+    assert_eq!(SfntHeader::compute_search_params(8), (128, 3, 0));
+    assert_eq!(SfntHeader::compute_search_params(11), (128, 3, 48));
+    assert_eq!(SfntHeader::compute_search_params(4095), (32768, 11, 32752));
+    // Too large for the fields, which saturate
+    assert_eq!(
+        SfntHeader::compute_search_params(u16::MAX),
+        (u16::MAX, 15, u16::MAX)
+    );
+}
+
+#[test]
+fn test_sfnt_header_validate_search_params() {
+    let mut header = SfntHeader {
+        numTables: 11,
+        searchRange: 128,
+        entrySelector: 3,
+        rangeShift: 48,
+        ..Default::default()
+    };
+    assert!(header.validate_search_params().is_ok());
+    assert!(SfntHeader::default().validate_search_params().is_ok());
+
+    header.rangeShift = 32;
+    let result = header.validate_search_params();
+    assert!(matches!(
+        result,
+        Err(FontIoError::InconsistentHeaderFields {
+            num_tables: 11,
+            expected: (128, 3, 48),
+            found: (128, 3, 32),
+        })
+    ));
+}
+
+#[test]
+fn test_sfnt_header_from_bytes() {
+    let font_data = include_bytes!("../../../.devtools/font.otf");
+    let header = SfntHeader::from_bytes(font_data).unwrap();
+    let expected =
+        SfntHeader::from_reader(&mut Cursor::new(font_data)).unwrap();
+    assert_eq!({ header.sfntVersion }, Magic::OpenType);
+    assert_eq!({ header.numTables }, 11);
+    assert_eq!(header.checksum(), expected.checksum());
+
+    let result = SfntHeader::from_bytes(&font_data[..11]);
+    assert!(matches!(result, Err(FontIoError::InvalidSizeForHeader(11))));
+    let result = SfntHeader::from_bytes(&[0xff; 12]);
+    assert!(matches!(result, Err(FontIoError::UnknownMagic(0xffffffff))));
+}
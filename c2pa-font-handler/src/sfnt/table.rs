@@ -15,15 +15,67 @@
 //! SFNT font file table.
 
 pub(crate) mod c2pa;
+pub(crate) mod cmap;
+#[cfg(feature = "thumbnails")]
+pub(crate) mod colr;
+#[cfg(feature = "thumbnails")]
+pub(crate) mod cpal;
 pub(crate) mod dsig;
+pub(crate) mod ebdt;
+pub(crate) mod eblc;
+pub(crate) mod fftm;
+pub(crate) mod fvar;
 pub(crate) mod head;
+pub(crate) mod hhea;
+pub(crate) mod hmtx;
+pub(crate) mod loca;
+pub(crate) mod maxp;
+pub(crate) mod name;
 pub(crate) mod named_table;
+pub(crate) mod os2;
+pub(crate) mod post;
+pub(crate) mod stat;
+pub(crate) mod vorg;
 
 // Export C2PA table
-pub use c2pa::TableC2PA;
+pub use c2pa::{C2PALayout, TableC2PA};
+// Export cmap table
+pub use cmap::{CmapSource, CmapSubtable, TableCmap};
+// Export COLR table
+#[cfg(feature = "thumbnails")]
+pub use colr::{ColrBaseGlyph, ColrLayer, TableColr};
+// Export CPAL table
+#[cfg(feature = "thumbnails")]
+pub use cpal::{CpalColor, TableCpal};
 // Export DSIG table
 pub use dsig::TableDSIG;
+// Export EBDT table
+pub use ebdt::{EmbeddedBitmap, EmbeddedBitmapFormat, TableEbdt};
+// Export EBLC table
+pub use eblc::{BigGlyphMetrics, EblcStrike, TableEblc};
+// Export FFTM table
+pub use fftm::TableFftm;
+// Export fvar table
+pub use fvar::{FvarAxis, FvarInstance, TableFvar};
 // Export head table
 pub use head::TableHead;
+// Export hhea table
+pub use hhea::TableHhea;
+// Export hmtx table
+pub use hmtx::TableHmtx;
+// Export loca table
+pub use loca::TableLoca;
+// Export maxp table
+pub use maxp::{TableMaxp, TableMaxpV1};
+// Export name table
+pub use name::{LocalizedName, NameRecord, TableName};
 // Export named table
 pub use named_table::NamedTable;
+// Export OS/2 table
+pub use os2::TableOs2;
+// Export post table
+pub use post::TablePost;
+// Export STAT table
+pub use stat::{StatAxisRecord, StatAxisValue, TableStat};
+// Export VORG table
+pub use vorg::TableVorg;
@@ -16,7 +16,6 @@
 
 use std::{
     io::{Read, Seek, SeekFrom},
-    mem::size_of,
     num::Wrapping,
 };
 
@@ -58,48 +57,22 @@ pub struct TableC2PARaw {
 
 impl TableC2PARaw {
     /// The minimum required size of a C2PA table.
-    const MINIMUM_SIZE: usize = 20;
+    pub(crate) const MINIMUM_SIZE: usize = 20;
 
     pub(crate) fn from_table(c2pa: &TableC2PA) -> Result<Self, FontIoError> {
+        let layout = c2pa.layout();
         Ok(Self {
             majorVersion: c2pa.major_version,
             minorVersion: c2pa.minor_version,
-            activeManifestUriOffset: if let Some(_uri) =
-                &c2pa.active_manifest_uri
-            {
-                Self::MINIMUM_SIZE as u32
-            } else {
-                0
-            },
-            activeManifestUriLength: if let Some(uri) =
-                &c2pa.active_manifest_uri
-            {
-                uri.len() as u16
-            } else {
-                0
-            },
+            activeManifestUriOffset: layout.uri_offset,
+            activeManifestUriLength: layout.uri_len,
             reserved: 0,
-            manifestStoreOffset: if let Some(_manifest_store) =
-                &c2pa.manifest_store
-            {
-                size_of::<TableC2PARaw>() as u32
-                    + if let Some(uri) = &c2pa.active_manifest_uri {
-                        uri.len() as u32
-                    } else {
-                        0_u32
-                    }
-            } else {
-                0_u32
-            },
-            manifestStoreLength: if let Some(store) = &c2pa.manifest_store {
-                store.len() as u32
-            } else {
-                0
-            },
+            manifestStoreOffset: layout.store_offset,
+            manifestStoreLength: layout.store_len,
         })
     }
 
-    fn from_reader<T: Read + ?Sized>(
+    pub(crate) fn from_reader<T: Read + ?Sized>(
         reader: &mut T,
     ) -> Result<Self, FontIoError> {
         Ok(Self {
@@ -112,6 +85,56 @@ impl TableC2PARaw {
             manifestStoreLength: reader.read_u32::<BigEndian>()?,
         })
     }
+
+    /// Checks the sections of the table are laid out as the specification
+    /// requires: each present section follows the header, an absent section
+    /// has both a NULL offset and a zero length, and the active manifest URI
+    /// ends before the manifest store starts.
+    ///
+    /// # Errors
+    /// Returns [`FontIoError::MalformedC2paTable`] describing the first
+    /// problem found.
+    pub(crate) fn validate_layout(&self) -> Result<(), FontIoError> {
+        let malformed =
+            |detail: String| Err(FontIoError::MalformedC2paTable(detail));
+        let header_len = Self::MINIMUM_SIZE as u64;
+        let sections = [
+            (
+                "active manifest URI",
+                self.activeManifestUriOffset,
+                self.activeManifestUriLength as u32,
+            ),
+            (
+                "manifest store",
+                self.manifestStoreOffset,
+                self.manifestStoreLength,
+            ),
+        ];
+        for (name, offset, length) in sections {
+            if (offset == 0) != (length == 0) {
+                return malformed(format!(
+                    "the {name} has offset {offset} but length {length}"
+                ));
+            }
+            if offset != 0 && (offset as u64) < header_len {
+                return malformed(format!(
+                    "the {name} at offset {offset} overlaps the header"
+                ));
+            }
+        }
+        let uri_end = self.activeManifestUriOffset as u64
+            + self.activeManifestUriLength as u64;
+        if self.activeManifestUriOffset != 0
+            && self.manifestStoreOffset != 0
+            && (self.manifestStoreOffset as u64) < uri_end
+        {
+            return malformed(format!(
+                "the manifest store at offset {} starts before the end of the active manifest URI at offset {uri_end}",
+                { self.manifestStoreOffset }
+            ));
+        }
+        Ok(())
+    }
 }
 
 impl FontDataWrite for TableC2PARaw {
@@ -149,6 +172,24 @@ impl FontTable for TableC2PARaw {
     }
 }
 
+/// Where the active manifest URI and the manifest store sit within a 'C2PA'
+/// table, as they are written by [`TableC2PA`].
+///
+/// # Remarks
+/// Offsets are from the beginning of the table. A section which is not
+/// present has an offset and length of zero.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct C2PALayout {
+    /// Offset to the active manifest URI.
+    pub uri_offset: u32,
+    /// Length of the active manifest URI in bytes.
+    pub uri_len: u16,
+    /// Offset to the manifest store.
+    pub store_offset: u32,
+    /// Length of the manifest store in bytes.
+    pub store_len: u32,
+}
+
 /// 'C2PA' font table, fully loaded.
 #[derive(Clone, Debug)]
 pub struct TableC2PA {
@@ -162,6 +203,61 @@ pub struct TableC2PA {
     pub manifest_store: Option<Vec<u8>>,
 }
 
+impl TableC2PA {
+    /// Computes the offsets and lengths of the active manifest URI and the
+    /// manifest store within the table, without serializing it.
+    ///
+    /// # Remarks
+    /// The URI immediately follows the fixed-size header, and the manifest
+    /// store immediately follows the URI.
+    pub fn layout(&self) -> C2PALayout {
+        let header_len = TableC2PARaw::MINIMUM_SIZE as u32;
+        let uri_len = self
+            .active_manifest_uri
+            .as_ref()
+            .map_or(0, |uri| uri.len() as u16);
+        let (uri_offset, store_start) = match &self.active_manifest_uri {
+            Some(_) => (header_len, header_len + uri_len as u32),
+            None => (0, header_len),
+        };
+        let (store_offset, store_len) = match &self.manifest_store {
+            Some(store) => (store_start, store.len() as u32),
+            None => (0, 0),
+        };
+        C2PALayout {
+            uri_offset,
+            uri_len,
+            store_offset,
+            store_len,
+        }
+    }
+}
+
+impl TableC2PA {
+    /// Reads the table as [`FontDataExactRead::from_reader_exact`] does, but
+    /// also rejects a table whose sections are not laid out as the
+    /// specification requires (e.g., with a manifest store overlapping the
+    /// active manifest URI), even if it could be read.
+    ///
+    /// # Errors
+    /// Returns [`FontIoError::MalformedC2paTable`] if the layout of the table
+    /// is not conformant, or an error if the table cannot be read.
+    pub fn from_reader_strict<T: Read + Seek + ?Sized>(
+        reader: &mut T,
+        offset: u64,
+        size: usize,
+    ) -> Result<Self, FontIoError> {
+        if size < TableC2PARaw::MINIMUM_SIZE {
+            return Err(FontIoError::LoadTableTruncated(FontTag::C2PA));
+        }
+        reader.seek(SeekFrom::Start(offset))?;
+        TableC2PARaw::from_reader(reader)
+            .map_err(|_| FontIoError::LoadTableTruncated(FontTag::C2PA))?
+            .validate_layout()?;
+        Self::from_reader_exact(reader, offset, size)
+    }
+}
+
 impl FontDataExactRead for TableC2PA {
     type Error = FontIoError;
 
@@ -171,7 +267,7 @@ impl FontDataExactRead for TableC2PA {
         size: usize,
     ) -> Result<Self, Self::Error> {
         // We need at least the amount of the raw table to read in
-        if size < size_of::<TableC2PARaw>() {
+        if size < TableC2PARaw::MINIMUM_SIZE {
             return Err(FontIoError::LoadTableTruncated(FontTag::C2PA));
         }
         reader.seek(SeekFrom::Start(offset))?;
@@ -184,11 +280,24 @@ impl FontDataExactRead for TableC2PA {
         let raw_table: TableC2PARaw = TableC2PARaw::from_reader(reader)
             .map_err(|_| FontIoError::LoadTableTruncated(FontTag::C2PA))?;
 
-        // Sanity check we are reading the correct amount of data
-        if (TableC2PARaw::MINIMUM_SIZE
-            + raw_table.activeManifestUriLength as usize
-            + raw_table.manifestStoreLength as usize)
-            != size
+        // The size given must exactly match the size described by the header,
+        // so we never read past (or stop short of) the end of the table
+        let uri_len = raw_table.activeManifestUriLength as usize;
+        let store_len = raw_table.manifestStoreLength as usize;
+        let expected_size = TableC2PARaw::MINIMUM_SIZE
+            .checked_add(uri_len)
+            .and_then(|len| len.checked_add(store_len));
+        if expected_size != Some(size) {
+            return Err(FontIoError::LoadTableTruncated(FontTag::C2PA));
+        }
+        // And both the URI and the manifest store must lie within the table
+        let fits = |offset: u32, len: usize| {
+            (offset as usize)
+                .checked_add(len)
+                .is_some_and(|end| end <= size)
+        };
+        if !fits(raw_table.activeManifestUriOffset, uri_len)
+            || !fits(raw_table.manifestStoreOffset, store_len)
         {
             return Err(FontIoError::LoadTableTruncated(FontTag::C2PA));
         }
@@ -277,7 +386,7 @@ impl FontTable for TableC2PA {
     fn len(&self) -> u32 {
         // The length of the table is the length of the raw table plus the
         // length of the active manifest URI and the manifest store.
-        let mut len = size_of::<TableC2PARaw>() as u32;
+        let mut len = TableC2PARaw::MINIMUM_SIZE as u32;
         if let Some(uri) = &self.active_manifest_uri {
             len += uri.len() as u32;
         }
@@ -448,6 +448,56 @@ fn test_table_c2pa_raw_from_table_c2pa_with_no_uri() {
     assert_eq!(content_credential_length, 4);
 }
 
+#[test]
+fn test_table_c2pa_layout() {
+    let table = TableC2PA {
+        major_version: 1,
+        minor_version: 4,
+        active_manifest_uri: Some("http://example.com".to_string()),
+        manifest_store: Some(vec![1, 2, 3, 4]),
+    };
+    let layout = table.layout();
+    assert_eq!(
+        layout,
+        C2PALayout {
+            uri_offset: 20,
+            uri_len: 18,
+            store_offset: 38,
+            store_len: 4,
+        }
+    );
+    // The layout matches where the sections are actually written
+    let mut data = Vec::new();
+    table.write(&mut data).unwrap();
+    let uri_start = layout.uri_offset as usize;
+    let uri_end = uri_start + layout.uri_len as usize;
+    assert_eq!(&data[uri_start..uri_end], b"http://example.com");
+    let store_start = layout.store_offset as usize;
+    let store_end = store_start + layout.store_len as usize;
+    assert_eq!(&data[store_start..store_end], &[1, 2, 3, 4]);
+    assert_eq!(store_end, table.len() as usize);
+}
+
+#[test]
+fn test_table_c2pa_layout_with_missing_sections() {
+    let table = TableC2PA {
+        major_version: 1,
+        minor_version: 4,
+        active_manifest_uri: None,
+        manifest_store: Some(vec![1, 2, 3, 4]),
+    };
+    assert_eq!(
+        table.layout(),
+        C2PALayout {
+            uri_offset: 0,
+            uri_len: 0,
+            store_offset: 20,
+            store_len: 4,
+        }
+    );
+    assert_eq!(TableC2PA::default().layout(), C2PALayout::default());
+}
+
 #[test]
 fn test_table_c2pa_update_remove_uri() {
     let mut table = TableC2PA {
@@ -611,6 +661,129 @@ fn test_table_c2pa_read_exact_with_invalid_uri_bytes() {
     assert!(matches!(error, FontIoError::StringFromUtf8(_)));
 }
 
+#[test]
+fn test_table_c2pa_read_exact_with_mismatched_size() {
+    let mut data = vec![];
+    data.extend_from_slice(&[0x00, 0x01]); // major_version
+    data.extend_from_slice(&[0x00, 0x04]); // minor_version
+    data.extend_from_slice(&[0x00, 0x00, 0x00, 0x14]); // active manifest uri offset
+    data.extend_from_slice(&[0x00, 0x04]); // active manifest uri length
+    data.extend_from_slice(&[0x00, 0x00]); // reserved
+    data.extend_from_slice(&[0x00, 0x00, 0x00, 0x00]); // content_credential offset
+    data.extend_from_slice(&[0x00, 0x00, 0x00, 0x00]); // content_credential length
+    data.extend_from_slice(b"test"); // active manifest uri
+    data.extend_from_slice(&[0x00; 8]); // trailing padding
+    let mut reader = Cursor::new(data);
+    // The header describes a 24 byte table, so anything else is rejected
+    for size in [22, 28, 32] {
+        let result = TableC2PA::from_reader_exact(&mut reader, 0, size);
+        assert!(
+            matches!(
+                result,
+                Err(FontIoError::LoadTableTruncated(FontTag::C2PA))
+            ),
+            "Expected LoadTableTruncated for size {size}"
+        );
+    }
+    let result = TableC2PA::from_reader_exact(&mut reader, 0, 24);
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_table_c2pa_read_exact_with_data_outside_table() {
+    let mut data = vec![];
+    data.extend_from_slice(&[0x00, 0x01]); // major_version
+    data.extend_from_slice(&[0x00, 0x04]); // minor_version
+    data.extend_from_slice(&[0x00, 0x00, 0x00, 0x16]); // active manifest uri offset
+    data.extend_from_slice(&[0x00, 0x04]); // active manifest uri length
+    data.extend_from_slice(&[0x00, 0x00]); // reserved
+    data.extend_from_slice(&[0x00, 0x00, 0x00, 0x00]); // content_credential offset
+    data.extend_from_slice(&[0x00, 0x00, 0x00, 0x00]); // content_credential length
+    data.extend_from_slice(b"testing"); // the URI runs past the table
+    let mut reader = Cursor::new(data);
+    let result = TableC2PA::from_reader_exact(&mut reader, 0, 24);
+    assert!(matches!(
+        result,
+        Err(FontIoError::LoadTableTruncated(FontTag::C2PA))
+    ));
+}
+
+/// Builds a C2PA table with the given section offsets and lengths, followed
+/// by the given body.
+fn c2pa_table_data(
+    uri_offset: u32,
+    uri_len: u16,
+    store_offset: u32,
+    store_len: u32,
+    body: &[u8],
+) -> Vec<u8> {
+    let mut data = vec![0x00, 0x00, 0x00, 0x01]; // major and minor versions
+    data.extend_from_slice(&uri_offset.to_be_bytes());
+    data.extend_from_slice(&uri_len.to_be_bytes());
+    data.extend_from_slice(&[0x00, 0x00]); // reserved
+    data.extend_from_slice(&store_offset.to_be_bytes());
+    data.extend_from_slice(&store_len.to_be_bytes());
+    data.extend_from_slice(body);
+    data
+}
+
+#[test]
+fn test_table_c2pa_read_strict() {
+    let data = c2pa_table_data(20, 4, 24, 4, b"uri!data");
+    let table =
+        TableC2PA::from_reader_strict(&mut Cursor::new(&data), 0, data.len())
+            .unwrap();
+    assert_eq!(table.active_manifest_uri.as_deref(), Some("uri!"));
+    assert_eq!(table.manifest_store.as_deref(), Some(&b"data"[..]));
+    // Absent sections are fine too
+    let data = c2pa_table_data(0, 0, 0, 0, b"");
+    let table =
+        TableC2PA::from_reader_strict(&mut Cursor::new(&data), 0, data.len())
+            .unwrap();
+    assert!(table.active_manifest_uri.is_none());
+    assert!(table.manifest_store.is_none());
+    // But a truncated table is still reported as such
+    let result = TableC2PA::from_reader_strict(&mut Cursor::new(&data), 0, 16);
+    assert!(matches!(
+        result,
+        Err(FontIoError::LoadTableTruncated(FontTag::C2PA))
+    ));
+}
+
+#[test]
+fn test_table_c2pa_read_strict_rejects_malformed_layouts() {
+    let malformed = [
+        // The manifest store overlaps the URI
+        c2pa_table_data(20, 4, 22, 4, b"uri!data"),
+        // The manifest store comes before the URI
+        c2pa_table_data(24, 4, 20, 4, b"datauri!"),
+        // The URI overlaps the header
+        c2pa_table_data(4, 4, 0, 0, b"uri!"),
+        // The URI has a length, but a NULL offset
+        c2pa_table_data(0, 4, 0, 0, b"uri!"),
+        // The manifest store has an offset, but no length
+        c2pa_table_data(0, 0, 20, 0, b""),
+    ];
+    for data in malformed {
+        // Each table can still be read leniently
+        assert!(TableC2PA::from_reader_exact(
+            &mut Cursor::new(&data),
+            0,
+            data.len()
+        )
+        .is_ok());
+        let result = TableC2PA::from_reader_strict(
+            &mut Cursor::new(&data),
+            0,
+            data.len(),
+        );
+        assert!(
+            matches!(result, Err(FontIoError::MalformedC2paTable(_))),
+            "Expected a malformed table error for {data:02x?}; found: {result:?}"
+        );
+    }
+}
+
 // Used to generate a test file for the C2PA table in the format of a font table
 #[ignore]
 #[test]
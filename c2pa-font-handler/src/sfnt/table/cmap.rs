@@ -0,0 +1,390 @@
+// Copyright 2025 Monotype Imaging Inc.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+//! 'cmap' SFNT table.
+
+use std::io::{Read, Seek, SeekFrom};
+
+use crate::{error::FontIoError, tag::FontTag, FontDataExactRead};
+
+/// A character-to-glyph mapping subtable available in the 'cmap' table.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[allow(non_snake_case)] // As named by Open Font Format / OpenType.
+pub struct CmapSubtable {
+    /// Platform ID.
+    pub platformID: u16,
+    /// Platform-specific encoding ID.
+    pub encodingID: u16,
+    /// Format of the subtable.
+    pub format: u16,
+}
+
+/// The kind of 'cmap' subtable a character was mapped by, as reported by
+/// [`TableCmap::lookup_with_source`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum CmapSource {
+    /// A Unicode subtable covering the full repertoire, i.e. Windows (3, 10)
+    /// or Unicode (0, 4) or (0, 6).
+    FullUnicode(CmapSubtable),
+    /// A Unicode subtable limited to the Basic Multilingual Plane, i.e.
+    /// Windows (3, 1) or Unicode (0, 0) through (0, 3).
+    Bmp(CmapSubtable),
+    /// A Windows symbol (3, 0) subtable.
+    Symbol(CmapSubtable),
+    /// A Macintosh Roman (1, 0) subtable, only queried for ASCII characters.
+    Macintosh(CmapSubtable),
+}
+
+impl CmapSource {
+    /// Returns the kind of the given subtable, or `None` for subtables which
+    /// are not used to map characters (e.g., Unicode variation sequences).
+    fn of(subtable: CmapSubtable) -> Option<Self> {
+        match (subtable.platformID, subtable.encodingID) {
+            (3, 10) | (0, 4) | (0, 6) => Some(Self::FullUnicode(subtable)),
+            (3, 1) | (0, 0..=3) => Some(Self::Bmp(subtable)),
+            (3, 0) => Some(Self::Symbol(subtable)),
+            (1, 0) => Some(Self::Macintosh(subtable)),
+            _ => None,
+        }
+    }
+
+    /// Returns the subtable the character was mapped by.
+    pub fn subtable(&self) -> CmapSubtable {
+        match self {
+            Self::FullUnicode(subtable)
+            | Self::Bmp(subtable)
+            | Self::Symbol(subtable)
+            | Self::Macintosh(subtable) => *subtable,
+        }
+    }
+}
+
+/// 'cmap' font table, mapping character codes to glyph IDs.
+///
+/// # Remarks
+/// A font may have several subtables, e.g. both a Macintosh (1, 0) and a
+/// Windows (3, 1) subtable, whose mappings differ; use
+/// [`TableCmap::glyph_for_char_in`] to query a particular one.
+#[derive(Clone, Debug)]
+pub struct TableCmap {
+    /// Table version number (0).
+    pub version: u16,
+    /// The subtables of the table, with their offsets.
+    encodings: Vec<(CmapSubtable, usize)>,
+    /// The data of the table, including the header.
+    data: Vec<u8>,
+}
+
+impl TableCmap {
+    /// The size of an encoding record.
+    const ENCODING_RECORD_SIZE: usize = 8;
+    /// The size of the table header.
+    const HEADER_SIZE: usize = 4;
+    /// Macintosh platform ID.
+    const PLATFORM_MACINTOSH: u16 = 1;
+    /// The platform and encoding IDs of the subtables used by
+    /// [`TableCmap::glyph_for_char`], in order of preference.
+    const PREFERRED_ENCODINGS: [(u16, u16); 8] = [
+        (3, 10),
+        (0, 6),
+        (0, 4),
+        (3, 1),
+        (0, 3),
+        (0, 2),
+        (0, 1),
+        (0, 0),
+    ];
+    /// The first code of the Private Use Area range symbol subtables commonly
+    /// map their single-byte codes into.
+    const SYMBOL_CODE_BASE: u32 = 0xf000;
+
+    /// Returns the subtables available in the table, in the order of their
+    /// encoding records.
+    pub fn subtables(&self) -> impl Iterator<Item = CmapSubtable> + '_ {
+        self.encodings.iter().map(|(subtable, _)| *subtable)
+    }
+
+    /// Returns the glyph ID for the character from the subtable with the
+    /// given platform and encoding IDs; or `None` if there is no such
+    /// subtable, its format is not supported, or it does not map the
+    /// character.
+    ///
+    /// # Remarks
+    /// The character is looked up by its Unicode scalar value; Macintosh
+    /// platform subtables are only queried for ASCII characters, whose codes
+    /// are shared with Unicode. Formats 0, 4, 6, 10, 12, and 13 are
+    /// supported.
+    pub fn glyph_for_char_in(
+        &self,
+        platform_id: u16,
+        encoding_id: u16,
+        c: char,
+    ) -> Option<u16> {
+        let (subtable, offset) =
+            self.encodings.iter().find(|(subtable, _)| {
+                subtable.platformID == platform_id
+                    && subtable.encodingID == encoding_id
+            })?;
+        if platform_id == Self::PLATFORM_MACINTOSH && !c.is_ascii() {
+            return None;
+        }
+        let data = self.data.get(*offset..)?;
+        let glyph_id = match subtable.format {
+            0 => lookup_format_0(data, c as u32),
+            4 => lookup_format_4(data, c as u32),
+            6 => lookup_format_6(data, c as u32),
+            10 => lookup_format_10(data, c as u32),
+            12 => lookup_format_12(data, c as u32, false),
+            13 => lookup_format_12(data, c as u32, true),
+            _ => None,
+        }?;
+        // Glyph 0 is the missing glyph
+        (glyph_id != 0).then_some(glyph_id)
+    }
+
+    /// Returns the glyph ID for the character from the preferred Unicode
+    /// subtable, i.e. the first of (3, 10), (0, 6), (0, 4), (3, 1), (0, 3),
+    /// (0, 2), (0, 1), and (0, 0) present in the table.
+    pub fn glyph_for_char(&self, c: char) -> Option<u16> {
+        let (platform_id, encoding_id) = Self::PREFERRED_ENCODINGS
+            .into_iter()
+            .find(|(platform, encoding)| {
+                self.subtables().any(|subtable| {
+                    subtable.platformID == *platform
+                        && subtable.encodingID == *encoding
+                })
+            })?;
+        self.glyph_for_char_in(platform_id, encoding_id, c)
+    }
+
+    /// Returns the glyph ID for the character along with the kind of subtable
+    /// which mapped it, or `None` if no subtable maps it.
+    ///
+    /// # Remarks
+    /// This is meant for diagnosing missing glyphs. Unlike
+    /// [`TableCmap::glyph_for_char`], which only consults the preferred
+    /// subtable, every subtable is consulted in turn: first the full Unicode
+    /// subtables, then the BMP ones, in the same order of preference, then
+    /// any symbol subtable, and finally any Macintosh one. A symbol subtable
+    /// is also queried for single-byte codes at their usual Private Use Area
+    /// offset (U+F000).
+    pub fn lookup_with_source(&self, c: char) -> Option<(u16, CmapSource)> {
+        let mut sources = self
+            .subtables()
+            .filter_map(CmapSource::of)
+            .collect::<Vec<_>>();
+        // Order the sources by preference, as the encoding records are sorted
+        // by platform and encoding IDs
+        sources.sort_by_key(|source| {
+            let subtable = source.subtable();
+            let ids = (subtable.platformID, subtable.encodingID);
+            match source {
+                CmapSource::FullUnicode(_) | CmapSource::Bmp(_) => {
+                    Self::PREFERRED_ENCODINGS
+                        .iter()
+                        .position(|preferred| *preferred == ids)
+                        .unwrap_or_default()
+                }
+                CmapSource::Symbol(_) => Self::PREFERRED_ENCODINGS.len(),
+                CmapSource::Macintosh(_) => Self::PREFERRED_ENCODINGS.len() + 1,
+            }
+        });
+        sources.into_iter().find_map(|source| {
+            let subtable = source.subtable();
+            let lookup = |c| {
+                self.glyph_for_char_in(
+                    subtable.platformID,
+                    subtable.encodingID,
+                    c,
+                )
+            };
+            let glyph_id = match source {
+                CmapSource::Symbol(_) => lookup(c).or_else(|| {
+                    u8::try_from(c)
+                        .ok()
+                        .and_then(|code| {
+                            char::from_u32(Self::SYMBOL_CODE_BASE | code as u32)
+                        })
+                        .and_then(lookup)
+                }),
+                _ => lookup(c),
+            }?;
+            Some((glyph_id, source))
+        })
+    }
+}
+
+/// Reads a big-endian `u16` at the given offset.
+fn read_u16(data: &[u8], offset: usize) -> Option<u16> {
+    let bytes = data.get(offset..offset.checked_add(2)?)?;
+    Some(u16::from_be_bytes([bytes[0], bytes[1]]))
+}
+
+/// Reads a big-endian `u32` at the given offset.
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    let bytes = data.get(offset..offset.checked_add(4)?)?;
+    Some(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+/// Looks up a code in a format 0 (byte encoding table) subtable.
+fn lookup_format_0(data: &[u8], code: u32) -> Option<u16> {
+    let code = u8::try_from(code).ok()?;
+    data.get(6 + code as usize).map(|glyph_id| *glyph_id as u16)
+}
+
+/// Looks up a code in a format 4 (segment mapping to delta values)
+/// subtable.
+fn lookup_format_4(data: &[u8], code: u32) -> Option<u16> {
+    let code = u16::try_from(code).ok()?;
+    let seg_count = read_u16(data, 6)? as usize / 2;
+    let end_codes = 14;
+    let start_codes = end_codes + seg_count * 2 + 2;
+    let id_deltas = start_codes + seg_count * 2;
+    let id_range_offsets = id_deltas + seg_count * 2;
+    // The segments are sorted by their end codes
+    let (mut low, mut high) = (0, seg_count);
+    while low < high {
+        let middle = (low + high) / 2;
+        if read_u16(data, end_codes + middle * 2)? < code {
+            low = middle + 1;
+        } else {
+            high = middle;
+        }
+    }
+    let segment = low;
+    if segment == seg_count {
+        return None;
+    }
+    let start_code = read_u16(data, start_codes + segment * 2)?;
+    if code < start_code {
+        return None;
+    }
+    let id_delta = read_u16(data, id_deltas + segment * 2)?;
+    let range_offset_position = id_range_offsets + segment * 2;
+    let id_range_offset = read_u16(data, range_offset_position)? as usize;
+    if id_range_offset == 0 {
+        return Some(code.wrapping_add(id_delta));
+    }
+    // The range offset is relative to its own position in the subtable
+    let glyph_position = range_offset_position
+        + id_range_offset
+        + (code - start_code) as usize * 2;
+    match read_u16(data, glyph_position)? {
+        0 => Some(0),
+        glyph_id => Some(glyph_id.wrapping_add(id_delta)),
+    }
+}
+
+/// Looks up a code in a format 6 (trimmed table mapping) subtable.
+fn lookup_format_6(data: &[u8], code: u32) -> Option<u16> {
+    let first_code = read_u16(data, 6)? as u32;
+    let entry_count = read_u16(data, 8)? as u32;
+    let index = code.checked_sub(first_code).filter(|i| *i < entry_count)?;
+    read_u16(data, 10 + index as usize * 2)
+}
+
+/// Looks up a code in a format 10 (trimmed array) subtable.
+fn lookup_format_10(data: &[u8], code: u32) -> Option<u16> {
+    let start_char_code = read_u32(data, 12)?;
+    let num_chars = read_u32(data, 16)?;
+    let index = code
+        .checked_sub(start_char_code)
+        .filter(|index| *index < num_chars)?;
+    read_u16(data, 20 + index as usize * 2)
+}
+
+/// Looks up a code in a format 12 (segmented coverage) or 13 (many-to-one
+/// range mappings) subtable.
+fn lookup_format_12(data: &[u8], code: u32, many_to_one: bool) -> Option<u16> {
+    let num_groups = read_u32(data, 12)? as usize;
+    let group = |index: usize| {
+        let offset = 16 + index * 12;
+        Some((
+            read_u32(data, offset)?,
+            read_u32(data, offset + 4)?,
+            read_u32(data, offset + 8)?,
+        ))
+    };
+    // The groups are sorted by their start codes
+    let (mut low, mut high) = (0, num_groups);
+    while low < high {
+        let middle = (low + high) / 2;
+        let (start, end, glyph_id) = group(middle)?;
+        if code < start {
+            high = middle;
+        } else if code > end {
+            low = middle + 1;
+        } else {
+            let glyph_id = if many_to_one {
+                glyph_id
+            } else {
+                glyph_id.checked_add(code - start)?
+            };
+            return u16::try_from(glyph_id).ok();
+        }
+    }
+    None
+}
+
+impl FontDataExactRead for TableCmap {
+    type Error = FontIoError;
+
+    fn from_reader_exact<T: Read + Seek + ?Sized>(
+        reader: &mut T,
+        offset: u64,
+        size: usize,
+    ) -> Result<Self, Self::Error> {
+        reader.seek(SeekFrom::Start(offset))?;
+        if size < Self::HEADER_SIZE {
+            return Err(FontIoError::LoadTableTruncated(FontTag::CMAP));
+        }
+        let mut data = vec![0; size];
+        reader
+            .read_exact(&mut data)
+            .map_err(|_| FontIoError::LoadTableTruncated(FontTag::CMAP))?;
+        let version = u16::from_be_bytes([data[0], data[1]]);
+        let num_tables = u16::from_be_bytes([data[2], data[3]]) as usize;
+        if Self::HEADER_SIZE + num_tables * Self::ENCODING_RECORD_SIZE > size {
+            return Err(FontIoError::LoadTableTruncated(FontTag::CMAP));
+        }
+        let encodings = (0..num_tables)
+            .map(|index| {
+                let record =
+                    Self::HEADER_SIZE + index * Self::ENCODING_RECORD_SIZE;
+                let platform_id = read_u16(&data, record)?;
+                let encoding_id = read_u16(&data, record + 2)?;
+                let offset = read_u32(&data, record + 4)? as usize;
+                let format = read_u16(&data, offset)?;
+                Some((
+                    CmapSubtable {
+                        platformID: platform_id,
+                        encodingID: encoding_id,
+                        format,
+                    },
+                    offset,
+                ))
+            })
+            .collect::<Option<Vec<_>>>()
+            .ok_or(FontIoError::LoadTableTruncated(FontTag::CMAP))?;
+        Ok(Self {
+            version,
+            encodings,
+            data,
+        })
+    }
+}
+
+#[cfg(test)]
+#[path = "cmap_test.rs"]
+mod tests;
@@ -0,0 +1,205 @@
+// Copyright 2025 Monotype Imaging Inc.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+//! Tests for the 'cmap' SFNT table module
+use std::io::Cursor;
+
+use super::*;
+
+/// A cmap table with a Macintosh (1, 0) format 0 subtable and a Windows
+/// (3, 10) format 12 subtable, which map 'A' differently.
+fn cmap_data() -> Vec<u8> {
+    let mut data = vec![
+        0x00, 0x00, // version 0
+        0x00, 0x02, // two subtables
+        0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x14, // (1, 0) at 20
+        0x00, 0x03, 0x00, 0x0a, 0x00, 0x00, 0x01, 0x1a, // (3, 10) at 282
+    ];
+    // Format 0, mapping 'A' to glyph 7
+    data.extend_from_slice(&[0x00, 0x00, 0x01, 0x06, 0x00, 0x00]);
+    let mut glyph_ids = [0u8; 256];
+    glyph_ids[b'A' as usize] = 7;
+    glyph_ids[0xe9] = 8;
+    data.extend_from_slice(&glyph_ids);
+    // Format 12, mapping 'A'..='C' to glyphs 10..=12, and U+1F600 to 20
+    data.extend_from_slice(&[
+        0x00, 0x0c, 0x00, 0x00, // format, reserved
+        0x00, 0x00, 0x00, 0x28, // length
+        0x00, 0x00, 0x00, 0x00, // language
+        0x00, 0x00, 0x00, 0x02, // two groups
+        0x00, 0x00, 0x00, 0x41, 0x00, 0x00, 0x00, 0x43, 0x00, 0x00, 0x00, 0x0a,
+        0x00, 0x01, 0xf6, 0x00, 0x00, 0x01, 0xf6, 0x00, 0x00, 0x00, 0x00, 0x14,
+    ]);
+    data
+}
+
+#[test]
+fn test_reader_exact() {
+    let data = cmap_data();
+    let cmap =
+        TableCmap::from_reader_exact(&mut Cursor::new(&data), 0, data.len())
+            .unwrap();
+    assert_eq!(cmap.version, 0);
+    assert_eq!(
+        cmap.subtables().collect::<Vec<_>>(),
+        vec![
+            CmapSubtable {
+                platformID: 1,
+                encodingID: 0,
+                format: 0,
+            },
+            CmapSubtable {
+                platformID: 3,
+                encodingID: 10,
+                format: 12,
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_glyph_for_char_in() {
+    let data = cmap_data();
+    let cmap =
+        TableCmap::from_reader_exact(&mut Cursor::new(&data), 0, data.len())
+            .unwrap();
+    // The subtables intentionally differ
+    assert_eq!(cmap.glyph_for_char_in(1, 0, 'A'), Some(7));
+    assert_eq!(cmap.glyph_for_char_in(3, 10, 'A'), Some(10));
+    assert_eq!(cmap.glyph_for_char_in(3, 10, 'C'), Some(12));
+    assert_eq!(cmap.glyph_for_char_in(3, 10, '😀'), Some(20));
+    assert_eq!(cmap.glyph_for_char_in(1, 0, 'B'), None);
+    // Mac Roman 0xE9 is not U+00E9
+    assert_eq!(cmap.glyph_for_char_in(1, 0, 'é'), None);
+    assert_eq!(cmap.glyph_for_char_in(1, 0, '😀'), None);
+    assert_eq!(cmap.glyph_for_char_in(3, 10, 'D'), None);
+    // No such subtable
+    assert_eq!(cmap.glyph_for_char_in(3, 1, 'A'), None);
+    // The Windows subtable is preferred
+    assert_eq!(cmap.glyph_for_char('B'), Some(11));
+}
+
+#[test]
+fn test_lookup_with_source() {
+    let data = cmap_data();
+    let cmap =
+        TableCmap::from_reader_exact(&mut Cursor::new(&data), 0, data.len())
+            .unwrap();
+    let windows = CmapSubtable {
+        platformID: 3,
+        encodingID: 10,
+        format: 12,
+    };
+    let macintosh = CmapSubtable {
+        platformID: 1,
+        encodingID: 0,
+        format: 0,
+    };
+    assert_eq!(
+        cmap.lookup_with_source('A'),
+        Some((10, CmapSource::FullUnicode(windows)))
+    );
+    assert_eq!(
+        cmap.lookup_with_source('😀'),
+        Some((20, CmapSource::FullUnicode(windows)))
+    );
+    assert_eq!(cmap.lookup_with_source('D'), None);
+    assert_eq!(cmap.lookup_with_source('é'), None);
+
+    // Without the Windows subtable, the Macintosh one is consulted
+    let mut data = data;
+    data[12..14].copy_from_slice(&[0x00, 0x07]);
+    let cmap =
+        TableCmap::from_reader_exact(&mut Cursor::new(&data), 0, data.len())
+            .unwrap();
+    assert_eq!(cmap.glyph_for_char('A'), None);
+    assert_eq!(
+        cmap.lookup_with_source('A'),
+        Some((7, CmapSource::Macintosh(macintosh)))
+    );
+    assert_eq!(cmap.lookup_with_source('B'), None);
+}
+
+#[test]
+fn test_lookup_with_source_symbol() {
+    let data = vec![
+        0x00, 0x00, // version 0
+        0x00, 0x02, // two subtables
+        0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x14, // (3, 0) at 20
+        0x00, 0x03, 0x00, 0x01, 0x00, 0x00, 0x00, 0x22, // (3, 1) at 34
+        // Format 6, mapping U+F041 and U+F042 to glyphs 3 and 4
+        0x00, 0x06, 0x00, 0x0e, 0x00, 0x00, 0xf0, 0x41, 0x00, 0x02, 0x00, 0x03,
+        0x00, 0x04, //
+        // Format 6, mapping 'B' to glyph 5
+        0x00, 0x06, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x42, 0x00, 0x01, 0x00, 0x05,
+    ];
+    let cmap =
+        TableCmap::from_reader_exact(&mut Cursor::new(&data), 0, data.len())
+            .unwrap();
+    let symbol = CmapSubtable {
+        platformID: 3,
+        encodingID: 0,
+        format: 6,
+    };
+    let bmp = CmapSubtable {
+        platformID: 3,
+        encodingID: 1,
+        format: 6,
+    };
+    // The BMP subtable is preferred to the symbol subtable
+    assert_eq!(
+        cmap.lookup_with_source('B'),
+        Some((5, CmapSource::Bmp(bmp)))
+    );
+    // Single-byte codes are looked up in the Private Use Area
+    assert_eq!(
+        cmap.lookup_with_source('A'),
+        Some((3, CmapSource::Symbol(symbol)))
+    );
+    assert_eq!(
+        cmap.lookup_with_source('\u{f042}').unwrap().1,
+        CmapSource::Symbol(symbol)
+    );
+    assert_eq!(cmap.lookup_with_source('C'), None);
+}
+
+#[test]
+fn test_glyph_for_char_in_many_to_one() {
+    let mut data = cmap_data();
+    // Turn the format 12 subtable into a format 13 subtable
+    data[283] = 0x0d;
+    let cmap =
+        TableCmap::from_reader_exact(&mut Cursor::new(&data), 0, data.len())
+            .unwrap();
+    assert_eq!(cmap.subtables().nth(1).unwrap().format, 13);
+    assert_eq!(cmap.glyph_for_char_in(3, 10, 'A'), Some(10));
+    assert_eq!(cmap.glyph_for_char_in(3, 10, 'C'), Some(10));
+}
+
+#[test]
+fn test_reader_exact_truncated() {
+    let data = cmap_data();
+    // The encoding records are cut off
+    let result = TableCmap::from_reader_exact(&mut Cursor::new(&data), 0, 12);
+    assert!(matches!(
+        result,
+        Err(FontIoError::LoadTableTruncated(FontTag::CMAP))
+    ));
+    // A subtable is out of bounds
+    let result = TableCmap::from_reader_exact(&mut Cursor::new(&data), 0, 200);
+    assert!(matches!(
+        result,
+        Err(FontIoError::LoadTableTruncated(FontTag::CMAP))
+    ));
+}
@@ -0,0 +1,138 @@
+// Copyright 2025 Monotype Imaging Inc.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+//! 'COLR' SFNT table.
+
+use std::io::{Read, Seek, SeekFrom};
+
+use byteorder::{BigEndian, ReadBytesExt};
+
+use crate::{error::FontIoError, tag::FontTag, FontDataExactRead};
+
+/// A base glyph record, mapping a glyph to its layers.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[allow(non_snake_case)] // As named by Open Font Format / OpenType.
+pub struct ColrBaseGlyph {
+    /// The glyph ID of the base glyph.
+    pub glyphID: u16,
+    /// Index of the first layer record for this glyph.
+    pub firstLayerIndex: u16,
+    /// Number of layer records for this glyph.
+    pub numLayers: u16,
+}
+
+/// A layer record, giving the glyph and palette entry to draw for a layer.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[allow(non_snake_case)] // As named by Open Font Format / OpenType.
+pub struct ColrLayer {
+    /// The glyph ID of the glyph outline to draw for the layer.
+    pub glyphID: u16,
+    /// Index of the palette entry to fill the layer with.
+    pub paletteIndex: u16,
+}
+
+impl ColrLayer {
+    /// Palette index indicating the text foreground color should be used.
+    pub const FOREGROUND_PALETTE_INDEX: u16 = 0xffff;
+}
+
+/// 'COLR' font table, holding the color layers of a color font.
+///
+/// # Remarks
+/// Only the version 0 layer records are read, which are also present in
+/// version 1 tables for backwards compatibility; the version 1 paint graphs
+/// are ignored.
+#[derive(Clone, Debug)]
+pub struct TableColr {
+    /// Version of the table.
+    pub version: u16,
+    /// The base glyph records, sorted by glyph ID.
+    pub base_glyphs: Vec<ColrBaseGlyph>,
+    /// The layer records.
+    pub layers: Vec<ColrLayer>,
+}
+
+impl TableColr {
+    /// The size of a base glyph record.
+    const BASE_GLYPH_RECORD_SIZE: usize = 6;
+    /// The size of the version 0 table header.
+    const HEADER_SIZE: usize = 14;
+    /// The size of a layer record.
+    const LAYER_RECORD_SIZE: usize = 4;
+
+    /// Returns the layers for the given glyph, or `None` if the glyph is not
+    /// a color glyph.
+    pub fn glyph_layers(&self, glyph_id: u16) -> Option<&[ColrLayer]> {
+        let index = self
+            .base_glyphs
+            .binary_search_by_key(&glyph_id, |base| base.glyphID)
+            .ok()?;
+        let base = &self.base_glyphs[index];
+        let first = base.firstLayerIndex as usize;
+        self.layers.get(first..first + base.numLayers as usize)
+    }
+}
+
+impl FontDataExactRead for TableColr {
+    type Error = FontIoError;
+
+    fn from_reader_exact<T: Read + Seek + ?Sized>(
+        reader: &mut T,
+        offset: u64,
+        size: usize,
+    ) -> Result<Self, Self::Error> {
+        reader.seek(SeekFrom::Start(offset))?;
+        if size < Self::HEADER_SIZE {
+            return Err(FontIoError::LoadTableTruncated(FontTag::COLR));
+        }
+        let version = reader.read_u16::<BigEndian>()?;
+        let num_base_glyphs = reader.read_u16::<BigEndian>()? as usize;
+        let base_glyphs_offset = reader.read_u32::<BigEndian>()? as usize;
+        let layers_offset = reader.read_u32::<BigEndian>()? as usize;
+        let num_layers = reader.read_u16::<BigEndian>()? as usize;
+        // Make sure the records are within the table
+        if base_glyphs_offset + num_base_glyphs * Self::BASE_GLYPH_RECORD_SIZE
+            > size
+            || layers_offset + num_layers * Self::LAYER_RECORD_SIZE > size
+        {
+            return Err(FontIoError::LoadTableTruncated(FontTag::COLR));
+        }
+        reader.seek(SeekFrom::Start(offset + base_glyphs_offset as u64))?;
+        let mut base_glyphs = Vec::with_capacity(num_base_glyphs);
+        for _ in 0..num_base_glyphs {
+            base_glyphs.push(ColrBaseGlyph {
+                glyphID: reader.read_u16::<BigEndian>()?,
+                firstLayerIndex: reader.read_u16::<BigEndian>()?,
+                numLayers: reader.read_u16::<BigEndian>()?,
+            });
+        }
+        reader.seek(SeekFrom::Start(offset + layers_offset as u64))?;
+        let mut layers = Vec::with_capacity(num_layers);
+        for _ in 0..num_layers {
+            layers.push(ColrLayer {
+                glyphID: reader.read_u16::<BigEndian>()?,
+                paletteIndex: reader.read_u16::<BigEndian>()?,
+            });
+        }
+        Ok(Self {
+            version,
+            base_glyphs,
+            layers,
+        })
+    }
+}
+
+#[cfg(test)]
+#[path = "colr_test.rs"]
+mod tests;
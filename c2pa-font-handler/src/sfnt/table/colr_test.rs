@@ -0,0 +1,92 @@
+// Copyright 2025 Monotype Imaging Inc.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+//! Tests for the 'COLR' SFNT table module
+use std::io::Cursor;
+
+use super::*;
+
+/// A COLR table with two base glyphs, one with two layers and one with one.
+const COLR_DATA: [u8; 38] = [
+    0x00, 0x00, // version
+    0x00, 0x02, // num base glyph records
+    0x00, 0x00, 0x00, 0x0e, // base glyph records offset
+    0x00, 0x00, 0x00, 0x1a, // layer records offset
+    0x00, 0x03, // num layer records
+    0x00, 0x02, 0x00, 0x00, 0x00, 0x02, // glyph 2, layers 0..2
+    0x00, 0x05, 0x00, 0x02, 0x00, 0x01, // glyph 5, layers 2..3
+    0x00, 0x0a, 0x00, 0x00, // glyph 10, palette entry 0
+    0x00, 0x0b, 0x00, 0x01, // glyph 11, palette entry 1
+    0x00, 0x0c, 0xff, 0xff, // glyph 12, foreground color
+];
+
+#[test]
+fn test_reader_exact() {
+    let mut reader = Cursor::new(COLR_DATA);
+    let colr =
+        TableColr::from_reader_exact(&mut reader, 0, COLR_DATA.len()).unwrap();
+    assert_eq!(colr.version, 0);
+    assert_eq!(colr.base_glyphs.len(), 2);
+    assert_eq!(colr.layers.len(), 3);
+    assert_eq!(
+        colr.glyph_layers(2).unwrap(),
+        &[
+            ColrLayer {
+                glyphID: 10,
+                paletteIndex: 0
+            },
+            ColrLayer {
+                glyphID: 11,
+                paletteIndex: 1
+            }
+        ]
+    );
+    let layers = colr.glyph_layers(5).unwrap();
+    assert_eq!(layers.len(), 1);
+    assert_eq!(layers[0].paletteIndex, ColrLayer::FOREGROUND_PALETTE_INDEX);
+    assert!(colr.glyph_layers(3).is_none());
+}
+
+#[test]
+fn test_reader_exact_truncated() {
+    let mut reader = Cursor::new(COLR_DATA);
+    let result = TableColr::from_reader_exact(&mut reader, 0, 10);
+    assert!(matches!(
+        result,
+        Err(FontIoError::LoadTableTruncated(FontTag::COLR))
+    ));
+    // The layer records run past the end of the table
+    let result = TableColr::from_reader_exact(&mut reader, 0, 34);
+    assert!(matches!(
+        result,
+        Err(FontIoError::LoadTableTruncated(FontTag::COLR))
+    ));
+}
+
+#[test]
+fn test_glyph_layers_out_of_range() {
+    let colr = TableColr {
+        version: 0,
+        base_glyphs: vec![ColrBaseGlyph {
+            glyphID: 1,
+            firstLayerIndex: 0,
+            numLayers: 2,
+        }],
+        layers: vec![ColrLayer {
+            glyphID: 2,
+            paletteIndex: 0,
+        }],
+    };
+    assert!(colr.glyph_layers(1).is_none());
+}
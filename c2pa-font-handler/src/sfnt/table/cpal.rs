@@ -0,0 +1,130 @@
+// Copyright 2025 Monotype Imaging Inc.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+//! 'CPAL' SFNT table.
+
+use std::io::{Read, Seek, SeekFrom};
+
+use byteorder::{BigEndian, ReadBytesExt};
+
+use crate::{error::FontIoError, tag::FontTag, FontDataExactRead};
+
+/// A color in a 'CPAL' palette.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct CpalColor {
+    /// Blue component.
+    pub blue: u8,
+    /// Green component.
+    pub green: u8,
+    /// Red component.
+    pub red: u8,
+    /// Alpha component.
+    pub alpha: u8,
+}
+
+impl CpalColor {
+    /// Returns the color (without alpha) as an `#rrggbb` hex string.
+    pub fn to_hex(&self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.red, self.green, self.blue)
+    }
+}
+
+/// 'CPAL' font table, holding the color palettes of a color font.
+///
+/// # Remarks
+/// Only the version 0 portion of the table (the palettes themselves) is read;
+/// the version 1 palette types and labels are ignored.
+#[derive(Clone, Debug)]
+#[allow(non_snake_case)] // As named by Open Font Format / OpenType.
+pub struct TableCpal {
+    /// Version of the table.
+    pub version: u16,
+    /// Number of entries in each palette.
+    pub numPaletteEntries: u16,
+    /// The palettes, each holding `numPaletteEntries` colors.
+    pub palettes: Vec<Vec<CpalColor>>,
+}
+
+impl TableCpal {
+    /// The size of a color record.
+    const COLOR_RECORD_SIZE: usize = 4;
+    /// The size of the fixed portion of the table header.
+    const HEADER_SIZE: usize = 12;
+
+    /// Returns the color at the given entry of the given palette.
+    pub fn color(&self, palette: usize, entry: u16) -> Option<CpalColor> {
+        self.palettes.get(palette)?.get(entry as usize).copied()
+    }
+}
+
+impl FontDataExactRead for TableCpal {
+    type Error = FontIoError;
+
+    fn from_reader_exact<T: Read + Seek + ?Sized>(
+        reader: &mut T,
+        offset: u64,
+        size: usize,
+    ) -> Result<Self, Self::Error> {
+        reader.seek(SeekFrom::Start(offset))?;
+        if size < Self::HEADER_SIZE {
+            return Err(FontIoError::LoadTableTruncated(FontTag::CPAL));
+        }
+        let version = reader.read_u16::<BigEndian>()?;
+        let num_palette_entries = reader.read_u16::<BigEndian>()?;
+        let num_palettes = reader.read_u16::<BigEndian>()?;
+        let num_color_records = reader.read_u16::<BigEndian>()?;
+        let color_records_offset = reader.read_u32::<BigEndian>()? as usize;
+        // Make sure the palette indices and the color records are all within
+        // the table
+        let color_records_end = color_records_offset
+            + num_color_records as usize * Self::COLOR_RECORD_SIZE;
+        if Self::HEADER_SIZE + num_palettes as usize * 2 > size
+            || color_records_end > size
+        {
+            return Err(FontIoError::LoadTableTruncated(FontTag::CPAL));
+        }
+        let mut first_color_indices = Vec::with_capacity(num_palettes as usize);
+        for _ in 0..num_palettes {
+            first_color_indices.push(reader.read_u16::<BigEndian>()? as usize);
+        }
+        reader.seek(SeekFrom::Start(offset + color_records_offset as u64))?;
+        let mut colors = Vec::with_capacity(num_color_records as usize);
+        for _ in 0..num_color_records {
+            colors.push(CpalColor {
+                blue: reader.read_u8()?,
+                green: reader.read_u8()?,
+                red: reader.read_u8()?,
+                alpha: reader.read_u8()?,
+            });
+        }
+        let palettes = first_color_indices
+            .into_iter()
+            .map(|first| {
+                colors
+                    .get(first..first + num_palette_entries as usize)
+                    .map(<[CpalColor]>::to_vec)
+                    .ok_or(FontIoError::LoadTableTruncated(FontTag::CPAL))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self {
+            version,
+            numPaletteEntries: num_palette_entries,
+            palettes,
+        })
+    }
+}
+
+#[cfg(test)]
+#[path = "cpal_test.rs"]
+mod tests;
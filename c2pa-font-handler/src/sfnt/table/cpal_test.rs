@@ -0,0 +1,80 @@
+// Copyright 2025 Monotype Imaging Inc.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+//! Tests for the 'CPAL' SFNT table module
+use std::io::Cursor;
+
+use super::*;
+
+/// A CPAL table with two palettes of two entries, sharing a color record.
+const CPAL_DATA: [u8; 28] = [
+    0x00, 0x00, // version
+    0x00, 0x02, // num palette entries
+    0x00, 0x02, // num palettes
+    0x00, 0x03, // num color records
+    0x00, 0x00, 0x00, 0x10, // color records offset
+    0x00, 0x00, // palette 0 first color record
+    0x00, 0x01, // palette 1 first color record
+    0x00, 0x00, 0xff, 0xff, // red
+    0x00, 0xff, 0x00, 0x80, // half transparent green
+    0xff, 0x00, 0x00, 0xff, // blue
+];
+
+#[test]
+fn test_reader_exact() {
+    let mut reader = Cursor::new(CPAL_DATA);
+    let cpal =
+        TableCpal::from_reader_exact(&mut reader, 0, CPAL_DATA.len()).unwrap();
+    assert_eq!(cpal.numPaletteEntries, 2);
+    assert_eq!(cpal.palettes.len(), 2);
+    let red = cpal.color(0, 0).unwrap();
+    assert_eq!(red.to_hex(), "#ff0000");
+    assert_eq!(red.alpha, 0xff);
+    let green = cpal.color(0, 1).unwrap();
+    assert_eq!(green, cpal.color(1, 0).unwrap());
+    assert_eq!(green.to_hex(), "#00ff00");
+    assert_eq!(green.alpha, 0x80);
+    assert_eq!(cpal.color(1, 1).unwrap().to_hex(), "#0000ff");
+    assert!(cpal.color(1, 2).is_none());
+    assert!(cpal.color(2, 0).is_none());
+}
+
+#[test]
+fn test_reader_exact_truncated() {
+    let mut reader = Cursor::new(CPAL_DATA);
+    let result = TableCpal::from_reader_exact(&mut reader, 0, 8);
+    assert!(matches!(
+        result,
+        Err(FontIoError::LoadTableTruncated(FontTag::CPAL))
+    ));
+    // The color records run past the end of the table
+    let result = TableCpal::from_reader_exact(&mut reader, 0, 24);
+    assert!(matches!(
+        result,
+        Err(FontIoError::LoadTableTruncated(FontTag::CPAL))
+    ));
+}
+
+#[test]
+fn test_reader_exact_palette_out_of_range() {
+    let mut data = CPAL_DATA;
+    // Point the second palette past the last color record
+    data[15] = 0x02;
+    let mut reader = Cursor::new(data);
+    let result = TableCpal::from_reader_exact(&mut reader, 0, data.len());
+    assert!(matches!(
+        result,
+        Err(FontIoError::LoadTableTruncated(FontTag::CPAL))
+    ));
+}
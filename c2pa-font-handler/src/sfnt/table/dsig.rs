@@ -28,6 +28,7 @@ use crate::{
 
 /// 'DSIG' font table, ignores actual signatures as we intend to only use this
 /// as a stub DSIG table.
+#[derive(Clone)]
 #[allow(non_snake_case)] // As named by Open Font Format / OpenType.
 pub struct TableDSIG {
     /// Version of the DSIG table.
@@ -43,22 +44,36 @@ pub struct TableDSIG {
 
 impl TableDSIG {
     /// The default version of the DSIG table.
-    const DEFAULT_VERSION: u32 = 0x00000001;
+    pub(crate) const DEFAULT_VERSION: u32 = 0x00000001;
     /// The flag to not resign the table.
-    const DO_NOT_RESIGN: u16 = 0x0001;
+    pub(crate) const DO_NOT_RESIGN: u16 = 0x0001;
     /// The size of a DSIG table.
-    const MINIMUM_SIZE: usize = 8;
+    pub(crate) const MINIMUM_SIZE: usize = 8;
 
     /// Create an empty DSIG stub table.
     pub(crate) fn stub() -> Self {
+        Self::stub_with(Self::DEFAULT_VERSION, Self::DO_NOT_RESIGN)
+    }
+
+    /// Create an empty DSIG stub table, with the given version and flags.
+    pub fn stub_with(version: u32, flags: u16) -> Self {
         Self {
-            version: Self::DEFAULT_VERSION,
+            version,
             numSignatures: 0,
-            flags: Self::DO_NOT_RESIGN,
+            flags,
             data: Vec::new(),
         }
     }
 
+    /// Create an empty DSIG stub table, keeping the given flags (e.g., the
+    /// resign permission bit) of the table it replaces.
+    pub fn stub_preserving_flags(original_flags: u16) -> Self {
+        Self {
+            flags: original_flags,
+            ..Self::stub()
+        }
+    }
+
     /// Check if this DSIG table is a stub.
     pub(crate) fn is_stubbed(&self) -> bool {
         self.version == Self::DEFAULT_VERSION
@@ -26,6 +26,30 @@ fn test_stub_dsig() {
     assert_eq!(stub.flags, 1);
 }
 
+#[test]
+fn test_stub_dsig_with() {
+    let stub = TableDSIG::stub_with(1, 0);
+    assert_eq!(stub.version, 1);
+    assert_eq!(stub.numSignatures, 0);
+    assert_eq!(stub.flags, 0);
+    assert!(stub.data.is_empty());
+    // Only the default contents are considered our stub
+    assert!(!stub.is_stubbed());
+    assert!(TableDSIG::stub_with(1, 1).is_stubbed());
+}
+
+#[test]
+fn test_stub_dsig_preserving_flags() {
+    let stub = TableDSIG::stub_preserving_flags(0x0000);
+    assert_eq!(stub.version, 1);
+    assert_eq!(stub.numSignatures, 0);
+    assert_eq!(stub.flags, 0);
+    assert!(stub.data.is_empty());
+    // Only the default flags make a stub recognizable as such
+    assert!(!stub.is_stubbed());
+    assert!(TableDSIG::stub_preserving_flags(1).is_stubbed());
+}
+
 #[test]
 fn test_table_dsig_read() {
     let mut reader = Cursor::new(vec![
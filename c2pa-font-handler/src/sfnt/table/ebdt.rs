@@ -0,0 +1,193 @@
+// Copyright 2025 Monotype Imaging Inc.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+//! 'EBDT' SFNT table.
+
+use std::io::{Cursor, Read, Seek, SeekFrom};
+
+use byteorder::{BigEndian, ReadBytesExt};
+
+use super::eblc::{BigGlyphMetrics, EblcGlyphLocation};
+use crate::{error::FontIoError, tag::FontTag, FontDataExactRead};
+
+/// The pixel format of an embedded bitmap.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EmbeddedBitmapFormat {
+    /// One bit per pixel.
+    Monochrome,
+    /// Multiple bits per pixel, with the given bit depth (2, 4, or 8).
+    Grayscale(u8),
+}
+
+/// A glyph's embedded bitmap, decoded from the 'EBDT' table.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmbeddedBitmap {
+    /// Width of the bitmap, in pixels.
+    pub width: u8,
+    /// Height of the bitmap, in pixels.
+    pub height: u8,
+    /// Distance from the horizontal origin to the left edge of the bitmap.
+    pub bearing_x: i8,
+    /// Distance from the horizontal origin to the top edge of the bitmap.
+    pub bearing_y: i8,
+    /// Horizontal advance width, in pixels.
+    pub advance: u8,
+    /// Number of bits per pixel in the font's data.
+    pub bit_depth: u8,
+    /// The coverage of each pixel, row by row from the top, scaled to the
+    /// range `0..=255`.
+    pub pixels: Vec<u8>,
+}
+
+impl EmbeddedBitmap {
+    /// Returns the pixel format of the bitmap in the font's data.
+    pub fn format(&self) -> EmbeddedBitmapFormat {
+        match self.bit_depth {
+            1 => EmbeddedBitmapFormat::Monochrome,
+            depth => EmbeddedBitmapFormat::Grayscale(depth),
+        }
+    }
+}
+
+/// 'EBDT' font table, holding the image data of embedded bitmaps.
+///
+/// # Remarks
+/// The image data is located using the 'EBLC' table.
+#[derive(Clone, Debug)]
+#[allow(non_snake_case)] // As named by Open Font Format / OpenType.
+pub struct TableEbdt {
+    /// Major version of the table.
+    pub majorVersion: u16,
+    /// Minor version of the table.
+    pub minorVersion: u16,
+    /// The data of the table, including the header.
+    data: Vec<u8>,
+}
+
+impl TableEbdt {
+    /// The size of the table header.
+    const HEADER_SIZE: usize = 4;
+
+    /// Decodes the bitmap at the given location, with the given bit depth;
+    /// returns `None` if the location is out of the table's bounds or the
+    /// image format is not supported.
+    ///
+    /// # Remarks
+    /// Image formats 1, 2, 5, 6, and 7 are supported. Composite bitmaps
+    /// (formats 8 and 9) are not.
+    pub(crate) fn bitmap(
+        &self,
+        location: &EblcGlyphLocation,
+        bit_depth: u8,
+    ) -> Option<EmbeddedBitmap> {
+        let start = location.offset as usize;
+        let end = start.checked_add(location.length as usize)?;
+        let mut reader = Cursor::new(self.data.get(start..end)?);
+        let (metrics, bit_aligned) = match location.image_format {
+            1 => (BigGlyphMetrics::from_small_reader(&mut reader).ok()?, false),
+            2 => (BigGlyphMetrics::from_small_reader(&mut reader).ok()?, true),
+            5 => (location.metrics?, true),
+            6 => (BigGlyphMetrics::from_reader(&mut reader).ok()?, false),
+            7 => (BigGlyphMetrics::from_reader(&mut reader).ok()?, true),
+            _ => return None,
+        };
+        let image = &reader.get_ref()[reader.position() as usize..];
+        let pixels = decode_pixels(
+            image,
+            metrics.width as usize,
+            metrics.height as usize,
+            bit_depth,
+            bit_aligned,
+        )?;
+        Some(EmbeddedBitmap {
+            width: metrics.width,
+            height: metrics.height,
+            bearing_x: metrics.horiBearingX,
+            bearing_y: metrics.horiBearingY,
+            advance: metrics.horiAdvance,
+            bit_depth,
+            pixels,
+        })
+    }
+}
+
+/// Decodes the image data into one coverage value per pixel, scaled to the
+/// range `0..=255`.
+///
+/// # Remarks
+/// Byte-aligned images pad each row to a whole byte; bit-aligned images do
+/// not.
+fn decode_pixels(
+    image: &[u8],
+    width: usize,
+    height: usize,
+    bit_depth: u8,
+    bit_aligned: bool,
+) -> Option<Vec<u8>> {
+    if !matches!(bit_depth, 1 | 2 | 4 | 8) {
+        return None;
+    }
+    let depth = bit_depth as usize;
+    let max_value = (1u16 << depth) - 1;
+    let row_bits = if bit_aligned {
+        width * depth
+    } else {
+        (width * depth).div_ceil(8) * 8
+    };
+    if (row_bits * height).div_ceil(8) > image.len() {
+        return None;
+    }
+    let mut pixels = Vec::with_capacity(width * height);
+    for row in 0..height {
+        for column in 0..width {
+            let bit = row * row_bits + column * depth;
+            // Pixels never straddle bytes, since the depth divides 8
+            let shift = 8 - depth - bit % 8;
+            let value = (image[bit / 8] >> shift) as u16 & max_value;
+            pixels.push((value * 255 / max_value) as u8);
+        }
+    }
+    Some(pixels)
+}
+
+impl FontDataExactRead for TableEbdt {
+    type Error = FontIoError;
+
+    fn from_reader_exact<T: Read + Seek + ?Sized>(
+        reader: &mut T,
+        offset: u64,
+        size: usize,
+    ) -> Result<Self, Self::Error> {
+        reader.seek(SeekFrom::Start(offset))?;
+        if size < Self::HEADER_SIZE {
+            return Err(FontIoError::LoadTableTruncated(FontTag::EBDT));
+        }
+        let mut data = vec![0; size];
+        reader
+            .read_exact(&mut data)
+            .map_err(|_| FontIoError::LoadTableTruncated(FontTag::EBDT))?;
+        let mut header = Cursor::new(&data);
+        let major_version = header.read_u16::<BigEndian>()?;
+        let minor_version = header.read_u16::<BigEndian>()?;
+        Ok(Self {
+            majorVersion: major_version,
+            minorVersion: minor_version,
+            data,
+        })
+    }
+}
+
+#[cfg(test)]
+#[path = "ebdt_test.rs"]
+mod tests;
@@ -0,0 +1,135 @@
+// Copyright 2025 Monotype Imaging Inc.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+//! Tests for the 'EBDT' SFNT table module
+use std::io::Cursor;
+
+use super::*;
+
+/// Reads an EBDT table holding the given image data after its header.
+fn ebdt(image_data: &[u8]) -> TableEbdt {
+    let mut data = vec![0x00, 0x02, 0x00, 0x00];
+    data.extend_from_slice(image_data);
+    TableEbdt::from_reader_exact(&mut Cursor::new(&data), 0, data.len())
+        .unwrap()
+}
+
+/// The location of image data right after the table's header.
+fn location(image_format: u16, length: u32) -> EblcGlyphLocation {
+    EblcGlyphLocation {
+        image_format,
+        offset: 4,
+        length,
+        metrics: None,
+    }
+}
+
+#[test]
+fn test_bitmap_byte_aligned_monochrome() {
+    let ebdt = ebdt(&[
+        0x02,
+        0x03,
+        0x00,
+        0x02,
+        0x04,        // small metrics, 3x2
+        0b1010_0000, // row 1
+        0b0100_0000, // row 2
+    ]);
+    assert_eq!((ebdt.majorVersion, ebdt.minorVersion), (2, 0));
+    let bitmap = ebdt.bitmap(&location(1, 7), 1).unwrap();
+    assert_eq!((bitmap.width, bitmap.height), (3, 2));
+    assert_eq!((bitmap.bearing_x, bitmap.bearing_y), (0, 2));
+    assert_eq!(bitmap.advance, 4);
+    assert_eq!(bitmap.format(), EmbeddedBitmapFormat::Monochrome);
+    assert_eq!(bitmap.pixels, vec![255, 0, 255, 0, 255, 0]);
+}
+
+#[test]
+fn test_bitmap_bit_aligned_monochrome() {
+    let ebdt = ebdt(&[
+        0x02,
+        0x03,
+        0x00,
+        0x02,
+        0x04,        // small metrics, 3x2
+        0b1010_1000, // both rows
+    ]);
+    let bitmap = ebdt.bitmap(&location(2, 6), 1).unwrap();
+    assert_eq!(bitmap.pixels, vec![255, 0, 255, 0, 255, 0]);
+}
+
+#[test]
+fn test_bitmap_grayscale_with_metrics_from_eblc() {
+    let ebdt = ebdt(&[0b1101_0010]);
+    let mut location = location(5, 1);
+    location.metrics = Some(BigGlyphMetrics {
+        height: 2,
+        width: 2,
+        horiAdvance: 3,
+        ..Default::default()
+    });
+    let bitmap = ebdt.bitmap(&location, 2).unwrap();
+    assert_eq!(bitmap.format(), EmbeddedBitmapFormat::Grayscale(2));
+    assert_eq!(bitmap.pixels, vec![255, 85, 0, 170]);
+    // Format 5 requires the metrics from the EBLC table
+    location.metrics = None;
+    assert!(ebdt.bitmap(&location, 2).is_none());
+}
+
+#[test]
+fn test_bitmap_big_metrics() {
+    let ebdt = ebdt(&[
+        0x01,
+        0x02,
+        0xff,
+        0x01,
+        0x03,
+        0x00,
+        0x00,
+        0x02,        // big metrics, 2x1
+        0b0100_0000, // row 1
+    ]);
+    let bitmap = ebdt.bitmap(&location(6, 9), 1).unwrap();
+    assert_eq!(bitmap.bearing_x, -1);
+    assert_eq!(bitmap.pixels, vec![0, 255]);
+    let bitmap = ebdt.bitmap(&location(7, 9), 1).unwrap();
+    assert_eq!(bitmap.pixels, vec![0, 255]);
+}
+
+#[test]
+fn test_bitmap_unsupported_or_out_of_bounds() {
+    let ebdt = ebdt(&[
+        0x02,
+        0x03,
+        0x00,
+        0x02,
+        0x04,        // small metrics, 3x2
+        0b1010_0000, // row 1, missing row 2
+    ]);
+    // Too little image data for the metrics
+    assert!(ebdt.bitmap(&location(1, 6), 1).is_none());
+    // Beyond the end of the table
+    assert!(ebdt.bitmap(&location(1, 7), 1).is_none());
+    // Composite bitmaps are not supported
+    assert!(ebdt.bitmap(&location(8, 6), 1).is_none());
+}
+
+#[test]
+fn test_reader_exact_truncated() {
+    let result = TableEbdt::from_reader_exact(&mut Cursor::new([0x00]), 0, 1);
+    assert!(matches!(
+        result,
+        Err(FontIoError::LoadTableTruncated(FontTag::EBDT))
+    ));
+}
@@ -0,0 +1,412 @@
+// Copyright 2025 Monotype Imaging Inc.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+//! 'EBLC' SFNT table.
+
+use std::io::{Cursor, Read, Seek, SeekFrom};
+
+use byteorder::{BigEndian, ReadBytesExt};
+
+use crate::{error::FontIoError, tag::FontTag, FontDataExactRead};
+
+/// The metrics of an embedded bitmap glyph, as stored in the 'EBLC' and
+/// 'EBDT' tables.
+///
+/// # Remarks
+/// Small glyph metrics are expanded into big glyph metrics, using the same
+/// values for the horizontal and vertical metrics.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[allow(non_snake_case)] // As named by Open Font Format / OpenType.
+pub struct BigGlyphMetrics {
+    /// Number of rows of data.
+    pub height: u8,
+    /// Number of columns of data.
+    pub width: u8,
+    /// Distance from the horizontal origin to the left edge of the bitmap.
+    pub horiBearingX: i8,
+    /// Distance from the horizontal origin to the top edge of the bitmap.
+    pub horiBearingY: i8,
+    /// Horizontal advance width, in pixels.
+    pub horiAdvance: u8,
+    /// Distance from the vertical origin to the left edge of the bitmap.
+    pub vertBearingX: i8,
+    /// Distance from the vertical origin to the top edge of the bitmap.
+    pub vertBearingY: i8,
+    /// Vertical advance height, in pixels.
+    pub vertAdvance: u8,
+}
+
+impl BigGlyphMetrics {
+    /// Reads big glyph metrics from the reader.
+    pub(crate) fn from_reader<T: Read + ?Sized>(
+        reader: &mut T,
+    ) -> Result<Self, std::io::Error> {
+        Ok(Self {
+            height: reader.read_u8()?,
+            width: reader.read_u8()?,
+            horiBearingX: reader.read_i8()?,
+            horiBearingY: reader.read_i8()?,
+            horiAdvance: reader.read_u8()?,
+            vertBearingX: reader.read_i8()?,
+            vertBearingY: reader.read_i8()?,
+            vertAdvance: reader.read_u8()?,
+        })
+    }
+
+    /// Reads small glyph metrics from the reader, expanding them into big
+    /// glyph metrics.
+    pub(crate) fn from_small_reader<T: Read + ?Sized>(
+        reader: &mut T,
+    ) -> Result<Self, std::io::Error> {
+        let height = reader.read_u8()?;
+        let width = reader.read_u8()?;
+        let bearing_x = reader.read_i8()?;
+        let bearing_y = reader.read_i8()?;
+        let advance = reader.read_u8()?;
+        Ok(Self {
+            height,
+            width,
+            horiBearingX: bearing_x,
+            horiBearingY: bearing_y,
+            horiAdvance: advance,
+            vertBearingX: bearing_x,
+            vertBearingY: bearing_y,
+            vertAdvance: advance,
+        })
+    }
+}
+
+/// Where the image data for a glyph is found in the 'EBDT' table.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) struct EblcGlyphLocation {
+    /// The format of the image data.
+    pub(crate) image_format: u16,
+    /// Offset of the image data from the start of the 'EBDT' table.
+    pub(crate) offset: u32,
+    /// Length of the image data.
+    pub(crate) length: u32,
+    /// The metrics of the glyph, for image formats which do not hold their
+    /// own metrics.
+    pub(crate) metrics: Option<BigGlyphMetrics>,
+}
+
+/// The offsets of the glyphs in an index subtable.
+#[derive(Clone, Debug)]
+enum EblcGlyphOffsets {
+    /// Offsets for every glyph in the range, plus one for the end of the last
+    /// glyph (index formats 1 and 3).
+    Dense(Vec<u32>),
+    /// Glyphs in the range all have the same size and metrics (index format
+    /// 2).
+    Constant {
+        image_size: u32,
+        metrics: BigGlyphMetrics,
+    },
+    /// Glyph ID and offset pairs for only the glyphs present, plus one for the
+    /// end of the last glyph (index format 4).
+    Sparse(Vec<(u16, u32)>),
+    /// Only the listed glyphs are present, all with the same size and metrics
+    /// (index format 5).
+    ConstantSparse {
+        image_size: u32,
+        metrics: BigGlyphMetrics,
+        glyph_ids: Vec<u16>,
+    },
+}
+
+/// An index subtable, locating the image data of a range of glyphs.
+#[derive(Clone, Debug)]
+pub(crate) struct EblcIndexSubtable {
+    /// First glyph ID of the range.
+    first_glyph: u16,
+    /// Last glyph ID of the range.
+    last_glyph: u16,
+    /// The format of the image data.
+    image_format: u16,
+    /// Offset of the image data of the range from the start of the 'EBDT'
+    /// table.
+    image_data_offset: u32,
+    /// The offsets of the glyphs in the range.
+    offsets: EblcGlyphOffsets,
+}
+
+impl EblcIndexSubtable {
+    /// Finds the location of the image data for the given glyph.
+    fn glyph_location(&self, glyph_id: u16) -> Option<EblcGlyphLocation> {
+        if glyph_id < self.first_glyph || glyph_id > self.last_glyph {
+            return None;
+        }
+        let (offset, length, metrics) = match &self.offsets {
+            EblcGlyphOffsets::Dense(offsets) => {
+                let index = (glyph_id - self.first_glyph) as usize;
+                let start = *offsets.get(index)?;
+                let end = *offsets.get(index + 1)?;
+                (start, end.checked_sub(start)?, None)
+            }
+            EblcGlyphOffsets::Constant {
+                image_size,
+                metrics,
+            } => (
+                (glyph_id - self.first_glyph) as u32 * image_size,
+                *image_size,
+                Some(*metrics),
+            ),
+            EblcGlyphOffsets::Sparse(pairs) => {
+                let index = pairs.iter().position(|(id, _)| *id == glyph_id)?;
+                let start = pairs[index].1;
+                let end = pairs.get(index + 1)?.1;
+                (start, end.checked_sub(start)?, None)
+            }
+            EblcGlyphOffsets::ConstantSparse {
+                image_size,
+                metrics,
+                glyph_ids,
+            } => {
+                let index = glyph_ids.iter().position(|id| *id == glyph_id)?;
+                (index as u32 * image_size, *image_size, Some(*metrics))
+            }
+        };
+        // Missing glyphs have no image data
+        if length == 0 {
+            return None;
+        }
+        Some(EblcGlyphLocation {
+            image_format: self.image_format,
+            offset: self.image_data_offset.checked_add(offset)?,
+            length,
+            metrics,
+        })
+    }
+}
+
+/// A strike, being the bitmaps of the glyphs at a single size.
+#[derive(Clone, Debug)]
+#[allow(non_snake_case)] // As named by Open Font Format / OpenType.
+pub struct EblcStrike {
+    /// Lowest glyph ID in the strike.
+    pub startGlyphIndex: u16,
+    /// Highest glyph ID in the strike.
+    pub endGlyphIndex: u16,
+    /// Horizontal pixels per em.
+    pub ppemX: u8,
+    /// Vertical pixels per em.
+    pub ppemY: u8,
+    /// Number of bits per pixel; 1 for monochrome bitmaps, or 2, 4, or 8 for
+    /// grayscale bitmaps.
+    pub bitDepth: u8,
+    /// The index subtables locating the glyphs of the strike.
+    pub(crate) index_subtables: Vec<EblcIndexSubtable>,
+}
+
+impl EblcStrike {
+    /// Finds the location of the image data for the given glyph.
+    pub(crate) fn glyph_location(
+        &self,
+        glyph_id: u16,
+    ) -> Option<EblcGlyphLocation> {
+        self.index_subtables
+            .iter()
+            .find_map(|subtable| subtable.glyph_location(glyph_id))
+    }
+}
+
+/// 'EBLC' font table, locating the embedded bitmaps in the 'EBDT' table.
+#[derive(Clone, Debug)]
+#[allow(non_snake_case)] // As named by Open Font Format / OpenType.
+pub struct TableEblc {
+    /// Major version of the table.
+    pub majorVersion: u16,
+    /// Minor version of the table.
+    pub minorVersion: u16,
+    /// The strikes of the table.
+    pub strikes: Vec<EblcStrike>,
+}
+
+impl TableEblc {
+    /// The size of a bitmap size record.
+    const BITMAP_SIZE_RECORD_SIZE: usize = 48;
+    /// The size of the table header.
+    const HEADER_SIZE: usize = 8;
+    /// The size of an index subtable array element.
+    const INDEX_SUBTABLE_ARRAY_ELEMENT_SIZE: usize = 8;
+
+    /// Finds the strike for the given pixels per em, falling back to the
+    /// strike with the nearest size.
+    pub fn strike(&self, ppem: u8) -> Option<&EblcStrike> {
+        self.strikes
+            .iter()
+            .min_by_key(|strike| strike.ppemY.abs_diff(ppem))
+    }
+
+    /// Reads an index subtable from the table data.
+    fn read_index_subtable(
+        data: &[u8],
+        offset: u64,
+        first_glyph: u16,
+        last_glyph: u16,
+    ) -> Result<Option<EblcIndexSubtable>, std::io::Error> {
+        let mut reader = Cursor::new(data);
+        reader.seek(SeekFrom::Start(offset))?;
+        let index_format = reader.read_u16::<BigEndian>()?;
+        let image_format = reader.read_u16::<BigEndian>()?;
+        let image_data_offset = reader.read_u32::<BigEndian>()?;
+        let glyph_count = last_glyph.saturating_sub(first_glyph) as usize + 1;
+        let offsets = match index_format {
+            1 => EblcGlyphOffsets::Dense(
+                (0..=glyph_count)
+                    .map(|_| reader.read_u32::<BigEndian>())
+                    .collect::<Result<_, _>>()?,
+            ),
+            2 => EblcGlyphOffsets::Constant {
+                image_size: reader.read_u32::<BigEndian>()?,
+                metrics: BigGlyphMetrics::from_reader(&mut reader)?,
+            },
+            3 => EblcGlyphOffsets::Dense(
+                (0..=glyph_count)
+                    .map(|_| reader.read_u16::<BigEndian>().map(u32::from))
+                    .collect::<Result<_, _>>()?,
+            ),
+            4 => {
+                let num_glyphs = reader.read_u32::<BigEndian>()?;
+                EblcGlyphOffsets::Sparse(
+                    (0..=num_glyphs)
+                        .map(|_| {
+                            Ok((
+                                reader.read_u16::<BigEndian>()?,
+                                reader.read_u16::<BigEndian>()? as u32,
+                            ))
+                        })
+                        .collect::<Result<_, std::io::Error>>()?,
+                )
+            }
+            5 => {
+                let image_size = reader.read_u32::<BigEndian>()?;
+                let metrics = BigGlyphMetrics::from_reader(&mut reader)?;
+                let num_glyphs = reader.read_u32::<BigEndian>()?;
+                EblcGlyphOffsets::ConstantSparse {
+                    image_size,
+                    metrics,
+                    glyph_ids: (0..num_glyphs)
+                        .map(|_| reader.read_u16::<BigEndian>())
+                        .collect::<Result<_, _>>()?,
+                }
+            }
+            _ => {
+                tracing::debug!(
+                    "Skipping unsupported EBLC index subtable format {index_format}"
+                );
+                return Ok(None);
+            }
+        };
+        Ok(Some(EblcIndexSubtable {
+            first_glyph,
+            last_glyph,
+            image_format,
+            image_data_offset,
+            offsets,
+        }))
+    }
+
+    /// Reads the table from its data.
+    fn from_data(data: &[u8]) -> Result<Self, std::io::Error> {
+        let mut reader = Cursor::new(data);
+        let major_version = reader.read_u16::<BigEndian>()?;
+        let minor_version = reader.read_u16::<BigEndian>()?;
+        let num_sizes = reader.read_u32::<BigEndian>()? as usize;
+        // Make sure the size records are within the table, before allocating
+        // for them
+        if Self::HEADER_SIZE + num_sizes * Self::BITMAP_SIZE_RECORD_SIZE
+            > data.len()
+        {
+            return Err(std::io::ErrorKind::UnexpectedEof.into());
+        }
+        let mut strikes = Vec::with_capacity(num_sizes);
+        for index in 0..num_sizes {
+            reader.seek(SeekFrom::Start(
+                (Self::HEADER_SIZE + index * Self::BITMAP_SIZE_RECORD_SIZE)
+                    as u64,
+            ))?;
+            let index_subtable_array_offset =
+                reader.read_u32::<BigEndian>()? as u64;
+            let _index_tables_size = reader.read_u32::<BigEndian>()?;
+            let number_of_index_subtables = reader.read_u32::<BigEndian>()?;
+            let _color_ref = reader.read_u32::<BigEndian>()?;
+            // Skip the horizontal and vertical line metrics
+            reader.seek(SeekFrom::Current(24))?;
+            let start_glyph_index = reader.read_u16::<BigEndian>()?;
+            let end_glyph_index = reader.read_u16::<BigEndian>()?;
+            let ppem_x = reader.read_u8()?;
+            let ppem_y = reader.read_u8()?;
+            let bit_depth = reader.read_u8()?;
+
+            let mut index_subtables = Vec::new();
+            for subtable in 0..number_of_index_subtables as u64 {
+                reader.seek(SeekFrom::Start(
+                    index_subtable_array_offset
+                        + subtable
+                            * Self::INDEX_SUBTABLE_ARRAY_ELEMENT_SIZE as u64,
+                ))?;
+                let first_glyph = reader.read_u16::<BigEndian>()?;
+                let last_glyph = reader.read_u16::<BigEndian>()?;
+                let additional_offset = reader.read_u32::<BigEndian>()? as u64;
+                if let Some(subtable) = Self::read_index_subtable(
+                    data,
+                    index_subtable_array_offset + additional_offset,
+                    first_glyph,
+                    last_glyph,
+                )? {
+                    index_subtables.push(subtable);
+                }
+            }
+            strikes.push(EblcStrike {
+                startGlyphIndex: start_glyph_index,
+                endGlyphIndex: end_glyph_index,
+                ppemX: ppem_x,
+                ppemY: ppem_y,
+                bitDepth: bit_depth,
+                index_subtables,
+            });
+        }
+        Ok(Self {
+            majorVersion: major_version,
+            minorVersion: minor_version,
+            strikes,
+        })
+    }
+}
+
+impl FontDataExactRead for TableEblc {
+    type Error = FontIoError;
+
+    fn from_reader_exact<T: Read + Seek + ?Sized>(
+        reader: &mut T,
+        offset: u64,
+        size: usize,
+    ) -> Result<Self, Self::Error> {
+        reader.seek(SeekFrom::Start(offset))?;
+        if size < Self::HEADER_SIZE {
+            return Err(FontIoError::LoadTableTruncated(FontTag::EBLC));
+        }
+        let mut data = vec![0; size];
+        reader
+            .read_exact(&mut data)
+            .map_err(|_| FontIoError::LoadTableTruncated(FontTag::EBLC))?;
+        Self::from_data(&data)
+            .map_err(|_| FontIoError::LoadTableTruncated(FontTag::EBLC))
+    }
+}
+
+#[cfg(test)]
+#[path = "eblc_test.rs"]
+mod tests;
@@ -0,0 +1,146 @@
+// Copyright 2025 Monotype Imaging Inc.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+//! Tests for the 'EBLC' SFNT table module
+use std::io::Cursor;
+
+use super::*;
+
+/// Builds an EBLC table with a single 12 ppem, 1-bit strike for glyphs 1 to
+/// 2, located by the given index subtable.
+fn eblc_data(index_subtable: &[u8]) -> Vec<u8> {
+    let mut data = vec![
+        0x00, 0x02, 0x00, 0x00, // version 2.0
+        0x00, 0x00, 0x00, 0x01, // one strike
+        0x00, 0x00, 0x00, 0x38, // index subtable array offset
+        0x00, 0x00, 0x00, 0x00, // index tables size
+        0x00, 0x00, 0x00, 0x01, // one index subtable
+        0x00, 0x00, 0x00, 0x00, // color ref
+    ];
+    // Horizontal and vertical line metrics
+    data.extend_from_slice(&[0; 24]);
+    data.extend_from_slice(&[
+        0x00, 0x01, 0x00, 0x02, // glyphs 1 to 2
+        0x0c, 0x0c, 0x01, 0x01, // 12 ppem, 1-bit, horizontal
+        // Index subtable array
+        0x00, 0x01, 0x00, 0x02, // glyphs 1 to 2
+        0x00, 0x00, 0x00, 0x08, // additional offset
+    ]);
+    data.extend_from_slice(index_subtable);
+    data
+}
+
+#[test]
+fn test_reader_exact_index_format_1() {
+    let data = eblc_data(&[
+        0x00, 0x01, 0x00, 0x01, // index format 1, image format 1
+        0x00, 0x00, 0x00, 0x04, // image data offset
+        0x00, 0x00, 0x00, 0x00, // glyph 1
+        0x00, 0x00, 0x00, 0x07, // glyph 2
+        0x00, 0x00, 0x00, 0x07, // end
+    ]);
+    let eblc =
+        TableEblc::from_reader_exact(&mut Cursor::new(&data), 0, data.len())
+            .unwrap();
+    assert_eq!(eblc.majorVersion, 2);
+    assert_eq!(eblc.strikes.len(), 1);
+    let strike = &eblc.strikes[0];
+    assert_eq!((strike.ppemX, strike.ppemY, strike.bitDepth), (12, 12, 1));
+    assert_eq!((strike.startGlyphIndex, strike.endGlyphIndex), (1, 2));
+    assert_eq!(
+        strike.glyph_location(1),
+        Some(EblcGlyphLocation {
+            image_format: 1,
+            offset: 4,
+            length: 7,
+            metrics: None,
+        })
+    );
+    // Glyph 2 has no image data, and glyph 3 is out of range
+    assert_eq!(strike.glyph_location(2), None);
+    assert_eq!(strike.glyph_location(3), None);
+}
+
+#[test]
+fn test_reader_exact_index_format_2() {
+    let data = eblc_data(&[
+        0x00, 0x02, 0x00, 0x05, // index format 2, image format 5
+        0x00, 0x00, 0x00, 0x04, // image data offset
+        0x00, 0x00, 0x00, 0x02, // image size
+        0x02, 0x03, 0x00, 0x02, 0x04, 0x00, 0x00, 0x02, // big metrics
+    ]);
+    let eblc =
+        TableEblc::from_reader_exact(&mut Cursor::new(&data), 0, data.len())
+            .unwrap();
+    let location = eblc.strikes[0].glyph_location(2).unwrap();
+    assert_eq!(location.image_format, 5);
+    assert_eq!((location.offset, location.length), (6, 2));
+    let metrics = location.metrics.unwrap();
+    assert_eq!((metrics.width, metrics.height), (3, 2));
+    assert_eq!(metrics.horiAdvance, 4);
+}
+
+#[test]
+fn test_reader_exact_index_format_5() {
+    let data = eblc_data(&[
+        0x00, 0x05, 0x00, 0x05, // index format 5, image format 5
+        0x00, 0x00, 0x00, 0x04, // image data offset
+        0x00, 0x00, 0x00, 0x02, // image size
+        0x02, 0x03, 0x00, 0x02, 0x04, 0x00, 0x00, 0x02, // big metrics
+        0x00, 0x00, 0x00, 0x01, // one glyph
+        0x00, 0x02, // glyph 2
+    ]);
+    let eblc =
+        TableEblc::from_reader_exact(&mut Cursor::new(&data), 0, data.len())
+            .unwrap();
+    let strike = &eblc.strikes[0];
+    assert_eq!(strike.glyph_location(1), None);
+    let location = strike.glyph_location(2).unwrap();
+    assert_eq!((location.offset, location.length), (4, 2));
+}
+
+#[test]
+fn test_strike_nearest_size() {
+    let data = eblc_data(&[
+        0x00, 0x03, 0x00, 0x01, // index format 3, image format 1
+        0x00, 0x00, 0x00, 0x04, // image data offset
+        0x00, 0x00, 0x00, 0x07, 0x00, 0x07, // offsets
+    ]);
+    let eblc =
+        TableEblc::from_reader_exact(&mut Cursor::new(&data), 0, data.len())
+            .unwrap();
+    assert_eq!(eblc.strike(12).unwrap().ppemY, 12);
+    assert_eq!(eblc.strike(16).unwrap().ppemY, 12);
+    assert!(eblc.strike(12).unwrap().glyph_location(1).is_some());
+}
+
+#[test]
+fn test_reader_exact_truncated() {
+    let data = eblc_data(&[
+        0x00, 0x01, 0x00, 0x01, // index format 1, image format 1
+        0x00, 0x00, 0x00, 0x04, // image data offset
+        0x00, 0x00, 0x00, 0x00, // glyph 1, missing the rest
+    ]);
+    let result =
+        TableEblc::from_reader_exact(&mut Cursor::new(&data), 0, data.len());
+    assert!(matches!(
+        result,
+        Err(FontIoError::LoadTableTruncated(FontTag::EBLC))
+    ));
+    let result = TableEblc::from_reader_exact(&mut Cursor::new(&data), 0, 4);
+    assert!(matches!(
+        result,
+        Err(FontIoError::LoadTableTruncated(FontTag::EBLC))
+    ));
+}
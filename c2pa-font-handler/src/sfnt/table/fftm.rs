@@ -0,0 +1,70 @@
+// Copyright 2025 Monotype Imaging Inc.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+//! 'FFTM' SFNT table.
+
+use std::io::{Read, Seek, SeekFrom};
+
+use byteorder::{BigEndian, ReadBytesExt};
+
+use crate::{error::FontIoError, tag::FontTag, FontDataExactRead};
+
+/// 'FFTM' font table, holding the timestamps FontForge records in the fonts
+/// it generates.
+///
+/// # Remarks
+/// The timestamps are in seconds since 12:00 midnight, January 1, 1904 UTC,
+/// as for the dates in the 'head' table.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[allow(non_snake_case)] // As named by FontForge.
+pub struct TableFftm {
+    /// Version of the table (1).
+    pub version: u32,
+    /// The build date of the FontForge which generated the font.
+    pub FFTimeStamp: i64,
+    /// The creation date of the FontForge source of the font.
+    pub sourceCreated: i64,
+    /// The modification date of the FontForge source of the font.
+    pub sourceModified: i64,
+}
+
+impl TableFftm {
+    /// The size of the table.
+    const SIZE: usize = 28;
+}
+
+impl FontDataExactRead for TableFftm {
+    type Error = FontIoError;
+
+    fn from_reader_exact<T: Read + Seek + ?Sized>(
+        reader: &mut T,
+        offset: u64,
+        size: usize,
+    ) -> Result<Self, Self::Error> {
+        reader.seek(SeekFrom::Start(offset))?;
+        if size < Self::SIZE {
+            return Err(FontIoError::LoadTableTruncated(FontTag::FFTM));
+        }
+        Ok(Self {
+            version: reader.read_u32::<BigEndian>()?,
+            FFTimeStamp: reader.read_i64::<BigEndian>()?,
+            sourceCreated: reader.read_i64::<BigEndian>()?,
+            sourceModified: reader.read_i64::<BigEndian>()?,
+        })
+    }
+}
+
+#[cfg(test)]
+#[path = "fftm_test.rs"]
+mod tests;
@@ -0,0 +1,58 @@
+// Copyright 2025 Monotype Imaging Inc.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+//! Tests for the 'FFTM' SFNT table module
+use std::io::Cursor;
+
+use super::*;
+
+/// An 'FFTM' table as written by FontForge.
+const FFTM_DATA: [u8; 28] = [
+    0x00, 0x00, 0x00, 0x01, // version 1
+    0x00, 0x00, 0x00, 0x00, 0xe1, 0x2b, 0x3c, 0x4d, // FFTimeStamp
+    0x00, 0x00, 0x00, 0x00, 0xd5, 0x00, 0x00, 0x00, // sourceCreated
+    0x00, 0x00, 0x00, 0x00, 0xe0, 0x00, 0x00, 0x01, // sourceModified
+];
+
+#[test]
+fn test_read_exact() {
+    let fftm = TableFftm::from_reader_exact(
+        &mut Cursor::new(&FFTM_DATA),
+        0,
+        FFTM_DATA.len(),
+    )
+    .unwrap();
+    assert_eq!(
+        fftm,
+        TableFftm {
+            version: 1,
+            FFTimeStamp: 0xe12b3c4d,
+            sourceCreated: 0xd5000000,
+            sourceModified: 0xe0000001,
+        }
+    );
+}
+
+#[test]
+fn test_read_exact_truncated() {
+    let result = TableFftm::from_reader_exact(
+        &mut Cursor::new(&FFTM_DATA),
+        0,
+        FFTM_DATA.len() - 1,
+    );
+    assert!(matches!(
+        result,
+        Err(FontIoError::LoadTableTruncated(FontTag::FFTM))
+    ));
+}
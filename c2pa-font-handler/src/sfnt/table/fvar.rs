@@ -0,0 +1,181 @@
+// Copyright 2025 Monotype Imaging Inc.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+//! 'fvar' SFNT table.
+
+use std::io::{Read, Seek, SeekFrom};
+
+use byteorder::{BigEndian, ReadBytesExt};
+
+use crate::{
+    error::FontIoError, tag::FontTag, FontDataExactRead, FontDataRead,
+};
+
+/// Converts a 16.16 fixed-point value to a floating-point value.
+pub(crate) fn fixed_to_f32(value: i32) -> f32 {
+    value as f32 / 65536.0
+}
+
+/// A variation axis of a variable font.
+#[derive(Clone, Debug, PartialEq)]
+#[allow(non_snake_case)] // As named by Open Font Format / OpenType.
+pub struct FvarAxis {
+    /// Tag identifying the design variation of the axis (e.g., 'wght').
+    pub axisTag: FontTag,
+    /// The minimum coordinate value for the axis.
+    pub minValue: f32,
+    /// The default coordinate value for the axis.
+    pub defaultValue: f32,
+    /// The maximum coordinate value for the axis.
+    pub maxValue: f32,
+    /// Axis qualifiers.
+    pub flags: u16,
+    /// The name ID for entries in the 'name' table that provide a display
+    /// name for this axis.
+    pub axisNameID: u16,
+}
+
+/// A named instance of a variable font.
+#[derive(Clone, Debug, PartialEq)]
+#[allow(non_snake_case)] // As named by Open Font Format / OpenType.
+pub struct FvarInstance {
+    /// The name ID for entries in the 'name' table that provide subfamily
+    /// names for this instance (e.g., "Bold").
+    pub subfamilyNameID: u16,
+    /// Reserved for future use.
+    pub flags: u16,
+    /// The coordinates of the instance, one for each axis.
+    pub coordinates: Vec<f32>,
+    /// The name ID for entries in the 'name' table that provide PostScript
+    /// names for this instance, if present.
+    pub postScriptNameID: Option<u16>,
+}
+
+/// 'fvar' font table, describing the variation axes and named instances of a
+/// variable font.
+#[derive(Clone, Debug)]
+#[allow(non_snake_case)] // As named by Open Font Format / OpenType.
+pub struct TableFvar {
+    /// Major version of the table.
+    pub majorVersion: u16,
+    /// Minor version of the table.
+    pub minorVersion: u16,
+    /// The variation axes.
+    pub axes: Vec<FvarAxis>,
+    /// The named instances.
+    pub instances: Vec<FvarInstance>,
+}
+
+impl TableFvar {
+    /// The size of a variation axis record.
+    const AXIS_RECORD_SIZE: usize = 20;
+    /// The size of the table header.
+    const HEADER_SIZE: usize = 16;
+
+    /// Returns the coordinates of the given instance, paired with the tag of
+    /// each axis.
+    pub fn instance_coordinates(
+        &self,
+        instance: &FvarInstance,
+    ) -> Vec<(FontTag, f32)> {
+        self.axes
+            .iter()
+            .map(|axis| axis.axisTag)
+            .zip(instance.coordinates.iter().copied())
+            .collect()
+    }
+}
+
+impl FontDataExactRead for TableFvar {
+    type Error = FontIoError;
+
+    fn from_reader_exact<T: Read + Seek + ?Sized>(
+        reader: &mut T,
+        offset: u64,
+        size: usize,
+    ) -> Result<Self, Self::Error> {
+        reader.seek(SeekFrom::Start(offset))?;
+        if size < Self::HEADER_SIZE {
+            return Err(FontIoError::LoadTableTruncated(FontTag::FVAR));
+        }
+        let major_version = reader.read_u16::<BigEndian>()?;
+        let minor_version = reader.read_u16::<BigEndian>()?;
+        let axes_array_offset = reader.read_u16::<BigEndian>()? as usize;
+        let _reserved = reader.read_u16::<BigEndian>()?;
+        let axis_count = reader.read_u16::<BigEndian>()? as usize;
+        let axis_size = reader.read_u16::<BigEndian>()? as usize;
+        let instance_count = reader.read_u16::<BigEndian>()? as usize;
+        let instance_size = reader.read_u16::<BigEndian>()? as usize;
+        // Instances hold a coordinate for each axis, optionally followed by
+        // the PostScript name ID
+        let coordinates_size = 4 + axis_count * 4;
+        if axis_size < Self::AXIS_RECORD_SIZE
+            || (instance_count > 0
+                && instance_size != coordinates_size
+                && instance_size != coordinates_size + 2)
+        {
+            return Err(FontIoError::LoadTableTruncated(FontTag::FVAR));
+        }
+        // Make sure the axes and instances (which follow the axes) are all
+        // within the table
+        let instances_offset = axes_array_offset + axis_count * axis_size;
+        if instances_offset + instance_count * instance_size > size {
+            return Err(FontIoError::LoadTableTruncated(FontTag::FVAR));
+        }
+        let mut axes = Vec::with_capacity(axis_count);
+        for i in 0..axis_count {
+            let record_offset = axes_array_offset + i * axis_size;
+            reader.seek(SeekFrom::Start(offset + record_offset as u64))?;
+            axes.push(FvarAxis {
+                axisTag: FontTag::from_reader(reader)?,
+                minValue: fixed_to_f32(reader.read_i32::<BigEndian>()?),
+                defaultValue: fixed_to_f32(reader.read_i32::<BigEndian>()?),
+                maxValue: fixed_to_f32(reader.read_i32::<BigEndian>()?),
+                flags: reader.read_u16::<BigEndian>()?,
+                axisNameID: reader.read_u16::<BigEndian>()?,
+            });
+        }
+        let mut instances = Vec::with_capacity(instance_count);
+        for i in 0..instance_count {
+            let record_offset = instances_offset + i * instance_size;
+            reader.seek(SeekFrom::Start(offset + record_offset as u64))?;
+            let subfamily_name_id = reader.read_u16::<BigEndian>()?;
+            let flags = reader.read_u16::<BigEndian>()?;
+            let coordinates = (0..axis_count)
+                .map(|_| reader.read_i32::<BigEndian>().map(fixed_to_f32))
+                .collect::<Result<Vec<_>, _>>()?;
+            let post_script_name_id = if instance_size > coordinates_size {
+                Some(reader.read_u16::<BigEndian>()?)
+            } else {
+                None
+            };
+            instances.push(FvarInstance {
+                subfamilyNameID: subfamily_name_id,
+                flags,
+                coordinates,
+                postScriptNameID: post_script_name_id,
+            });
+        }
+        Ok(Self {
+            majorVersion: major_version,
+            minorVersion: minor_version,
+            axes,
+            instances,
+        })
+    }
+}
+
+#[cfg(test)]
+#[path = "fvar_test.rs"]
+mod tests;
@@ -0,0 +1,141 @@
+// Copyright 2025 Monotype Imaging Inc.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+//! Tests for the 'fvar' SFNT table module
+use std::io::Cursor;
+
+use super::*;
+
+/// An fvar table with 'wght' and 'wdth' axes, and two named instances with
+/// PostScript name IDs.
+const FVAR_DATA: [u8; 84] = [
+    0x00, 0x01, // major version
+    0x00, 0x00, // minor version
+    0x00, 0x10, // axes array offset
+    0x00, 0x02, // reserved
+    0x00, 0x02, // axis count
+    0x00, 0x14, // axis size
+    0x00, 0x02, // instance count
+    0x00, 0x0e, // instance size
+    // 'wght' axis
+    b'w', b'g', b'h', b't', //
+    0x00, 0x64, 0x00, 0x00, // min 100.0
+    0x01, 0x90, 0x00, 0x00, // default 400.0
+    0x03, 0x84, 0x00, 0x00, // max 900.0
+    0x00, 0x00, // flags
+    0x01, 0x00, // axis name ID 256
+    // 'wdth' axis
+    b'w', b'd', b't', b'h', //
+    0x00, 0x4b, 0x00, 0x00, // min 75.0
+    0x00, 0x64, 0x00, 0x00, // default 100.0
+    0x00, 0x64, 0x80, 0x00, // max 100.5
+    0x00, 0x00, // flags
+    0x01, 0x01, // axis name ID 257
+    // "Bold" instance
+    0x01, 0x02, // subfamily name ID 258
+    0x00, 0x00, // flags
+    0x02, 0xbc, 0x00, 0x00, // wght 700.0
+    0x00, 0x64, 0x00, 0x00, // wdth 100.0
+    0x01, 0x03, // PostScript name ID 259
+    // "Condensed" instance
+    0x01, 0x04, // subfamily name ID 260
+    0x00, 0x00, // flags
+    0x01, 0x90, 0x00, 0x00, // wght 400.0
+    0x00, 0x4b, 0x00, 0x00, // wdth 75.0
+    0xff, 0xff, // No PostScript name ID
+];
+
+#[test]
+fn test_reader_exact() {
+    let mut reader = Cursor::new(FVAR_DATA);
+    let fvar =
+        TableFvar::from_reader_exact(&mut reader, 0, FVAR_DATA.len()).unwrap();
+    assert_eq!(fvar.majorVersion, 1);
+    assert_eq!(fvar.minorVersion, 0);
+    assert_eq!(fvar.axes.len(), 2);
+    let wght = &fvar.axes[0];
+    assert_eq!(wght.axisTag, FontTag::new(*b"wght"));
+    assert_eq!(
+        (wght.minValue, wght.defaultValue, wght.maxValue),
+        (100.0, 400.0, 900.0)
+    );
+    assert_eq!(wght.axisNameID, 256);
+    let wdth = &fvar.axes[1];
+    assert_eq!(wdth.axisTag, FontTag::new(*b"wdth"));
+    assert_eq!(
+        (wdth.minValue, wdth.defaultValue, wdth.maxValue),
+        (75.0, 100.0, 100.5)
+    );
+    assert_eq!(fvar.instances.len(), 2);
+    let bold = &fvar.instances[0];
+    assert_eq!(bold.subfamilyNameID, 258);
+    assert_eq!(bold.coordinates, vec![700.0, 100.0]);
+    assert_eq!(bold.postScriptNameID, Some(259));
+    assert_eq!(
+        fvar.instance_coordinates(bold),
+        vec![
+            (FontTag::new(*b"wght"), 700.0),
+            (FontTag::new(*b"wdth"), 100.0)
+        ]
+    );
+    assert_eq!(fvar.instances[1].postScriptNameID, Some(0xffff));
+}
+
+#[test]
+fn test_reader_exact_without_post_script_name_ids() {
+    let mut data = FVAR_DATA.to_vec();
+    // Shrink the instances, dropping the PostScript name IDs
+    data[15] = 0x0c;
+    data.drain(82..84);
+    data.drain(68..70);
+    let mut reader = Cursor::new(&data);
+    let fvar =
+        TableFvar::from_reader_exact(&mut reader, 0, data.len()).unwrap();
+    assert_eq!(fvar.instances.len(), 2);
+    assert_eq!(fvar.instances[1].subfamilyNameID, 260);
+    assert_eq!(fvar.instances[1].coordinates, vec![400.0, 75.0]);
+    assert!(fvar
+        .instances
+        .iter()
+        .all(|instance| instance.postScriptNameID.is_none()));
+}
+
+#[test]
+fn test_reader_exact_truncated() {
+    let mut reader = Cursor::new(FVAR_DATA);
+    let result = TableFvar::from_reader_exact(&mut reader, 0, 12);
+    assert!(matches!(
+        result,
+        Err(FontIoError::LoadTableTruncated(FontTag::FVAR))
+    ));
+    // The last instance runs past the end of the table
+    let result =
+        TableFvar::from_reader_exact(&mut reader, 0, FVAR_DATA.len() - 1);
+    assert!(matches!(
+        result,
+        Err(FontIoError::LoadTableTruncated(FontTag::FVAR))
+    ));
+}
+
+#[test]
+fn test_reader_exact_invalid_instance_size() {
+    let mut data = FVAR_DATA;
+    data[15] = 0x0d;
+    let mut reader = Cursor::new(data);
+    let result = TableFvar::from_reader_exact(&mut reader, 0, data.len());
+    assert!(matches!(
+        result,
+        Err(FontIoError::LoadTableTruncated(FontTag::FVAR))
+    ));
+}
@@ -27,13 +27,13 @@ use crate::{
 };
 
 /// Spec-mandated magic number for the 'head' table.
-const HEAD_TABLE_MAGIC_NUMBER: u32 = 0x5f0f3cf5;
+pub(crate) const HEAD_TABLE_MAGIC_NUMBER: u32 = 0x5f0f3cf5;
 /// The 'head' table's checksumAdjustment value should be such that the
 /// whole-font checksum comes out to this value.
 pub(crate) const SFNT_EXPECTED_CHECKSUM: u32 = 0xb1b0afba;
 
 /// 'head' font table
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 #[repr(C, packed(1))]
 #[allow(non_snake_case)] // As named by Open Font Format / OpenType.
 pub struct TableHead {
@@ -77,17 +77,14 @@ pub struct TableHead {
 
 impl TableHead {
     /// The size of a 'head' table.
-    const SIZE: usize = size_of::<Self>();
-}
+    pub(crate) const SIZE: usize = size_of::<Self>();
 
-impl FontDataExactRead for TableHead {
-    type Error = FontIoError;
-
-    fn from_reader_exact<T: Read + Seek + ?Sized>(
+    /// Reads a 'head' table without validating its magic number.
+    pub(crate) fn from_reader_unchecked<T: Read + Seek + ?Sized>(
         reader: &mut T,
         offset: u64,
         size: usize,
-    ) -> Result<Self, Self::Error> {
+    ) -> Result<Self, FontIoError> {
         reader.seek(SeekFrom::Start(offset))?;
         if size != Self::SIZE {
             Err(FontIoError::LoadTableTruncated(FontTag::HEAD))
@@ -141,16 +138,27 @@ impl FontDataExactRead for TableHead {
                 // stream is left, so we don't do anything, since that is
                 // simplest.
             };
-            if head.magicNumber != HEAD_TABLE_MAGIC_NUMBER {
-                return Err(FontIoError::InvalidHeadMagicNumber(
-                    head.magicNumber,
-                ));
-            }
             Ok(head)
         }
     }
 }
 
+impl FontDataExactRead for TableHead {
+    type Error = FontIoError;
+
+    fn from_reader_exact<T: Read + Seek + ?Sized>(
+        reader: &mut T,
+        offset: u64,
+        size: usize,
+    ) -> Result<Self, Self::Error> {
+        let head = Self::from_reader_unchecked(reader, offset, size)?;
+        if head.magicNumber != HEAD_TABLE_MAGIC_NUMBER {
+            return Err(FontIoError::InvalidHeadMagicNumber(head.magicNumber));
+        }
+        Ok(head)
+    }
+}
+
 impl FontDataWrite for TableHead {
     type Error = FontIoError;
 
@@ -53,6 +53,15 @@ fn test_reader_with_bad_magic_number() {
     assert!(matches!(err, FontIoError::InvalidHeadMagicNumber(0u32)));
 }
 
+#[test]
+fn test_reader_unchecked_with_bad_magic_number() {
+    let mut reader = std::io::Cursor::new(vec![0; TableHead::SIZE]);
+    let head =
+        TableHead::from_reader_unchecked(&mut reader, 0, TableHead::SIZE)
+            .unwrap();
+    assert_eq!({ head.magicNumber }, 0);
+}
+
 #[test]
 fn test_reader_with_valid_data() {
     let mut reader = std::io::Cursor::new(vec![
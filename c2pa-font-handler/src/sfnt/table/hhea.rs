@@ -0,0 +1,116 @@
+// Copyright 2025 Monotype Imaging Inc.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+//! 'hhea' SFNT table.
+
+use std::io::{Read, Seek, SeekFrom};
+
+use byteorder::{BigEndian, ReadBytesExt};
+
+use crate::{error::FontIoError, tag::FontTag, FontDataExactRead};
+
+/// 'hhea' font table, holding the metrics for horizontal layout.
+#[derive(Clone, Debug)]
+#[allow(non_snake_case)] // As named by Open Font Format / OpenType.
+pub struct TableHhea {
+    /// Major version of the table (1).
+    pub majorVersion: u16,
+    /// Minor version of the table (0).
+    pub minorVersion: u16,
+    /// Typographic ascent.
+    pub ascender: i16,
+    /// Typographic descent.
+    pub descender: i16,
+    /// Typographic line gap.
+    pub lineGap: i16,
+    /// Maximum advance width in the 'hmtx' table.
+    pub advanceWidthMax: u16,
+    /// Minimum left side bearing in the 'hmtx' table.
+    pub minLeftSideBearing: i16,
+    /// Minimum right side bearing.
+    pub minRightSideBearing: i16,
+    /// Maximum extent, i.e. the left side bearing plus the width of the
+    /// glyph's bounding box.
+    pub xMaxExtent: i16,
+    /// Rise of the slope of the caret (1 for vertical).
+    pub caretSlopeRise: i16,
+    /// Run of the slope of the caret (0 for vertical).
+    pub caretSlopeRun: i16,
+    /// Shift of the slanted highlight off the glyph.
+    pub caretOffset: i16,
+    /// Format of the metric data (0).
+    pub metricDataFormat: i16,
+    /// Number of advance widths and left side bearing pairs in the 'hmtx'
+    /// table.
+    pub numberOfHMetrics: u16,
+}
+
+impl TableHhea {
+    /// Offset of the metric data format, following the reserved fields.
+    const METRIC_DATA_FORMAT_OFFSET: u64 = 32;
+    /// The size of the table.
+    const SIZE: usize = 36;
+}
+
+impl FontDataExactRead for TableHhea {
+    type Error = FontIoError;
+
+    fn from_reader_exact<T: Read + Seek + ?Sized>(
+        reader: &mut T,
+        offset: u64,
+        size: usize,
+    ) -> Result<Self, Self::Error> {
+        reader.seek(SeekFrom::Start(offset))?;
+        if size < Self::SIZE {
+            return Err(FontIoError::LoadTableTruncated(FontTag::HHEA));
+        }
+        let major_version = reader.read_u16::<BigEndian>()?;
+        let minor_version = reader.read_u16::<BigEndian>()?;
+        let ascender = reader.read_i16::<BigEndian>()?;
+        let descender = reader.read_i16::<BigEndian>()?;
+        let line_gap = reader.read_i16::<BigEndian>()?;
+        let advance_width_max = reader.read_u16::<BigEndian>()?;
+        let min_left_side_bearing = reader.read_i16::<BigEndian>()?;
+        let min_right_side_bearing = reader.read_i16::<BigEndian>()?;
+        let x_max_extent = reader.read_i16::<BigEndian>()?;
+        let caret_slope_rise = reader.read_i16::<BigEndian>()?;
+        let caret_slope_run = reader.read_i16::<BigEndian>()?;
+        let caret_offset = reader.read_i16::<BigEndian>()?;
+        // Skip the reserved fields
+        reader
+            .seek(SeekFrom::Start(offset + Self::METRIC_DATA_FORMAT_OFFSET))?;
+        let metric_data_format = reader.read_i16::<BigEndian>()?;
+        let number_of_h_metrics = reader.read_u16::<BigEndian>()?;
+        Ok(Self {
+            majorVersion: major_version,
+            minorVersion: minor_version,
+            ascender,
+            descender,
+            lineGap: line_gap,
+            advanceWidthMax: advance_width_max,
+            minLeftSideBearing: min_left_side_bearing,
+            minRightSideBearing: min_right_side_bearing,
+            xMaxExtent: x_max_extent,
+            caretSlopeRise: caret_slope_rise,
+            caretSlopeRun: caret_slope_run,
+            caretOffset: caret_offset,
+            metricDataFormat: metric_data_format,
+            numberOfHMetrics: number_of_h_metrics,
+        })
+    }
+}
+
+#[cfg(test)]
+#[path = "hhea_test.rs"]
+mod tests;
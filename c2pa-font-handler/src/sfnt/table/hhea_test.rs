@@ -0,0 +1,62 @@
+// Copyright 2025 Monotype Imaging Inc.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+//! Tests for the 'hhea' SFNT table module
+use std::io::Cursor;
+
+use super::*;
+
+/// The 'hhea' table of the test font.
+const HHEA_DATA: [u8; 36] = [
+    0x00, 0x01, 0x00, 0x00, // majorVersion, minorVersion
+    0x02, 0xee, 0xff, 0x06, // ascender, descender
+    0x00, 0x64, 0x02, 0x7f, // lineGap, advanceWidthMax
+    0x00, 0x00, 0xff, 0xff, // minLeftSideBearing, minRightSideBearing
+    0x02, 0x80, 0x00, 0x01, // xMaxExtent, caretSlopeRise
+    0x00, 0x00, 0x00, 0x00, // caretSlopeRun, caretOffset
+    0x00, 0x00, 0x00, 0x00, // reserved
+    0x00, 0x00, 0x00, 0x00, // reserved
+    0x00, 0x00, 0x00, 0x05, // metricDataFormat, numberOfHMetrics
+];
+
+#[test]
+fn test_reader_exact() {
+    let hhea =
+        TableHhea::from_reader_exact(&mut Cursor::new(&HHEA_DATA), 0, 36)
+            .unwrap();
+    assert_eq!(hhea.majorVersion, 1);
+    assert_eq!(hhea.minorVersion, 0);
+    assert_eq!(hhea.ascender, 750);
+    assert_eq!(hhea.descender, -250);
+    assert_eq!(hhea.lineGap, 100);
+    assert_eq!(hhea.advanceWidthMax, 639);
+    assert_eq!(hhea.minLeftSideBearing, 0);
+    assert_eq!(hhea.minRightSideBearing, -1);
+    assert_eq!(hhea.xMaxExtent, 640);
+    assert_eq!(hhea.caretSlopeRise, 1);
+    assert_eq!(hhea.caretSlopeRun, 0);
+    assert_eq!(hhea.caretOffset, 0);
+    assert_eq!(hhea.metricDataFormat, 0);
+    assert_eq!(hhea.numberOfHMetrics, 5);
+}
+
+#[test]
+fn test_reader_exact_truncated() {
+    let result =
+        TableHhea::from_reader_exact(&mut Cursor::new(&HHEA_DATA), 0, 34);
+    assert!(matches!(
+        result,
+        Err(FontIoError::LoadTableTruncated(FontTag::HHEA))
+    ));
+}
@@ -0,0 +1,99 @@
+// Copyright 2025 Monotype Imaging Inc.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+//! 'hmtx' SFNT table.
+
+use std::io::{Read, Seek, SeekFrom};
+
+use byteorder::{BigEndian, ReadBytesExt};
+
+use crate::{error::FontIoError, tag::FontTag};
+
+/// 'hmtx' font table, holding the advance width and left side bearing of
+/// each glyph.
+///
+/// # Remarks
+/// Only the first `numberOfHMetrics` glyphs have their own advance width; the
+/// remaining glyphs share the advance width of the last of them (e.g., in a
+/// monospaced font).
+#[derive(Clone, Debug)]
+pub struct TableHmtx {
+    /// The advance width and left side bearing pairs.
+    pub h_metrics: Vec<(u16, i16)>,
+    /// The left side bearings of the glyphs following those with their own
+    /// advance width.
+    pub left_side_bearings: Vec<i16>,
+}
+
+impl TableHmtx {
+    /// Reads the table, using the number of advance widths from the 'hhea'
+    /// table's `numberOfHMetrics` and the number of glyphs from the 'maxp'
+    /// table.
+    ///
+    /// # Errors
+    /// Returns [`FontIoError::LoadTableTruncated`] if the table is too small
+    /// for the number of metrics, or if there are no metrics at all.
+    pub fn from_reader_with_counts<T: Read + Seek + ?Sized>(
+        reader: &mut T,
+        offset: u64,
+        size: usize,
+        number_of_h_metrics: u16,
+        num_glyphs: u16,
+    ) -> Result<Self, FontIoError> {
+        let h_metric_count = number_of_h_metrics as usize;
+        let bearing_count =
+            (num_glyphs as usize).saturating_sub(h_metric_count);
+        if h_metric_count == 0 || h_metric_count * 4 + bearing_count * 2 > size
+        {
+            return Err(FontIoError::LoadTableTruncated(FontTag::HMTX));
+        }
+        reader.seek(SeekFrom::Start(offset))?;
+        let h_metrics = (0..h_metric_count)
+            .map(|_| {
+                Ok((
+                    reader.read_u16::<BigEndian>()?,
+                    reader.read_i16::<BigEndian>()?,
+                ))
+            })
+            .collect::<Result<Vec<_>, std::io::Error>>()?;
+        let left_side_bearings = (0..bearing_count)
+            .map(|_| reader.read_i16::<BigEndian>())
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self {
+            h_metrics,
+            left_side_bearings,
+        })
+    }
+
+    /// Returns the number of glyphs with metrics in the table.
+    pub fn num_glyphs(&self) -> usize {
+        self.h_metrics.len() + self.left_side_bearings.len()
+    }
+
+    /// Returns the advance width of the given glyph, or `None` if the glyph
+    /// is out of range.
+    pub fn advance_width(&self, gid: u16) -> Option<u16> {
+        if gid as usize >= self.num_glyphs() {
+            return None;
+        }
+        self.h_metrics
+            .get(gid as usize)
+            .or(self.h_metrics.last())
+            .map(|(advance_width, _)| *advance_width)
+    }
+}
+
+#[cfg(test)]
+#[path = "hmtx_test.rs"]
+mod tests;
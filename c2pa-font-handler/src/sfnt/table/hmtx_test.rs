@@ -0,0 +1,76 @@
+// Copyright 2025 Monotype Imaging Inc.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+//! Tests for the 'hmtx' SFNT table module
+use std::io::Cursor;
+
+use super::*;
+
+/// An 'hmtx' table with two advance widths, followed by the left side
+/// bearings of two more glyphs.
+const HMTX_DATA: [u8; 12] = [
+    0x01, 0xf4, 0x00, 0x0a, // glyph 0: advanceWidth 500, lsb 10
+    0x02, 0x58, 0xff, 0xfb, // glyph 1: advanceWidth 600, lsb -5
+    0x00, 0x14, // glyph 2: lsb 20
+    0x00, 0x1e, // glyph 3: lsb 30
+];
+
+#[test]
+fn test_reader_with_counts() {
+    let hmtx = TableHmtx::from_reader_with_counts(
+        &mut Cursor::new(&HMTX_DATA),
+        0,
+        HMTX_DATA.len(),
+        2,
+        4,
+    )
+    .unwrap();
+    assert_eq!(hmtx.h_metrics, vec![(500, 10), (600, -5)]);
+    assert_eq!(hmtx.left_side_bearings, vec![20, 30]);
+    assert_eq!(hmtx.num_glyphs(), 4);
+    assert_eq!(hmtx.advance_width(0), Some(500));
+    assert_eq!(hmtx.advance_width(1), Some(600));
+    // The remaining glyphs share the last advance width
+    assert_eq!(hmtx.advance_width(2), Some(600));
+    assert_eq!(hmtx.advance_width(3), Some(600));
+    assert_eq!(hmtx.advance_width(4), None);
+}
+
+#[test]
+fn test_reader_truncated() {
+    // Too small for the left side bearings of a fifth glyph
+    let result = TableHmtx::from_reader_with_counts(
+        &mut Cursor::new(&HMTX_DATA),
+        0,
+        HMTX_DATA.len(),
+        2,
+        5,
+    );
+    assert!(matches!(
+        result,
+        Err(FontIoError::LoadTableTruncated(FontTag::HMTX))
+    ));
+    // There must be at least one advance width
+    let result = TableHmtx::from_reader_with_counts(
+        &mut Cursor::new(&HMTX_DATA),
+        0,
+        HMTX_DATA.len(),
+        0,
+        4,
+    );
+    assert!(matches!(
+        result,
+        Err(FontIoError::LoadTableTruncated(FontTag::HMTX))
+    ));
+}
@@ -0,0 +1,102 @@
+// Copyright 2025 Monotype Imaging Inc.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+//! 'loca' SFNT table.
+
+use std::io::{Read, Seek, SeekFrom};
+
+use byteorder::{BigEndian, ReadBytesExt};
+
+use crate::{error::FontIoError, tag::FontTag};
+
+/// 'loca' font table, holding the offsets of each glyph within the 'glyf'
+/// table.
+///
+/// # Remarks
+/// The offsets are always stored as byte offsets, regardless of whether the
+/// table uses the short or long format.
+#[derive(Clone, Debug)]
+pub struct TableLoca {
+    /// The byte offsets of the glyphs, with one extra offset marking the end
+    /// of the last glyph.
+    pub offsets: Vec<u32>,
+}
+
+impl TableLoca {
+    /// `indexToLocFormat` value for long (32-bit) offsets.
+    pub const LONG_FORMAT: i16 = 1;
+    /// `indexToLocFormat` value for short (16-bit, halved) offsets.
+    pub const SHORT_FORMAT: i16 = 0;
+
+    /// Reads the table, using the number of glyphs from the 'maxp' table and
+    /// the offset format from the 'head' table's `indexToLocFormat`.
+    ///
+    /// # Errors
+    /// Returns [`FontIoError::InvalidIndexToLocFormat`] for an unknown offset
+    /// format, or [`FontIoError::LoadTableTruncated`] if the table is too
+    /// small for the number of glyphs.
+    pub fn from_reader_with_format<T: Read + Seek + ?Sized>(
+        reader: &mut T,
+        offset: u64,
+        size: usize,
+        num_glyphs: u16,
+        index_to_loc_format: i16,
+    ) -> Result<Self, FontIoError> {
+        let count = num_glyphs as usize + 1;
+        let entry_size = match index_to_loc_format {
+            Self::SHORT_FORMAT => 2,
+            Self::LONG_FORMAT => 4,
+            _ => {
+                return Err(FontIoError::InvalidIndexToLocFormat(
+                    index_to_loc_format,
+                ))
+            }
+        };
+        if count * entry_size > size {
+            return Err(FontIoError::LoadTableTruncated(FontTag::LOCA));
+        }
+        reader.seek(SeekFrom::Start(offset))?;
+        let offsets = (0..count)
+            .map(|_| match index_to_loc_format {
+                // Short offsets are stored divided by 2
+                Self::SHORT_FORMAT => {
+                    reader.read_u16::<BigEndian>().map(|o| o as u32 * 2)
+                }
+                _ => reader.read_u32::<BigEndian>(),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self { offsets })
+    }
+
+    /// Returns the number of glyphs with offsets in the table.
+    pub fn num_glyphs(&self) -> usize {
+        self.offsets.len().saturating_sub(1)
+    }
+
+    /// Returns the byte range, as start (inclusive) and end (exclusive)
+    /// offsets within the 'glyf' table, of the given glyph; or `None` if the
+    /// glyph is out of range or its offsets are not in order.
+    ///
+    /// # Remarks
+    /// Glyphs without outlines (e.g., space) have an empty range.
+    pub fn glyph_range(&self, gid: u16) -> Option<(u32, u32)> {
+        let start = *self.offsets.get(gid as usize)?;
+        let end = *self.offsets.get(gid as usize + 1)?;
+        (start <= end).then_some((start, end))
+    }
+}
+
+#[cfg(test)]
+#[path = "loca_test.rs"]
+mod tests;
@@ -0,0 +1,91 @@
+// Copyright 2025 Monotype Imaging Inc.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+//! Tests for the 'loca' SFNT table module
+use std::io::Cursor;
+
+use super::*;
+
+#[test]
+fn test_reader_short_format() {
+    // Three glyphs, where the second is empty
+    let data = [0x00, 0x00, 0x00, 0x0a, 0x00, 0x0a, 0x00, 0x18];
+    let loca = TableLoca::from_reader_with_format(
+        &mut Cursor::new(data),
+        0,
+        data.len(),
+        3,
+        TableLoca::SHORT_FORMAT,
+    )
+    .unwrap();
+    assert_eq!(loca.offsets, vec![0, 20, 20, 48]);
+    assert_eq!(loca.num_glyphs(), 3);
+    assert_eq!(loca.glyph_range(0), Some((0, 20)));
+    assert_eq!(loca.glyph_range(1), Some((20, 20)));
+    assert_eq!(loca.glyph_range(2), Some((20, 48)));
+    assert_eq!(loca.glyph_range(3), None);
+}
+
+#[test]
+fn test_reader_long_format() {
+    let data = [
+        0x00, 0x00, 0x00, 0x00, // glyph 0
+        0x00, 0x01, 0x00, 0x00, // glyph 1
+        0x00, 0x00, 0x00, 0x10, // end, before glyph 1
+    ];
+    let loca = TableLoca::from_reader_with_format(
+        &mut Cursor::new(data),
+        0,
+        data.len(),
+        2,
+        TableLoca::LONG_FORMAT,
+    )
+    .unwrap();
+    assert_eq!(loca.offsets, vec![0, 0x10000, 0x10]);
+    assert_eq!(loca.glyph_range(0), Some((0, 0x10000)));
+    // Offsets out of order
+    assert_eq!(loca.glyph_range(1), None);
+}
+
+#[test]
+fn test_reader_truncated() {
+    let data = [0x00, 0x00, 0x00, 0x0a];
+    let result = TableLoca::from_reader_with_format(
+        &mut Cursor::new(data),
+        0,
+        data.len(),
+        2,
+        TableLoca::SHORT_FORMAT,
+    );
+    assert!(matches!(
+        result,
+        Err(FontIoError::LoadTableTruncated(FontTag::LOCA))
+    ));
+}
+
+#[test]
+fn test_reader_invalid_format() {
+    let data = [0x00, 0x00, 0x00, 0x0a];
+    let result = TableLoca::from_reader_with_format(
+        &mut Cursor::new(data),
+        0,
+        data.len(),
+        1,
+        2,
+    );
+    assert!(matches!(
+        result,
+        Err(FontIoError::InvalidIndexToLocFormat(2))
+    ));
+}
@@ -0,0 +1,184 @@
+// Copyright 2025 Monotype Imaging Inc.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+//! 'maxp' SFNT table.
+
+use std::io::{Read, Seek, SeekFrom, Write};
+
+use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
+
+use crate::{
+    error::FontIoError, tag::FontTag, utils, FontDataChecksum,
+    FontDataExactRead, FontDataWrite, FontTable,
+};
+
+/// Version 0.5 of the 'maxp' table, used by fonts with CFF outlines.
+const MAXP_VERSION_0_5: u32 = 0x00005000;
+/// Version 1.0 of the 'maxp' table, used by fonts with TrueType outlines.
+const MAXP_VERSION_1_0: u32 = 0x00010000;
+
+/// 'maxp' font table
+#[derive(Clone, Debug)]
+#[allow(non_snake_case)] // As named by Open Font Format / OpenType.
+pub struct TableMaxp {
+    /// Version of the table; 0x00005000 for version 0.5, 0x00010000 for
+    /// version 1.0.
+    pub version: u32,
+    /// The number of glyphs in the font.
+    pub numGlyphs: u16,
+    /// The additional fields present in version 1.0 of the table.
+    pub v1: Option<TableMaxpV1>,
+}
+
+/// The fields of the 'maxp' table which are only present in version 1.0.
+#[derive(Clone, Debug, Default)]
+#[allow(non_snake_case)] // As named by Open Font Format / OpenType.
+pub struct TableMaxpV1 {
+    /// Maximum points in a non-composite glyph.
+    pub maxPoints: u16,
+    /// Maximum contours in a non-composite glyph.
+    pub maxContours: u16,
+    /// Maximum points in a composite glyph.
+    pub maxCompositePoints: u16,
+    /// Maximum contours in a composite glyph.
+    pub maxCompositeContours: u16,
+    /// 1 if instructions do not use the twilight zone, 2 otherwise.
+    pub maxZones: u16,
+    /// Maximum points used in the twilight zone.
+    pub maxTwilightPoints: u16,
+    /// Number of storage area locations.
+    pub maxStorage: u16,
+    /// Number of function definitions.
+    pub maxFunctionDefs: u16,
+    /// Number of instruction definitions.
+    pub maxInstructionDefs: u16,
+    /// Maximum stack depth.
+    pub maxStackElements: u16,
+    /// Maximum byte count for glyph instructions.
+    pub maxSizeOfInstructions: u16,
+    /// Maximum number of components referenced at the top level of a
+    /// composite glyph.
+    pub maxComponentElements: u16,
+    /// Maximum levels of recursion.
+    pub maxComponentDepth: u16,
+}
+
+impl TableMaxp {
+    /// The size of a version 0.5 'maxp' table.
+    const SIZE_V0_5: usize = 6;
+    /// The size of a version 1.0 'maxp' table.
+    const SIZE_V1_0: usize = 32;
+
+    /// Returns the number of glyphs in the font.
+    pub fn num_glyphs(&self) -> u16 {
+        self.numGlyphs
+    }
+}
+
+impl FontDataExactRead for TableMaxp {
+    type Error = FontIoError;
+
+    fn from_reader_exact<T: Read + Seek + ?Sized>(
+        reader: &mut T,
+        offset: u64,
+        size: usize,
+    ) -> Result<Self, Self::Error> {
+        reader.seek(SeekFrom::Start(offset))?;
+        if size < Self::SIZE_V0_5 {
+            return Err(FontIoError::LoadTableTruncated(FontTag::MAXP));
+        }
+        let version = reader.read_u32::<BigEndian>()?;
+        let num_glyphs = reader.read_u16::<BigEndian>()?;
+        let v1 = match version {
+            MAXP_VERSION_0_5 => None,
+            MAXP_VERSION_1_0 if size >= Self::SIZE_V1_0 => Some(TableMaxpV1 {
+                maxPoints: reader.read_u16::<BigEndian>()?,
+                maxContours: reader.read_u16::<BigEndian>()?,
+                maxCompositePoints: reader.read_u16::<BigEndian>()?,
+                maxCompositeContours: reader.read_u16::<BigEndian>()?,
+                maxZones: reader.read_u16::<BigEndian>()?,
+                maxTwilightPoints: reader.read_u16::<BigEndian>()?,
+                maxStorage: reader.read_u16::<BigEndian>()?,
+                maxFunctionDefs: reader.read_u16::<BigEndian>()?,
+                maxInstructionDefs: reader.read_u16::<BigEndian>()?,
+                maxStackElements: reader.read_u16::<BigEndian>()?,
+                maxSizeOfInstructions: reader.read_u16::<BigEndian>()?,
+                maxComponentElements: reader.read_u16::<BigEndian>()?,
+                maxComponentDepth: reader.read_u16::<BigEndian>()?,
+            }),
+            MAXP_VERSION_1_0 => {
+                return Err(FontIoError::LoadTableTruncated(FontTag::MAXP));
+            }
+            _ => return Err(FontIoError::InvalidMaxpVersion(version)),
+        };
+        Ok(Self {
+            version,
+            numGlyphs: num_glyphs,
+            v1,
+        })
+    }
+}
+
+impl FontDataWrite for TableMaxp {
+    type Error = FontIoError;
+
+    fn write<TDest: Write + ?Sized>(
+        &self,
+        dest: &mut TDest,
+    ) -> Result<(), Self::Error> {
+        dest.write_u32::<BigEndian>(self.version)?;
+        dest.write_u16::<BigEndian>(self.numGlyphs)?;
+        if let Some(v1) = &self.v1 {
+            dest.write_u16::<BigEndian>(v1.maxPoints)?;
+            dest.write_u16::<BigEndian>(v1.maxContours)?;
+            dest.write_u16::<BigEndian>(v1.maxCompositePoints)?;
+            dest.write_u16::<BigEndian>(v1.maxCompositeContours)?;
+            dest.write_u16::<BigEndian>(v1.maxZones)?;
+            dest.write_u16::<BigEndian>(v1.maxTwilightPoints)?;
+            dest.write_u16::<BigEndian>(v1.maxStorage)?;
+            dest.write_u16::<BigEndian>(v1.maxFunctionDefs)?;
+            dest.write_u16::<BigEndian>(v1.maxInstructionDefs)?;
+            dest.write_u16::<BigEndian>(v1.maxStackElements)?;
+            dest.write_u16::<BigEndian>(v1.maxSizeOfInstructions)?;
+            dest.write_u16::<BigEndian>(v1.maxComponentElements)?;
+            dest.write_u16::<BigEndian>(v1.maxComponentDepth)?;
+        } else {
+            // Pad the 6-byte version 0.5 table up to a 4-byte boundary
+            dest.write_u16::<BigEndian>(0_u16)?;
+        }
+        Ok(())
+    }
+}
+
+impl FontDataChecksum for TableMaxp {
+    fn checksum(&self) -> std::num::Wrapping<u32> {
+        let mut data = Vec::with_capacity(Self::SIZE_V1_0);
+        // Writing to a vector cannot fail
+        let _ = self.write(&mut data);
+        utils::checksum(&data)
+    }
+}
+
+impl FontTable for TableMaxp {
+    fn len(&self) -> u32 {
+        match self.v1 {
+            Some(_) => Self::SIZE_V1_0 as u32,
+            None => Self::SIZE_V0_5 as u32,
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "maxp_test.rs"]
+mod tests;
@@ -0,0 +1,99 @@
+// Copyright 2025 Monotype Imaging Inc.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+//! Tests for the 'maxp' SFNT table module
+use std::io::Cursor;
+
+use super::*;
+
+#[test]
+fn test_reader_exact_version_0_5() {
+    let mut reader = Cursor::new(vec![
+        0x00, 0x00, 0x50, 0x00, // version
+        0x01, 0x02, // num glyphs
+    ]);
+    let maxp = TableMaxp::from_reader_exact(&mut reader, 0, 6).unwrap();
+    assert_eq!(maxp.version, MAXP_VERSION_0_5);
+    assert_eq!(maxp.num_glyphs(), 0x0102);
+    assert!(maxp.v1.is_none());
+    assert_eq!(maxp.len(), 6);
+}
+
+#[test]
+fn test_reader_exact_version_1_0() {
+    let mut data = vec![
+        0x00, 0x01, 0x00, 0x00, // version
+        0x00, 0x10, // num glyphs
+    ];
+    // Fill in the remaining 13 fields with increasing values
+    for value in 1..=13u16 {
+        data.extend_from_slice(&value.to_be_bytes());
+    }
+    let mut reader = Cursor::new(data.clone());
+    let maxp = TableMaxp::from_reader_exact(&mut reader, 0, 32).unwrap();
+    assert_eq!(maxp.num_glyphs(), 16);
+    let v1 = maxp.v1.as_ref().unwrap();
+    assert_eq!(v1.maxPoints, 1);
+    assert_eq!(v1.maxZones, 5);
+    assert_eq!(v1.maxComponentDepth, 13);
+    assert_eq!(maxp.len(), 32);
+    // Writing it back out should produce the same data
+    let mut written = Vec::new();
+    maxp.write(&mut written).unwrap();
+    assert_eq!(written, data);
+    assert_eq!(maxp.checksum(), utils::checksum(&data));
+}
+
+#[test]
+fn test_reader_exact_truncated() {
+    let mut reader = Cursor::new(vec![0x00, 0x00, 0x50, 0x00]);
+    let result = TableMaxp::from_reader_exact(&mut reader, 0, 4);
+    assert!(matches!(
+        result,
+        Err(FontIoError::LoadTableTruncated(FontTag::MAXP))
+    ));
+    // A version 1.0 table needs all 32 bytes
+    let mut reader =
+        Cursor::new(vec![0x00, 0x01, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00]);
+    let result = TableMaxp::from_reader_exact(&mut reader, 0, 8);
+    assert!(matches!(
+        result,
+        Err(FontIoError::LoadTableTruncated(FontTag::MAXP))
+    ));
+}
+
+#[test]
+fn test_reader_exact_invalid_version() {
+    let mut reader = Cursor::new(vec![0x00, 0x02, 0x00, 0x00, 0x00, 0x10]);
+    let result = TableMaxp::from_reader_exact(&mut reader, 0, 6);
+    assert!(matches!(
+        result,
+        Err(FontIoError::InvalidMaxpVersion(0x00020000))
+    ));
+}
+
+#[test]
+fn test_write_version_0_5_is_padded() {
+    let maxp = TableMaxp {
+        version: MAXP_VERSION_0_5,
+        numGlyphs: 5,
+        v1: None,
+    };
+    let mut written = Vec::new();
+    maxp.write(&mut written).unwrap();
+    assert_eq!(
+        written,
+        vec![0x00, 0x00, 0x50, 0x00, 0x00, 0x05, 0x00, 0x00]
+    );
+}
@@ -0,0 +1,369 @@
+// Copyright 2025 Monotype Imaging Inc.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+//! 'name' SFNT table.
+
+use std::{
+    collections::BTreeMap,
+    io::{Read, Seek, SeekFrom},
+};
+
+use byteorder::{BigEndian, ReadBytesExt};
+
+use crate::{error::FontIoError, tag::FontTag, FontDataExactRead};
+
+/// A name record of the 'name' table, with its string decoded.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[allow(non_snake_case)] // As named by Open Font Format / OpenType.
+pub struct NameRecord {
+    /// Platform ID.
+    pub platformID: u16,
+    /// Platform-specific encoding ID.
+    pub encodingID: u16,
+    /// Language ID.
+    pub languageID: u16,
+    /// Name ID.
+    pub nameID: u16,
+    /// The decoded string, or `None` if the platform and encoding are not
+    /// supported, or the string fails to decode.
+    ///
+    /// # Remarks
+    /// Strings for the Unicode and Windows platforms are decoded from
+    /// UTF-16BE; strings for the Macintosh platform are decoded only when they
+    /// are plain ASCII.
+    pub value: Option<String>,
+}
+
+/// A decoded name string, along with the platform and language it is for.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LocalizedName {
+    /// Platform ID.
+    pub platform_id: u16,
+    /// Platform-specific encoding ID.
+    pub encoding_id: u16,
+    /// Language ID, as recorded in the name record.
+    pub language_id: u16,
+    /// The IETF BCP 47 language tag for the language ID, if known.
+    ///
+    /// # Remarks
+    /// Tags are taken from the language-tag records of format 1 tables, and
+    /// otherwise looked up for the more common Windows and Macintosh
+    /// language IDs.
+    pub language_tag: Option<String>,
+    /// The decoded string.
+    pub value: String,
+}
+
+/// 'name' font table.
+#[derive(Clone, Debug)]
+pub struct TableName {
+    /// Table version number (0 or 1).
+    pub format: u16,
+    /// The name records of the table.
+    pub records: Vec<NameRecord>,
+    /// The language tags of a format 1 table, for the language IDs starting
+    /// at 0x8000; `None` where a tag fails to decode.
+    pub language_tags: Vec<Option<String>>,
+}
+
+impl TableName {
+    /// Name ID of the font family name.
+    pub const FAMILY_NAME_ID: u16 = 1;
+    /// The first language ID referring to a language-tag record.
+    const FIRST_LANGUAGE_TAG_ID: u16 = 0x8000;
+    /// The size of the table header.
+    const HEADER_SIZE: usize = 6;
+    /// Windows platform language ID for English (United States).
+    const LANGUAGE_ENGLISH_US: u16 = 0x0409;
+    /// The size of a language-tag record.
+    const LANGUAGE_TAG_RECORD_SIZE: usize = 4;
+    /// Macintosh platform ID.
+    const PLATFORM_MACINTOSH: u16 = 1;
+    /// Unicode platform ID.
+    const PLATFORM_UNICODE: u16 = 0;
+    /// Windows platform ID.
+    const PLATFORM_WINDOWS: u16 = 3;
+    /// The size of a name record.
+    const RECORD_SIZE: usize = 12;
+    /// Name ID of the typographic family name.
+    pub const TYPOGRAPHIC_FAMILY_NAME_ID: u16 = 16;
+
+    /// Returns the decoded strings of all the records with the given name ID.
+    pub fn names(&self, name_id: u16) -> impl Iterator<Item = &str> {
+        self.records
+            .iter()
+            .filter(move |record| record.nameID == name_id)
+            .filter_map(|record| record.value.as_deref())
+    }
+
+    /// Returns the decoded string for the given name ID, preferring the
+    /// Windows platform's English (United States) record, if any.
+    pub fn name(&self, name_id: u16) -> Option<&str> {
+        let mut records = self.records.iter().filter(|record| {
+            record.nameID == name_id && record.value.is_some()
+        });
+        records
+            .clone()
+            .find(|record| {
+                record.platformID == Self::PLATFORM_WINDOWS
+                    && record.languageID == Self::LANGUAGE_ENGLISH_US
+            })
+            .or_else(|| records.next())
+            .and_then(|record| record.value.as_deref())
+    }
+
+    /// Returns the IETF BCP 47 language tag for the language ID of the given
+    /// platform, if known.
+    pub fn language_tag(
+        &self,
+        platform_id: u16,
+        language_id: u16,
+    ) -> Option<&str> {
+        if language_id >= Self::FIRST_LANGUAGE_TAG_ID {
+            return self
+                .language_tags
+                .get((language_id - Self::FIRST_LANGUAGE_TAG_ID) as usize)?
+                .as_deref();
+        }
+        let languages = match platform_id {
+            Self::PLATFORM_WINDOWS => WINDOWS_LANGUAGE_TAGS,
+            Self::PLATFORM_MACINTOSH => MACINTOSH_LANGUAGE_TAGS,
+            _ => return None,
+        };
+        languages
+            .iter()
+            .find(|(id, _)| *id == language_id)
+            .map(|(_, tag)| *tag)
+    }
+
+    /// Returns every decoded name, grouped by name ID, with all of its
+    /// localized variants in the order of the table's records.
+    ///
+    /// # Remarks
+    /// Records whose strings cannot be decoded are skipped.
+    pub fn localized_names(&self) -> BTreeMap<u16, Vec<LocalizedName>> {
+        let mut names: BTreeMap<u16, Vec<LocalizedName>> = BTreeMap::new();
+        for record in &self.records {
+            let Some(value) = &record.value else {
+                continue;
+            };
+            names.entry(record.nameID).or_default().push(LocalizedName {
+                platform_id: record.platformID,
+                encoding_id: record.encodingID,
+                language_id: record.languageID,
+                language_tag: self
+                    .language_tag(record.platformID, record.languageID)
+                    .map(str::to_string),
+                value: value.clone(),
+            });
+        }
+        names
+    }
+
+    /// Returns the family names of the font, preferring the typographic
+    /// family names (name ID 16) over the legacy family names (name ID 1).
+    pub fn family_names(&self) -> Vec<&str> {
+        let typographic: Vec<&str> =
+            self.names(Self::TYPOGRAPHIC_FAMILY_NAME_ID).collect();
+        if typographic.is_empty() {
+            self.names(Self::FAMILY_NAME_ID).collect()
+        } else {
+            typographic
+        }
+    }
+
+    /// Decodes a string of the given platform.
+    fn decode(platform_id: u16, data: &[u8]) -> Option<String> {
+        match platform_id {
+            Self::PLATFORM_UNICODE | Self::PLATFORM_WINDOWS => {
+                if data.len() % 2 != 0 {
+                    return None;
+                }
+                let units = data
+                    .chunks_exact(2)
+                    .map(|unit| u16::from_be_bytes([unit[0], unit[1]]));
+                char::decode_utf16(units)
+                    .collect::<Result<String, _>>()
+                    .ok()
+            }
+            Self::PLATFORM_MACINTOSH if data.is_ascii() => {
+                Some(data.iter().map(|byte| *byte as char).collect())
+            }
+            _ => None,
+        }
+    }
+}
+
+impl FontDataExactRead for TableName {
+    type Error = FontIoError;
+
+    fn from_reader_exact<T: Read + Seek + ?Sized>(
+        reader: &mut T,
+        offset: u64,
+        size: usize,
+    ) -> Result<Self, Self::Error> {
+        reader.seek(SeekFrom::Start(offset))?;
+        if size < Self::HEADER_SIZE {
+            return Err(FontIoError::LoadTableTruncated(FontTag::NAME));
+        }
+        let mut data = vec![0; size];
+        reader
+            .read_exact(&mut data)
+            .map_err(|_| FontIoError::LoadTableTruncated(FontTag::NAME))?;
+        let format = u16::from_be_bytes([data[0], data[1]]);
+        let count = u16::from_be_bytes([data[2], data[3]]) as usize;
+        let storage_offset = u16::from_be_bytes([data[4], data[5]]) as usize;
+        if Self::HEADER_SIZE + count * Self::RECORD_SIZE > size {
+            return Err(FontIoError::LoadTableTruncated(FontTag::NAME));
+        }
+        let mut records = Vec::with_capacity(count);
+        for index in 0..count {
+            let start = Self::HEADER_SIZE + index * Self::RECORD_SIZE;
+            let mut record = &data[start..start + Self::RECORD_SIZE];
+            let platform_id = record.read_u16::<BigEndian>()?;
+            let encoding_id = record.read_u16::<BigEndian>()?;
+            let language_id = record.read_u16::<BigEndian>()?;
+            let name_id = record.read_u16::<BigEndian>()?;
+            let length = record.read_u16::<BigEndian>()? as usize;
+            let string_offset = record.read_u16::<BigEndian>()? as usize;
+            let string_start = storage_offset + string_offset;
+            let string = data
+                .get(string_start..string_start + length)
+                .ok_or(FontIoError::LoadTableTruncated(FontTag::NAME))?;
+            records.push(NameRecord {
+                platformID: platform_id,
+                encodingID: encoding_id,
+                languageID: language_id,
+                nameID: name_id,
+                value: Self::decode(platform_id, string),
+            });
+        }
+        // Format 1 tables follow the name records with language tags
+        let mut language_tags = Vec::new();
+        if format >= 1 {
+            let start = Self::HEADER_SIZE + count * Self::RECORD_SIZE;
+            let tag_count = data
+                .get(start..start + 2)
+                .map(|count| u16::from_be_bytes([count[0], count[1]]) as usize)
+                .ok_or(FontIoError::LoadTableTruncated(FontTag::NAME))?;
+            for index in 0..tag_count {
+                let record_start =
+                    start + 2 + index * Self::LANGUAGE_TAG_RECORD_SIZE;
+                let mut record = data
+                    .get(
+                        record_start
+                            ..record_start + Self::LANGUAGE_TAG_RECORD_SIZE,
+                    )
+                    .ok_or(FontIoError::LoadTableTruncated(FontTag::NAME))?;
+                let length = record.read_u16::<BigEndian>()? as usize;
+                let tag_offset = record.read_u16::<BigEndian>()? as usize;
+                let tag_start = storage_offset + tag_offset;
+                let tag = data
+                    .get(tag_start..tag_start + length)
+                    .ok_or(FontIoError::LoadTableTruncated(FontTag::NAME))?;
+                // Language tags are always UTF-16BE
+                language_tags.push(Self::decode(Self::PLATFORM_UNICODE, tag));
+            }
+        }
+        Ok(Self {
+            format,
+            records,
+            language_tags,
+        })
+    }
+}
+
+/// IETF BCP 47 language tags for the more common Windows language IDs.
+const WINDOWS_LANGUAGE_TAGS: &[(u16, &str)] = &[
+    (0x0401, "ar-SA"),
+    (0x0402, "bg-BG"),
+    (0x0403, "ca-ES"),
+    (0x0404, "zh-TW"),
+    (0x0405, "cs-CZ"),
+    (0x0406, "da-DK"),
+    (0x0407, "de-DE"),
+    (0x0408, "el-GR"),
+    (0x0409, "en-US"),
+    (0x040b, "fi-FI"),
+    (0x040c, "fr-FR"),
+    (0x040d, "he-IL"),
+    (0x040e, "hu-HU"),
+    (0x040f, "is-IS"),
+    (0x0410, "it-IT"),
+    (0x0411, "ja-JP"),
+    (0x0412, "ko-KR"),
+    (0x0413, "nl-NL"),
+    (0x0414, "nb-NO"),
+    (0x0415, "pl-PL"),
+    (0x0416, "pt-BR"),
+    (0x0418, "ro-RO"),
+    (0x0419, "ru-RU"),
+    (0x041a, "hr-HR"),
+    (0x041b, "sk-SK"),
+    (0x041d, "sv-SE"),
+    (0x041e, "th-TH"),
+    (0x041f, "tr-TR"),
+    (0x0421, "id-ID"),
+    (0x0422, "uk-UA"),
+    (0x0424, "sl-SI"),
+    (0x0425, "et-EE"),
+    (0x0426, "lv-LV"),
+    (0x0427, "lt-LT"),
+    (0x042a, "vi-VN"),
+    (0x0439, "hi-IN"),
+    (0x0804, "zh-CN"),
+    (0x0809, "en-GB"),
+    (0x080c, "fr-BE"),
+    (0x0816, "pt-PT"),
+    (0x0c04, "zh-HK"),
+    (0x0c0a, "es-ES"),
+    (0x1004, "zh-SG"),
+];
+
+/// IETF BCP 47 language tags for the more common Macintosh language IDs.
+const MACINTOSH_LANGUAGE_TAGS: &[(u16, &str)] = &[
+    (0, "en"),
+    (1, "fr"),
+    (2, "de"),
+    (3, "it"),
+    (4, "nl"),
+    (5, "sv"),
+    (6, "es"),
+    (7, "da"),
+    (8, "pt"),
+    (9, "no"),
+    (10, "he"),
+    (11, "ja"),
+    (12, "ar"),
+    (13, "fi"),
+    (14, "el"),
+    (15, "is"),
+    (17, "tr"),
+    (18, "hr"),
+    (19, "zh-Hant"),
+    (21, "hi"),
+    (22, "th"),
+    (23, "ko"),
+    (24, "lt"),
+    (25, "pl"),
+    (26, "hu"),
+    (27, "et"),
+    (28, "lv"),
+    (32, "ru"),
+    (33, "zh-Hans"),
+];
+
+#[cfg(test)]
+#[path = "name_test.rs"]
+mod tests;
@@ -0,0 +1,179 @@
+// Copyright 2025 Monotype Imaging Inc.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+//! Tests for the 'name' SFNT table module
+use std::io::Cursor;
+
+use super::*;
+
+/// A name table with a Macintosh family name, and Windows family and
+/// typographic family names.
+const NAME_DATA: [u8; 64] = [
+    0x00, 0x00, // format 0
+    0x00, 0x03, // three records
+    0x00, 0x2a, // storage offset
+    0x00, 0x01, 0x00, 0x00, 0x00, 0x00, // Macintosh, Roman, English
+    0x00, 0x01, 0x00, 0x04, 0x00, 0x00, // family name, "Test"
+    0x00, 0x03, 0x00, 0x01, 0x04, 0x09, // Windows, Unicode BMP, en-US
+    0x00, 0x01, 0x00, 0x08, 0x00, 0x04, // family name, "Test"
+    0x00, 0x03, 0x00, 0x01, 0x04, 0x09, // Windows, Unicode BMP, en-US
+    0x00, 0x10, 0x00, 0x0a, 0x00, 0x0c, // typographic family, "Tésts"
+    // String storage
+    b'T', b'e', b's', b't', //
+    0x00, b'T', 0x00, b'e', 0x00, b's', 0x00, b't', //
+    0x00, b'T', 0x00, 0xe9, 0x00, b's', 0x00, b't', 0x00, b's', //
+];
+
+#[test]
+fn test_reader_exact() {
+    let name = TableName::from_reader_exact(&mut Cursor::new(NAME_DATA), 0, 64)
+        .unwrap();
+    assert_eq!(name.format, 0);
+    assert_eq!(name.records.len(), 3);
+    assert_eq!(
+        name.records[0],
+        NameRecord {
+            platformID: 1,
+            encodingID: 0,
+            languageID: 0,
+            nameID: TableName::FAMILY_NAME_ID,
+            value: Some("Test".to_string()),
+        }
+    );
+    assert_eq!(name.records[1].value.as_deref(), Some("Test"));
+    assert_eq!(
+        name.names(TableName::FAMILY_NAME_ID).collect::<Vec<_>>(),
+        vec!["Test", "Test"]
+    );
+    // The typographic family name is preferred
+    assert_eq!(name.family_names(), vec!["Tésts"]);
+}
+
+#[test]
+fn test_reader_exact_undecodable_string() {
+    let mut data = NAME_DATA;
+    // A non-ASCII Macintosh string, and an odd-length UTF-16 string
+    data[42] = 0xe9;
+    data[31] = 0x09;
+    let name =
+        TableName::from_reader_exact(&mut Cursor::new(data), 0, 64).unwrap();
+    assert_eq!(name.records[0].value, None);
+    assert_eq!(name.records[2].value, None);
+    assert_eq!(name.family_names(), vec!["Test"]);
+}
+
+#[test]
+fn test_reader_exact_truncated() {
+    // Records beyond the end of the table
+    let result =
+        TableName::from_reader_exact(&mut Cursor::new(NAME_DATA), 0, 30);
+    assert!(matches!(
+        result,
+        Err(FontIoError::LoadTableTruncated(FontTag::NAME))
+    ));
+    // Strings beyond the end of the table
+    let result =
+        TableName::from_reader_exact(&mut Cursor::new(NAME_DATA), 0, 60);
+    assert!(matches!(
+        result,
+        Err(FontIoError::LoadTableTruncated(FontTag::NAME))
+    ));
+}
+
+#[test]
+fn test_name_prefers_windows_english() {
+    let name = TableName::from_reader_exact(&mut Cursor::new(NAME_DATA), 0, 64)
+        .unwrap();
+    assert_eq!(name.name(TableName::FAMILY_NAME_ID), Some("Test"));
+    assert_eq!(
+        name.name(TableName::TYPOGRAPHIC_FAMILY_NAME_ID),
+        Some("Tésts")
+    );
+    assert_eq!(name.name(2), None);
+    let mut data = NAME_DATA;
+    // Make the Windows family name Macintosh too, so the first one is used
+    data[19] = 0x01;
+    data[42] = b'F';
+    let name =
+        TableName::from_reader_exact(&mut Cursor::new(data), 0, 64).unwrap();
+    assert_eq!(name.name(TableName::FAMILY_NAME_ID), Some("Fest"));
+}
+
+#[test]
+fn test_localized_names() {
+    let name = TableName::from_reader_exact(&mut Cursor::new(NAME_DATA), 0, 64)
+        .unwrap();
+    assert!(name.language_tags.is_empty());
+    let names = name.localized_names();
+    assert_eq!(names.len(), 2);
+    assert_eq!(
+        names[&TableName::FAMILY_NAME_ID],
+        vec![
+            LocalizedName {
+                platform_id: 1,
+                encoding_id: 0,
+                language_id: 0,
+                language_tag: Some("en".to_string()),
+                value: "Test".to_string(),
+            },
+            LocalizedName {
+                platform_id: 3,
+                encoding_id: 1,
+                language_id: 0x0409,
+                language_tag: Some("en-US".to_string()),
+                value: "Test".to_string(),
+            },
+        ]
+    );
+    // Undecodable strings are skipped
+    let mut data = NAME_DATA;
+    data[42] = 0xe9;
+    let name =
+        TableName::from_reader_exact(&mut Cursor::new(data), 0, 64).unwrap();
+    assert_eq!(name.localized_names()[&TableName::FAMILY_NAME_ID].len(), 1);
+}
+
+#[test]
+fn test_reader_exact_format_1_language_tags() {
+    let data = [
+        0x00, 0x01, // format 1
+        0x00, 0x01, // one record
+        0x00, 0x18, // storage offset
+        0x00, 0x03, 0x00, 0x01, 0x80, 0x00, // Windows, Unicode BMP, tag 0
+        0x00, 0x01, 0x00, 0x04, 0x00, 0x00, // family name, "Test"
+        0x00, 0x01, // one language tag
+        0x00, 0x04, 0x00, 0x04, // length 4, offset 4
+        // String storage
+        0x00, b'T', 0x00, b't', //
+        0x00, b'd', 0x00, b'e', //
+    ];
+    let name =
+        TableName::from_reader_exact(&mut Cursor::new(data), 0, data.len())
+            .unwrap();
+    assert_eq!(name.language_tags, vec![Some("de".to_string())]);
+    assert_eq!(name.language_tag(3, 0x8000), Some("de"));
+    assert_eq!(name.language_tag(3, 0x8001), None);
+    // Unknown language IDs, and platforms without language IDs
+    assert_eq!(name.language_tag(3, 0x0001), None);
+    assert_eq!(name.language_tag(0, 0), None);
+    let names = name.localized_names();
+    assert_eq!(names[&1][0].language_tag.as_deref(), Some("de"));
+
+    // Language-tag records beyond the end of the table
+    let result = TableName::from_reader_exact(&mut Cursor::new(data), 0, 22);
+    assert!(matches!(
+        result,
+        Err(FontIoError::LoadTableTruncated(FontTag::NAME))
+    ));
+}
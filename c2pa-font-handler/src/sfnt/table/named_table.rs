@@ -13,15 +13,19 @@
 //  limitations under the License.
 
 //! Named table enumeration.
-use std::io::{Read, Seek, Write};
+use std::{
+    io::{Cursor, Read, Seek, Write},
+    sync::Arc,
+};
 
-use super::{dsig::TableDSIG, head::TableHead, TableC2PA};
+use super::{c2pa::TableC2PARaw, dsig::TableDSIG, head::TableHead, TableC2PA};
 use crate::{
     data::Data, error::FontIoError, tag::FontTag, FontDataChecksum,
     FontDataExactRead, FontDataWrite, FontTable,
 };
 
 /// Various types of tables by name
+#[derive(Clone)]
 pub enum NamedTable {
     /// 'C2PA' table
     C2PA(TableC2PA),
@@ -46,13 +50,33 @@ impl std::fmt::Display for NamedTable {
 }
 
 impl NamedTable {
+    /// Returns the structural minimum size of the table with the given tag,
+    /// for the tables which have typed parsers; other tables may be of any
+    /// size.
+    pub(crate) fn minimum_size(tag: &FontTag) -> usize {
+        match *tag {
+            FontTag::C2PA => TableC2PARaw::MINIMUM_SIZE,
+            FontTag::DSIG => TableDSIG::MINIMUM_SIZE,
+            FontTag::HEAD => TableHead::SIZE,
+            _ => 0,
+        }
+    }
+
     /// Creates a new `NamedTable` from a reader.
+    ///
+    /// # Errors
+    /// Returns [`FontIoError::LoadTableTruncated`] if the size is below the
+    /// structural minimum of a table with a typed parser (e.g., a 'head'
+    /// table shorter than 54 bytes), before reading any of its data.
     pub fn from_reader_exact<T: Read + Seek + ?Sized>(
         tag: &FontTag,
         reader: &mut T,
         offset: u64,
         size: usize,
     ) -> Result<Self, FontIoError> {
+        if size < Self::minimum_size(tag) {
+            return Err(FontIoError::LoadTableTruncated(*tag));
+        }
         match *tag {
             FontTag::C2PA => TableC2PA::from_reader_exact(reader, offset, size)
                 .map(NamedTable::C2PA),
@@ -64,6 +88,31 @@ impl NamedTable {
                 .map(NamedTable::Generic),
         }
     }
+
+    /// Creates a new `NamedTable` from a buffer holding the whole font, a raw
+    /// table sharing its bytes with the buffer rather than copying them.
+    ///
+    /// # Errors
+    /// As for [`NamedTable::from_reader_exact`].
+    pub(crate) fn from_shared(
+        tag: &FontTag,
+        buffer: &Arc<[u8]>,
+        offset: u64,
+        size: usize,
+    ) -> Result<Self, FontIoError> {
+        match *tag {
+            FontTag::C2PA | FontTag::DSIG | FontTag::HEAD => {
+                Self::from_reader_exact(
+                    tag,
+                    &mut Cursor::new(&buffer[..]),
+                    offset,
+                    size,
+                )
+            }
+            _ => Data::from_shared(buffer, offset as usize, size)
+                .map(NamedTable::Generic),
+        }
+    }
 }
 
 impl FontDataWrite for NamedTable {
@@ -303,27 +303,27 @@ fn test_named_table_generic_read_exact() {
 
 #[test]
 fn test_named_table_generic_len() {
-    let generic = NamedTable::Generic(Data {
-        data: vec![0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00],
-    });
+    let generic = NamedTable::Generic(Data::new(vec![
+        0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00,
+    ]));
     assert_eq!(generic.len(), 8);
     assert!(!generic.is_empty());
 }
 
 #[test]
 fn test_name_table_generic_checksum() {
-    let generic = NamedTable::Generic(Data {
-        data: vec![0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00],
-    });
+    let generic = NamedTable::Generic(Data::new(vec![
+        0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00,
+    ]));
     let checksum = generic.checksum();
     assert_eq!(checksum.0, 0x00000001);
 }
 
 #[test]
 fn test_named_table_generic_write() {
-    let generic = NamedTable::Generic(Data {
-        data: vec![0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00],
-    });
+    let generic = NamedTable::Generic(Data::new(vec![
+        0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00,
+    ]));
     let mut buffer = Vec::new();
     let mut writer = Cursor::new(&mut buffer);
     generic.write(&mut writer).unwrap();
@@ -334,9 +334,7 @@ fn test_named_table_generic_write() {
 #[test]
 fn test_display_of_named_tables() {
     let c2pa = NamedTable::C2PA(TableC2PA::default());
-    let generic = NamedTable::Generic(Data {
-        data: vec![0x00, 0x00, 0x00, 0x01],
-    });
+    let generic = NamedTable::Generic(Data::new(vec![0x00, 0x00, 0x00, 0x01]));
     let dsig = NamedTable::DSIG(TableDSIG {
         version: 1,
         numSignatures: 0,
@@ -368,3 +366,35 @@ fn test_display_of_named_tables() {
     assert_eq!(format!("{dsig}"), "DSIG");
     assert_eq!(format!("{head}"), "HEAD");
 }
+
+#[test]
+fn test_named_table_read_exact_below_minimum_size() {
+    // Plenty of data, but declared lengths below each table's minimum
+    let data = vec![0; 64];
+    for (tag, size) in [
+        (FontTag::HEAD, 2),
+        (FontTag::HEAD, 53),
+        (FontTag::DSIG, 7),
+        (FontTag::C2PA, 19),
+    ] {
+        let result = NamedTable::from_reader_exact(
+            &tag,
+            &mut Cursor::new(&data),
+            0,
+            size,
+        );
+        assert!(
+            matches!(result, Err(FontIoError::LoadTableTruncated(t)) if t == tag),
+            "Expected {tag} of {size} bytes to be rejected"
+        );
+    }
+    // Tables without typed parsers may be of any size
+    let result = NamedTable::from_reader_exact(
+        &FontTag::new(*b"test"),
+        &mut Cursor::new(&data),
+        0,
+        2,
+    );
+    assert!(matches!(result, Ok(NamedTable::Generic(_))));
+    assert_eq!(NamedTable::minimum_size(&FontTag::HEAD), 54);
+}
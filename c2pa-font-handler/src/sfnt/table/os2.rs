@@ -0,0 +1,116 @@
+// Copyright 2025 Monotype Imaging Inc.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+//! 'OS/2' SFNT table.
+
+use std::io::{Read, Seek, SeekFrom};
+
+use byteorder::{BigEndian, ReadBytesExt};
+
+use crate::{
+    error::FontIoError, tag::FontTag, FontDataExactRead, FontDataRead,
+};
+
+/// 'OS/2' font table, with the fields through `fsSelection` which are common
+/// to all versions of the table.
+#[derive(Clone, Debug)]
+#[allow(non_snake_case)] // As named by Open Font Format / OpenType.
+pub struct TableOs2 {
+    /// Version of the table.
+    pub version: u16,
+    /// Average weighted advance width of the lowercase letters and space.
+    pub xAvgCharWidth: i16,
+    /// Visual weight class (e.g., 400 for regular, 700 for bold).
+    pub usWeightClass: u16,
+    /// Relative change from the normal aspect ratio (e.g., 5 for medium).
+    pub usWidthClass: u16,
+    /// Font embedding licensing rights.
+    pub fsType: u16,
+    /// Font family class and subclass.
+    pub sFamilyClass: i16,
+    /// PANOSE classification number.
+    pub panose: [u8; 10],
+    /// Unicode character ranges supported by the font.
+    pub ulUnicodeRange: [u32; 4],
+    /// Font vendor identification.
+    pub achVendID: FontTag,
+    /// Font selection flags.
+    pub fsSelection: u16,
+}
+
+impl TableOs2 {
+    /// Offset of the family class, following the skipped metrics.
+    const FAMILY_CLASS_OFFSET: u64 = 30;
+    /// The size of the fields read from the table.
+    const MINIMUM_SIZE: usize = 64;
+
+    /// Returns the font vendor identification, or `None` if it is blank
+    /// (i.e., all spaces or NULs).
+    ///
+    /// # Remarks
+    /// Vendor IDs shorter than four characters are padded with spaces, as
+    /// for any tag (e.g., `"MS  "`); NUL padding is replaced with spaces.
+    pub fn vendor_id(&self) -> Option<FontTag> {
+        let data =
+            self.achVendID
+                .data()
+                .map(|byte| if byte == 0 { b' ' } else { byte });
+        (data != [b' '; 4]).then(|| FontTag::new(data))
+    }
+}
+
+impl FontDataExactRead for TableOs2 {
+    type Error = FontIoError;
+
+    fn from_reader_exact<T: Read + Seek + ?Sized>(
+        reader: &mut T,
+        offset: u64,
+        size: usize,
+    ) -> Result<Self, Self::Error> {
+        reader.seek(SeekFrom::Start(offset))?;
+        if size < Self::MINIMUM_SIZE {
+            return Err(FontIoError::LoadTableTruncated(FontTag::OS2));
+        }
+        let version = reader.read_u16::<BigEndian>()?;
+        let x_avg_char_width = reader.read_i16::<BigEndian>()?;
+        let us_weight_class = reader.read_u16::<BigEndian>()?;
+        let us_width_class = reader.read_u16::<BigEndian>()?;
+        let fs_type = reader.read_u16::<BigEndian>()?;
+        // Skip the subscript, superscript, and strikeout metrics
+        reader.seek(SeekFrom::Start(offset + Self::FAMILY_CLASS_OFFSET))?;
+        let s_family_class = reader.read_i16::<BigEndian>()?;
+        let mut panose = [0; 10];
+        reader.read_exact(&mut panose)?;
+        let mut ul_unicode_range = [0; 4];
+        reader.read_u32_into::<BigEndian>(&mut ul_unicode_range)?;
+        let ach_vend_id = FontTag::from_reader(reader)?;
+        let fs_selection = reader.read_u16::<BigEndian>()?;
+        Ok(Self {
+            version,
+            xAvgCharWidth: x_avg_char_width,
+            usWeightClass: us_weight_class,
+            usWidthClass: us_width_class,
+            fsType: fs_type,
+            sFamilyClass: s_family_class,
+            panose,
+            ulUnicodeRange: ul_unicode_range,
+            achVendID: ach_vend_id,
+            fsSelection: fs_selection,
+        })
+    }
+}
+
+#[cfg(test)]
+#[path = "os2_test.rs"]
+mod tests;
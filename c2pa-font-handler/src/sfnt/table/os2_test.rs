@@ -0,0 +1,82 @@
+// Copyright 2025 Monotype Imaging Inc.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+//! Tests for the 'OS/2' SFNT table module
+use std::io::Cursor;
+
+use super::*;
+
+/// The first 64 bytes of a version 4 'OS/2' table, with the given vendor ID.
+fn os2_data(vendor_id: &[u8; 4]) -> Vec<u8> {
+    let mut data = vec![
+        0x00, 0x04, // version
+        0x01, 0x5b, // xAvgCharWidth
+        0x01, 0x90, // usWeightClass
+        0x00, 0x05, // usWidthClass
+        0x00, 0x08, // fsType
+    ];
+    // Subscript, superscript, and strikeout metrics
+    data.extend_from_slice(&[0; 20]);
+    data.extend_from_slice(&[0x08, 0x01]); // sFamilyClass
+    data.extend_from_slice(&[2, 11, 6, 3, 0, 0, 0, 0, 0, 0]); // panose
+    data.extend_from_slice(&[0, 0, 0, 1]); // ulUnicodeRange1
+    data.extend_from_slice(&[0; 12]); // ulUnicodeRange2-4
+    data.extend_from_slice(vendor_id);
+    data.extend_from_slice(&[0x00, 0x40]); // fsSelection
+    data
+}
+
+#[test]
+fn test_reader_exact() {
+    let data = os2_data(b"MONO");
+    let os2 =
+        TableOs2::from_reader_exact(&mut Cursor::new(&data), 0, 64).unwrap();
+    assert_eq!(os2.version, 4);
+    assert_eq!(os2.xAvgCharWidth, 347);
+    assert_eq!(os2.usWeightClass, 400);
+    assert_eq!(os2.usWidthClass, 5);
+    assert_eq!(os2.fsType, 8);
+    assert_eq!(os2.sFamilyClass, 0x0801);
+    assert_eq!(os2.panose, [2, 11, 6, 3, 0, 0, 0, 0, 0, 0]);
+    assert_eq!(os2.ulUnicodeRange, [1, 0, 0, 0]);
+    assert_eq!(os2.achVendID, FontTag::new(*b"MONO"));
+    assert_eq!(os2.fsSelection, 0x0040);
+    assert_eq!(os2.vendor_id(), Some(FontTag::new(*b"MONO")));
+}
+
+#[test]
+fn test_vendor_id_padding() {
+    let vendor_id = |id: &[u8; 4]| {
+        let data = os2_data(id);
+        TableOs2::from_reader_exact(&mut Cursor::new(&data), 0, 64)
+            .unwrap()
+            .vendor_id()
+    };
+    assert_eq!(vendor_id(b"MS  "), Some(FontTag::new(*b"MS  ")));
+    // NUL padding is treated as space padding
+    assert_eq!(vendor_id(b"MS\0\0"), Some(FontTag::new(*b"MS  ")));
+    // Blank vendor IDs are reported as missing
+    assert_eq!(vendor_id(b"    "), None);
+    assert_eq!(vendor_id(b"\0\0\0\0"), None);
+}
+
+#[test]
+fn test_reader_exact_truncated() {
+    let data = os2_data(b"MONO");
+    let result = TableOs2::from_reader_exact(&mut Cursor::new(&data), 0, 62);
+    assert!(matches!(
+        result,
+        Err(FontIoError::LoadTableTruncated(FontTag::OS2))
+    ));
+}
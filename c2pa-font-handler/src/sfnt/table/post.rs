@@ -0,0 +1,93 @@
+// Copyright 2025 Monotype Imaging Inc.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+//! 'post' SFNT table.
+
+use std::io::{Read, Seek, SeekFrom};
+
+use byteorder::{BigEndian, ReadBytesExt};
+
+use crate::{error::FontIoError, tag::FontTag, FontDataExactRead};
+
+/// 'post' font table, with the header fields common to all versions of the
+/// table; the glyph names of version 2.0 are not read.
+#[derive(Clone, Debug)]
+#[allow(non_snake_case)] // As named by Open Font Format / OpenType.
+pub struct TablePost {
+    /// Version of the table.
+    pub version: u32,
+    /// Italic angle in counter-clockwise degrees from the vertical, as a
+    /// 16.16 fixed-point number.
+    pub italicAngle: i32,
+    /// Suggested distance of the top of the underline from the baseline.
+    pub underlinePosition: i16,
+    /// Suggested thickness of the underline.
+    pub underlineThickness: i16,
+    /// Non-zero if the font is monospaced.
+    pub isFixedPitch: u32,
+    /// Minimum memory usage when the font is downloaded as a Type 42 font.
+    pub minMemType42: u32,
+    /// Maximum memory usage when the font is downloaded as a Type 42 font.
+    pub maxMemType42: u32,
+    /// Minimum memory usage when the font is downloaded as a Type 1 font.
+    pub minMemType1: u32,
+    /// Maximum memory usage when the font is downloaded as a Type 1 font.
+    pub maxMemType1: u32,
+}
+
+impl TablePost {
+    /// The size of the header fields read from the table.
+    const MINIMUM_SIZE: usize = 32;
+
+    /// Returns the italic angle in counter-clockwise degrees from the
+    /// vertical; negative for text leaning to the right.
+    pub fn italic_angle(&self) -> f32 {
+        self.italicAngle as f32 / 65536.0
+    }
+
+    /// Returns whether the font is monospaced.
+    pub fn is_fixed_pitch(&self) -> bool {
+        self.isFixedPitch != 0
+    }
+}
+
+impl FontDataExactRead for TablePost {
+    type Error = FontIoError;
+
+    fn from_reader_exact<T: Read + Seek + ?Sized>(
+        reader: &mut T,
+        offset: u64,
+        size: usize,
+    ) -> Result<Self, Self::Error> {
+        reader.seek(SeekFrom::Start(offset))?;
+        if size < Self::MINIMUM_SIZE {
+            return Err(FontIoError::LoadTableTruncated(FontTag::POST));
+        }
+        Ok(Self {
+            version: reader.read_u32::<BigEndian>()?,
+            italicAngle: reader.read_i32::<BigEndian>()?,
+            underlinePosition: reader.read_i16::<BigEndian>()?,
+            underlineThickness: reader.read_i16::<BigEndian>()?,
+            isFixedPitch: reader.read_u32::<BigEndian>()?,
+            minMemType42: reader.read_u32::<BigEndian>()?,
+            maxMemType42: reader.read_u32::<BigEndian>()?,
+            minMemType1: reader.read_u32::<BigEndian>()?,
+            maxMemType1: reader.read_u32::<BigEndian>()?,
+        })
+    }
+}
+
+#[cfg(test)]
+#[path = "post_test.rs"]
+mod tests;
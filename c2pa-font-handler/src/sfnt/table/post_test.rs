@@ -0,0 +1,57 @@
+// Copyright 2025 Monotype Imaging Inc.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+//! Tests for the 'post' SFNT table module
+use std::io::Cursor;
+
+use super::*;
+
+/// A version 3.0 'post' table, with the given italic angle and fixed pitch.
+fn post_data(italic_angle: i32, is_fixed_pitch: u32) -> Vec<u8> {
+    let mut data = vec![0x00, 0x03, 0x00, 0x00]; // version
+    data.extend_from_slice(&italic_angle.to_be_bytes());
+    data.extend_from_slice(&[0xff, 0x9c]); // underlinePosition
+    data.extend_from_slice(&[0x00, 0x32]); // underlineThickness
+    data.extend_from_slice(&is_fixed_pitch.to_be_bytes());
+    data.extend_from_slice(&[0; 16]); // Memory usage
+    data
+}
+
+#[test]
+fn test_reader_exact() {
+    let data = post_data(-12 << 16 | 0x8000, 1);
+    let post =
+        TablePost::from_reader_exact(&mut Cursor::new(&data), 0, 32).unwrap();
+    assert_eq!(post.version, 0x00030000);
+    assert_eq!(post.underlinePosition, -100);
+    assert_eq!(post.underlineThickness, 50);
+    assert_eq!(post.italic_angle(), -11.5);
+    assert!(post.is_fixed_pitch());
+
+    let data = post_data(0, 0);
+    let post =
+        TablePost::from_reader_exact(&mut Cursor::new(&data), 0, 32).unwrap();
+    assert_eq!(post.italic_angle(), 0.0);
+    assert!(!post.is_fixed_pitch());
+}
+
+#[test]
+fn test_reader_exact_truncated() {
+    let data = post_data(0, 0);
+    let result = TablePost::from_reader_exact(&mut Cursor::new(&data), 0, 31);
+    assert!(matches!(
+        result,
+        Err(FontIoError::LoadTableTruncated(FontTag::POST))
+    ));
+}
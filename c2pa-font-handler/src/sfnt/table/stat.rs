@@ -0,0 +1,282 @@
+// Copyright 2025 Monotype Imaging Inc.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+//! 'STAT' SFNT table.
+
+use std::io::{Cursor, Read, Seek, SeekFrom};
+
+use byteorder::{BigEndian, ReadBytesExt};
+
+use super::fvar::fixed_to_f32;
+use crate::{
+    error::FontIoError, tag::FontTag, FontDataExactRead, FontDataRead,
+};
+
+/// A design axis of the style attributes.
+#[derive(Clone, Debug, PartialEq)]
+#[allow(non_snake_case)] // As named by Open Font Format / OpenType.
+pub struct StatAxisRecord {
+    /// Tag identifying the design axis (e.g., 'wght').
+    pub axisTag: FontTag,
+    /// The name ID for entries in the 'name' table that provide a display
+    /// name for this axis.
+    pub axisNameID: u16,
+    /// The recommended ordering of this axis, when composing style names.
+    pub axisOrdering: u16,
+}
+
+/// An axis value table of the style attributes, describing a named value (or
+/// range of values) on one or more design axes.
+#[derive(Clone, Debug, PartialEq)]
+#[allow(non_snake_case)] // As named by Open Font Format / OpenType.
+pub enum StatAxisValue {
+    /// A single value on a single axis (format 1).
+    Single {
+        /// Index of the design axis.
+        axisIndex: u16,
+        /// Axis value flags.
+        flags: u16,
+        /// The name ID of the value's name.
+        valueNameID: u16,
+        /// The value on the axis.
+        value: f32,
+    },
+    /// A range of values on a single axis (format 2).
+    Range {
+        /// Index of the design axis.
+        axisIndex: u16,
+        /// Axis value flags.
+        flags: u16,
+        /// The name ID of the value's name.
+        valueNameID: u16,
+        /// The nominal value on the axis.
+        nominalValue: f32,
+        /// The minimum value of the range.
+        rangeMinValue: f32,
+        /// The maximum value of the range.
+        rangeMaxValue: f32,
+    },
+    /// A single value on a single axis, linked to another value for style
+    /// linking (format 3).
+    Linked {
+        /// Index of the design axis.
+        axisIndex: u16,
+        /// Axis value flags.
+        flags: u16,
+        /// The name ID of the value's name.
+        valueNameID: u16,
+        /// The value on the axis.
+        value: f32,
+        /// The linked value (e.g., bold for regular).
+        linkedValue: f32,
+    },
+    /// A combination of values on multiple axes (format 4).
+    Multiple {
+        /// Axis value flags.
+        flags: u16,
+        /// The name ID of the value's name.
+        valueNameID: u16,
+        /// The index of each design axis, paired with the value on it.
+        axisValues: Vec<(u16, f32)>,
+    },
+}
+
+impl StatAxisValue {
+    /// Flag set for values which are elided when composing style names.
+    pub const ELIDABLE_AXIS_VALUE_NAME: u16 = 0x0002;
+    /// Flag set for values which are older sibling attributes, e.g. the
+    /// default (Regular) values of the font family.
+    pub const OLDER_SIBLING_FONT_ATTRIBUTE: u16 = 0x0001;
+
+    /// Returns the axis value flags.
+    pub fn flags(&self) -> u16 {
+        match self {
+            Self::Single { flags, .. }
+            | Self::Range { flags, .. }
+            | Self::Linked { flags, .. }
+            | Self::Multiple { flags, .. } => *flags,
+        }
+    }
+
+    /// Returns the name ID of the value's name.
+    pub fn value_name_id(&self) -> u16 {
+        match self {
+            Self::Single { valueNameID, .. }
+            | Self::Range { valueNameID, .. }
+            | Self::Linked { valueNameID, .. }
+            | Self::Multiple { valueNameID, .. } => *valueNameID,
+        }
+    }
+
+    /// Reads an axis value table, returning `None` for unknown formats.
+    fn from_reader<T: Read + ?Sized>(
+        reader: &mut T,
+    ) -> Result<Option<Self>, std::io::Error> {
+        let format = reader.read_u16::<BigEndian>()?;
+        if format == 4 {
+            let axis_count = reader.read_u16::<BigEndian>()?;
+            let flags = reader.read_u16::<BigEndian>()?;
+            let value_name_id = reader.read_u16::<BigEndian>()?;
+            let axis_values = (0..axis_count)
+                .map(|_| {
+                    Ok((
+                        reader.read_u16::<BigEndian>()?,
+                        fixed_to_f32(reader.read_i32::<BigEndian>()?),
+                    ))
+                })
+                .collect::<Result<_, std::io::Error>>()?;
+            return Ok(Some(Self::Multiple {
+                flags,
+                valueNameID: value_name_id,
+                axisValues: axis_values,
+            }));
+        }
+        let axis_index = reader.read_u16::<BigEndian>()?;
+        let flags = reader.read_u16::<BigEndian>()?;
+        let value_name_id = reader.read_u16::<BigEndian>()?;
+        let mut read_fixed =
+            || reader.read_i32::<BigEndian>().map(fixed_to_f32);
+        Ok(match format {
+            1 => Some(Self::Single {
+                axisIndex: axis_index,
+                flags,
+                valueNameID: value_name_id,
+                value: read_fixed()?,
+            }),
+            2 => Some(Self::Range {
+                axisIndex: axis_index,
+                flags,
+                valueNameID: value_name_id,
+                nominalValue: read_fixed()?,
+                rangeMinValue: read_fixed()?,
+                rangeMaxValue: read_fixed()?,
+            }),
+            3 => Some(Self::Linked {
+                axisIndex: axis_index,
+                flags,
+                valueNameID: value_name_id,
+                value: read_fixed()?,
+                linkedValue: read_fixed()?,
+            }),
+            _ => None,
+        })
+    }
+}
+
+/// 'STAT' font table, describing the style attributes of the fonts in a
+/// family.
+#[derive(Clone, Debug)]
+#[allow(non_snake_case)] // As named by Open Font Format / OpenType.
+pub struct TableStat {
+    /// Major version of the table.
+    pub majorVersion: u16,
+    /// Minor version of the table.
+    pub minorVersion: u16,
+    /// The design axes.
+    pub designAxes: Vec<StatAxisRecord>,
+    /// The axis value tables; tables of unknown formats are skipped.
+    pub axisValues: Vec<StatAxisValue>,
+    /// The name ID of the name to use when all the style's axis value names
+    /// are elided (e.g., "Regular"), if present (version 1.1 and later).
+    pub elidedFallbackNameID: Option<u16>,
+}
+
+impl TableStat {
+    /// The minimum size of a design axis record.
+    const AXIS_RECORD_SIZE: usize = 8;
+    /// The size of the version 1.0 table header.
+    const HEADER_SIZE: usize = 18;
+}
+
+impl FontDataExactRead for TableStat {
+    type Error = FontIoError;
+
+    fn from_reader_exact<T: Read + Seek + ?Sized>(
+        reader: &mut T,
+        offset: u64,
+        size: usize,
+    ) -> Result<Self, Self::Error> {
+        reader.seek(SeekFrom::Start(offset))?;
+        if size < Self::HEADER_SIZE {
+            return Err(FontIoError::LoadTableTruncated(FontTag::STAT));
+        }
+        let mut data = vec![0; size];
+        reader
+            .read_exact(&mut data)
+            .map_err(|_| FontIoError::LoadTableTruncated(FontTag::STAT))?;
+        let truncated = |_| FontIoError::LoadTableTruncated(FontTag::STAT);
+        let mut reader = Cursor::new(&data);
+        let major_version = reader.read_u16::<BigEndian>()?;
+        let minor_version = reader.read_u16::<BigEndian>()?;
+        let design_axis_size = reader.read_u16::<BigEndian>()? as u64;
+        let design_axis_count = reader.read_u16::<BigEndian>()?;
+        let design_axes_offset = reader.read_u32::<BigEndian>()? as u64;
+        let axis_value_count = reader.read_u16::<BigEndian>()?;
+        let axis_value_offsets_offset = reader.read_u32::<BigEndian>()? as u64;
+        let elided_fallback_name_id = if minor_version >= 1 {
+            Some(reader.read_u16::<BigEndian>().map_err(truncated)?)
+        } else {
+            None
+        };
+        if design_axis_count > 0
+            && design_axis_size < Self::AXIS_RECORD_SIZE as u64
+        {
+            return Err(FontIoError::LoadTableTruncated(FontTag::STAT));
+        }
+
+        let design_axes = (0..design_axis_count as u64)
+            .map(|index| {
+                reader.seek(SeekFrom::Start(
+                    design_axes_offset + index * design_axis_size,
+                ))?;
+                Ok(StatAxisRecord {
+                    axisTag: FontTag::from_reader(&mut reader)
+                        .map_err(|_| std::io::ErrorKind::UnexpectedEof)?,
+                    axisNameID: reader.read_u16::<BigEndian>()?,
+                    axisOrdering: reader.read_u16::<BigEndian>()?,
+                })
+            })
+            .collect::<Result<Vec<_>, std::io::Error>>()
+            .map_err(truncated)?;
+
+        let mut axis_values = Vec::new();
+        for index in 0..axis_value_count as u64 {
+            reader
+                .seek(SeekFrom::Start(axis_value_offsets_offset + index * 2))?;
+            // Axis value offsets are from the start of the offsets array
+            let value_offset =
+                reader.read_u16::<BigEndian>().map_err(truncated)? as u64;
+            reader.seek(SeekFrom::Start(
+                axis_value_offsets_offset + value_offset,
+            ))?;
+            match StatAxisValue::from_reader(&mut reader).map_err(truncated)? {
+                Some(value) => axis_values.push(value),
+                None => tracing::debug!(
+                    "Skipping STAT axis value table of unknown format"
+                ),
+            }
+        }
+        Ok(Self {
+            majorVersion: major_version,
+            minorVersion: minor_version,
+            designAxes: design_axes,
+            axisValues: axis_values,
+            elidedFallbackNameID: elided_fallback_name_id,
+        })
+    }
+}
+
+#[cfg(test)]
+#[path = "stat_test.rs"]
+mod tests;
@@ -0,0 +1,130 @@
+// Copyright 2025 Monotype Imaging Inc.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+//! Tests for the 'STAT' SFNT table module
+use std::io::Cursor;
+
+use super::*;
+
+/// A version 1.1 STAT table with a 'wght' axis, and axis values of each
+/// format, followed by one of an unknown format.
+const STAT_DATA: [u8; 108] = [
+    0x00, 0x01, 0x00, 0x01, // version 1.1
+    0x00, 0x08, 0x00, 0x01, // design axis size, count
+    0x00, 0x00, 0x00, 0x14, // design axes offset
+    0x00, 0x05, // axis value count
+    0x00, 0x00, 0x00, 0x1c, // axis value offsets offset
+    0x01, 0x02, // elided fallback name ID 258
+    // 'wght' axis
+    b'w', b'g', b'h', b't', 0x01, 0x00, 0x00, 0x00, //
+    // Axis value offsets
+    0x00, 0x0a, 0x00, 0x16, 0x00, 0x2a, 0x00, 0x3a, 0x00, 0x48, //
+    // Format 1, "Regular" at 400, elidable
+    0x00, 0x01, 0x00, 0x00, 0x00, 0x02, 0x01, 0x01, //
+    0x01, 0x90, 0x00, 0x00, //
+    // Format 2, 600 to 900, nominally 700
+    0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x01, 0x03, //
+    0x02, 0xbc, 0x00, 0x00, 0x02, 0x58, 0x00, 0x00, 0x03, 0x84, 0x00,
+    0x00, //
+    // Format 3, 400 linked to 700
+    0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x01, 0x04, //
+    0x01, 0x90, 0x00, 0x00, 0x02, 0xbc, 0x00, 0x00, //
+    // Format 4, 700 on the 'wght' axis
+    0x00, 0x04, 0x00, 0x01, 0x00, 0x00, 0x01, 0x05, //
+    0x00, 0x00, 0x02, 0xbc, 0x00, 0x00, //
+    // Unknown format
+    0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+];
+
+#[test]
+fn test_reader_exact() {
+    let stat = TableStat::from_reader_exact(
+        &mut Cursor::new(STAT_DATA),
+        0,
+        STAT_DATA.len(),
+    )
+    .unwrap();
+    assert_eq!((stat.majorVersion, stat.minorVersion), (1, 1));
+    assert_eq!(stat.elidedFallbackNameID, Some(258));
+    assert_eq!(
+        stat.designAxes,
+        vec![StatAxisRecord {
+            axisTag: FontTag::new(*b"wght"),
+            axisNameID: 256,
+            axisOrdering: 0,
+        }]
+    );
+    assert_eq!(
+        stat.axisValues,
+        vec![
+            StatAxisValue::Single {
+                axisIndex: 0,
+                flags: StatAxisValue::ELIDABLE_AXIS_VALUE_NAME,
+                valueNameID: 257,
+                value: 400.0,
+            },
+            StatAxisValue::Range {
+                axisIndex: 0,
+                flags: 0,
+                valueNameID: 259,
+                nominalValue: 700.0,
+                rangeMinValue: 600.0,
+                rangeMaxValue: 900.0,
+            },
+            StatAxisValue::Linked {
+                axisIndex: 0,
+                flags: 0,
+                valueNameID: 260,
+                value: 400.0,
+                linkedValue: 700.0,
+            },
+            StatAxisValue::Multiple {
+                flags: 0,
+                valueNameID: 261,
+                axisValues: vec![(0, 700.0)],
+            },
+        ]
+    );
+    assert_eq!(stat.axisValues[0].flags(), 2);
+    assert_eq!(stat.axisValues[3].value_name_id(), 261);
+}
+
+#[test]
+fn test_reader_exact_version_1_0() {
+    let mut data = STAT_DATA;
+    // Version 1.0 has no elided fallback name ID
+    data[3] = 0x00;
+    let stat =
+        TableStat::from_reader_exact(&mut Cursor::new(data), 0, data.len())
+            .unwrap();
+    assert_eq!(stat.elidedFallbackNameID, None);
+    assert_eq!(stat.axisValues.len(), 4);
+}
+
+#[test]
+fn test_reader_exact_truncated() {
+    let result =
+        TableStat::from_reader_exact(&mut Cursor::new(STAT_DATA), 0, 16);
+    assert!(matches!(
+        result,
+        Err(FontIoError::LoadTableTruncated(FontTag::STAT))
+    ));
+    // Missing the last axis value tables
+    let result =
+        TableStat::from_reader_exact(&mut Cursor::new(STAT_DATA), 0, 90);
+    assert!(matches!(
+        result,
+        Err(FontIoError::LoadTableTruncated(FontTag::STAT))
+    ));
+}
@@ -0,0 +1,99 @@
+// Copyright 2025 Monotype Imaging Inc.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+//! 'VORG' SFNT table.
+
+use std::io::{Read, Seek, SeekFrom};
+
+use byteorder::{BigEndian, ReadBytesExt};
+
+use crate::{error::FontIoError, tag::FontTag, FontDataExactRead};
+
+/// 'VORG' font table, holding the vertical origins of the glyphs of a font
+/// with CFF outlines (e.g., a CJK font), for vertical layout.
+#[derive(Clone, Debug)]
+#[allow(non_snake_case)] // As named by Open Font Format / OpenType.
+pub struct TableVorg {
+    /// Major version of the table (1).
+    pub majorVersion: u16,
+    /// Minor version of the table (0).
+    pub minorVersion: u16,
+    /// The y coordinate of the vertical origin of the glyphs without their
+    /// own entry.
+    pub defaultVertOriginY: i16,
+    /// The glyph IDs and y coordinates of the vertical origins of the glyphs
+    /// whose origin differs from the default, sorted by glyph ID.
+    pub vert_origin_y_metrics: Vec<(u16, i16)>,
+}
+
+impl TableVorg {
+    /// The size of the table header.
+    const HEADER_SIZE: usize = 8;
+    /// The size of a vertical origin record.
+    const RECORD_SIZE: usize = 4;
+
+    /// Returns the y coordinate of the vertical origin of the given glyph,
+    /// falling back to the default for glyphs without their own entry.
+    pub fn vertical_origin(&self, gid: u16) -> i16 {
+        self.vert_origin_y_metrics
+            .binary_search_by_key(&gid, |(glyph_index, _)| *glyph_index)
+            .map(|index| self.vert_origin_y_metrics[index].1)
+            .unwrap_or(self.defaultVertOriginY)
+    }
+}
+
+impl FontDataExactRead for TableVorg {
+    type Error = FontIoError;
+
+    fn from_reader_exact<T: Read + Seek + ?Sized>(
+        reader: &mut T,
+        offset: u64,
+        size: usize,
+    ) -> Result<Self, Self::Error> {
+        reader.seek(SeekFrom::Start(offset))?;
+        if size < Self::HEADER_SIZE {
+            return Err(FontIoError::LoadTableTruncated(FontTag::VORG));
+        }
+        let major_version = reader.read_u16::<BigEndian>()?;
+        let minor_version = reader.read_u16::<BigEndian>()?;
+        let default_vert_origin_y = reader.read_i16::<BigEndian>()?;
+        let num_vert_origin_y_metrics =
+            reader.read_u16::<BigEndian>()? as usize;
+        if Self::HEADER_SIZE + num_vert_origin_y_metrics * Self::RECORD_SIZE
+            > size
+        {
+            return Err(FontIoError::LoadTableTruncated(FontTag::VORG));
+        }
+        let mut vert_origin_y_metrics = (0..num_vert_origin_y_metrics)
+            .map(|_| {
+                Ok((
+                    reader.read_u16::<BigEndian>()?,
+                    reader.read_i16::<BigEndian>()?,
+                ))
+            })
+            .collect::<Result<Vec<_>, std::io::Error>>()?;
+        // The records should already be sorted, but lookups rely on it
+        vert_origin_y_metrics.sort_by_key(|(glyph_index, _)| *glyph_index);
+        Ok(Self {
+            majorVersion: major_version,
+            minorVersion: minor_version,
+            defaultVertOriginY: default_vert_origin_y,
+            vert_origin_y_metrics,
+        })
+    }
+}
+
+#[cfg(test)]
+#[path = "vorg_test.rs"]
+mod tests;
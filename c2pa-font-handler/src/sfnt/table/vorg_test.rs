@@ -0,0 +1,60 @@
+// Copyright 2025 Monotype Imaging Inc.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+//! Tests for the 'VORG' SFNT table module
+use std::io::Cursor;
+
+use super::*;
+
+/// A 'VORG' table with a default origin of 880 and two glyphs with their own
+/// origins.
+const VORG_DATA: [u8; 16] = [
+    0x00, 0x01, 0x00, 0x00, // version 1.0
+    0x03, 0x70, // defaultVertOriginY: 880
+    0x00, 0x02, // numVertOriginYMetrics
+    0x00, 0x05, 0x03, 0x84, // glyph 5: 900
+    0x00, 0x0a, 0xff, 0x9c, // glyph 10: -100
+];
+
+#[test]
+fn test_read_exact() {
+    let vorg = TableVorg::from_reader_exact(
+        &mut Cursor::new(&VORG_DATA),
+        0,
+        VORG_DATA.len(),
+    )
+    .unwrap();
+    assert_eq!({ vorg.majorVersion }, 1);
+    assert_eq!({ vorg.minorVersion }, 0);
+    assert_eq!({ vorg.defaultVertOriginY }, 880);
+    assert_eq!(vorg.vert_origin_y_metrics, vec![(5, 900), (10, -100)]);
+    assert_eq!(vorg.vertical_origin(5), 900);
+    assert_eq!(vorg.vertical_origin(10), -100);
+    // Other glyphs fall back to the default
+    assert_eq!(vorg.vertical_origin(0), 880);
+    assert_eq!(vorg.vertical_origin(7), 880);
+    assert_eq!(vorg.vertical_origin(u16::MAX), 880);
+}
+
+#[test]
+fn test_read_exact_truncated() {
+    for size in [4, VORG_DATA.len() - 1] {
+        let result =
+            TableVorg::from_reader_exact(&mut Cursor::new(&VORG_DATA), 0, size);
+        assert!(matches!(
+            result,
+            Err(FontIoError::LoadTableTruncated(FontTag::VORG))
+        ));
+    }
+}
@@ -14,9 +14,12 @@
 
 //! Font tag
 
+#[cfg(feature = "std")]
 use std::io::{Read, Seek, Write};
 
+#[cfg(feature = "std")]
 use super::{error::FontIoError, FontDataRead, FontDataWrite};
+#[cfg(feature = "std")]
 use crate::FontDataExactRead;
 
 /// Four-character tag which names a font table
@@ -29,12 +32,56 @@ pub struct FontTag {
 impl FontTag {
     /// Tag for the 'C2PA' table
     pub const C2PA: FontTag = FontTag { data: *b"C2PA" };
+    /// Tag for the Character to Glyph Index Mapping table
+    pub const CMAP: FontTag = FontTag { data: *b"cmap" };
+    /// Tag for the Color table
+    pub const COLR: FontTag = FontTag { data: *b"COLR" };
+    /// Tag for the Color Palette table
+    pub const CPAL: FontTag = FontTag { data: *b"CPAL" };
     /// Tag for the Digital Signature table
     pub const DSIG: FontTag = FontTag { data: *b"DSIG" };
+    /// Tag for the Embedded Bitmap Data table
+    pub const EBDT: FontTag = FontTag { data: *b"EBDT" };
+    /// Tag for the Embedded Bitmap Location table
+    pub const EBLC: FontTag = FontTag { data: *b"EBLC" };
+    /// Tag for the FontForge Timestamp table
+    pub const FFTM: FontTag = FontTag { data: *b"FFTM" };
+    /// Tag for the Font Variations table
+    pub const FVAR: FontTag = FontTag { data: *b"fvar" };
     /// Tag for the 'head' table
     pub const HEAD: FontTag = FontTag { data: *b"head" };
+    /// Tag for the Horizontal Header table
+    pub const HHEA: FontTag = FontTag { data: *b"hhea" };
+    /// Tag for the Horizontal Metrics table
+    pub const HMTX: FontTag = FontTag { data: *b"hmtx" };
+    /// Tag for the Index to Location table
+    pub const LOCA: FontTag = FontTag { data: *b"loca" };
+    /// Tag for the 'maxp' table
+    pub const MAXP: FontTag = FontTag { data: *b"maxp" };
+    /// Tag for the Naming table
+    pub const NAME: FontTag = FontTag { data: *b"name" };
+    /// Tag for the OS/2 and Windows Metrics table
+    pub const OS2: FontTag = FontTag { data: *b"OS/2" };
+    /// Tag for the PostScript table
+    pub const POST: FontTag = FontTag { data: *b"post" };
+    /// Tags of the tables required in every OpenType font
+    const REQUIRED: [FontTag; 8] = [
+        FontTag::CMAP,
+        FontTag::HEAD,
+        FontTag::HHEA,
+        FontTag::HMTX,
+        FontTag::MAXP,
+        FontTag::NAME,
+        FontTag::OS2,
+        FontTag::POST,
+    ];
     /// Size for a `FontTag`
+    #[cfg(feature = "std")]
     pub(crate) const SIZE: usize = 4;
+    /// Tag for the Style Attributes table
+    pub const STAT: FontTag = FontTag { data: *b"STAT" };
+    /// Tag for the Vertical Origin table
+    pub const VORG: FontTag = FontTag { data: *b"VORG" };
 
     /// Creates a new `SfntTag` from a four-character array.
     pub fn new(source_data: [u8; 4]) -> Self {
@@ -45,8 +92,33 @@ impl FontTag {
     pub fn data(&self) -> [u8; 4] {
         self.data
     }
+
+    /// Returns whether this is the tag of the 'C2PA' table.
+    pub fn is_c2pa(&self) -> bool {
+        *self == Self::C2PA
+    }
+
+    /// Returns whether this is the tag of the Digital Signature table.
+    pub fn is_dsig(&self) -> bool {
+        *self == Self::DSIG
+    }
+
+    /// Returns whether this is the tag of one of the tables required in every
+    /// OpenType font ('cmap', 'head', 'hhea', 'hmtx', 'maxp', 'name', 'OS/2',
+    /// and 'post').
+    pub fn is_required(&self) -> bool {
+        Self::REQUIRED.contains(self)
+    }
+
+    /// Returns whether this is the tag of a table which is added, replaced,
+    /// or removed as part of signing the font ('C2PA' and 'DSIG'), rather
+    /// than being part of the font's content.
+    pub fn is_optional_signing(&self) -> bool {
+        self.is_c2pa() || self.is_dsig()
+    }
 }
 
+#[cfg(feature = "std")]
 impl FontDataRead for FontTag {
     type Error = FontIoError;
 
@@ -59,6 +131,7 @@ impl FontDataRead for FontTag {
     }
 }
 
+#[cfg(feature = "std")]
 impl FontDataExactRead for FontTag {
     type Error = FontIoError;
 
@@ -75,6 +148,7 @@ impl FontDataExactRead for FontTag {
     }
 }
 
+#[cfg(feature = "std")]
 impl FontDataWrite for FontTag {
     type Error = FontIoError;
 
@@ -87,18 +161,25 @@ impl FontDataWrite for FontTag {
     }
 }
 
-impl std::fmt::Display for FontTag {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", String::from_utf8_lossy(&self.data))
+impl core::fmt::Display for FontTag {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        // As `String::from_utf8_lossy` would, without allocating
+        for chunk in self.data.utf8_chunks() {
+            f.write_str(chunk.valid())?;
+            if !chunk.invalid().is_empty() {
+                core::fmt::Write::write_char(f, char::REPLACEMENT_CHARACTER)?;
+            }
+        }
+        Ok(())
     }
 }
 
-impl std::fmt::Debug for FontTag {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Debug for FontTag {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "FontTag({self})")
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 #[path = "tag_test.rs"]
 mod tests;
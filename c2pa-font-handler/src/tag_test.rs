@@ -57,6 +57,10 @@ fn test_tag_write() {
 fn test_tag_display() {
     let tag = FontTag::new(*b"bb2c");
     assert_eq!(format!("{tag}"), "bb2c");
+    // Invalid UTF-8 is replaced, as it is by `String::from_utf8_lossy`
+    let tag = FontTag::new([b'a', 0xff, b'b', 0xc3]);
+    assert_eq!(format!("{tag}"), String::from_utf8_lossy(&tag.data()));
+    assert_eq!(format!("{tag}"), "a\u{fffd}b\u{fffd}");
 }
 
 #[test]
@@ -64,3 +68,18 @@ fn test_tag_debug() {
     let tag = FontTag::new(*b"bb2c");
     assert_eq!(format!("{tag:?}"), "FontTag(bb2c)");
 }
+
+#[test]
+fn test_tag_classification() {
+    assert!(FontTag::C2PA.is_c2pa());
+    assert!(!FontTag::DSIG.is_c2pa());
+    assert!(FontTag::DSIG.is_dsig());
+    assert!(!FontTag::new(*b"dsig").is_dsig());
+    assert!(FontTag::HEAD.is_required());
+    assert!(FontTag::new(*b"OS/2").is_required());
+    assert!(!FontTag::LOCA.is_required());
+    assert!(!FontTag::C2PA.is_required());
+    assert!(FontTag::C2PA.is_optional_signing());
+    assert!(FontTag::DSIG.is_optional_signing());
+    assert!(!FontTag::HEAD.is_optional_signing());
+}
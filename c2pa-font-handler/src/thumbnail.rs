@@ -16,9 +16,11 @@
 
 use std::{
     fs::File,
-    io::{Read, Seek},
+    io::{Cursor, Read, Seek},
 };
 
+pub(crate) mod cache;
+pub use cache::FontCache;
 pub mod error;
 #[cfg(feature = "png-thumbnails")]
 pub(crate) mod png_thumbnail;
@@ -28,16 +30,37 @@ pub use png_thumbnail::{PngThumbnailRenderer, PngThumbnailRendererConfig};
 #[cfg(feature = "svg-thumbnails")]
 pub(crate) mod svg_thumbnail;
 #[cfg(feature = "svg-thumbnails")]
-pub use svg_thumbnail::{SvgThumbnailRenderer, SvgThumbnailRendererConfig};
+pub use svg_thumbnail::{
+    glyph_bounds, glyphs_to_paths, GlyphPath, SvgThumbnailRenderer,
+    SvgThumbnailRendererConfig, VerticalAlign,
+};
 
 pub(crate) mod text;
 use text::TextFontSystemContext;
 pub use text::{
-    BinarySearchContext, CosmicTextThumbnailGenerator, FontSizeSearchStrategy,
-    FontSystemConfig, LinearSearchContext,
+    measure_text_with_font, BinarySearchContext, ClipBehavior,
+    CosmicTextThumbnailGenerator, FontNameInfo, FontSizeSearchStrategy,
+    FontSystemConfig, FullNameTextProvider, LineHeightBasis,
+    LinearSearchContext, SampleTextProvider, TextProvider, UnrenderablePolicy,
+};
+
+use crate::{
+    magic::Magic,
+    mime_type::{FontMimeTypeGuesser, FontMimeTypes},
+    sfnt::font::SfntFont,
+    Font, MutFontDataWrite,
 };
 
-use crate::mime_type::{FontMimeTypeGuesser, FontMimeTypes};
+/// Information about how a thumbnail was rendered.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ThumbnailMetadata {
+    /// The width of the thumbnail, in pixels.
+    pub width: f32,
+    /// The height of the thumbnail, in pixels.
+    pub height: f32,
+    /// The point size the text was rendered at.
+    pub point_size: f32,
+}
 
 /// Represents a thumbnail.
 #[derive(Debug)]
@@ -46,13 +69,32 @@ pub struct Thumbnail {
     pub(crate) data: Vec<u8>,
     /// The mime type of the thumbnail.
     pub(crate) mime_type: String,
+    /// Information about how the thumbnail was rendered, if known.
+    pub(crate) metadata: Option<ThumbnailMetadata>,
 }
 
 impl Thumbnail {
     /// Create a new thumbnail with the given data and mime type.
-    #[allow(dead_code)]
-    fn new(data: Vec<u8>, mime_type: String) -> Self {
-        Self { data, mime_type }
+    pub fn new(data: Vec<u8>, mime_type: String) -> Self {
+        Self {
+            data,
+            mime_type,
+            metadata: None,
+        }
+    }
+
+    /// Create a new thumbnail with the given data and mime type, along with
+    /// information about how it was rendered.
+    pub fn with_metadata(
+        data: Vec<u8>,
+        mime_type: String,
+        metadata: ThumbnailMetadata,
+    ) -> Self {
+        Self {
+            data,
+            mime_type,
+            metadata: Some(metadata),
+        }
     }
 
     /// Get the data of the thumbnail.
@@ -65,6 +107,22 @@ impl Thumbnail {
         &self.mime_type
     }
 
+    /// Get the information about how the thumbnail was rendered, if known.
+    pub fn metadata(&self) -> Option<&ThumbnailMetadata> {
+        self.metadata.as_ref()
+    }
+
+    /// Get the thumbnail as a `data:` URI, with its data base64 encoded, for
+    /// embedding directly in HTML or JSON.
+    pub fn to_data_uri(&self) -> String {
+        use base64::Engine;
+        format!(
+            "data:{};base64,{}",
+            self.mime_type,
+            base64::engine::general_purpose::STANDARD.encode(&self.data)
+        )
+    }
+
     /// Get the mime type of the thumbnail as an owned string.
     pub fn into_parts(self) -> (Vec<u8>, String) {
         (self.data, self.mime_type)
@@ -137,4 +195,38 @@ pub trait ThumbnailGenerator {
         reader: &mut R,
         mime_type: Option<&FontMimeTypes>,
     ) -> Result<Thumbnail, error::FontThumbnailError>;
+
+    /// Create a thumbnail from an already parsed SFNT font.
+    ///
+    /// This function will create a thumbnail for the font as it is in memory,
+    /// including any changes made to it (e.g., adding a C2PA table), without
+    /// the caller having to write it out and read it back in.
+    ///
+    /// # Parameters
+    /// - `font`: The font for which the thumbnail should be created.
+    ///
+    /// # Errors
+    /// Returns an error if the font could not be written, or the thumbnail
+    /// could not be created from it.
+    ///
+    /// # Remarks
+    /// The default implementation writes a copy of the font to an in-memory
+    /// buffer once, leaving the given font untouched.
+    fn create_thumbnail_from_font(
+        &self,
+        font: &SfntFont,
+    ) -> Result<Thumbnail, error::FontThumbnailError> {
+        // Writing the font refreshes its header and directory, so write a copy
+        let mut font = font.clone();
+        let mut font_data = Vec::new();
+        font.write(&mut font_data)?;
+        let mime_type = match font.header().sfntVersion {
+            Magic::OpenType => FontMimeTypes::OTF,
+            _ => FontMimeTypes::TTF,
+        };
+        self.create_thumbnail_from_stream(
+            &mut Cursor::new(font_data),
+            Some(&mime_type),
+        )
+    }
 }
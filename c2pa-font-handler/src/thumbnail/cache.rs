@@ -0,0 +1,124 @@
+// Copyright 2025 Monotype Imaging Inc.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+//! An in-memory cache of parsed fonts, shared across thumbnail requests.
+
+use std::{
+    collections::VecDeque,
+    hash::{DefaultHasher, Hash, Hasher},
+    sync::{Arc, Mutex},
+};
+
+use super::{error::FontThumbnailError, text::ParsedFont};
+
+/// A cache of parsed fonts, keyed by a hash of their data, so that repeated
+/// thumbnail requests for the same font skip loading it into a font database.
+///
+/// # Remarks
+/// The least recently used font is evicted when the cache is full. A cache
+/// with a capacity of zero caches nothing.
+#[derive(Debug)]
+pub struct FontCache {
+    /// The maximum number of fonts kept in the cache
+    capacity: usize,
+    /// The cached fonts with the hashes of their data, from the least to the
+    /// most recently used
+    fonts: Mutex<VecDeque<(u64, Arc<ParsedFont>)>>,
+}
+
+impl FontCache {
+    /// The default maximum number of fonts kept in the cache.
+    pub const DEFAULT_CAPACITY: usize = 16;
+
+    /// Creates an empty cache holding at most `capacity` fonts.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            fonts: Mutex::new(VecDeque::with_capacity(capacity)),
+        }
+    }
+
+    /// Returns the maximum number of fonts kept in the cache.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of fonts in the cache.
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Returns whether the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    /// Removes all the fonts from the cache.
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    /// Returns the parsed font for the given data, parsing it and adding it
+    /// to the cache if it is not already there.
+    pub(crate) fn get_or_parse(
+        &self,
+        font_data: Vec<u8>,
+    ) -> Result<Arc<ParsedFont>, FontThumbnailError> {
+        if self.capacity == 0 {
+            return ParsedFont::new(font_data).map(Arc::new);
+        }
+        let mut hasher = DefaultHasher::new();
+        font_data.hash(&mut hasher);
+        let hash = hasher.finish();
+        {
+            let mut fonts = self.lock();
+            // Compare the data as well, in case of a hash collision
+            let index = fonts.iter().position(|(key, font)| {
+                *key == hash && *font.data == font_data
+            });
+            // Move a hit to the back, as the most recently used
+            if let Some(entry) = index.and_then(|index| fonts.remove(index)) {
+                let font = entry.1.clone();
+                fonts.push_back(entry);
+                return Ok(font);
+            }
+        }
+        // Parse without holding the lock, so other requests are not blocked
+        let font = Arc::new(ParsedFont::new(font_data)?);
+        let mut fonts = self.lock();
+        while fonts.len() >= self.capacity {
+            fonts.pop_front();
+        }
+        fonts.push_back((hash, font.clone()));
+        Ok(font)
+    }
+
+    /// Locks the cached fonts, recovering them if another thread panicked
+    /// while holding the lock.
+    fn lock(
+        &self,
+    ) -> std::sync::MutexGuard<'_, VecDeque<(u64, Arc<ParsedFont>)>> {
+        self.fonts.lock().unwrap_or_else(|error| error.into_inner())
+    }
+}
+
+impl Default for FontCache {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_CAPACITY)
+    }
+}
+
+#[cfg(test)]
+#[path = "cache_test.rs"]
+mod tests;
@@ -0,0 +1,75 @@
+// Copyright 2025 Monotype Imaging Inc.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+//! Tests for the font cache.
+
+use std::sync::Arc;
+
+use super::FontCache;
+
+const FONT_DATA: &[u8] = include_bytes!("../../../.devtools/font.otf");
+
+#[test]
+fn test_font_cache_hit() {
+    let cache = FontCache::default();
+    assert_eq!(FontCache::DEFAULT_CAPACITY, cache.capacity());
+    assert!(cache.is_empty());
+    let first = cache.get_or_parse(FONT_DATA.to_vec()).unwrap();
+    let second = cache.get_or_parse(FONT_DATA.to_vec()).unwrap();
+    assert!(Arc::ptr_eq(&first, &second), "Expected the cached font");
+    assert_eq!(1, cache.len());
+    cache.clear();
+    assert!(cache.is_empty());
+}
+
+#[test]
+fn test_font_cache_evicts_least_recently_used() {
+    // Trailing padding changes the data, but not the parsed font
+    let padded = |count: usize| {
+        let mut data = FONT_DATA.to_vec();
+        data.resize(data.len() + count, 0);
+        data
+    };
+    let cache = FontCache::new(2);
+    let first = cache.get_or_parse(padded(0)).unwrap();
+    cache.get_or_parse(padded(4)).unwrap();
+    // Use the first font again, so the second is the least recently used
+    let first_again = cache.get_or_parse(padded(0)).unwrap();
+    assert!(Arc::ptr_eq(&first, &first_again));
+    cache.get_or_parse(padded(8)).unwrap();
+    assert_eq!(2, cache.len());
+    let first_again = cache.get_or_parse(padded(0)).unwrap();
+    assert!(
+        Arc::ptr_eq(&first, &first_again),
+        "Expected first to be kept"
+    );
+    assert_eq!(2, cache.len());
+}
+
+#[test]
+fn test_font_cache_with_zero_capacity() {
+    let cache = FontCache::new(0);
+    let first = cache.get_or_parse(FONT_DATA.to_vec()).unwrap();
+    let second = cache.get_or_parse(FONT_DATA.to_vec()).unwrap();
+    assert!(!Arc::ptr_eq(&first, &second));
+    assert!(cache.is_empty());
+}
+
+#[test]
+fn test_font_cache_with_invalid_font() {
+    let cache = FontCache::default();
+    let result = cache.get_or_parse(vec![0; 16]);
+    assert!(result.is_err());
+    assert!(cache.is_empty());
+}
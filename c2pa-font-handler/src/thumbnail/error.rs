@@ -34,12 +34,19 @@ pub enum FontThumbnailError {
     /// A font was not found
     #[error("No font found")]
     NoFontFound,
-    /// No full name found in the font
+    /// No full name (or other text to render) found in the font
     #[error("No full name found")]
     NoFullNameFound,
     /// Error when the buffer size is invalid
     #[error("The buffer size is invalid")]
     InvalidBufferSize,
+    /// The scale requested for a thumbnail is not a finite, positive number
+    #[error("Invalid thumbnail scale: {0}")]
+    InvalidScale(f32),
+    /// The point size requested for measuring text is not a finite, positive
+    /// number
+    #[error("Invalid point size: {0}")]
+    InvalidPointSize(f32),
     #[cfg(feature = "svg-thumbnails")]
     /// Could not create a Rect from the given values
     #[error("Invalid values for Rect")]
@@ -47,6 +54,10 @@ pub enum FontThumbnailError {
     /// Failed to find a point size that would accommodate the width and text
     #[error("Failed to find an appropriate font point size to fit the width")]
     FailedToFindAppropriateSize,
+    /// The text does not fit the width at the minimum point size, and
+    /// clipping it was not allowed
+    #[error("The text does not fit the width at the minimum font point size")]
+    TextDoesNotFit,
     /// Failed to create a Pixmap object
     #[error("Failed to create pixmap")]
     FailedToCreatePixmap,
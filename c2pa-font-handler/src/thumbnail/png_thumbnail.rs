@@ -109,6 +109,7 @@ impl Renderer for PngThumbnailRenderer {
         text_system_context: &mut TextFontSystemContext,
     ) -> Result<super::Thumbnail, super::error::FontThumbnailError> {
         let angle = text_system_context.angle;
+        let slant_margin = text_system_context.slant_margin;
         let (font_system, swash_cache, text_buffer) =
             text_system_context.mut_cosmic_text_parts();
         // Got some reason, the `swash` library used by `cosmic-text` puts
@@ -118,11 +119,14 @@ impl Renderer for PngThumbnailRenderer {
             .layout_runs()
             .next()
             .ok_or(FontThumbnailError::InvalidBufferSize)?;
-        // Grab the cache key for the first glyph, so we can get the image
-        // information from the swash cache
+        // Grab the cache key for the left most glyph, so we can get the image
+        // information from the swash cache. Right-to-left runs store their
+        // glyphs from right to left, so the first glyph is not always the
+        // left most one.
         let x = layout_run
             .glyphs
-            .first()
+            .iter()
+            .min_by(|a, b| a.x.total_cmp(&b.x))
             .ok_or(FontThumbnailError::InvalidBufferSize)?
             .physical((0., 0.), 1.0)
             .cache_key;
@@ -149,7 +153,9 @@ impl Renderer for PngThumbnailRenderer {
             return Err(FontThumbnailError::InvalidBufferSize);
         }
 
-        // Calculate the width taken up by the italic angle
+        // Calculate the width taken up by the italic angle over the full
+        // height of the line, less what the buffer was already widened by
+        // for the slant of the ascender.
         let width_italic_buffer = match angle {
             // If we have an angle get the tangent of the angle
             Some(angle) => {
@@ -159,15 +165,10 @@ impl Renderer for PngThumbnailRenderer {
             _ => 0.0,
         }
         .abs();
+        let width_italic_buffer = (width_italic_buffer - slant_margin).max(0.0);
 
         // The total width will be our specified width + the width from the
-        // italic angle. QUESTION: Should not the `cosmic-text` library
-        // really already include this in the width calculation? Are we doing
-        // something wrong?
-        // TODO: Investigate whether `cosmic-text` library already includes
-        // the italic angle in the width calculation. Verify if this
-        // additional calculation is necessary or if it indicates a potential
-        // issue.
+        // italic angle.
         let width = width + width_italic_buffer + offset as f32;
 
         // Create a new pixel map for the main text
@@ -227,9 +228,14 @@ impl Renderer for PngThumbnailRenderer {
         let mut png_buffer = Vec::new();
         let mut png_cursor = std::io::Cursor::new(&mut png_buffer);
         gray_image.write_to(&mut png_cursor, image::ImageFormat::Png)?;
-        Ok(super::Thumbnail::new(
+        Ok(super::Thumbnail::with_metadata(
             png_buffer,
             Self::MIME_TYPE.to_string(),
+            super::ThumbnailMetadata {
+                width: gray_image.width() as f32,
+                height: gray_image.height() as f32,
+                point_size: buffer.metrics().font_size,
+            },
         ))
     }
 }
@@ -79,6 +79,14 @@ fn test_default_png_thumbnail_renderer() {
     assert_eq!(thumbnail.mime_type(), "image/png");
     assert!(!thumbnail.data().is_empty());
     assert!(thumbnail.data().starts_with(b"\x89PNG\r\n\x1a\n"));
+    // The metadata matches the dimensions in the PNG's header
+    let metadata = thumbnail.metadata().unwrap();
+    let data = thumbnail.data();
+    let width = u32::from_be_bytes(data[16..20].try_into().unwrap());
+    let height = u32::from_be_bytes(data[20..24].try_into().unwrap());
+    assert_eq!(metadata.width, width as f32);
+    assert_eq!(metadata.height, height as f32);
+    assert_eq!(metadata.point_size, context.text_buffer.metrics().font_size);
 }
 
 // Verify the error path when the buffer size is invalid
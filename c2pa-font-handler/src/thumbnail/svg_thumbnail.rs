@@ -14,14 +14,25 @@
 
 //! Thumbnail handling for C2PA fonts using SVG format.
 
-use resvg::usvg::{Options, Tree};
+use std::{collections::HashMap, io::Cursor};
+
+use cosmic_text::{fontdb::ID, CacheKey, FontSystem};
+use resvg::usvg::{tiny_skia_path::PathBuilder, Options, Rect, Tree};
 use svg::{
-    node::element::{Group, Style},
+    node::element::{Group, Rectangle, Style},
     Document, Node,
 };
 
 use super::{text::TextFontSystemContext, Renderer};
-use crate::thumbnail::error::FontThumbnailError;
+use crate::{
+    sfnt::{
+        font::SfntFont,
+        table::{ColrLayer, CpalColor, TableColr, TableCpal},
+    },
+    tag::FontTag,
+    thumbnail::error::FontThumbnailError,
+    FontDataRead,
+};
 
 /// Trait for rounding values to a specified precision.
 trait PrecisionRound {
@@ -43,21 +54,84 @@ impl PrecisionRound for (f32, f32) {
     }
 }
 
+/// How the coordinates of glyph outlines are written in SVG path data.
+#[derive(Clone, Copy, Debug)]
+struct PathNumberFormat {
+    /// The number of decimal places for horizontal coordinates
+    x_precision: u32,
+    /// The number of decimal places for vertical coordinates
+    y_precision: u32,
+    /// Whether to drop trailing zeros, rather than always writing every
+    /// decimal place
+    trim_trailing_zeros: bool,
+}
+
+impl PathNumberFormat {
+    /// Format a value, rounded to the given precision.
+    fn number(&self, value: f32, precision: u32) -> String {
+        let rounded = value.round_to(precision);
+        // Avoid writing a negative zero, which rounding small negative values
+        // produces
+        let rounded = if rounded == 0.0 { 0.0 } else { rounded };
+        if self.trim_trailing_zeros {
+            // Display writes the shortest form, without trailing zeros
+            rounded.to_string()
+        } else {
+            format!("{rounded:.0$}", precision as usize)
+        }
+    }
+
+    /// Format a point, as path data parameters.
+    fn point(&self, x: f32, y: f32) -> String {
+        format!(
+            "{},{}",
+            self.number(x, self.x_precision),
+            self.number(y, self.y_precision)
+        )
+    }
+}
+
+/// Vertical alignment of the glyphs within the SVG thumbnail.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum VerticalAlign {
+    /// The view box is fitted to the glyph outlines, so the placement depends
+    /// only on the glyphs drawn (default).
+    #[default]
+    Baseline,
+    /// The font's ascender is placed at the top of the line box.
+    Top,
+    /// The font's ascender and descender are centered within the line box.
+    Center,
+}
+
 /// Configuration for the SVG thumbnail renderer.
 ///
 /// # Remarks
 /// This configuration allows customization of the SVG thumbnail rendering,
-/// including the precision of the coordinates and the fill color for the
-/// glyphs.
+/// including the precision of the coordinates, the fill color for the
+/// glyphs, an optional background color, and whether color fonts are drawn in
+/// color.
 ///
 /// Default values are provided for both precision and fill color, but they can
-/// be overridden when creating an instance of this configuration.
+/// be overridden when creating an instance of this configuration. By default,
+/// no background is drawn, leaving the thumbnail transparent.
 #[derive(Clone, Debug)]
 pub struct SvgThumbnailRendererConfig {
     /// The default precision for rounding SVG coordinates
     pub(crate) default_precision: u32,
+    /// The precision for rounding horizontal and vertical coordinates, if
+    /// they differ from the default precision
+    pub(crate) axis_precision: Option<(u32, u32)>,
+    /// Whether to drop trailing zeros from the coordinates in path data
+    pub(crate) trim_trailing_zeros: bool,
     /// The fill color for the glyphs in the SVG thumbnail
     pub(crate) glyph_fill_color: String,
+    /// The fill color for a rectangle drawn behind the glyphs, if any
+    pub(crate) background_color: Option<String>,
+    /// Whether to draw the glyphs of color ('COLR'/'CPAL') fonts in color
+    pub(crate) color: bool,
+    /// The vertical alignment of the glyphs
+    pub(crate) vertical_align: VerticalAlign,
 }
 
 impl SvgThumbnailRendererConfig {
@@ -73,9 +147,85 @@ impl SvgThumbnailRendererConfig {
     ) -> Self {
         Self {
             default_precision,
+            axis_precision: None,
+            trim_trailing_zeros: true,
             glyph_fill_color: glyph_fill_color.into(),
+            background_color: None,
+            color: false,
+            vertical_align: VerticalAlign::default(),
+        }
+    }
+
+    /// Set the number of decimal places the coordinates are rounded to; a
+    /// precision of 0 rounds them to integers, for the smallest output.
+    pub fn with_precision(mut self, precision: u32) -> Self {
+        self.default_precision = precision;
+        self.axis_precision = None;
+        self
+    }
+
+    /// Set separate numbers of decimal places for horizontal and vertical
+    /// coordinates, e.g. for very wide or very short thumbnails.
+    pub fn with_axis_precision(
+        mut self,
+        x_precision: u32,
+        y_precision: u32,
+    ) -> Self {
+        self.axis_precision = Some((x_precision, y_precision));
+        self
+    }
+
+    /// Set whether trailing zeros are dropped from the coordinates in path
+    /// data (default), or every decimal place of the precision is written.
+    pub fn with_trim_trailing_zeros(
+        mut self,
+        trim_trailing_zeros: bool,
+    ) -> Self {
+        self.trim_trailing_zeros = trim_trailing_zeros;
+        self
+    }
+
+    /// Get the format of the coordinates in path data.
+    fn path_number_format(&self) -> PathNumberFormat {
+        let (x_precision, y_precision) = self
+            .axis_precision
+            .unwrap_or((self.default_precision, self.default_precision));
+        PathNumberFormat {
+            x_precision,
+            y_precision,
+            trim_trailing_zeros: self.trim_trailing_zeros,
         }
     }
+
+    /// Set whether the glyphs of color fonts (with 'COLR' and 'CPAL' tables)
+    /// are drawn as layers filled from the font's first palette, rather than
+    /// with the single glyph fill color.
+    pub fn with_color(mut self, color: bool) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Set the vertical alignment of the glyphs. Other than with
+    /// [`VerticalAlign::Baseline`], the view box spans the full height of
+    /// the text's line box, using the font's ascender and descender to place
+    /// the glyphs within it.
+    pub fn with_vertical_align(
+        mut self,
+        vertical_align: VerticalAlign,
+    ) -> Self {
+        self.vertical_align = vertical_align;
+        self
+    }
+
+    /// Set the color of a solid background rectangle, filling the entire view
+    /// box, to be drawn behind the glyphs.
+    pub fn with_background_color<S: Into<String>>(
+        mut self,
+        background_color: S,
+    ) -> Self {
+        self.background_color = Some(background_color.into());
+        self
+    }
 }
 
 impl Default for SvgThumbnailRendererConfig {
@@ -102,6 +252,8 @@ impl SvgThumbnailRenderer {
     const PATH: &'static str = "path";
     /// The scale transformation to flip the SVG vertically.
     const SCALE: &'static str = "scale(1, -1)";
+    /// The name of the SVG style attribute.
+    const STYLE: &'static str = "style";
     /// The name of the SVG transform attribute.
     const TRANSFORM: &'static str = "transform";
     /// The viewBox attribute for the SVG document.
@@ -111,6 +263,249 @@ impl SvgThumbnailRenderer {
     pub fn new(config: SvgThumbnailRendererConfig) -> Self {
         Self { config }
     }
+
+    /// Build the SVG path data for the given outline commands, writing the
+    /// coordinates in the given format.
+    fn outline_path_data(
+        outline_commands: Option<&[cosmic_text::Command]>,
+        format: &PathNumberFormat,
+    ) -> String {
+        // Go through each command and build the path
+        outline_commands
+            .unwrap_or_default()
+            .iter()
+            .map(|command| match command {
+                cosmic_text::Command::MoveTo(p1) => {
+                    format!("M{}", format.point(p1.x, p1.y))
+                }
+                cosmic_text::Command::LineTo(p1) => {
+                    format!("L{}", format.point(p1.x, p1.y))
+                }
+                cosmic_text::Command::CurveTo(p1, p2, p3) => format!(
+                    "C{},{},{}",
+                    format.point(p1.x, p1.y),
+                    format.point(p2.x, p2.y),
+                    format.point(p3.x, p3.y)
+                ),
+                cosmic_text::Command::QuadTo(p1, p2) => {
+                    format!(
+                        "Q{},{}",
+                        format.point(p1.x, p1.y),
+                        format.point(p2.x, p2.y)
+                    )
+                }
+                cosmic_text::Command::Close => "z".to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+/// The color tables of a color font.
+struct ColorTables {
+    /// The color layers of the glyphs
+    colr: TableColr,
+    /// The color palettes
+    cpal: TableCpal,
+}
+
+impl ColorTables {
+    /// Load the color tables of the font with the given ID, if it has both a
+    /// 'COLR' and 'CPAL' table which can be parsed.
+    fn load(font_system: &mut FontSystem, font_id: ID) -> Option<Self> {
+        let font = font_system.get_font(font_id)?;
+        let sfnt = SfntFont::from_reader(&mut Cursor::new(font.data())).ok()?;
+        let colr = sfnt.parse_table::<TableColr>(&FontTag::COLR)?.ok()?;
+        let cpal = sfnt.parse_table::<TableCpal>(&FontTag::CPAL)?.ok()?;
+        tracing::trace!("Using color tables for font {font_id}");
+        Some(Self { colr, cpal })
+    }
+
+    /// Get the color to fill the given layer with, from the first palette.
+    ///
+    /// # Remarks
+    /// Layers using the foreground color (or a missing palette entry) get no
+    /// color, so they use the glyph fill color.
+    fn layer_color(&self, layer: &ColrLayer) -> Option<CpalColor> {
+        self.cpal.color(0, layer.paletteIndex)
+    }
+}
+
+/// Get the style to fill a color glyph's layer with the given color.
+fn fill_style(color: &CpalColor) -> String {
+    if color.alpha == u8::MAX {
+        format!("fill: {}", color.to_hex())
+    } else {
+        format!(
+            "fill: {}; fill-opacity: {}",
+            color.to_hex(),
+            (color.alpha as f32 / u8::MAX as f32).round_to(2)
+        )
+    }
+}
+
+/// The outline of a glyph, or of one layer of a color glyph, as path data.
+///
+/// # Remarks
+/// The outline is in the font's coordinate space, scaled to the font size,
+/// with the y-axis pointing up from the baseline; the position is in the same
+/// space, relative to the start of the glyph's line.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GlyphPath {
+    /// The path data of the outline, as for the `d` attribute of an SVG
+    /// `path` element.
+    pub d: String,
+    /// The horizontal position of the glyph.
+    pub x: f32,
+    /// The vertical position of the glyph.
+    pub y: f32,
+    /// The index of the line (layout run) the glyph is on.
+    pub line: usize,
+    /// The color to fill the path with, for the layers of color glyphs which
+    /// do not use the foreground color.
+    pub color: Option<CpalColor>,
+}
+
+/// Extract the outlines of the glyphs laid out in the context's text buffer as
+/// path data, rounding the coordinates to the given precision, so they can be
+/// drawn without building an SVG document (e.g., onto a canvas).
+///
+/// # Remarks
+/// Glyphs without outlines, such as spaces, are skipped. Color glyphs are
+/// drawn with their plain outlines; see
+/// [`SvgThumbnailRendererConfig::with_color`] for drawing them in color.
+pub fn glyphs_to_paths(
+    context: &mut TextFontSystemContext,
+    precision: u32,
+) -> Vec<GlyphPath> {
+    let format = PathNumberFormat {
+        x_precision: precision,
+        y_precision: precision,
+        trim_trailing_zeros: true,
+    };
+    collect_glyph_paths(context, &format, false)
+}
+
+/// Compute the tight bounding boxes of the glyphs laid out in the context's
+/// text buffer, paired with their glyph IDs, e.g. to pack glyphs closely.
+///
+/// # Remarks
+/// As for [`GlyphPath`], each box is in the font's coordinate space, scaled
+/// to the font size, with the y-axis pointing up from the baseline, and is
+/// offset by the glyph's position relative to the start of its line. The
+/// boxes fit the outlines themselves, rather than their control points;
+/// glyphs without outlines, such as spaces, are skipped.
+pub fn glyph_bounds(context: &mut TextFontSystemContext) -> Vec<(u16, Rect)> {
+    let mut bounds = Vec::new();
+    let (font_system, swash_cache, text_buffer) =
+        context.mut_cosmic_text_parts();
+    for layout_run in text_buffer.layout_runs() {
+        for glyph in layout_run.glyphs {
+            let (x, y) = (glyph.x + glyph.x_offset, glyph.y + glyph.y_offset);
+            let cache_key = glyph.physical((0., 0.), 1.0).cache_key;
+            let Some(outline) = swash_cache
+                .get_outline_commands(font_system, cache_key)
+                .and_then(outline_bounds)
+            else {
+                continue;
+            };
+            if let Some(rect) = Rect::from_ltrb(
+                outline.left() + x,
+                outline.top() + y,
+                outline.right() + x,
+                outline.bottom() + y,
+            ) {
+                bounds.push((cache_key.glyph_id, rect));
+            }
+        }
+    }
+    bounds
+}
+
+/// Compute the tight bounds of the given outline commands, if they describe
+/// a non-empty outline.
+fn outline_bounds(outline_commands: &[cosmic_text::Command]) -> Option<Rect> {
+    let mut builder = PathBuilder::new();
+    for command in outline_commands {
+        match command {
+            cosmic_text::Command::MoveTo(p1) => builder.move_to(p1.x, p1.y),
+            cosmic_text::Command::LineTo(p1) => builder.line_to(p1.x, p1.y),
+            cosmic_text::Command::CurveTo(p1, p2, p3) => {
+                builder.cubic_to(p1.x, p1.y, p2.x, p2.y, p3.x, p3.y)
+            }
+            cosmic_text::Command::QuadTo(p1, p2) => {
+                builder.quad_to(p1.x, p1.y, p2.x, p2.y)
+            }
+            cosmic_text::Command::Close => builder.close(),
+        }
+    }
+    builder.finish()?.compute_tight_bounds()
+}
+
+/// Extract the outlines of the glyphs laid out in the context's text buffer,
+/// drawing the glyphs of color fonts as stacks of colored layers if `color`
+/// is set.
+fn collect_glyph_paths(
+    context: &mut TextFontSystemContext,
+    format: &PathNumberFormat,
+    color: bool,
+) -> Vec<GlyphPath> {
+    let mut glyph_paths = Vec::new();
+    // The color tables of each font, loaded as the fonts are encountered
+    let mut color_tables = HashMap::new();
+    let (font_system, swash_cache, text_buffer) =
+        context.mut_cosmic_text_parts();
+    for (line, layout_run) in text_buffer.layout_runs().enumerate() {
+        for glyph in layout_run.glyphs {
+            // Get the x/y offsets
+            let (x, y) = (glyph.x + glyph.x_offset, glyph.y + glyph.y_offset);
+            // We will need the physical glyph to get the outline commands
+            let physical_glyph = glyph.physical((0., 0.), 1.0);
+            let cache_key = physical_glyph.cache_key;
+            // Color glyphs are drawn as a stack of colored layers
+            let color_tables = if color {
+                color_tables
+                    .entry(cache_key.font_id)
+                    .or_insert_with(|| {
+                        ColorTables::load(font_system, cache_key.font_id)
+                    })
+                    .as_ref()
+            } else {
+                None
+            };
+            let mut add_path = |cache_key, color| {
+                let d = SvgThumbnailRenderer::outline_path_data(
+                    swash_cache.get_outline_commands(font_system, cache_key),
+                    format,
+                );
+                // Don't add empty data paths
+                if !d.is_empty() {
+                    glyph_paths.push(GlyphPath {
+                        d,
+                        x,
+                        y,
+                        line,
+                        color,
+                    });
+                }
+            };
+            match color_tables.and_then(|tables| {
+                Some((tables, tables.colr.glyph_layers(cache_key.glyph_id)?))
+            }) {
+                Some((tables, layers)) => {
+                    for layer in layers {
+                        let layer_key = CacheKey {
+                            glyph_id: layer.glyphID,
+                            ..cache_key
+                        };
+                        add_path(layer_key, tables.layer_color(layer));
+                    }
+                }
+                None => add_path(cache_key, None),
+            }
+        }
+    }
+    glyph_paths
 }
 
 impl Default for SvgThumbnailRenderer {
@@ -124,13 +519,22 @@ impl Renderer for SvgThumbnailRenderer {
         &self,
         text_system_context: &mut TextFontSystemContext,
     ) -> Result<super::Thumbnail, super::error::FontThumbnailError> {
-        let precision = self.config.default_precision;
-        tracing::trace!("Rendering SVG thumbnail with precision: {precision}");
-        let mut svg_doc = Document::new();
+        let format = self.config.path_number_format();
+        tracing::trace!("Rendering SVG thumbnail with format: {format:?}");
+        let mut groups = Vec::new();
         let mut tmp_doc = Document::new();
-        let (font_system, swash_cache, text_buffer) =
-            text_system_context.mut_cosmic_text_parts();
-        for layout_run in text_buffer.layout_runs() {
+        let (ascender, descender) =
+            (text_system_context.ascender, text_system_context.descender);
+        let glyph_paths = collect_glyph_paths(
+            text_system_context,
+            &format,
+            self.config.color,
+        );
+        let text_buffer = &text_system_context.text_buffer;
+        let font_size = text_buffer.metrics().font_size;
+        // The bottom of the last line box, when aligning to the line boxes
+        let mut lines_bottom: f32 = 0.0;
+        for (line, layout_run) in text_buffer.layout_runs().enumerate() {
             let mut group = Group::new();
             // Add a style to have the fill as black and the stroke to none
             group = group.add(Style::new(
@@ -142,76 +546,52 @@ impl Renderer for SvgThumbnailRenderer {
                 )
                 .as_str(),
             ));
-            for glyph in layout_run.glyphs {
-                let mut data = svg::node::element::path::Data::new();
-                // Get the x/y offsets
-                let (x_offset, y_offset) =
-                    (glyph.x + glyph.x_offset, glyph.y + glyph.y_offset);
-                // We will need the physical glyph to get the outline commands
-                let physical_glyph = glyph.physical((0., 0.), 1.0);
-                let cache_key = physical_glyph.cache_key;
-                let outline_commands =
-                    swash_cache.get_outline_commands(font_system, cache_key);
-                // Go through each command and build the path
-                if let Some(commands) = outline_commands {
-                    for command in commands {
-                        match command {
-                            cosmic_text::Command::MoveTo(p1) => {
-                                let rounded_data =
-                                    (p1.x, p1.y).round_to(precision);
-                                data = data.move_to(rounded_data);
-                            }
-                            cosmic_text::Command::LineTo(p1) => {
-                                let rounded_data =
-                                    (p1.x, p1.y).round_to(precision);
-                                data = data.line_to(rounded_data);
-                            }
-                            cosmic_text::Command::CurveTo(p1, p2, p3) => {
-                                let p1_rounded_data =
-                                    (p1.x, p1.y).round_to(precision);
-                                let p2_rounded_data =
-                                    (p2.x, p2.y).round_to(precision);
-                                let p3_rounded_data =
-                                    (p3.x, p3.y).round_to(precision);
-                                data = data.cubic_curve_to((
-                                    p1_rounded_data,
-                                    p2_rounded_data,
-                                    p3_rounded_data,
-                                ));
-                            }
-                            cosmic_text::Command::QuadTo(p1, p2) => {
-                                let p1_rounded_data =
-                                    (p1.x, p1.y).round_to(precision);
-                                let p2_rounded_data =
-                                    (p2.x, p2.y).round_to(precision);
-                                data = data.quadratic_curve_to((
-                                    p1_rounded_data,
-                                    p2_rounded_data,
-                                ));
-                            }
-                            cosmic_text::Command::Close => {
-                                data = data.close();
-                            }
-                        }
-                    }
-                }
-                // Don't add empty data paths
-                if !data.is_empty() {
-                    let path = svg::node::element::Path::new()
-                        .set(
-                            Self::TRANSFORM,
-                            format!("translate({x_offset}, {y_offset})"),
-                        )
-                        .set("d", data.clone());
-                    group = group.add(path);
+            for glyph_path in
+                glyph_paths.iter().filter(|path| path.line == line)
+            {
+                let mut path = svg::node::element::Path::new()
+                    .set(
+                        Self::TRANSFORM,
+                        format!(
+                            "translate({}, {})",
+                            glyph_path.x, glyph_path.y
+                        ),
+                    )
+                    .set("d", glyph_path.d.as_str());
+                if let Some(color) = &glyph_path.color {
+                    path = path.set(Self::STYLE, fill_style(color));
                 }
+                group = group.add(path);
             }
 
-            group.assign(Self::TRANSFORM, Self::SCALE);
+            // The baseline offset from the top of the line box, if aligning
+            // to the line box
+            let baseline = match self.config.vertical_align {
+                VerticalAlign::Baseline => None,
+                VerticalAlign::Top => Some(ascender * font_size),
+                VerticalAlign::Center => Some(
+                    (layout_run.line_height
+                        + (ascender + descender) * font_size)
+                        / 2.0,
+                ),
+            };
+            match baseline {
+                Some(baseline) => {
+                    let y = (layout_run.line_top + baseline)
+                        .round_to(format.y_precision);
+                    group.assign(
+                        Self::TRANSFORM,
+                        format!("translate(0, {y}) {}", Self::SCALE),
+                    );
+                    lines_bottom = lines_bottom
+                        .max(layout_run.line_top + layout_run.line_height);
+                }
+                None => group.assign(Self::TRANSFORM, Self::SCALE),
+            }
             // We will need to create a temporary document to get the bounding
             // box of the entire group
             tmp_doc = tmp_doc.add(group.clone());
-            svg_doc.append(group);
+            groups.push(group);
         }
         // Convert the temporary document to a string, so we can get the
         // bounding box
@@ -237,22 +617,50 @@ impl Renderer for SvgThumbnailRenderer {
         // the bounding box is not quite right and 1 pixel row is being
         // clipped for items where the character goes below the
         // baseline.
-        svg_doc = svg_doc.set(
-            Self::VIEW_BOX,
-            (
+        let view_box = match self.config.vertical_align {
+            VerticalAlign::Baseline => (
                 bounding_box.x() - 1.0,
                 bounding_box.y() - 1.0,
                 bounding_box.width() + 2.0,
                 bounding_box.height() + 2.0,
             ),
-        );
+            // Only the horizontal extent comes from the glyphs, the vertical
+            // extent is that of the line boxes
+            VerticalAlign::Top | VerticalAlign::Center => (
+                bounding_box.x() - 1.0,
+                0.0,
+                bounding_box.width() + 2.0,
+                lines_bottom.ceil(),
+            ),
+        };
+        let mut svg_doc = Document::new().set(Self::VIEW_BOX, view_box);
+        // The background must be the first child, so it is drawn behind the
+        // glyphs
+        if let Some(background_color) = &self.config.background_color {
+            svg_doc = svg_doc.add(
+                Rectangle::new()
+                    .set("x", view_box.0)
+                    .set("y", view_box.1)
+                    .set("width", view_box.2)
+                    .set("height", view_box.3)
+                    .set(Self::FILL, background_color.as_str()),
+            );
+        }
+        for group in groups {
+            svg_doc.append(group);
+        }
 
         let mut svg_buffer = Vec::new();
         let svg_cursor = std::io::Cursor::new(&mut svg_buffer);
         svg::write(svg_cursor, &svg_doc)?;
-        Ok(super::Thumbnail::new(
+        Ok(super::Thumbnail::with_metadata(
             svg_buffer,
             SvgThumbnailRenderer::MIME_TYPE.to_string(),
+            super::ThumbnailMetadata {
+                width: view_box.2,
+                height: view_box.3,
+                point_size: font_size,
+            },
         ))
     }
 }
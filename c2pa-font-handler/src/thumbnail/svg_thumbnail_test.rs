@@ -17,7 +17,10 @@
 use std::io::Cursor;
 
 use super::*;
-use crate::thumbnail::text::{create_font_system, FontSystemConfig};
+use crate::thumbnail::{
+    text::{create_font_system, FontSystemConfig},
+    Thumbnail,
+};
 
 /// Sets up a test context with a dummy font system and swash cache.
 fn setup_cosmic_text_for_test() -> TextFontSystemContext {
@@ -45,6 +48,29 @@ fn test_svg_renderer() {
     assert_eq!("image/svg+xml", thumbnail.mime_type());
     assert!(!thumbnail.data().is_empty());
     assert!(thumbnail.data().starts_with(b"<svg"));
+    // The metadata matches the view box and the buffer's font size
+    let metadata = thumbnail.metadata().unwrap();
+    assert_eq!(metadata.point_size, context.text_buffer.metrics().font_size);
+    let svg = std::str::from_utf8(thumbnail.data()).unwrap();
+    assert!(svg.contains(&format!(" {} {}\"", metadata.width, metadata.height)));
+}
+
+#[test]
+fn test_svg_thumbnail_to_data_uri() {
+    let thumbnail = Thumbnail::new(b"<svg/>".to_vec(), "image/svg+xml".into());
+    assert_eq!(
+        thumbnail.to_data_uri(),
+        "data:image/svg+xml;base64,PHN2Zy8+"
+    );
+
+    // A rendered thumbnail is inlined whole
+    let mut context = setup_cosmic_text_for_test();
+    let thumbnail = SvgThumbnailRenderer::default()
+        .render_thumbnail(&mut context)
+        .unwrap();
+    let uri = thumbnail.to_data_uri();
+    let encoded = uri.strip_prefix("data:image/svg+xml;base64,").unwrap();
+    assert_eq!(encoded.len(), thumbnail.data().len().div_ceil(3) * 4);
 }
 
 #[test]
@@ -77,6 +103,108 @@ fn test_precision_rounding() {
     assert_eq!(rounded_point, (1.235, 2.346));
 }
 
+#[test]
+fn test_precision_rounding_to_integers() {
+    assert_eq!(1.5_f32.round_to(0), 2.0);
+    assert_eq!(1.234_f32.round_to(0), 1.0);
+    assert_eq!((-2.6_f32, 0.4_f32).round_to(0), (-3.0, 0.0));
+}
+
+/// Get the numbers in the path data of the given paths.
+fn path_numbers(paths: &[GlyphPath]) -> Vec<String> {
+    paths
+        .iter()
+        .flat_map(|path| {
+            path.d
+                .split(|c: char| {
+                    c.is_ascii_alphabetic() || c == ',' || c == ' '
+                })
+                .filter(|number| !number.is_empty())
+                .map(str::to_string)
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// Get the paths of an SVG thumbnail rendered with the given configuration.
+fn render_paths(config: SvgThumbnailRendererConfig) -> Vec<GlyphPath> {
+    let mut context = setup_cosmic_text_for_test();
+    let thumbnail = SvgThumbnailRenderer::new(config.clone())
+        .render_thumbnail(&mut context)
+        .unwrap();
+    let paths =
+        collect_glyph_paths(&mut context, &config.path_number_format(), false);
+    let svg = std::str::from_utf8(thumbnail.data()).unwrap();
+    for path in &paths {
+        assert!(svg.contains(&format!("<path d=\"{}\"", path.d)));
+    }
+    paths
+}
+
+#[test]
+fn test_svg_renderer_with_precision_0() {
+    let paths =
+        render_paths(SvgThumbnailRendererConfig::default().with_precision(0));
+    let numbers = path_numbers(&paths);
+    assert!(!numbers.is_empty());
+    for number in &numbers {
+        assert!(number.parse::<i32>().is_ok(), "Not an integer: {number}");
+        assert_ne!(number, "-0");
+    }
+    // Fixed-width output is the same, as there are no decimal places
+    let fixed = render_paths(
+        SvgThumbnailRendererConfig::default()
+            .with_precision(0)
+            .with_trim_trailing_zeros(false),
+    );
+    assert_eq!(path_numbers(&fixed), numbers);
+}
+
+#[test]
+fn test_svg_renderer_with_precision_4() {
+    let config = SvgThumbnailRendererConfig::default().with_precision(4);
+    let trimmed = path_numbers(&render_paths(config.clone()));
+    for number in &trimmed {
+        if let Some((_, decimals)) = number.split_once('.') {
+            assert!(decimals.len() <= 4, "Too precise: {number}");
+            assert!(!decimals.ends_with('0'), "Not trimmed: {number}");
+        }
+    }
+    let fixed =
+        path_numbers(&render_paths(config.with_trim_trailing_zeros(false)));
+    assert_eq!(fixed.len(), trimmed.len());
+    for (fixed, trimmed) in fixed.iter().zip(&trimmed) {
+        let (_, decimals) = fixed.split_once('.').unwrap();
+        assert_eq!(decimals.len(), 4, "Not fixed width: {fixed}");
+        assert_eq!(fixed.parse::<f32>(), trimmed.parse::<f32>());
+    }
+    // Trimming only ever makes the path data smaller
+    assert!(
+        trimmed.iter().map(String::len).sum::<usize>()
+            <= fixed.iter().map(String::len).sum::<usize>()
+    );
+}
+
+#[test]
+fn test_svg_renderer_with_axis_precision() {
+    let paths = render_paths(
+        SvgThumbnailRendererConfig::default()
+            .with_axis_precision(0, 3)
+            .with_trim_trailing_zeros(false),
+    );
+    // Coordinates alternate between x and y
+    for (index, number) in path_numbers(&paths).iter().enumerate() {
+        let decimals = number.split_once('.').map_or(0, |(_, d)| d.len());
+        assert_eq!(decimals, if index % 2 == 0 { 0 } else { 3 });
+    }
+    // Setting a single precision resets the axes
+    let config = SvgThumbnailRendererConfig::default()
+        .with_axis_precision(0, 3)
+        .with_precision(1);
+    assert!(config.axis_precision.is_none());
+    assert_eq!(config.default_precision, 1);
+}
+
 #[test]
 fn test_default_svg_thumbnail_renderer_config() {
     let config = SvgThumbnailRendererConfig::default();
@@ -88,4 +216,247 @@ fn test_default_svg_thumbnail_renderer_config() {
         config.glyph_fill_color,
         SvgThumbnailRendererConfig::SVG_GLYPH_FILL_COLOR
     );
+    assert!(config.background_color.is_none());
+}
+
+#[test]
+fn test_svg_renderer_without_background() {
+    let mut context = setup_cosmic_text_for_test();
+    let renderer = SvgThumbnailRenderer::default();
+    let thumbnail = renderer.render_thumbnail(&mut context).unwrap();
+    let svg = std::str::from_utf8(thumbnail.data()).unwrap();
+    assert!(!svg.contains("<rect"));
+}
+
+#[test]
+fn test_svg_renderer_with_background() {
+    let mut context = setup_cosmic_text_for_test();
+    let config =
+        SvgThumbnailRendererConfig::default().with_background_color("#ffeedd");
+    let renderer = SvgThumbnailRenderer::new(config);
+    let thumbnail = renderer.render_thumbnail(&mut context).unwrap();
+    let svg = std::str::from_utf8(thumbnail.data()).unwrap();
+    // The background rectangle must be the first child of the document
+    let rect_start = svg.find("<rect").expect("Expected a background rect");
+    let group_start = svg.find("<g").expect("Expected a glyph group");
+    assert!(rect_start < group_start);
+    assert!(svg.contains(r##"fill="#ffeedd""##));
+    // And it should cover the entire view box
+    let view_box = svg
+        .split("viewBox=\"")
+        .nth(1)
+        .and_then(|s| s.split('"').next())
+        .unwrap()
+        .split(' ')
+        .collect::<Vec<_>>();
+    let rect = &svg[rect_start..];
+    assert!(rect.contains(&format!("x=\"{}\"", view_box[0])));
+    assert!(rect.contains(&format!("y=\"{}\"", view_box[1])));
+    assert!(rect.contains(&format!("width=\"{}\"", view_box[2])));
+    assert!(rect.contains(&format!("height=\"{}\"", view_box[3])));
+}
+
+/// Gets the view box and the glyph group's transform of the given SVG.
+fn view_box_and_transform(svg: &str) -> (Vec<f32>, String) {
+    let attribute = |svg: &str, name: &str| {
+        svg.split(&format!("{name}=\""))
+            .nth(1)
+            .and_then(|s| s.split('"').next())
+            .unwrap()
+            .to_string()
+    };
+    let view_box = attribute(svg, "viewBox")
+        .split(' ')
+        .map(|v| v.parse().unwrap())
+        .collect();
+    let group = &svg[svg.find("<g").unwrap()..];
+    (view_box, attribute(group, "transform"))
+}
+
+#[test]
+fn test_svg_renderer_with_vertical_align() {
+    let mut context = setup_cosmic_text_for_test();
+    let (ascender, descender) = (context.ascender, context.descender);
+    assert!(ascender > 0.0 && descender <= 0.0);
+    let (font_size, line_height) = {
+        let metrics = context.text_buffer.metrics();
+        (metrics.font_size, metrics.line_height)
+    };
+
+    // The default leaves the group as is
+    let renderer = SvgThumbnailRenderer::default();
+    let thumbnail = renderer.render_thumbnail(&mut context).unwrap();
+    let svg = std::str::from_utf8(thumbnail.data()).unwrap();
+    let (_, transform) = view_box_and_transform(svg);
+    assert_eq!(transform, "scale(1, -1)");
+
+    for (vertical_align, baseline) in [
+        (VerticalAlign::Top, ascender * font_size),
+        (
+            VerticalAlign::Center,
+            (line_height + (ascender + descender) * font_size) / 2.0,
+        ),
+    ] {
+        let config = SvgThumbnailRendererConfig::default()
+            .with_vertical_align(vertical_align);
+        let renderer = SvgThumbnailRenderer::new(config);
+        let thumbnail = renderer.render_thumbnail(&mut context).unwrap();
+        let svg = std::str::from_utf8(thumbnail.data()).unwrap();
+        let (view_box, transform) = view_box_and_transform(svg);
+        // The view box spans the line box
+        assert_eq!(view_box[1], 0.0);
+        assert_eq!(view_box[3], line_height.ceil());
+        assert_eq!(
+            transform,
+            format!("translate(0, {}) scale(1, -1)", baseline.round_to(2))
+        );
+    }
+}
+
+/// Builds a copy of the test font with 'COLR' and 'CPAL' tables, where every
+/// glyph is drawn as a single red layer of itself.
+fn color_font_data() -> Vec<u8> {
+    let font_data = include_bytes!("../../../.devtools/font.otf");
+    let read_u16 =
+        |at: usize| u16::from_be_bytes([font_data[at], font_data[at + 1]]);
+    let read_u32 = |at: usize| {
+        u32::from_be_bytes(font_data[at..at + 4].try_into().unwrap())
+    };
+    // Grab the existing tables
+    let mut tables = (0..read_u16(4) as usize)
+        .map(|index| {
+            let entry = 12 + index * 16;
+            let offset = read_u32(entry + 8) as usize;
+            let length = read_u32(entry + 12) as usize;
+            (
+                font_data[entry..entry + 4].to_vec(),
+                font_data[offset..offset + length].to_vec(),
+            )
+        })
+        .collect::<Vec<_>>();
+    // Map glyphs 0..5 to a single layer of themselves, using palette entry 0
+    let mut colr = vec![0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x0e];
+    colr.extend_from_slice(&(14u32 + 5 * 6).to_be_bytes());
+    colr.extend_from_slice(&5u16.to_be_bytes());
+    for glyph in 0..5u16 {
+        colr.extend_from_slice(&glyph.to_be_bytes());
+        colr.extend_from_slice(&glyph.to_be_bytes());
+        colr.extend_from_slice(&1u16.to_be_bytes());
+    }
+    for glyph in 0..5u16 {
+        colr.extend_from_slice(&glyph.to_be_bytes());
+        colr.extend_from_slice(&0u16.to_be_bytes());
+    }
+    tables.push((b"COLR".to_vec(), colr));
+    let cpal = vec![
+        0x00, 0x00, 0x00, 0x01, 0x00, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00, 0x0e,
+        0x00, 0x00, 0x00, 0x00, 0xff, 0xff,
+    ];
+    tables.push((b"CPAL".to_vec(), cpal));
+    tables.sort();
+    // And write out a new font with them
+    let num_tables = tables.len();
+    let mut data = font_data[0..4].to_vec();
+    data.extend_from_slice(&(num_tables as u16).to_be_bytes());
+    data.extend_from_slice(&[0x00, 0x80, 0x00, 0x03, 0x00, 0x50]);
+    let mut offset = 12 + num_tables * 16;
+    for (tag, table) in &tables {
+        data.extend_from_slice(tag);
+        data.extend_from_slice(&0u32.to_be_bytes());
+        data.extend_from_slice(&(offset as u32).to_be_bytes());
+        data.extend_from_slice(&(table.len() as u32).to_be_bytes());
+        offset += table.len().next_multiple_of(4);
+    }
+    for (_, table) in &tables {
+        data.extend_from_slice(table);
+        data.resize(data.len().next_multiple_of(4), 0);
+    }
+    data
+}
+
+#[test]
+fn test_svg_renderer_with_color() {
+    let font_data = color_font_data();
+    let render = |config: SvgThumbnailRendererConfig| {
+        let mut context = create_font_system(
+            &FontSystemConfig::default(),
+            &mut Cursor::new(&font_data),
+        )
+        .unwrap();
+        let thumbnail = SvgThumbnailRenderer::new(config)
+            .render_thumbnail(&mut context)
+            .unwrap();
+        String::from_utf8(thumbnail.data().to_vec()).unwrap()
+    };
+    // Color is opt-in
+    let svg = render(SvgThumbnailRendererConfig::default());
+    assert!(!svg.contains("#ff0000"));
+    let svg = render(SvgThumbnailRendererConfig::default().with_color(true));
+    assert!(svg.contains(r#"style="fill: #ff0000""#));
+}
+
+#[test]
+fn test_svg_renderer_with_color_for_non_color_font() {
+    let mut context = setup_cosmic_text_for_test();
+    let config = SvgThumbnailRendererConfig::default().with_color(true);
+    let thumbnail = SvgThumbnailRenderer::new(config)
+        .render_thumbnail(&mut context)
+        .unwrap();
+    let svg = std::str::from_utf8(thumbnail.data()).unwrap();
+    assert!(svg.contains("<path"));
+    assert!(!svg.contains("style=\""));
+}
+
+#[test]
+fn test_glyphs_to_paths() {
+    let mut context = setup_cosmic_text_for_test();
+    let paths = glyphs_to_paths(&mut context, 2);
+    assert!(!paths.is_empty());
+    for path in &paths {
+        assert!(path.d.starts_with('M'), "Unexpected path data: {}", path.d);
+        assert_eq!(path.line, 0);
+        assert_eq!(path.color, None);
+    }
+    // The SVG renderer draws the same paths
+    let thumbnail = SvgThumbnailRenderer::default()
+        .render_thumbnail(&mut context)
+        .unwrap();
+    let svg = std::str::from_utf8(thumbnail.data()).unwrap();
+    for path in &paths {
+        assert!(svg.contains(&format!(
+            "<path d=\"{}\" transform=\"translate({}, {})\"",
+            path.d, path.x, path.y
+        )));
+    }
+}
+
+#[test]
+fn test_glyph_bounds() {
+    let mut context = setup_cosmic_text_for_test();
+    let bounds = glyph_bounds(&mut context);
+    // A box for each glyph with an outline, at the glyph's position
+    let paths = glyphs_to_paths(&mut context, 2);
+    assert_eq!(bounds.len(), paths.len());
+    for ((_, rect), path) in bounds.iter().zip(&paths) {
+        assert!(rect.width() > 0.0 && rect.height() > 0.0);
+        assert!(rect.right() > path.x);
+    }
+}
+
+#[test]
+fn test_outline_bounds_are_tight() {
+    use cosmic_text::Command;
+    // The curve only reaches halfway to its control point
+    let commands = [
+        Command::MoveTo((0.0, 0.0).into()),
+        Command::QuadTo((5.0, 10.0).into(), (10.0, 0.0).into()),
+        Command::Close,
+    ];
+    let rect = outline_bounds(&commands).unwrap();
+    assert_eq!(
+        (rect.left(), rect.top(), rect.right(), rect.bottom()),
+        (0.0, 0.0, 10.0, 5.0)
+    );
+    // Nothing to bound without an outline
+    assert_eq!(outline_bounds(&[]), None);
 }
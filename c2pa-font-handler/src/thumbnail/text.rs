@@ -19,24 +19,30 @@
 //! used to generate thumbnails for fonts, which can be used in C2PA
 //! operations.
 
-#[cfg(feature = "woff")]
-use std::io::Cursor;
 use std::{
-    io::{Read, Seek},
+    io::{Cursor, Read, Seek},
     sync::Arc,
 };
 
 use cosmic_text::{
-    fontdb::{Database, ID},
+    fontdb::{Database, Source, ID},
     ttf_parser::{name_id, PlatformId},
     Attrs, BorrowedWithFontSystem, Buffer, CacheKeyFlags, Fallback, Font,
     FontFeatures, FontSystem, Metrics, SwashCache,
 };
+use unicode_bidi::BidiInfo;
 
-use super::{error::FontThumbnailError, Renderer, ThumbnailGenerator};
-use crate::mime_type::{FontMimeTypeGuesser, FontMimeTypes};
+use super::{
+    cache::FontCache, error::FontThumbnailError, Renderer, ThumbnailGenerator,
+};
 #[cfg(feature = "woff")]
-use crate::{sfnt::font::SfntFont, FontDataRead, MutFontDataWrite};
+use crate::MutFontDataWrite;
+use crate::{
+    mime_type::{FontMimeTypeGuesser, FontMimeTypes},
+    sfnt::{font::SfntFont, table::FvarAxis},
+    tag::FontTag,
+    FontDataRead,
+};
 
 /// Context for the text font system, which includes the font system, swash
 /// cache, text buffer, and the angle of the font if it is italic.
@@ -50,6 +56,13 @@ pub struct TextFontSystemContext {
     pub text_buffer: Buffer,
     /// The angle of the font, if it is italic
     pub angle: Option<f32>,
+    /// The ascender of the font, as a fraction of the em
+    pub ascender: f32,
+    /// The descender of the font, as a fraction of the em (negative when it
+    /// is below the baseline)
+    pub descender: f32,
+    /// The width added to the text buffer for the slant of an italic font
+    pub slant_margin: f32,
 }
 
 impl TextFontSystemContext {
@@ -68,6 +81,40 @@ impl TextFontSystemContext {
             &mut self.text_buffer,
         )
     }
+
+    /// Re-shapes the text buffer using the given base metrics multiplied by
+    /// `scale`, then resizes the buffer to fit the scaled text, along with the
+    /// scaled slant margin.
+    pub(crate) fn scale_text_buffer(
+        &mut self,
+        base_metrics: Metrics,
+        scale: f32,
+    ) -> Result<(), FontThumbnailError> {
+        let metrics = Metrics::new(
+            base_metrics.font_size * scale,
+            base_metrics.line_height * scale,
+        );
+        let mut buffer = self.text_buffer.borrow_with(&mut self.font_system);
+        buffer.set_metrics(metrics);
+        // Remove the size constraints, so the scaled text is laid out exactly
+        // as it was at the base size, without any extra wrapping
+        buffer.set_size(None, None);
+        buffer.shape_until_scroll(true);
+        let size = layout_size(&mut buffer);
+        if size.w <= 0.0 {
+            return Err(FontThumbnailError::InvalidBufferSize);
+        }
+        // Keep the margin for the slant of the ascender in step with the
+        // scaled font size; it is only non-zero when italic compensation is
+        // enabled for an italic font
+        if let Some(angle) = self.angle.filter(|_| self.slant_margin > 0.0) {
+            self.slant_margin =
+                (self.ascender * metrics.font_size * angle.to_radians().tan())
+                    .abs();
+        }
+        buffer.set_size(Some(size.w + self.slant_margin), Some(size.h));
+        Ok(())
+    }
 }
 
 /// A thumbnail generator that uses the cosmic-text crate to render text
@@ -79,6 +126,8 @@ pub struct CosmicTextThumbnailGenerator<'a> {
     renderer: Box<dyn Renderer>,
     /// The font system configuration to use for the thumbnail generation
     font_system_config: FontSystemConfig<'a>,
+    /// The cache of parsed fonts shared across thumbnail requests, if any
+    font_cache: Option<Arc<FontCache>>,
 }
 
 impl<'a> CosmicTextThumbnailGenerator<'a> {
@@ -87,6 +136,7 @@ impl<'a> CosmicTextThumbnailGenerator<'a> {
         Self {
             renderer: render,
             font_system_config: FontSystemConfig::default(),
+            font_cache: None,
         }
     }
 
@@ -98,16 +148,61 @@ impl<'a> CosmicTextThumbnailGenerator<'a> {
         Self {
             renderer,
             font_system_config,
+            font_cache: None,
         }
     }
-}
 
-impl<'a> ThumbnailGenerator for CosmicTextThumbnailGenerator<'a> {
-    fn create_thumbnail_from_stream<R: Read + Seek + ?Sized>(
+    /// Uses the given cache of parsed fonts, so repeated requests for the
+    /// same font data skip parsing it; the cache may be shared with other
+    /// generators.
+    pub fn with_font_cache(mut self, font_cache: Arc<FontCache>) -> Self {
+        self.font_cache = Some(font_cache);
+        self
+    }
+
+    /// Create thumbnails at multiple scales from a stream.
+    ///
+    /// The font system is created (and the font size searched for) only once,
+    /// after which the text is re-shaped at each of the given scales of the
+    /// final point size and rendered. The returned thumbnails are in the same
+    /// order as `scales`.
+    ///
+    /// # Errors
+    /// Returns [`FontThumbnailError::InvalidScale`] if any of the scales is not
+    /// a finite, positive number, or an error if the font system could not be
+    /// created or a thumbnail could not be rendered.
+    pub fn create_thumbnails_multi<R: Read + Seek + ?Sized>(
         &self,
         reader: &mut R,
         mime_type: Option<&FontMimeTypes>,
-    ) -> Result<super::Thumbnail, super::error::FontThumbnailError> {
+        scales: &[f32],
+    ) -> Result<Vec<super::Thumbnail>, FontThumbnailError> {
+        // Validate the scales before doing any of the heavy lifting
+        if let Some(scale) =
+            scales.iter().find(|s| !s.is_finite() || **s <= 0.0)
+        {
+            return Err(FontThumbnailError::InvalidScale(*scale));
+        }
+        let mut context = self.create_context(reader, mime_type)?;
+        // Keep up with the metrics found for the base (1x) thumbnail
+        let base_metrics = context.text_buffer.metrics();
+        scales
+            .iter()
+            .map(|&scale| {
+                tracing::trace!("Rendering thumbnail at scale {scale}");
+                context.scale_text_buffer(base_metrics, scale)?;
+                self.renderer.render_thumbnail(&mut context)
+            })
+            .collect()
+    }
+
+    /// Create the text font system context for the font data in the stream,
+    /// ready for rendering.
+    fn create_context<R: Read + Seek + ?Sized>(
+        &self,
+        reader: &mut R,
+        mime_type: Option<&FontMimeTypes>,
+    ) -> Result<TextFontSystemContext, FontThumbnailError> {
         // Determine the MIME type, guessing if not provided
         let mime = match mime_type {
             Some(m) => m,
@@ -122,10 +217,9 @@ impl<'a> ThumbnailGenerator for CosmicTextThumbnailGenerator<'a> {
         match mime {
             FontMimeTypes::OTF | FontMimeTypes::TTF => {
                 tracing::trace!("Creating font system from SFNT data");
-                let mut context =
-                    create_font_system(&self.font_system_config, reader)?;
+                let context = self.create_font_system(reader)?;
                 tracing::trace!("Rendering thumbnail for SFNT font");
-                self.renderer.render_thumbnail(&mut context)
+                Ok(context)
             }
             #[cfg(feature = "woff")]
             FontMimeTypes::WOFF => {
@@ -141,10 +235,9 @@ impl<'a> ThumbnailGenerator for CosmicTextThumbnailGenerator<'a> {
 
                 tracing::trace!("Creating font system from SFNT data created from WOFF/WOFF2");
                 let mut cursor = Cursor::new(font_buf);
-                let mut context =
-                    create_font_system(&self.font_system_config, &mut cursor)?;
+                let context = self.create_font_system(&mut cursor)?;
                 tracing::trace!("Rendering thumbnail for WOFF/WOFF2 font");
-                self.renderer.render_thumbnail(&mut context)
+                Ok(context)
             }
             _ => {
                 tracing::warn!(
@@ -154,15 +247,96 @@ impl<'a> ThumbnailGenerator for CosmicTextThumbnailGenerator<'a> {
             }
         }
     }
+
+    /// Create the font system for the SFNT font data in the stream, using the
+    /// font cache when there is one.
+    fn create_font_system<R: Read + Seek + ?Sized>(
+        &self,
+        reader: &mut R,
+    ) -> Result<TextFontSystemContext, FontThumbnailError> {
+        let Some(font_cache) = &self.font_cache else {
+            return create_font_system(&self.font_system_config, reader);
+        };
+        let font_data = std::io::Read::bytes(reader)
+            .collect::<std::io::Result<Vec<u8>>>()?;
+        let parsed_font = font_cache.get_or_parse(font_data)?;
+        create_font_system_from_parsed(&self.font_system_config, &parsed_font)
+    }
+}
+
+impl<'a> ThumbnailGenerator for CosmicTextThumbnailGenerator<'a> {
+    fn create_thumbnail_from_stream<R: Read + Seek + ?Sized>(
+        &self,
+        reader: &mut R,
+        mime_type: Option<&FontMimeTypes>,
+    ) -> Result<super::Thumbnail, super::error::FontThumbnailError> {
+        let mut context = self.create_context(reader, mime_type)?;
+        self.renderer.render_thumbnail(&mut context)
+    }
 }
 
-/// Information about the font
-struct FontNameInfo {
+/// Information about the font, from the English names in its 'name' table
+#[derive(Clone, Debug, Default)]
+pub struct FontNameInfo {
+    /// Family name of the font
+    pub family_name: Option<String>,
     /// Full name of the font
-    full_name: Option<String>,
+    pub full_name: Option<String>,
     /// Sample text for the font
-    #[allow(unused)]
-    sample_text: Option<String>,
+    pub sample_text: Option<String>,
+    /// PostScript name of the font
+    pub postscript_name: Option<String>,
+}
+
+/// Provides the text to render in the thumbnail of a font.
+///
+/// # Remarks
+/// Any function (or closure) taking the [`FontNameInfo`] of the font and
+/// returning the text is a text provider.
+pub trait TextProvider: Send + Sync {
+    /// Returns the text to render for the font with the given names.
+    fn text_for(&self, font_info: &FontNameInfo) -> String;
+}
+
+impl<F> TextProvider for F
+where
+    F: Fn(&FontNameInfo) -> String + Send + Sync,
+{
+    fn text_for(&self, font_info: &FontNameInfo) -> String {
+        self(font_info)
+    }
+}
+
+impl std::fmt::Debug for dyn TextProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("TextProvider")
+    }
+}
+
+/// Renders the full name of the font (the default).
+#[derive(Clone, Copy, Debug, Default)]
+pub struct FullNameTextProvider;
+
+impl TextProvider for FullNameTextProvider {
+    fn text_for(&self, font_info: &FontNameInfo) -> String {
+        font_info.full_name.clone().unwrap_or_default()
+    }
+}
+
+/// Renders the sample text of the font, falling back to its full name if it
+/// has no sample text.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SampleTextProvider;
+
+impl TextProvider for SampleTextProvider {
+    fn text_for(&self, font_info: &FontNameInfo) -> String {
+        font_info
+            .sample_text
+            .as_ref()
+            .or(font_info.full_name.as_ref())
+            .cloned()
+            .unwrap_or_default()
+    }
 }
 
 impl From<Arc<Font>> for FontNameInfo {
@@ -205,18 +379,22 @@ impl From<Arc<Font>> for FontNameInfo {
                 .and_then(|name| name.to_string())
         };
 
+        let family_name = find_name(name_id::FAMILY);
         let full_name = find_name(name_id::FULL_NAME);
         let sample_text = find_name(name_id::SAMPLE_TEXT);
+        let postscript_name = find_name(name_id::POST_SCRIPT_NAME);
 
         FontNameInfo {
+            family_name,
             full_name,
             sample_text,
+            postscript_name,
         }
     }
 }
 
 /// Information about a loaded font, including its ID and attributes.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 struct LoadedFont<'a> {
     /// The ID of the loaded font in the font database
     id: ID,
@@ -227,9 +405,9 @@ struct LoadedFont<'a> {
 /// Load font data into the font database, returning the ID of the loaded font
 fn load_font_data<'a>(
     font_db: &mut Database,
-    font_data: Vec<u8>,
+    font_data: impl Into<Arc<Vec<u8>>>,
 ) -> Result<LoadedFont<'a>, FontThumbnailError> {
-    font_db.load_font_data(font_data);
+    font_db.load_font_source(Source::Binary(font_data.into()));
     let face = font_db
         .faces()
         .last()
@@ -252,6 +430,32 @@ fn load_font_data<'a>(
     Ok(LoadedFont { id: face.id, attrs })
 }
 
+/// A font loaded into a font database of its own, from which font systems can
+/// be created without parsing the font again.
+#[derive(Clone, Debug)]
+pub(crate) struct ParsedFont {
+    /// The font data
+    pub(crate) data: Arc<Vec<u8>>,
+    /// The font database, which only contains the font
+    database: Database,
+    /// The loaded font
+    loaded_font: LoadedFont<'static>,
+}
+
+impl ParsedFont {
+    /// Parses the font data into a font database of its own.
+    pub(crate) fn new(font_data: Vec<u8>) -> Result<Self, FontThumbnailError> {
+        let data = Arc::new(font_data);
+        let mut database = Database::new();
+        let loaded_font = load_font_data(&mut database, data.clone())?;
+        Ok(Self {
+            data,
+            database,
+            loaded_font,
+        })
+    }
+}
+
 /// The cosmic-text crate provides a [`Fallback`] trait that is used to provide
 /// fallback fonts In our scenario, we do not want to use any fallback fonts for
 /// generating the thumbnail, so we implement a no-op version of the trait
@@ -327,11 +531,21 @@ pub struct BinarySearchContext {
     pub minimum_point_size: f32,
     /// Maximum point size to stop searching at
     pub maximum_point_size: f32,
+    /// Maximum number of sizes to try before settling for the best size found
+    /// so far, bounding the search for pathological fonts
+    pub max_iterations: u32,
+    /// Precision of the search: the sizes tried are multiples of it, and the
+    /// search stops once the range left to search is no wider than it
+    pub epsilon: f32,
 }
 
 impl BinarySearchContext {
+    /// Default precision for binary search strategy
+    pub const DEFAULT_EPSILON: f32 = 1.0;
     /// Default maximum point size for binary search strategy
     const DEFAULT_MAXIMUM_POINT_SIZE: f32 = 512.0;
+    /// Default maximum number of sizes to try for binary search strategy
+    pub const DEFAULT_MAX_ITERATIONS: u32 = 32;
     /// Default minimum point size for binary search strategy
     const DEFAULT_MINIMUM_POINT_SIZE: f32 = 6.0;
     /// Default starting point size for binary search strategy
@@ -347,6 +561,33 @@ impl BinarySearchContext {
             starting_point_size,
             minimum_point_size,
             maximum_point_size,
+            max_iterations: Self::DEFAULT_MAX_ITERATIONS,
+            epsilon: Self::DEFAULT_EPSILON,
+        }
+    }
+
+    /// Set the maximum number of sizes to try before settling for the best
+    /// size found so far
+    pub fn with_max_iterations(mut self, max_iterations: u32) -> Self {
+        self.max_iterations = max_iterations;
+        self
+    }
+
+    /// Set the precision of the search, trading speed for a closer fit (e.g.,
+    /// 0.5 to find half-point sizes); a value which is not positive is
+    /// treated as the default
+    pub fn with_epsilon(mut self, epsilon: f32) -> Self {
+        self.epsilon = epsilon;
+        self
+    }
+
+    /// Returns the precision of the search, falling back to the default for
+    /// a value which is not positive
+    fn effective_epsilon(&self) -> f32 {
+        if self.epsilon > 0.0 {
+            self.epsilon
+        } else {
+            Self::DEFAULT_EPSILON
         }
     }
 }
@@ -412,6 +653,69 @@ impl Default for FontSizeSearchStrategy {
     }
 }
 
+/// Behavior when the text does not fit the width, even at the minimum point
+/// size of the search strategy.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ClipBehavior {
+    /// Replace the end of the text with an ellipsis ("...") (default).
+    #[default]
+    Ellipsis,
+    /// Fail with [`FontThumbnailError::TextDoesNotFit`].
+    Error,
+    /// Drop the end of the text, without any indication it was clipped.
+    TruncateSilently,
+}
+
+/// Behavior when the font has no glyphs for any of the text to render (e.g.,
+/// a symbol font whose name is in Latin script), so its thumbnail would only
+/// show missing glyphs.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum UnrenderablePolicy {
+    /// Generate the thumbnail as for any other text, failing as the font
+    /// size search does (e.g., with
+    /// [`FontThumbnailError::FailedToFindAppropriateSize`] if the text has no
+    /// width) (default).
+    #[default]
+    Error,
+    /// Render a placeholder instead: the PostScript name of the font (or the
+    /// text, if it has none), with the system's fonts allowed as fallbacks
+    /// for the glyphs the font lacks.
+    Placeholder,
+}
+
+/// The font metrics the line height is derived from, before the line height
+/// factor and the point size are applied.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LineHeightBasis {
+    /// The distance from the ascender to the descender (default).
+    #[default]
+    AscenderDescender,
+    /// The cap height from the 'OS/2' table, for tightly cropped single-line
+    /// thumbnails; falls back to the ascender if the font records none.
+    CapHeight,
+    /// The em square, i.e., the point size itself.
+    EmSquare,
+}
+
+impl LineHeightBasis {
+    /// Returns the height of the basis in the given font, as a fraction of
+    /// the em.
+    fn em_height(self, face: &cosmic_text::ttf_parser::Face) -> f32 {
+        let units_per_em = face.units_per_em() as f32;
+        match self {
+            Self::AscenderDescender => {
+                (face.ascender() as f32 - face.descender() as f32)
+                    / units_per_em
+            }
+            Self::CapHeight => {
+                face.capital_height().unwrap_or(face.ascender()) as f32
+                    / units_per_em
+            }
+            Self::EmSquare => 1.0,
+        }
+    }
+}
+
 /// Configuration for the font system used to generate thumbnails
 #[derive(Debug, Clone)]
 pub struct FontSystemConfig<'a> {
@@ -419,12 +723,25 @@ pub struct FontSystemConfig<'a> {
     default_locale: &'a str,
     /// The line height factor for the thumbnail
     line_height_factor: f32,
+    /// The font metrics the line height is derived from
+    line_height_basis: LineHeightBasis,
     /// The maximum width for the thumbnail
     maximum_width: u32,
     /// The total width padding to apply to the thumbnail
     total_width_padding: f32,
     /// The strategy to use for searching for the appropriate font size
     font_size_search_strategy: FontSizeSearchStrategy,
+    /// What to do when the text does not fit at the minimum point size
+    clip_behavior: ClipBehavior,
+    /// The variation axis coordinates used as hints when matching the face of
+    /// variable fonts
+    variation_hints: Vec<(FontTag, f32)>,
+    /// Whether to widen the buffer to make room for the slant of italic fonts
+    italic_compensation: bool,
+    /// Provides the text to render
+    text_provider: Arc<dyn TextProvider>,
+    /// What to do when the font has no glyphs for the text
+    on_unrenderable: UnrenderablePolicy,
 }
 
 impl FontSystemConfig<'static> {
@@ -450,9 +767,15 @@ impl<'a> FontSystemConfig<'a> {
         Self {
             default_locale,
             line_height_factor,
+            line_height_basis: LineHeightBasis::default(),
             maximum_width,
             total_width_padding,
             font_size_search_strategy,
+            clip_behavior: ClipBehavior::default(),
+            variation_hints: Vec::new(),
+            italic_compensation: true,
+            text_provider: Arc::new(FullNameTextProvider),
+            on_unrenderable: UnrenderablePolicy::default(),
         }
     }
 
@@ -482,12 +805,24 @@ pub struct FontSystemConfigBuilder<'a> {
     default_locale: Option<&'a str>,
     /// The line height factor for the thumbnail
     line_height_factor: Option<f32>,
+    /// The font metrics the line height is derived from
+    line_height_basis: Option<LineHeightBasis>,
     /// The maximum width for the thumbnail
     maximum_width: Option<u32>,
     /// The total width padding to apply to the thumbnail
     total_width_padding: Option<f32>,
     /// The strategy to use for searching for the appropriate font size
     font_size_search_strategy: Option<FontSizeSearchStrategy>,
+    /// What to do when the text does not fit at the minimum point size
+    clip_behavior: Option<ClipBehavior>,
+    /// The variation axis coordinates of the instance to render
+    variation_hints: Option<Vec<(FontTag, f32)>>,
+    /// Whether to widen the buffer to make room for the slant of italic fonts
+    italic_compensation: Option<bool>,
+    /// Provides the text to render
+    text_provider: Option<Arc<dyn TextProvider>>,
+    /// What to do when the font has no glyphs for the text
+    on_unrenderable: Option<UnrenderablePolicy>,
 }
 
 impl<'a> FontSystemConfigBuilder<'a> {
@@ -508,6 +843,13 @@ impl<'a> FontSystemConfigBuilder<'a> {
         self
     }
 
+    /// Set the font metrics the line height is derived from; by default, the
+    /// distance from the ascender to the descender.
+    pub fn line_height_basis(mut self, basis: LineHeightBasis) -> Self {
+        self.line_height_basis = Some(basis);
+        self
+    }
+
     /// Set the maximum width for the thumbnail
     pub fn maximum_width(mut self, width: u32) -> Self {
         self.maximum_width = Some(width);
@@ -526,6 +868,57 @@ impl<'a> FontSystemConfigBuilder<'a> {
         self
     }
 
+    /// Set what to do when the text does not fit at the minimum point size
+    pub fn clip_behavior(mut self, behavior: ClipBehavior) -> Self {
+        self.clip_behavior = Some(behavior);
+        self
+    }
+
+    /// Set variation axis coordinates (e.g., `wght` at `700.0`) used as hints
+    /// when matching the face of a variable font; none by default.
+    ///
+    /// # Remarks
+    /// The hints only affect which face is matched, never the glyph outlines:
+    /// `cosmic-text` does not apply variation coordinates when rendering, so a
+    /// variable font is always rendered at its default instance. Only the
+    /// `wght` axis is used, as the weight to match. Axes not found in the
+    /// font's 'fvar' table are ignored, and coordinates are clamped to the
+    /// range of their axis.
+    pub fn variation_hints(mut self, hints: Vec<(FontTag, f32)>) -> Self {
+        self.variation_hints = Some(hints);
+        self
+    }
+
+    /// Set whether the final buffer is widened by the overhang of the slant
+    /// of italic (or oblique) fonts, so the tops of the glyphs at the end of
+    /// the line are not clipped; enabled by default.
+    ///
+    /// # Remarks
+    /// The overhang is computed from the italic angle of the font and the
+    /// height of its ascender at the chosen font size.
+    pub fn italic_compensation(mut self, enabled: bool) -> Self {
+        self.italic_compensation = Some(enabled);
+        self
+    }
+
+    /// Set the provider of the text to render; by default, the full name of
+    /// the font is rendered (see [`FullNameTextProvider`]).
+    pub fn text_provider(
+        mut self,
+        provider: impl TextProvider + 'static,
+    ) -> Self {
+        self.text_provider = Some(Arc::new(provider));
+        self
+    }
+
+    /// Set what to do when the font has no glyphs for any of the text to
+    /// render; by default, the thumbnail is generated as usual (see
+    /// [`UnrenderablePolicy::Error`]).
+    pub fn on_unrenderable(mut self, policy: UnrenderablePolicy) -> Self {
+        self.on_unrenderable = Some(policy);
+        self
+    }
+
     /// Build the font system configuration from the builder parameters
     pub fn build(self) -> FontSystemConfig<'a> {
         let default_config = FontSystemConfig::default();
@@ -536,6 +929,9 @@ impl<'a> FontSystemConfigBuilder<'a> {
             line_height_factor: self
                 .line_height_factor
                 .unwrap_or(default_config.line_height_factor),
+            line_height_basis: self
+                .line_height_basis
+                .unwrap_or(default_config.line_height_basis),
             maximum_width: self
                 .maximum_width
                 .unwrap_or(default_config.maximum_width),
@@ -545,6 +941,21 @@ impl<'a> FontSystemConfigBuilder<'a> {
             font_size_search_strategy: self
                 .font_size_search_strategy
                 .unwrap_or(default_config.font_size_search_strategy),
+            clip_behavior: self
+                .clip_behavior
+                .unwrap_or(default_config.clip_behavior),
+            variation_hints: self
+                .variation_hints
+                .unwrap_or(default_config.variation_hints),
+            italic_compensation: self
+                .italic_compensation
+                .unwrap_or(default_config.italic_compensation),
+            text_provider: self
+                .text_provider
+                .unwrap_or(default_config.text_provider),
+            on_unrenderable: self
+                .on_unrenderable
+                .unwrap_or(default_config.on_unrenderable),
         }
     }
 }
@@ -552,6 +963,11 @@ impl<'a> FontSystemConfigBuilder<'a> {
 /// For a given stream of font data, create a font system and a buffer that fits
 /// the given width and height, ready for rendering.
 ///
+/// # Remarks
+/// The buffer holds the text from the configured [`TextProvider`]; if that
+/// text is empty (e.g., the font has no full name), this fails with
+/// [`FontThumbnailError::NoFullNameFound`].
+///
 /// # Parameters
 /// - `config`: The configuration for the font system.
 /// - `stream`: The stream containing the font data.
@@ -565,17 +981,34 @@ pub fn create_font_system<R: Read + Seek + ?Sized>(
 ) -> Result<TextFontSystemContext, FontThumbnailError> {
     let font_data =
         std::io::Read::bytes(stream).collect::<std::io::Result<Vec<u8>>>()?;
-    // Create a local font database, which only contains the font we loaded
-    let mut font_db = Database::new();
-    // Load the given font file into the font database, getting the ID of the
-    // font to use with the font system
-    let loaded_font: LoadedFont = load_font_data(&mut font_db, font_data)?;
+    // Load the given font file into a local font database, which only
+    // contains the font we loaded
+    let parsed_font = ParsedFont::new(font_data)?;
+    create_font_system_from_parsed(config, &parsed_font)
+}
 
-    // And build a font system from this local database
+/// Create a font system and a buffer, as [`create_font_system`] does, for a
+/// font which has already been parsed.
+pub(crate) fn create_font_system_from_parsed(
+    config: &FontSystemConfig,
+    parsed_font: &ParsedFont,
+) -> Result<TextFontSystemContext, FontThumbnailError> {
+    // Only variable fonts have axes to match the variation hints against
+    let axes = if config.variation_hints.is_empty() {
+        Vec::new()
+    } else {
+        SfntFont::from_reader(&mut Cursor::new(parsed_font.data.as_slice()))
+            .ok()
+            .and_then(|font| font.variation_axes())
+            .unwrap_or_default()
+    };
+    let loaded_font = &parsed_font.loaded_font;
+
+    // And build a font system from the font's local database
     let mut font_system =
         cosmic_text::FontSystem::new_with_locale_and_db_and_fallback(
             config.default_locale.to_string(),
-            font_db.clone(),
+            parsed_font.database.clone(),
             NoFallback::default(),
         );
     // Get reference to the font from the font system
@@ -586,51 +1019,274 @@ pub fn create_font_system<R: Read + Seek + ?Sized>(
     // of the slant later
     let angle = f.rustybuzz().italic_angle();
     let font_info = FontNameInfo::from(f.clone());
-    let full_name = font_info
-        .full_name
-        .ok_or(FontThumbnailError::NoFullNameFound)?;
+    let mut text = config.text_provider.text_for(&font_info);
+    if text.is_empty() {
+        return Err(FontThumbnailError::NoFullNameFound);
+    }
+    // Fall back to a placeholder if the font cannot render any of the text
+    let renderable = text.chars().any(|c| {
+        !c.is_whitespace()
+            && !c.is_control()
+            && f.rustybuzz().glyph_index(c).is_some()
+    });
+    if !renderable && config.on_unrenderable == UnrenderablePolicy::Placeholder
+    {
+        tracing::warn!(
+            text,
+            "The font has no glyphs for the text; rendering a placeholder"
+        );
+        if let Some(postscript_name) =
+            font_info.postscript_name.filter(|name| !name.is_empty())
+        {
+            text = postscript_name;
+        }
+        let mut database = parsed_font.database.clone();
+        database.load_system_fonts();
+        font_system = cosmic_text::FontSystem::new_with_locale_and_db(
+            config.default_locale.to_string(),
+            database,
+        );
+    }
 
     // Create a swash cache for the font system, to cache rendering
     let swash_cache = SwashCache::new();
 
-    let ascender = f.rustybuzz().ascender() as i32;
-    let descender = f.rustybuzz().descender() as i32;
-    let max_height: f32 =
-        (ascender - descender) as f32 / f.rustybuzz().units_per_em() as f32;
+    let units_per_em = f.rustybuzz().units_per_em() as f32;
+    let ascender = f.rustybuzz().ascender() as f32 / units_per_em;
+    let descender = f.rustybuzz().descender() as f32 / units_per_em;
+    let max_height = config.line_height_basis.em_height(f.rustybuzz());
 
     // Find a buffer that fits the width
-    let buffer = get_buffer_with_pt_size_fits_width(
-        &full_name,
+    let attrs = apply_variation_hints(
         loaded_font.attrs.clone(),
+        &config.variation_hints,
+        &axes,
+    );
+    let mut buffer = get_buffer_with_pt_size_fits_width(
+        &text,
+        attrs,
         &mut font_system,
         config,
         |x| (max_height * config.line_height_factor * x).ceil(),
     )?;
 
+    // Widen the buffer by the overhang of the slant at the top of the
+    // ascender, which is not included in the advance widths of the glyphs
+    let slant_margin = match angle {
+        Some(angle) if config.italic_compensation => {
+            let font_size = buffer.metrics().font_size;
+            (ascender * font_size * angle.to_radians().tan()).abs()
+        }
+        _ => 0.0,
+    };
+    if slant_margin > 0.0 {
+        let (width, height) = buffer.size();
+        buffer.set_size(
+            &mut font_system,
+            width.map(|width| width + slant_margin),
+            height,
+        );
+    }
+
     Ok(TextFontSystemContext {
         font_system,
         swash_cache,
         text_buffer: buffer,
         angle,
+        ascender,
+        descender,
+        slant_margin,
     })
 }
 
-/// If the string is longer than 3 characters, it will replace the last 3
-/// characters with an ellipsis ("..."). Otherwise, it will return the
-/// original string.
+/// For a given stream of font data, measure the width and height of the given
+/// text rendered in the font at the given point size.
+///
+/// # Remarks
+/// The text is laid out without any width constraint, so it is only wrapped
+/// at explicit line breaks. The height is the number of lines multiplied by
+/// the line height, which is derived from the font metrics of the configured
+/// line height basis and the configured line height factor, as it is when
+/// generating thumbnails. Only the locale, line height basis and factor, and
+/// variation hints of the `config` are used.
+///
+/// # Returns
+/// A tuple containing the width and height of the laid out text.
+pub fn measure_text_with_font<R: Read + Seek + ?Sized>(
+    config: &FontSystemConfig,
+    stream: &mut R,
+    text: &str,
+    point_size: f32,
+) -> Result<(f32, f32), FontThumbnailError> {
+    let font_data =
+        std::io::Read::bytes(stream).collect::<std::io::Result<Vec<u8>>>()?;
+    let parsed_font = ParsedFont::new(font_data)?;
+    let size =
+        measure_text_with_parsed(config, &parsed_font, text, point_size)?;
+    Ok((size.w, size.h))
+}
+
+/// Measure the text, as [`measure_text_with_font`] does, for a font which has
+/// already been parsed.
+fn measure_text_with_parsed(
+    config: &FontSystemConfig,
+    parsed_font: &ParsedFont,
+    text: &str,
+    point_size: f32,
+) -> Result<Size, FontThumbnailError> {
+    if !point_size.is_finite() || point_size <= 0.0 {
+        return Err(FontThumbnailError::InvalidPointSize(point_size));
+    }
+    let axes = if config.variation_hints.is_empty() {
+        Vec::new()
+    } else {
+        SfntFont::from_reader(&mut Cursor::new(parsed_font.data.as_slice()))
+            .ok()
+            .and_then(|font| font.variation_axes())
+            .unwrap_or_default()
+    };
+    let loaded_font = &parsed_font.loaded_font;
+    let mut font_system =
+        cosmic_text::FontSystem::new_with_locale_and_db_and_fallback(
+            config.default_locale.to_string(),
+            parsed_font.database.clone(),
+            NoFallback::default(),
+        );
+    let f = font_system
+        .get_font(loaded_font.id)
+        .ok_or(FontThumbnailError::NoFontFound)?;
+    let line_height = (config.line_height_basis.em_height(f.rustybuzz())
+        * config.line_height_factor
+        * point_size)
+        .ceil();
+
+    let attrs = apply_variation_hints(
+        loaded_font.attrs.clone(),
+        &config.variation_hints,
+        &axes,
+    );
+    let mut buffer =
+        Buffer::new(&mut font_system, Metrics::new(point_size, line_height));
+    let mut borrowed_buffer = buffer.borrow_with(&mut font_system);
+    borrowed_buffer.set_size(None, None);
+    borrowed_buffer.set_text(text, &attrs, cosmic_text::Shaping::Advanced);
+    borrowed_buffer.shape_until_scroll(true);
+    Ok(layout_size(&mut borrowed_buffer))
+}
+
+/// Applies the given variation hints to the attributes used to match the
+/// font's face, skipping any axes which are not among the font's axes.
+fn apply_variation_hints<'a>(
+    mut attrs: Attrs<'a>,
+    hints: &[(FontTag, f32)],
+    axes: &[FvarAxis],
+) -> Attrs<'a> {
+    for (tag, value) in hints {
+        let Some(axis) = axes.iter().find(|axis| axis.axisTag == *tag) else {
+            tracing::warn!("Ignoring variation hint for unknown axis {tag}");
+            continue;
+        };
+        let value = value.clamp(axis.minValue, axis.maxValue);
+        match &tag.data {
+            b"wght" => {
+                attrs = attrs.weight(cosmic_text::Weight(value.round() as u16));
+            }
+            _ => tracing::debug!(
+                "Variation hint for axis {tag} cannot be used to match the face"
+            ),
+        }
+    }
+    attrs
+}
+
+/// The number of characters removed from the visual end of the text when it
+/// is clipped.
+const CLIPPED_CHAR_COUNT: usize = 3;
+
+/// If the string is longer than 3 characters, it will replace the 3
+/// characters at the visual end of the text with an ellipsis ("...").
+/// Otherwise, it will return the original string.
+///
+/// # Remarks
+/// The visual end of the text depends on its base direction; for
+/// right-to-left text it is the left most edge of the rendered line. The
+/// ellipsis is appended logically, which places it at the visual end since
+/// trailing neutral characters take the direction of the paragraph.
 fn clip_text_to_ellipsis(text: &str) -> String {
-    let char_count = text.chars().count();
-    // If the text is longer than 3 characters, replace the last 3 with ellipsis
-    if char_count > 3 {
-        format!(
-            "{}...",
-            text.chars().take(char_count - 3).collect::<String>()
-        )
+    if text.chars().count() > CLIPPED_CHAR_COUNT {
+        format!("{}...", remove_visual_end(text, CLIPPED_CHAR_COUNT))
     } else {
         text.to_string()
     }
 }
 
+/// Removes `count` characters from the visual end of the text, following the
+/// Unicode Bidirectional Algorithm to find which characters are rendered
+/// last.
+///
+/// # Remarks
+/// For text with a single direction this is the same as removing the last
+/// `count` characters. For mixed text, the last visual run may be in the
+/// opposite direction of the paragraph, in which case its visual end is its
+/// logical start.
+fn remove_visual_end(text: &str, count: usize) -> String {
+    let mut text = text.to_string();
+    for _ in 0..count {
+        let Some(range) = visual_end_char_range(&text) else {
+            break;
+        };
+        text.replace_range(range, "");
+    }
+    text
+}
+
+/// Gets the byte range of the character rendered at the visual end of the
+/// last paragraph of the text.
+fn visual_end_char_range(text: &str) -> Option<std::ops::Range<usize>> {
+    let bidi_info = BidiInfo::new(text, None);
+    let paragraph = bidi_info.paragraphs.last()?;
+    let (levels, runs) =
+        bidi_info.visual_runs(paragraph, paragraph.range.clone());
+    // The visual end is the right most run for left-to-right paragraphs and
+    // the left most run for right-to-left paragraphs
+    let run = if paragraph.level.is_rtl() {
+        runs.first()?
+    } else {
+        runs.last()?
+    };
+    let run_text = &text[run.clone()];
+    // A run in the same direction as the paragraph ends visually where it
+    // ends logically; otherwise it ends visually where it starts logically
+    if levels[run.start].is_rtl() == paragraph.level.is_rtl() {
+        let (offset, ch) = run_text.char_indices().next_back()?;
+        let start = run.start + offset;
+        Some(start..start + ch.len_utf8())
+    } else {
+        let ch = run_text.chars().next()?;
+        Some(run.start..run.start + ch.len_utf8())
+    }
+}
+
+/// Clips the text which did not fit at the minimum point size, according to
+/// the given clip behavior.
+fn clip_text(
+    text: &str,
+    behavior: ClipBehavior,
+) -> Result<String, FontThumbnailError> {
+    match behavior {
+        ClipBehavior::Ellipsis => Ok(clip_text_to_ellipsis(text)),
+        ClipBehavior::Error => Err(FontThumbnailError::TextDoesNotFit),
+        ClipBehavior::TruncateSilently => {
+            // Drop the same characters the ellipsis would have replaced
+            if text.chars().count() > CLIPPED_CHAR_COUNT {
+                Ok(remove_visual_end(text, CLIPPED_CHAR_COUNT))
+            } else {
+                Ok(text.to_string())
+            }
+        }
+    }
+}
+
 /// Finds a buffer that fits the given width, using the configured search
 /// strategy to determine the font size.
 ///
@@ -696,13 +1352,12 @@ fn get_buffer_with_linear_search<T: Fn(f32) -> f32>(
     }
     // At this point we have reached our minimum size, so setup to use it
     // which will result in text clipping, but that is fine
+    let text = clip_text(text, config.clip_behavior)?;
     font_size = linear_search_context.minimum_point_size;
     line_height = line_height_fn(font_size);
     borrowed_buffer.set_size(Some(width), Some(line_height));
     borrowed_buffer.set_metrics(Metrics::new(font_size, line_height));
     borrowed_buffer.shape_until_scroll(true);
-    // get the text replacing the last 3 characters with ellipsis
-    let text = clip_text_to_ellipsis(text);
     borrowed_buffer.set_text(&text, &attrs, cosmic_text::Shaping::Advanced);
     let size = measure_text(&text, &attrs, &mut borrowed_buffer)?;
     // We still run the chance of an invalid size returned, so take that into
@@ -749,12 +1404,22 @@ fn get_buffer_with_binary_search<T: Fn(f32) -> f32>(
     // Keep up with what was the best size
     let mut best_size: Option<(f32, Buffer)> = None;
 
-    const EPSILON: f32 = 1.0; // A small value to avoid infinite loop
-
-    while high - low > EPSILON {
+    // The precision, which also keeps the search from looping indefinitely
+    let epsilon = context.effective_epsilon();
+
+    let mut iterations = 0;
+    while high - low > epsilon {
+        // Bound the search, using the best size found so far
+        if iterations == context.max_iterations {
+            tracing::trace!(
+                "Stopping binary search after {iterations} iterations in range [{low}, {high}]"
+            );
+            break;
+        }
+        iterations += 1;
         // Calculate the midpoint of the current range, rounding to the nearest
-        // integer to avoid floating point precision issues
-        let mid = ((low + high) / 2.0).round();
+        // multiple of the precision to avoid floating point precision issues
+        let mid = ((low + high) / 2.0 / epsilon).round() * epsilon;
         let line_height: f32 = line_height_fn(mid);
         // Make sure we use a height that is large enough to account for
         // line wrapping
@@ -808,7 +1473,8 @@ fn get_buffer_with_binary_search<T: Fn(f32) -> f32>(
         Ok(buffer)
     } else {
         // Otherwise, we did not find a size that fits. So we will use the
-        // minimum font size and use the text with ellipsis
+        // minimum font size and use the clipped text
+        let text = clip_text(text, config.clip_behavior)?;
         let final_font_size = context.minimum_point_size;
         let line_height: f32 = line_height_fn(final_font_size);
         let height = line_height;
@@ -820,8 +1486,6 @@ fn get_buffer_with_binary_search<T: Fn(f32) -> f32>(
         borrowed_buffer.set_size(Some(width), Some(height));
         borrowed_buffer.set_metrics(Metrics::new(final_font_size, line_height));
         borrowed_buffer.set_wrap(cosmic_text::Wrap::Glyph);
-        // get the text replacing the last 3 characters with ellipsis
-        let text = clip_text_to_ellipsis(text);
         borrowed_buffer.set_text(&text, &attrs, cosmic_text::Shaping::Advanced);
         borrowed_buffer.shape_until_scroll(true);
         let size = measure_text(&text, &attrs, &mut borrowed_buffer)?;
@@ -951,6 +1615,11 @@ fn measure_text_in_buffer(
     if buffer_width < 0.0 || buffer_height < 0.0 {
         return Err(FontThumbnailError::InvalidBufferSize);
     }
+    Ok(layout_size(buffer))
+}
+
+/// Get the size of the bounding box of the text laid out in the buffer
+fn layout_size(buffer: &mut BorrowedWithFontSystem<Buffer>) -> Size {
     // Find the maximum width of the layout lines and keep track of the total
     // number of lines
     let (width, total_lines) = buffer
@@ -958,10 +1627,10 @@ fn measure_text_in_buffer(
         .fold((0.0, 0usize), |(width, total_lines), run| {
             (run.line_w.max(width), total_lines + 1)
         });
-    Ok(Size {
+    Size {
         w: width,
         h: total_lines as f32 * buffer.metrics().line_height,
-    })
+    }
 }
 
 #[cfg(test)]
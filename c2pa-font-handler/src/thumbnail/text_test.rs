@@ -14,23 +14,28 @@
 
 //! Tests for the text portion of font thumbnails.
 
-use std::io::Cursor;
+use std::{io::Cursor, sync::Arc};
 
 use cosmic_text::{fontdb::Database, Buffer, Fallback, FontSystem, Metrics};
 
 use super::{
-    create_font_system, measure_text, measure_text_in_buffer, NoFallback,
+    apply_variation_hints, create_font_system, measure_text,
+    measure_text_in_buffer, measure_text_with_font, NoFallback,
 };
 use crate::{
     mime_type::FontMimeTypes,
+    sfnt::table::FvarAxis,
+    tag::FontTag,
     thumbnail::{
         error::FontThumbnailError,
         text::{
-            clip_text_to_ellipsis, load_font_data, FontNameInfo,
-            FontSizeSearchStrategy, FontSystemConfig, LoadedFont,
+            clip_text, clip_text_to_ellipsis, load_font_data, FontNameInfo,
+            FontSizeSearchStrategy, FontSystemConfig, FullNameTextProvider,
+            LineHeightBasis, LoadedFont, SampleTextProvider, TextProvider,
+            UnrenderablePolicy,
         },
-        BinarySearchContext, CosmicTextThumbnailGenerator, LinearSearchContext,
-        ThumbnailGenerator,
+        BinarySearchContext, ClipBehavior, CosmicTextThumbnailGenerator,
+        FontCache, LinearSearchContext, ThumbnailGenerator,
     },
 };
 
@@ -48,6 +53,7 @@ fn test_font_name_info_conversion() {
     let font = font_system.get_font(font_id).unwrap();
 
     let font_name_info: FontNameInfo = FontNameInfo::from(font);
+    assert_eq!(font_name_info.family_name, Some("AnEmptyFont".to_string()));
     assert_eq!(
         font_name_info.full_name,
         Some("AnEmptyFont Regular".to_string())
@@ -98,6 +104,7 @@ fn test_new_font_system_config() {
         config.font_size_search_strategy,
         FontSizeSearchStrategy::Fixed(12.0),
     ));
+    assert_eq!(config.clip_behavior, ClipBehavior::Ellipsis);
 }
 
 /// Tests the creation of a font system with the default configuration.
@@ -110,6 +117,81 @@ fn test_create_font_system_default_config() {
     assert!(result.is_ok(), "Expected successful font system creation");
 }
 
+#[test]
+fn test_text_providers() {
+    let font_info = FontNameInfo {
+        family_name: Some("Family".to_string()),
+        full_name: Some("Family Regular".to_string()),
+        sample_text: Some("Sample".to_string()),
+        postscript_name: Some("Family-Regular".to_string()),
+    };
+    assert_eq!(FullNameTextProvider.text_for(&font_info), "Family Regular");
+    assert_eq!(SampleTextProvider.text_for(&font_info), "Sample");
+    // Without sample text, the full name is used instead
+    let font_info = FontNameInfo {
+        sample_text: None,
+        ..font_info
+    };
+    assert_eq!(SampleTextProvider.text_for(&font_info), "Family Regular");
+    assert_eq!(FullNameTextProvider.text_for(&FontNameInfo::default()), "");
+}
+
+/// Tests the creation of a font system rendering the text from a custom
+/// provider.
+#[test]
+fn test_create_font_system_with_text_provider() {
+    let font_data = include_bytes!("../../../.devtools/font.otf");
+    let text_with = |config: FontSystemConfig| {
+        let context =
+            create_font_system(&config, &mut Cursor::new(font_data)).unwrap();
+        context.text_buffer.lines[0].text().to_string()
+    };
+    assert_eq!(
+        text_with(FontSystemConfig::default()),
+        "AnEmptyFont Regular"
+    );
+    let config = FontSystemConfig::builder()
+        .text_provider(|font_info: &FontNameInfo| {
+            format!("{} Sans", font_info.family_name.as_deref().unwrap_or("?"))
+        })
+        .build();
+    assert_eq!(text_with(config), "AnEmptyFont Sans");
+
+    // There must be some text to render
+    let config = FontSystemConfig::builder()
+        .text_provider(|_: &FontNameInfo| String::new())
+        .build();
+    let result = create_font_system(&config, &mut Cursor::new(font_data));
+    assert!(matches!(result, Err(FontThumbnailError::NoFullNameFound)));
+}
+
+/// Tests that a placeholder is rendered for text the font has no glyphs for,
+/// if the configuration asks for one.
+#[test]
+#[tracing_test::traced_test]
+fn test_create_font_system_with_unrenderable_policy() {
+    let font_data = include_bytes!("../../../.devtools/font.otf");
+    let text_with = |text: &'static str, policy| {
+        let config = FontSystemConfig::builder()
+            .text_provider(move |_: &FontNameInfo| text.to_string())
+            .on_unrenderable(policy)
+            .build();
+        let context =
+            create_font_system(&config, &mut Cursor::new(font_data)).unwrap();
+        context.text_buffer.lines[0].text().to_string()
+    };
+    // The test font only has glyphs for 'A' and the space
+    assert_eq!(text_with("zz", UnrenderablePolicy::Error), "zz");
+    assert!(!logs_contain("rendering a placeholder"));
+    assert_eq!(
+        text_with("zz", UnrenderablePolicy::Placeholder),
+        "AnEmptyFont-Regular"
+    );
+    assert!(logs_contain("rendering a placeholder"));
+    // Text with any glyph in the font is rendered as is
+    assert_eq!(text_with("zAz", UnrenderablePolicy::Placeholder), "zAz");
+}
+
 /// Tests the creation of a font system with a default binary search strategy.
 #[test]
 fn test_create_font_system_with_default_binary() {
@@ -126,6 +208,67 @@ fn test_create_font_system_with_default_binary() {
     assert!(result.is_ok(), "Expected successful font system creation");
 }
 
+/// Tests that the binary search strategy settles for the best size found
+/// when it runs out of iterations.
+#[test]
+#[tracing_test::traced_test]
+fn test_create_font_system_with_binary_iteration_cap() {
+    let font_size_with = |max_iterations| {
+        let config = FontSystemConfig::new(
+            "en-US",
+            1.075,
+            400,
+            0.1,
+            FontSizeSearchStrategy::Binary(
+                BinarySearchContext::default()
+                    .with_max_iterations(max_iterations),
+            ),
+        );
+        let font_data = include_bytes!("../../../.devtools/font.otf");
+        let context =
+            create_font_system(&config, &mut Cursor::new(font_data)).unwrap();
+        context.text_buffer.metrics().font_size
+    };
+    let uncapped = font_size_with(BinarySearchContext::DEFAULT_MAX_ITERATIONS);
+    // Without any iterations, no size was found, so the minimum is used
+    assert_eq!(font_size_with(0), 6.0);
+    assert!(logs_contain("Stopping binary search after 0 iterations"));
+    // A few iterations only get part of the way to the best size
+    let capped = font_size_with(3);
+    assert!(capped <= uncapped, "Expected {capped} <= {uncapped}");
+}
+
+/// Tests that a finer binary search precision finds a size at least as large.
+#[test]
+fn test_create_font_system_with_binary_epsilon() {
+    let font_size_with = |epsilon| {
+        let config = FontSystemConfig::new(
+            "en-US",
+            1.075,
+            400,
+            0.1,
+            FontSizeSearchStrategy::Binary(
+                BinarySearchContext::default().with_epsilon(epsilon),
+            ),
+        );
+        let font_data = include_bytes!("../../../.devtools/font.otf");
+        let context =
+            create_font_system(&config, &mut Cursor::new(font_data)).unwrap();
+        context.text_buffer.metrics().font_size
+    };
+    let default = font_size_with(BinarySearchContext::DEFAULT_EPSILON);
+    let fine = font_size_with(0.5);
+    assert!(fine >= default, "Expected {fine} >= {default}");
+    // The sizes tried are multiples of the precision
+    assert_eq!(fine % 0.5, 0.0);
+    let coarse = font_size_with(8.0);
+    assert!(coarse <= default, "Expected {coarse} <= {default}");
+    assert_eq!(coarse % 8.0, 0.0);
+    // A precision which is not positive falls back to the default
+    assert_eq!(font_size_with(0.0), default);
+    assert_eq!(font_size_with(-1.0), default);
+}
+
 /// Tests the creation of a font system with a default linear search strategy.
 #[test]
 fn test_create_font_system_with_default_linear() {
@@ -195,6 +338,39 @@ fn test_create_font_system_with_clipping_with_binary() {
     assert_eq!("AnEmptyFont Regu...", text_buffer.lines[0].text());
 }
 
+/// Test the clip behavior is honored when the text does not fit at the
+/// minimum point size.
+#[test]
+fn test_create_font_system_with_clip_behavior() {
+    let font_data = include_bytes!("../../../.devtools/font.otf");
+    for strategy in [
+        FontSizeSearchStrategy::binary(80.0, 50.0, 70.0),
+        FontSizeSearchStrategy::linear(100.0, 8.0, 80.0),
+    ] {
+        let config = FontSystemConfig::builder()
+            .maximum_width(100)
+            .search_strategy(strategy.clone())
+            .clip_behavior(ClipBehavior::Error)
+            .build();
+        let result = create_font_system(&config, &mut Cursor::new(font_data));
+        assert!(
+            matches!(result, Err(FontThumbnailError::TextDoesNotFit)),
+            "Expected TextDoesNotFit for {strategy:?}; got: {result:?}"
+        );
+
+        let config = FontSystemConfig::builder()
+            .maximum_width(100)
+            .search_strategy(strategy)
+            .clip_behavior(ClipBehavior::TruncateSilently)
+            .build();
+        let mut context =
+            create_font_system(&config, &mut Cursor::new(font_data)).unwrap();
+        let (_font_system, _swash_cache, text_buffer) =
+            context.mut_cosmic_text_parts();
+        assert_eq!("AnEmptyFont Regu", text_buffer.lines[0].text());
+    }
+}
+
 /// Test the error case when attempting to create a font system
 /// with a font size search strategy that fails to find an appropriate size.
 #[test]
@@ -387,6 +563,28 @@ fn test_new_cosmic_text_thumbnail_generator() {
     );
 }
 
+#[test]
+fn test_cosmic_text_thumbnail_generator_with_font_cache() {
+    let mut renderer = crate::thumbnail::MockRenderer::new();
+    renderer.expect_render_thumbnail().times(2).returning(|_| {
+        Ok(crate::thumbnail::Thumbnail::new(
+            b"<svg></svg>".to_vec(),
+            "image/svg+xml".to_string(),
+        ))
+    });
+    let font_cache = Arc::new(FontCache::default());
+    let generator = CosmicTextThumbnailGenerator::new(Box::new(renderer))
+        .with_font_cache(font_cache.clone());
+    for _ in 0..2 {
+        let mut font_data =
+            Cursor::new(include_bytes!("../../../.devtools/font.otf"));
+        let result =
+            generator.create_thumbnail_from_stream(&mut font_data, None);
+        assert!(result.is_ok(), "Expected successful thumbnail creation");
+    }
+    assert_eq!(1, font_cache.len(), "Expected the font to be parsed once");
+}
+
 #[test]
 #[tracing_test::traced_test]
 fn test_new_cosmic_text_thumbnail_generator_with_unsupported_mime_type() {
@@ -416,6 +614,152 @@ fn test_new_cosmic_text_thumbnail_generator_with_unsupported_mime_type() {
     ), "Expected log message about unsupported MIME type");
 }
 
+#[test]
+fn test_create_thumbnails_multi() {
+    let mut renderer = crate::thumbnail::MockRenderer::new();
+    // Record the font size and buffer width used for each render
+    renderer
+        .expect_render_thumbnail()
+        .times(3)
+        .returning(|context| {
+            let metrics = context.text_buffer.metrics();
+            let (width, _) = context.text_buffer.size();
+            Ok(crate::thumbnail::Thumbnail::new(
+                format!("{}:{}", metrics.font_size, width.unwrap_or_default())
+                    .into_bytes(),
+                "text/plain".to_string(),
+            ))
+        });
+    let generator = CosmicTextThumbnailGenerator::new(Box::new(renderer));
+    let mut font_data =
+        Cursor::new(include_bytes!("../../../.devtools/font.otf"));
+    let result = generator.create_thumbnails_multi(
+        &mut font_data,
+        None,
+        &[1.0, 2.0, 0.5],
+    );
+    assert!(result.is_ok(), "Expected successful thumbnail creation");
+    let sizes = result
+        .unwrap()
+        .iter()
+        .map(|thumbnail| {
+            let data = std::str::from_utf8(thumbnail.data()).unwrap();
+            let (font_size, width) = data.split_once(':').unwrap();
+            (
+                font_size.parse::<f32>().unwrap(),
+                width.parse::<f32>().unwrap(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(3, sizes.len());
+    let (base_size, base_width) = sizes[0];
+    assert!(base_width > 0.0);
+    assert_eq!(base_size * 2.0, sizes[1].0);
+    assert_eq!(base_size * 0.5, sizes[2].0);
+    // The buffer is resized to fit the scaled text
+    assert!(sizes[1].1 > base_width);
+    assert!(sizes[2].1 < base_width);
+}
+
+#[test]
+fn test_measure_text_with_font() {
+    let config = FontSystemConfig::default();
+    let font_data = include_bytes!("../../../.devtools/font.otf");
+    let measure = |text: &str, point_size: f32| {
+        measure_text_with_font(
+            &config,
+            &mut Cursor::new(font_data),
+            text,
+            point_size,
+        )
+    };
+    let (width, height) = measure("AnEmptyFont", 20.0).unwrap();
+    assert!(width > 0.0);
+    assert!(height >= 20.0);
+
+    // The size scales with the point size
+    let (double_width, double_height) = measure("AnEmptyFont", 40.0).unwrap();
+    assert!((double_width - width * 2.0).abs() < 1.0);
+    assert!(double_height > height);
+
+    // Long text isn't wrapped, only explicit line breaks are honored
+    let (long_width, long_height) =
+        measure(&"AnEmptyFont".repeat(20), 20.0).unwrap();
+    assert!(long_width > width * 19.0);
+    assert_eq!(long_height, height);
+    let (two_line_width, two_line_height) =
+        measure("AnEmptyFont\nAnEmptyFont", 20.0).unwrap();
+    assert_eq!(two_line_width, width);
+    assert_eq!(two_line_height, height * 2.0);
+
+    for point_size in [0.0, -1.0, f32::NAN, f32::INFINITY] {
+        let result = measure("AnEmptyFont", point_size);
+        assert!(
+            matches!(result, Err(FontThumbnailError::InvalidPointSize(_))),
+            "Expected InvalidPointSize error for {point_size}; found: {result:?}"
+        );
+    }
+}
+
+#[test]
+fn test_measure_text_with_line_height_basis() {
+    let font_data = include_bytes!("../../../.devtools/font.otf");
+    let measure_height = |basis: LineHeightBasis| {
+        let config = FontSystemConfig::builder()
+            .line_height_factor(1.0)
+            .line_height_basis(basis)
+            .build();
+        measure_text_with_font(
+            &config,
+            &mut Cursor::new(font_data),
+            "AnEmptyFont",
+            100.0,
+        )
+        .unwrap()
+        .1
+    };
+    let full_height = measure_height(LineHeightBasis::AscenderDescender);
+    let cap_height = measure_height(LineHeightBasis::CapHeight);
+    let em_height = measure_height(LineHeightBasis::EmSquare);
+    // The em square is the point size itself
+    assert_eq!(em_height, 100.0);
+    // The cap height crops off the descender (and any space above the caps)
+    assert!(cap_height > 0.0);
+    assert!(cap_height < full_height);
+    // Which is the default basis
+    assert_eq!(
+        measure_text_with_font(
+            &FontSystemConfig::builder().line_height_factor(1.0).build(),
+            &mut Cursor::new(font_data),
+            "AnEmptyFont",
+            100.0,
+        )
+        .unwrap()
+        .1,
+        full_height
+    );
+}
+
+#[test]
+fn test_create_thumbnails_multi_with_invalid_scale() {
+    let mut renderer = crate::thumbnail::MockRenderer::new();
+    renderer.expect_render_thumbnail().never();
+    let generator = CosmicTextThumbnailGenerator::new(Box::new(renderer));
+    let mut font_data =
+        Cursor::new(include_bytes!("../../../.devtools/font.otf"));
+    for scale in [0.0, -1.0, f32::NAN, f32::INFINITY] {
+        let result = generator.create_thumbnails_multi(
+            &mut font_data,
+            None,
+            &[1.0, scale],
+        );
+        assert!(
+            matches!(result, Err(FontThumbnailError::InvalidScale(_))),
+            "Expected InvalidScale error for {scale}; found: {result:?}"
+        );
+    }
+}
+
 #[cfg(feature = "woff")]
 #[test]
 #[tracing_test::traced_test]
@@ -585,6 +929,11 @@ fn test_font_system_config_builder_with_defaults() {
         config.line_height_factor, 1.075,
         "Expected default line height factor to be 1.075"
     );
+    assert_eq!(
+        config.line_height_basis,
+        LineHeightBasis::AscenderDescender,
+        "Expected default line height basis to be the ascender and descender"
+    );
     assert_eq!(
         config.maximum_width, 400,
         "Expected default maximum width to be 400"
@@ -611,10 +960,14 @@ fn test_create_binary_font_size_search_strategy() {
             starting_point_size,
             minimum_point_size,
             maximum_point_size,
+            max_iterations,
+            epsilon,
         })
         if starting_point_size == expected_starting_point_size
            && minimum_point_size == expected_minimum_point_size
            && maximum_point_size ==expected_maximum_point_size
+           && max_iterations == BinarySearchContext::DEFAULT_MAX_ITERATIONS
+           && epsilon == BinarySearchContext::DEFAULT_EPSILON
     ));
 
     let strategy =
@@ -625,7 +978,9 @@ fn test_create_binary_font_size_search_strategy() {
             starting_point_size,
             minimum_point_size,
             maximum_point_size,
-        }) if starting_point_size == 42.0 && minimum_point_size == 6.0 && maximum_point_size == 512.0
+            max_iterations: 32,
+            epsilon,
+        }) if starting_point_size == 42.0 && minimum_point_size == 6.0 && maximum_point_size == 512.0 && epsilon == 1.0
     ));
 }
 
@@ -696,3 +1051,241 @@ fn test_clip_text_with_ellipsis() {
         "Did not expect clipped text to end with ellipsis for short text"
     );
 }
+
+#[test]
+fn test_clip_text_with_ellipsis_right_to_left() {
+    // An Arabic sample, the visual end of the text is the left most edge,
+    // which is also the logical end
+    let text = "خط عربي جميل";
+    let clipped_text = clip_text_to_ellipsis(text);
+    assert_eq!("خط عربي ج...", clipped_text);
+    let truncated_text =
+        clip_text(text, ClipBehavior::TruncateSilently).unwrap();
+    assert_eq!("خط عربي ج", truncated_text);
+
+    // A left-to-right paragraph ending with an Arabic run renders the start
+    // of the Arabic run at the visual end
+    let text = "Font عربي";
+    let clipped_text = clip_text_to_ellipsis(text);
+    assert_eq!("Font ي...", clipped_text);
+
+    // A right-to-left paragraph ending with a Latin run renders the start of
+    // the Latin run at the visual end
+    let text = "خط Font";
+    let clipped_text = clip_text_to_ellipsis(text);
+    assert_eq!("خط t...", clipped_text);
+}
+
+#[test]
+#[tracing_test::traced_test]
+fn test_apply_variation_hints() {
+    let axes = [FvarAxis {
+        axisTag: FontTag::new(*b"wght"),
+        minValue: 100.0,
+        defaultValue: 400.0,
+        maxValue: 900.0,
+        flags: 0,
+        axisNameID: 256,
+    }];
+    let attrs = cosmic_text::Attrs::new();
+    let applied = apply_variation_hints(
+        attrs.clone(),
+        &[(FontTag::new(*b"wght"), 700.0)],
+        &axes,
+    );
+    assert_eq!(applied.weight, cosmic_text::Weight::BOLD);
+    // Values are clamped to the range of the axis
+    let applied = apply_variation_hints(
+        attrs.clone(),
+        &[(FontTag::new(*b"wght"), 1000.0)],
+        &axes,
+    );
+    assert_eq!(applied.weight, cosmic_text::Weight::BLACK);
+    // And unknown axes are ignored
+    let applied = apply_variation_hints(
+        attrs.clone(),
+        &[(FontTag::new(*b"wdth"), 75.0)],
+        &axes,
+    );
+    assert_eq!(applied, attrs);
+    assert!(logs_contain(
+        "Ignoring variation hint for unknown axis wdth"
+    ));
+}
+
+#[test]
+#[tracing_test::traced_test]
+fn test_create_font_system_with_variation_hints_for_static_font() {
+    let config = FontSystemConfig::builder()
+        .variation_hints(vec![(FontTag::new(*b"wght"), 700.0)])
+        .build();
+    assert_eq!(config.variation_hints.len(), 1);
+    let font_data = include_bytes!("../../../.devtools/font.otf");
+    let result = create_font_system(&config, &mut Cursor::new(font_data));
+    assert!(
+        result.is_ok(),
+        "Expected the default instance to be rendered"
+    );
+    assert!(logs_contain(
+        "Ignoring variation hint for unknown axis wght"
+    ));
+}
+
+#[test]
+#[tracing_test::traced_test]
+fn test_create_thumbnail_from_font() {
+    use crate::{
+        c2pa::{C2PASupport, ContentCredentialRecord},
+        sfnt::font::SfntFont,
+        tag::FontTag,
+        Font, FontDataRead, FontHeader,
+    };
+
+    let mut renderer = crate::thumbnail::MockRenderer::new();
+    renderer.expect_render_thumbnail().returning(|_| {
+        Ok(crate::thumbnail::Thumbnail::new(
+            b"<svg></svg>".to_vec(),
+            "image/svg+xml".to_string(),
+        ))
+    });
+    let generator = CosmicTextThumbnailGenerator::new(Box::new(renderer));
+    let font_data = include_bytes!("../../../.devtools/font.otf");
+    let mut font = SfntFont::from_reader(&mut Cursor::new(font_data)).unwrap();
+    // Render the font as it is in memory, with a pending C2PA table
+    let record = ContentCredentialRecord::builder()
+        .with_content_credential(vec![0x00, 0x01, 0x02, 0x03])
+        .build()
+        .unwrap();
+    font.add_c2pa_record(record).unwrap();
+    let thumbnail = generator.create_thumbnail_from_font(&font).unwrap();
+    assert_eq!("image/svg+xml", thumbnail.mime_type());
+    // The MIME type comes from the font, rather than being guessed
+    assert!(!logs_contain("Guessing MIME type for font data"));
+    assert!(logs_contain(
+        "Attempting to generate thumbnail for source data with MIME type: font/otf"
+    ));
+    // And the font itself is unchanged, still pending the added table
+    assert_eq!(font.header().num_tables(), 11);
+    assert!(font.contains_table(&FontTag::C2PA));
+}
+
+/// Creates an oblique version of the test font, by setting the italic angle
+/// in its 'post' table to -12 degrees.
+fn oblique_font_data() -> Vec<u8> {
+    let mut font_data = include_bytes!("../../../.devtools/font.otf").to_vec();
+    // The 'post' table is at offset 1312, and the italic angle (a 16.16
+    // fixed point number) follows its version
+    font_data[1316..1320].copy_from_slice(&(-12_i32 << 16).to_be_bytes());
+    font_data
+}
+
+#[test]
+fn test_create_font_system_with_italic_compensation() {
+    let font_data = oblique_font_data();
+    let mut context = create_font_system(
+        &FontSystemConfig::default(),
+        &mut Cursor::new(&font_data),
+    )
+    .unwrap();
+    assert_eq!(context.angle(), Some(-12.0));
+    let uncompensated_config = FontSystemConfig::builder()
+        .italic_compensation(false)
+        .build();
+    let mut uncompensated_context =
+        create_font_system(&uncompensated_config, &mut Cursor::new(&font_data))
+            .unwrap();
+    assert_eq!(uncompensated_context.slant_margin, 0.0);
+
+    // The text is laid out the same either way
+    let font_size = context.text_buffer.metrics().font_size;
+    assert_eq!(
+        font_size,
+        uncompensated_context.text_buffer.metrics().font_size
+    );
+    let text_width = {
+        let (font_system, _, buffer) =
+            uncompensated_context.mut_cosmic_text_parts();
+        measure_text_in_buffer(&mut buffer.borrow_with(font_system))
+            .unwrap()
+            .w
+    };
+
+    // But the final buffer is wider than the text by the slant overhang
+    let expected_margin =
+        (context.ascender * font_size * 12_f32.to_radians().tan()).abs();
+    assert!(expected_margin > 0.0);
+    assert!((context.slant_margin - expected_margin).abs() < 0.001);
+    let buffer_width = context.text_buffer.size().0.unwrap();
+    assert!((buffer_width - (text_width + expected_margin)).abs() < 0.001);
+    assert_eq!(
+        uncompensated_context.text_buffer.size().0.unwrap(),
+        text_width
+    );
+    // And the text still fits on one line
+    let (font_system, _, buffer) = context.mut_cosmic_text_parts();
+    assert_eq!(buffer.borrow_with(font_system).layout_runs().count(), 1);
+}
+
+#[test]
+fn test_create_thumbnails_multi_with_italic_compensation() {
+    let mut renderer = crate::thumbnail::MockRenderer::new();
+    // Record the font size, slant margin and widths used for each render
+    renderer
+        .expect_render_thumbnail()
+        .times(2)
+        .returning(|context| {
+            let metrics = context.text_buffer.metrics();
+            let slant_margin = context.slant_margin;
+            let (font_system, _, buffer) = context.mut_cosmic_text_parts();
+            let buffer_width = buffer.size().0.unwrap_or_default();
+            let text_width =
+                measure_text_in_buffer(&mut buffer.borrow_with(font_system))
+                    .unwrap()
+                    .w;
+            Ok(crate::thumbnail::Thumbnail::new(
+                format!(
+                    "{}:{slant_margin}:{buffer_width}:{text_width}",
+                    metrics.font_size
+                )
+                .into_bytes(),
+                "text/plain".to_string(),
+            ))
+        });
+    let generator = CosmicTextThumbnailGenerator::new(Box::new(renderer));
+    let font_data = oblique_font_data();
+    let ascender = create_font_system(
+        &FontSystemConfig::default(),
+        &mut Cursor::new(&font_data),
+    )
+    .unwrap()
+    .ascender;
+    let thumbnails = generator
+        .create_thumbnails_multi(
+            &mut Cursor::new(&font_data),
+            None,
+            &[1.0, 2.0],
+        )
+        .unwrap();
+    let renders = thumbnails
+        .iter()
+        .map(|thumbnail| {
+            std::str::from_utf8(thumbnail.data())
+                .unwrap()
+                .split(':')
+                .map(|value| value.parse::<f32>().unwrap())
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    for render in &renders {
+        let (font_size, slant_margin, buffer_width, text_width) =
+            (render[0], render[1], render[2], render[3]);
+        // The margin follows the scaled font size
+        let expected_margin =
+            (ascender * font_size * 12_f32.to_radians().tan()).abs();
+        assert!(expected_margin > 0.0);
+        assert!((slant_margin - expected_margin).abs() < 0.001);
+        // And the buffer is widened by it at every scale
+        assert!((buffer_width - (text_width + slant_margin)).abs() < 0.001);
+    }
+    assert!((renders[1][1] - 2.0 * renders[0][1]).abs() < 0.001);
+}
@@ -14,15 +14,32 @@
 
 //! Various utilities for working with fonts.
 
-use std::{mem::size_of, num::Wrapping};
+use core::{mem::size_of, num::Wrapping};
 
 use byteorder::{BigEndian, ByteOrder};
 
+#[cfg(feature = "std")]
+use crate::error::FontSaveError;
+
 /// Round the given value up to the next multiple of four (4).
+#[cfg(feature = "std")]
 pub fn align_to_four(size: u32) -> u32 {
     (size + 3) & (!3)
 }
 
+/// Advances the running offset past a table of the given length (padded up to
+/// a multiple of four), failing if the new offset no longer fits in the 32-bit
+/// offsets used by the font formats.
+#[cfg(feature = "std")]
+pub(crate) fn advance_offset(
+    offset: u32,
+    length: u32,
+) -> Result<u32, FontSaveError> {
+    let aligned_length = (length as u64 + 3) & !3;
+    u32::try_from(offset as u64 + aligned_length)
+        .map_err(|_| FontSaveError::FontTooLarge)
+}
+
 /// Computes a 32-bit big-endian OpenType-style checksum on the given byte
 /// array, which is presumed to start on a 4-byte boundary.
 ///
@@ -32,7 +49,7 @@ pub fn align_to_four(size: u32) -> u32 {
 ///
 /// # Panics
 /// Panics if the the `bytes` array is not aligned on a 4-byte boundary.
-pub(crate) fn checksum(bytes: &[u8]) -> Wrapping<u32> {
+pub fn checksum(bytes: &[u8]) -> Wrapping<u32> {
     // Cut your pie into 1x4cm pieces to serve
     let words = bytes.chunks_exact(size_of::<u32>());
     // ...and then any remainder...
@@ -75,6 +92,7 @@ pub(crate) fn checksum(bytes: &[u8]) -> Wrapping<u32> {
 /// # Remarks
 /// Note that trailing pad bytes do not affect this checksum - it's not a real
 /// CRC.
+#[cfg(feature = "std")]
 pub(crate) fn checksum_biased(bytes: &[u8], bias: u32) -> Wrapping<u32> {
     let bytes_len = bytes.len();
     match bytes_len {
@@ -117,6 +135,6 @@ pub(crate) fn u32_from_u16_pair(hi: u16, lo: u16) -> Wrapping<u32> {
     Wrapping((hi as u32 * 65536) + lo as u32)
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 #[path = "utils_test.rs"]
 mod tests;
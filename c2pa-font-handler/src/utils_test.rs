@@ -30,6 +30,23 @@ fn test_align_to_four() {
     assert_eq!(aligned_size, 8);
 }
 
+#[test]
+fn test_advance_offset() {
+    assert_eq!(advance_offset(12, 5).unwrap(), 20);
+    assert_eq!(advance_offset(12, 0).unwrap(), 12);
+    assert_eq!(advance_offset(u32::MAX - 7, 4).unwrap(), u32::MAX - 3);
+    // The padding alone pushes it over the limit
+    assert!(matches!(
+        advance_offset(u32::MAX - 3, 1),
+        Err(FontSaveError::FontTooLarge)
+    ));
+    // As does a huge table, which would also overflow when aligned
+    assert!(matches!(
+        advance_offset(12, u32::MAX),
+        Err(FontSaveError::FontTooLarge)
+    ));
+}
+
 #[test]
 fn test_checksum() {
     let data = [0x00, 0x01, 0x02, 0x03];
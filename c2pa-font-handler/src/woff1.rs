@@ -17,4 +17,5 @@
 pub mod directory;
 pub mod font;
 pub mod header;
+pub mod metadata;
 pub mod table;
@@ -147,6 +147,11 @@ impl Woff1Directory {
         self.entries.push(entry);
     }
 
+    /// Returns mutable access to the entries in the directory.
+    pub(crate) fn entries_mut(&mut self) -> &mut [Woff1DirectoryEntry] {
+        &mut self.entries
+    }
+
     /// Sorts the entries in the directory, based on the provided closure.
     pub(crate) fn sort_entries<F, K>(&mut self, f: F)
     where
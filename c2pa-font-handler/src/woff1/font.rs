@@ -18,11 +18,16 @@ use std::{
     collections::{btree_map::Entry, BTreeMap},
     fmt::Display,
     io::{Cursor, Read, Seek, SeekFrom, Write},
+    num::Wrapping,
+    time::{Duration, Instant},
 };
 
+use byteorder::{BigEndian, ReadBytesExt};
+
 use super::{
     directory::{Woff1Directory, Woff1DirectoryEntry},
     header::Woff1Header,
+    metadata::WoffMetadata,
     table::NamedTable,
 };
 use crate::{
@@ -31,11 +36,14 @@ use crate::{
     compression::{CompressingWriter, DecompressingReader},
     data::Data,
     error::FontIoError,
+    metrics::{FontMetrics, FontOperation},
     sfnt::{
-        directory::SfntDirectoryEntry, header::SfntHeader, table::TableC2PA,
+        directory::{SfntDirectory, SfntDirectoryEntry},
+        header::SfntHeader,
+        table::{head::SFNT_EXPECTED_CHECKSUM, TableC2PA},
     },
     tag::FontTag,
-    utils::align_to_four,
+    utils::{advance_offset, align_to_four},
     Font, FontDataChecksum, FontDataExactRead, FontDataRead, FontDataWrite,
     FontDirectory, FontDirectoryEntry, FontHeader, FontTable, FontTableReader,
     MutFontDataWrite,
@@ -48,6 +56,11 @@ pub struct Woff1Font {
     pub(crate) tables: BTreeMap<FontTag, NamedTable>,
     pub(crate) metadata: Option<Data>,
     pub(crate) private_data: Option<Data>,
+    /// The compression statistics of the last write, if they were collected.
+    last_write_stats: Option<CompressionStats>,
+    /// The maximum size a compressed table may decompress to, from the
+    /// options the font was read with.
+    max_decompressed_table_size: u32,
 }
 
 impl Woff1Font {
@@ -89,6 +102,7 @@ impl Woff1Font {
                     return Self::decompress_table_from_stream(
                         &tmp_entry,
                         &mut reader,
+                        self.max_decompressed_table_size,
                     );
                 }
                 NamedTable::Generic(_data) => {
@@ -100,11 +114,21 @@ impl Woff1Font {
     }
 
     /// Read and decompress a table from the WOFF1 font, for the
-    /// given directory entry.
+    /// given directory entry, refusing tables which would decompress to more
+    /// than `max_size` bytes.
     fn decompress_table_from_stream<R: Read + Seek + ?Sized>(
         entry: &Woff1DirectoryEntry,
         reader: &mut R,
+        max_size: u32,
     ) -> Result<NamedTable, FontIoError> {
+        // Check the declared size before allocating anything for it
+        if entry.origLength > max_size {
+            return Err(FontIoError::DecompressedSizeExceeded {
+                tag: entry.tag,
+                size: entry.origLength,
+                limit: max_size,
+            });
+        }
         // Seek to the start of the compressed data
         reader.seek(SeekFrom::Start(entry.offset as u64))?;
 
@@ -131,23 +155,17 @@ impl Woff1Font {
         Ok(table)
     }
 
-    /// Optimizes the table data by compressing it if it is larger than
-    /// the original data. If the compressed data is larger than the
-    /// original data, the original data is returned.
-    fn optimize_table_data<R: Read + Seek + ?Sized>(
-        reader: &mut R,
-        offset: u64,
-        length: u32,
+    /// Optimizes the table data by compressing it if the compressed data is
+    /// smaller than the original data. If the compressed data is larger than
+    /// the original data, the original data is returned without copying it.
+    fn optimize_table_data(
+        uncompressed_data: Vec<u8>,
     ) -> Result<WoffTableData, FontIoError> {
-        // Seek to the position we are to read from
-        reader.seek(SeekFrom::Start(offset))?;
-
-        // Create a buffer to hold the uncompressed data
-        let mut uncompressed_data = vec![0; length as usize];
-        reader.read_exact(&mut uncompressed_data)?;
-
-        // Create a buffer to hold the compressed data
-        let mut compressed_data = Vec::new();
+        let length = uncompressed_data.len() as u32;
+        // Create a buffer to hold the compressed data; it is only used when
+        // it is smaller than the original data, so size it as such to avoid
+        // growing it along the way.
+        let mut compressed_data = Vec::with_capacity(length as usize);
         {
             let mut compressed_writer =
                 CompressingWriter::builder(&mut compressed_data).build();
@@ -178,6 +196,211 @@ impl Woff1Font {
         }
     }
 
+    /// Serializes the given C2PA table and optimizes it for storage, exactly
+    /// as it will be written to the font.
+    fn optimize_c2pa_table(
+        c2pa: &NamedTable,
+    ) -> Result<WoffTableData, FontIoError> {
+        let mut data_to_compress = Vec::with_capacity(c2pa.len() as usize);
+        c2pa.write(&mut data_to_compress)?;
+        Self::optimize_table_data(data_to_compress)
+    }
+
+    /// Compresses the extension metadata, which (unlike the tables) the WOFF
+    /// specification requires to always be compressed.
+    fn compress_metadata(metadata: &Data) -> Result<Data, FontIoError> {
+        let mut compressed_data = Vec::new();
+        let mut compressed_writer =
+            CompressingWriter::builder(&mut compressed_data).build();
+        compressed_writer.write_all(&metadata.data)?;
+        compressed_writer.finish()?;
+        Ok(Data::new(compressed_data))
+    }
+
+    /// Reads the extension metadata described by the header, decompressing it
+    /// and refusing metadata which would decompress to more than `max_size`
+    /// bytes.
+    ///
+    /// # Remarks
+    /// The metadata is always compressed, even when compressing it does not
+    /// shrink it; only metadata which does not start with a zlib header, and
+    /// so cannot be compressed, is read as is (as some producers store it).
+    fn read_metadata<R: Read + Seek + ?Sized>(
+        header: &Woff1Header,
+        reader: &mut R,
+        max_size: u32,
+    ) -> Result<Data, FontIoError> {
+        let (offset, length, original_length) =
+            (header.metaOffset, header.metaLength, header.metaOrigLength);
+        if length == original_length
+            && !Self::has_zlib_header(reader, offset, length)?
+        {
+            return Data::from_reader_exact(
+                reader,
+                offset as u64,
+                length as usize,
+            );
+        }
+        // Check the declared size before allocating anything for it
+        if original_length > max_size {
+            return Err(FontIoError::DecompressedSizeExceeded {
+                tag: WOFF_METADATA_CHUNK_NAME,
+                size: original_length,
+                limit: max_size,
+            });
+        }
+        reader.seek(SeekFrom::Start(offset as u64))?;
+        let mut stream_slice = reader.take(length as u64);
+        let mut decompress_reader =
+            DecompressingReader::builder(&mut stream_slice).build();
+        let mut metadata = vec![0; original_length as usize];
+        decompress_reader.read_exact(&mut metadata)?;
+        Ok(Data::new(metadata))
+    }
+
+    /// Checks whether the `length` bytes at `offset` start with a zlib header
+    /// (see RFC 1950), i.e., one for the deflate method with a valid check.
+    fn has_zlib_header<R: Read + Seek + ?Sized>(
+        reader: &mut R,
+        offset: u32,
+        length: u32,
+    ) -> Result<bool, FontIoError> {
+        if length < 2 {
+            return Ok(false);
+        }
+        reader.seek(SeekFrom::Start(offset as u64))?;
+        let header = reader.read_u16::<BigEndian>()?;
+        Ok((header >> 8) & 0x0f == 8 && header % 31 == 0)
+    }
+
+    /// Parses the font's extension metadata, if it has any.
+    ///
+    /// # Errors
+    /// Returns an error if the metadata isn't UTF-8 or isn't valid WOFF
+    /// metadata XML.
+    pub fn extension_metadata(
+        &self,
+    ) -> Result<Option<WoffMetadata>, FontIoError> {
+        self.metadata
+            .as_ref()
+            .map(|metadata| {
+                let xml = String::from_utf8(metadata.data.to_vec())?;
+                WoffMetadata::from_xml(&xml)
+            })
+            .transpose()
+    }
+
+    /// Computes the size, in bytes, of the font as it would be written by
+    /// `write`, without actually writing it.
+    ///
+    /// # Remarks
+    /// The C2PA table is compressed (when doing so saves space), and the
+    /// extension metadata is always compressed, as part of writing the font,
+    /// so they are compressed here as well in order to report the exact size.
+    ///
+    /// # Errors
+    /// Returns an error if the C2PA table or metadata fails to be compressed.
+    pub fn computed_size(&self) -> Result<u64, FontIoError> {
+        self.computed_size_with_options(&WoffWriteOptions::default())
+    }
+
+    /// Computes the size, in bytes, of the font as it would be written by
+    /// `write_with_options` with the given options, without actually writing
+    /// it.
+    ///
+    /// # Remarks
+    /// As for [`Woff1Font::computed_size`], the C2PA table and metadata are
+    /// compressed in order to report the exact size; with
+    /// [`WoffWriteOptions::with_force_uncompressed`], every table is sized
+    /// uncompressed instead.
+    ///
+    /// # Errors
+    /// Returns an error if the C2PA table or metadata fails to be compressed.
+    pub fn computed_size_with_options(
+        &self,
+        options: &WoffWriteOptions,
+    ) -> Result<u64, FontIoError> {
+        let mut size = Woff1Header::SIZE as u64
+            + Woff1DirectoryEntry::SIZE as u64 * self.tables.len() as u64;
+        size += self
+            .directory
+            .entries()
+            .iter()
+            .filter(|entry| !entry.tag.is_c2pa())
+            .filter_map(|entry| {
+                let table = self.tables.get(&entry.tag)?;
+                // Compressed tables are decompressed when forced uncompressed
+                Some(
+                    if options.force_uncompressed
+                        && entry.compLength < entry.origLength
+                    {
+                        entry.origLength
+                    } else {
+                        table.len()
+                    },
+                )
+            })
+            .map(|length| align_to_four(length) as u64)
+            .sum::<u64>();
+        if let Some(c2pa) = self.tables.get(&FontTag::C2PA) {
+            let length = if options.force_uncompressed {
+                c2pa.len()
+            } else {
+                Self::optimize_c2pa_table(c2pa)?.compressed_length()
+            };
+            size += align_to_four(length) as u64;
+        }
+        if let Some(meta) = &self.metadata {
+            size += align_to_four(Self::compress_metadata(meta)?.len()) as u64;
+        }
+        if let Some(private) = &self.private_data {
+            size += align_to_four(private.len()) as u64;
+        }
+        Ok(size)
+    }
+
+    /// Computes the size of the SFNT font reconstructed from the tables, with
+    /// or without the C2PA table.
+    fn reconstructed_sfnt_size(&self, include_c2pa: bool) -> u64 {
+        let entries = self
+            .directory
+            .entries()
+            .iter()
+            .filter(|entry| include_c2pa || !entry.tag.is_c2pa())
+            .collect::<Vec<_>>();
+        SfntHeader::SIZE as u64
+            + SfntDirectoryEntry::SIZE as u64 * entries.len() as u64
+            + entries
+                .iter()
+                .map(|entry| (entry.origLength as u64 + 3) & !3)
+                .sum::<u64>()
+    }
+
+    /// Checks the total SFNT size in the header matches the size of the SFNT
+    /// font reconstructed from the tables.
+    ///
+    /// # Remarks
+    /// The C2PA table belongs to the WOFF font, and is not carried over to the
+    /// reconstructed SFNT font, so a total size either with or without it is
+    /// accepted.
+    ///
+    /// # Errors
+    /// Returns [`FontIoError::SfntSizeMismatch`] if the sizes do not match.
+    pub fn check_total_sfnt_size(&self) -> Result<(), FontIoError> {
+        let declared = self.header.totalSfntSize;
+        let without_c2pa = self.reconstructed_sfnt_size(false);
+        if declared as u64 == without_c2pa
+            || declared as u64 == self.reconstructed_sfnt_size(true)
+        {
+            Ok(())
+        } else {
+            Err(FontIoError::SfntSizeMismatch {
+                declared,
+                computed: without_c2pa,
+            })
+        }
+    }
+
     /// Prepare a new header based on the current state of the font.
     fn prepare_header(&self) -> Woff1Header {
         // Fill in the new header with the old header's values
@@ -199,82 +422,66 @@ impl Woff1Font {
     }
 }
 
-impl FontDataRead for Woff1Font {
-    type Error = FontIoError;
+impl Woff1Font {
+    /// Returns the compression statistics of the last write, if it was done
+    /// with [`WoffWriteOptions::with_compression_stats`].
+    pub fn last_write_stats(&self) -> Option<&CompressionStats> {
+        self.last_write_stats.as_ref()
+    }
 
-    fn from_reader<T: Read + Seek + ?Sized>(
-        reader: &mut T,
-    ) -> Result<Self, Self::Error> {
-        // Read in the WOFF1 header
-        let header = Woff1Header::from_reader(reader)?;
-        // Determine if we have extension metadata to read
-        let meta_length = header.metaLength;
-        // Determine if we have private data to read
-        let private_length = header.privLength;
-        // Read in the directory
-        let directory = Woff1Directory::from_reader_with_count(
-            reader,
-            header.num_tables() as usize,
-        )?;
-        // And setup to read the contents of the tables
-        let mut tables = BTreeMap::new();
+    /// Writes the font to the destination, using the given options.
+    ///
+    /// # Remarks
+    /// With [`WoffWriteOptions::with_force_uncompressed`], every table is
+    /// stored uncompressed (with `compLength == origLength`), decompressing
+    /// any tables that were read compressed.
+    pub fn write_with_options<TDest: std::io::Write + ?Sized>(
+        &mut self,
+        dest: &mut TDest,
+        options: &WoffWriteOptions,
+    ) -> Result<(), FontIoError> {
+        let start = Instant::now();
+        self.write_tables(dest, options)?;
+        self.metrics(FontOperation::Write, start.elapsed()).emit();
+        Ok(())
+    }
 
-        for entry in directory.entries() {
-            // check if the entry is compressed
-            let table = if entry.compLength < entry.origLength
-                && entry.tag == FontTag::C2PA
-            {
-                Self::decompress_table_from_stream(entry, reader)?
-            } else {
-                // Read in the table data
-                NamedTable::from_reader_exact(
-                    &entry.tag(),
-                    reader,
-                    entry.offset as u64,
-                    entry.length() as usize,
-                )?
-            };
-            tables.insert(entry.tag, table);
+    /// Collects the metrics of an operation on the font which took
+    /// `duration`, from its header and directory.
+    fn metrics(
+        &self,
+        operation: FontOperation,
+        duration: Duration,
+    ) -> FontMetrics {
+        let entries = self.directory.entries();
+        FontMetrics {
+            operation,
+            format: "woff",
+            duration,
+            num_tables: self.tables.len(),
+            size: self.header.length as u64,
+            tables_original_size: entries
+                .iter()
+                .map(|entry| entry.origLength as u64)
+                .sum(),
+            tables_stored_size: entries
+                .iter()
+                .map(|entry| entry.compLength as u64)
+                .sum(),
         }
-        // If we had extension metadata to read, read it
-        let meta = if meta_length > 0 {
-            Some(Data::from_reader_exact(
-                reader,
-                header.metaOffset as u64,
-                meta_length as usize,
-            )?)
-        } else {
-            None
-        };
-        // If we had private data to read, read it
-        let private_data = if private_length > 0 {
-            Some(Data::from_reader_exact(
-                reader,
-                header.privOffset as u64,
-                private_length as usize,
-            )?)
-        } else {
-            None
-        };
-
-        // Return the WOFF1 font
-        Ok(Self {
-            header,
-            directory,
-            tables,
-            metadata: meta,
-            private_data,
-        })
     }
-}
-
-impl MutFontDataWrite for Woff1Font {
-    type Error = FontIoError;
 
-    fn write<TDest: std::io::Write + ?Sized>(
+    /// Writes the font to the destination, using the given options, laying
+    /// out the tables afresh.
+    fn write_tables<TDest: std::io::Write + ?Sized>(
         &mut self,
         dest: &mut TDest,
-    ) -> Result<(), Self::Error> {
+        options: &WoffWriteOptions,
+    ) -> Result<(), FontIoError> {
+        if options.force_uncompressed {
+            self.decompress_tables()?;
+        }
+
         // Setup to write our new header and directory
         let mut neo_header = self.prepare_header();
         let mut neo_directory = Woff1Directory::default();
@@ -287,24 +494,25 @@ impl MutFontDataWrite for Woff1Font {
             + new_table_count as u32 * Woff1DirectoryEntry::SIZE as u32;
 
         // Iterate over the old directory and add entries to the new directory
-        self.directory
+        for entry in self
+            .directory
             .physical_order()
             .iter()
-            .filter(|entry| entry.tag != FontTag::C2PA)
-            .for_each(|entry| {
-                // If we have a table for the entry, add it to the new directory
-                if let Some(table) = self.tables.get(&entry.tag) {
-                    let neo_entry = Woff1DirectoryEntry {
-                        tag: entry.tag,
-                        offset: running_offset,
-                        compLength: entry.compLength,
-                        origLength: entry.origLength,
-                        origChecksum: entry.origChecksum,
-                    };
-                    neo_directory.add_entry(neo_entry);
-                    running_offset += align_to_four(table.len());
-                }
-            });
+            .filter(|entry| !entry.tag.is_c2pa())
+        {
+            // If we have a table for the entry, add it to the new directory
+            if let Some(table) = self.tables.get(&entry.tag) {
+                let neo_entry = Woff1DirectoryEntry {
+                    tag: entry.tag,
+                    offset: running_offset,
+                    compLength: entry.compLength,
+                    origLength: entry.origLength,
+                    origChecksum: entry.origChecksum,
+                };
+                neo_directory.add_entry(neo_entry);
+                running_offset = advance_offset(running_offset, table.len())?;
+            }
+        }
 
         // We will need to keep up with the original checksum for the C2PA table
         // to write it later in the directory entry
@@ -315,13 +523,16 @@ impl MutFontDataWrite for Woff1Font {
             .get(&FontTag::C2PA)
             .map(|c2pa| {
                 original_checksum = c2pa.checksum().0;
-                let mut data_to_compress = Vec::new();
-                c2pa.write(&mut data_to_compress)?;
-                let c2pa_table = Self::optimize_table_data(
-                    &mut Cursor::new(data_to_compress),
-                    0,
-                    c2pa.len(),
-                )?;
+                let c2pa_table = if options.force_uncompressed {
+                    let mut data = Vec::with_capacity(c2pa.len() as usize);
+                    c2pa.write(&mut data)?;
+                    WoffTableData::Uncompressed {
+                        length: data.len() as u32,
+                        data: Data::new(data),
+                    }
+                } else {
+                    Self::optimize_c2pa_table(c2pa)?
+                };
                 // Add the C2PA table to the new directory
                 neo_directory.add_entry(Woff1DirectoryEntry {
                     tag: FontTag::C2PA,
@@ -331,20 +542,35 @@ impl MutFontDataWrite for Woff1Font {
                     origChecksum: original_checksum,
                 });
 
-                running_offset += align_to_four(c2pa_table.compressed_length());
+                running_offset = advance_offset(
+                    running_offset,
+                    c2pa_table.compressed_length(),
+                )?;
                 Ok::<_, FontIoError>(c2pa_table)
             })
             .transpose()?;
 
         // Sort the new directory by tag
         neo_directory.sort_entries(|entry| entry.tag);
+        // Keep track of how well the tables compressed, if asked to
+        self.last_write_stats = options
+            .compression_stats
+            .then(|| CompressionStats::from_directory(&neo_directory));
 
-        // If we have extension metadata, update the header
-        if let Some(meta) = &self.metadata {
+        // If we have extension metadata, compress it and update the header
+        let compressed_meta = self
+            .metadata
+            .as_ref()
+            .map(Self::compress_metadata)
+            .transpose()?;
+        if let (Some(meta), Some(compressed)) =
+            (&self.metadata, &compressed_meta)
+        {
             neo_header.metaOffset = running_offset;
-            let meta_length = meta.len();
+            let meta_length = compressed.len();
             neo_header.metaLength = meta_length;
-            running_offset += align_to_four(meta_length);
+            neo_header.metaOrigLength = meta.len();
+            running_offset = advance_offset(running_offset, meta_length)?;
         }
 
         // If we have private data, update the header
@@ -352,7 +578,7 @@ impl MutFontDataWrite for Woff1Font {
             let private_length = private.len();
             neo_header.privOffset = running_offset;
             neo_header.privLength = private_length;
-            running_offset += align_to_four(private_length);
+            running_offset = advance_offset(running_offset, private_length)?;
         }
 
         // Update the header with the new length of the entire file
@@ -364,7 +590,8 @@ impl MutFontDataWrite for Woff1Font {
                 new_table_count as u32 * SfntDirectoryEntry::SIZE as u32; // Size of table record (directory of font tables)
                                                                           // Add the size of each table in the directory
             for table in neo_directory.entries() {
-                total_sfnt_size += align_to_four(table.origLength);
+                total_sfnt_size =
+                    advance_offset(total_sfnt_size, table.origLength)?;
             }
             total_sfnt_size
         };
@@ -399,7 +626,7 @@ impl MutFontDataWrite for Woff1Font {
             }
         }
         // If we have metadata, write it
-        if let Some(meta) = &self.metadata {
+        if let Some(meta) = &compressed_meta {
             meta.write(dest)?;
         }
         // If we have private data, write it
@@ -408,6 +635,190 @@ impl MutFontDataWrite for Woff1Font {
         }
         Ok(())
     }
+
+    /// Replaces every compressed table with its decompressed data, updating
+    /// the directory to match.
+    fn decompress_tables(&mut self) -> Result<(), FontIoError> {
+        let compressed = self
+            .directory
+            .entries()
+            .iter()
+            .filter(|entry| entry.compLength < entry.origLength)
+            .map(|entry| entry.tag)
+            .collect::<Vec<_>>();
+        for tag in compressed {
+            let table = self.get_decompressed_table(&tag)?;
+            self.tables.insert(tag, table);
+        }
+        for entry in self.directory.entries_mut() {
+            entry.compLength = entry.origLength;
+        }
+        Ok(())
+    }
+}
+
+impl FontDataRead for Woff1Font {
+    type Error = FontIoError;
+
+    fn from_reader<T: Read + Seek + ?Sized>(
+        reader: &mut T,
+    ) -> Result<Self, Self::Error> {
+        Self::from_reader_with_options(reader, &WoffReadOptions::default())
+    }
+}
+
+impl Woff1Font {
+    /// Reads a font from the given reader, using the given options.
+    ///
+    /// # Remarks
+    /// A font which declares no tables (e.g., a container for extension
+    /// metadata alone) is read with an empty set of tables, even though it
+    /// cannot be converted to an SFNT font.
+    ///
+    /// # Errors
+    /// Returns [`FontIoError::InvalidWoffReserved`] if the header's reserved
+    /// field is not zero, or [`FontIoError::InvalidWoffLength`] if the
+    /// header's length is more than the bytes left in the stream or falls
+    /// short of the font's data (unless the options allow either), or an
+    /// error if the font cannot be read.
+    pub fn from_reader_with_options<T: Read + Seek + ?Sized>(
+        reader: &mut T,
+        options: &WoffReadOptions,
+    ) -> Result<Self, FontIoError> {
+        let start = Instant::now();
+        let font = Self::read_with_options(reader, options)?;
+        font.metrics(FontOperation::Parse, start.elapsed()).emit();
+        Ok(font)
+    }
+
+    /// Reads a font from the given reader, using the given options.
+    fn read_with_options<T: Read + Seek + ?Sized>(
+        reader: &mut T,
+        options: &WoffReadOptions,
+    ) -> Result<Self, FontIoError> {
+        // Determine the number of bytes available for the WOFF1 data, which
+        // may be followed by other data in the stream
+        let start = reader.stream_position()?;
+        let available_length = reader.seek(SeekFrom::End(0))? - start;
+        reader.seek(SeekFrom::Start(start))?;
+        // Read in the WOFF1 header
+        let header = Woff1Header::from_reader(reader)?;
+        // Copy the fields out of the packed header
+        let (reserved, length) = (header.reserved, header.length);
+        if reserved != 0 {
+            if !options.lenient_header {
+                return Err(FontIoError::InvalidWoffReserved(reserved));
+            }
+            tracing::warn!(
+                "Ignoring non-zero reserved field in WOFF header: {reserved}"
+            );
+        }
+        // Determine if we have extension metadata to read
+        let meta_length = header.metaLength;
+        // Determine if we have private data to read
+        let private_length = header.privLength;
+        // Read in the directory
+        let directory = Woff1Directory::from_reader_with_count(
+            reader,
+            header.num_tables() as usize,
+        )?;
+        // The length must cover all of the font's data, and no more than the
+        // bytes available
+        let data_length = directory
+            .entries()
+            .iter()
+            .map(|entry| entry.offset as u64 + entry.compLength as u64)
+            .chain([
+                header.metaOffset as u64 + meta_length as u64,
+                header.privOffset as u64 + private_length as u64,
+            ])
+            .max()
+            .unwrap_or_default();
+        let actual_length = if length as u64 > available_length {
+            Some(available_length)
+        } else {
+            (data_length > length as u64).then_some(data_length)
+        };
+        if let Some(actual_length) = actual_length {
+            if !options.lenient_header {
+                return Err(FontIoError::InvalidWoffLength {
+                    header: length,
+                    actual: actual_length,
+                });
+            }
+            tracing::warn!(
+                "Ignoring WOFF header length {length}, which does not match the data length {actual_length}"
+            );
+        }
+        // And setup to read the contents of the tables
+        let mut tables = BTreeMap::new();
+
+        for entry in directory.entries() {
+            // The C2PA table is always kept uncompressed in memory, so it is
+            // decompressed here when stored compressed, and otherwise (i.e.,
+            // when compression would not save space) read as is, like the
+            // other tables are
+            let table =
+                if entry.compLength < entry.origLength && entry.tag.is_c2pa() {
+                    Self::decompress_table_from_stream(
+                        entry,
+                        reader,
+                        options.max_decompressed_table_size,
+                    )?
+                } else {
+                    // Read in the table data
+                    NamedTable::from_reader_exact(
+                        &entry.tag(),
+                        reader,
+                        entry.offset as u64,
+                        entry.length() as usize,
+                    )?
+                };
+            tables.insert(entry.tag, table);
+        }
+        // If we had extension metadata to read, read it
+        let meta = if meta_length > 0 {
+            Some(Self::read_metadata(
+                &header,
+                reader,
+                options.max_decompressed_table_size,
+            )?)
+        } else {
+            None
+        };
+        // If we had private data to read, read it
+        let private_data = if private_length > 0 {
+            Some(Data::from_reader_exact(
+                reader,
+                header.privOffset as u64,
+                private_length as usize,
+            )?)
+        } else {
+            None
+        };
+
+        // Return the WOFF1 font
+        Ok(Self {
+            header,
+            directory,
+            tables,
+            metadata: meta,
+            private_data,
+            last_write_stats: None,
+            max_decompressed_table_size: options.max_decompressed_table_size,
+        })
+    }
+}
+
+impl MutFontDataWrite for Woff1Font {
+    type Error = FontIoError;
+
+    fn write<TDest: std::io::Write + ?Sized>(
+        &mut self,
+        dest: &mut TDest,
+    ) -> Result<(), Self::Error> {
+        self.write_with_options(dest, &WoffWriteOptions::default())
+    }
 }
 
 impl Font for Woff1Font {
@@ -430,6 +841,10 @@ impl Font for Woff1Font {
     fn table(&self, tag: &FontTag) -> Option<&Self::Table> {
         self.tables.get(tag)
     }
+
+    fn tables_iter(&self) -> impl Iterator<Item = (&FontTag, &Self::Table)> {
+        self.tables.iter()
+    }
 }
 
 /// Pseudo tag for WOFF header chunk
@@ -451,6 +866,7 @@ const WOFF_PRIVATE_DATA_CHUNK_NAME: FontTag = FontTag {
 
 /// WOFF chunk type
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum WoffChunkType {
     /// Header
     Header,
@@ -711,6 +1127,320 @@ impl WoffTableData {
     }
 }
 
+/// Options for reading a WOFF1 font.
+#[derive(Clone, Debug)]
+pub struct WoffReadOptions {
+    lenient_header: bool,
+    max_decompressed_table_size: u32,
+}
+
+impl WoffReadOptions {
+    /// The default maximum size of a decompressed table, 64MB.
+    pub const DEFAULT_MAX_DECOMPRESSED_TABLE_SIZE: u32 = 64 * 1024 * 1024;
+
+    /// Sets the maximum size, in bytes, a compressed table (or the compressed
+    /// extension metadata) may declare it decompresses to; larger tables fail
+    /// to read with [`FontIoError::DecompressedSizeExceeded`], protecting
+    /// against decompression bombs. Defaults to
+    /// [`Self::DEFAULT_MAX_DECOMPRESSED_TABLE_SIZE`].
+    ///
+    /// # Remarks
+    /// The limit is kept with the font, so it also applies to the tables
+    /// decompressed later on (e.g., when converting the font to SFNT).
+    pub fn with_max_decompressed_table_size(mut self, max_size: u32) -> Self {
+        self.max_decompressed_table_size = max_size;
+        self
+    }
+
+    /// Sets whether a header with a non-zero reserved field, or with a length
+    /// that does not match the length of the data, is tolerated with a
+    /// warning, rather than failing to read the font; disabled by default.
+    ///
+    /// # Remarks
+    /// The length matches the data when it covers all of the tables,
+    /// extension metadata and private data, without running past the end of
+    /// the stream; the font may be followed by other data in the stream.
+    pub fn with_lenient_header(mut self, lenient: bool) -> Self {
+        self.lenient_header = lenient;
+        self
+    }
+}
+
+impl Default for WoffReadOptions {
+    fn default() -> Self {
+        Self {
+            lenient_header: false,
+            max_decompressed_table_size:
+                Self::DEFAULT_MAX_DECOMPRESSED_TABLE_SIZE,
+        }
+    }
+}
+
+/// Options for writing a WOFF1 font.
+#[derive(Clone, Debug, Default)]
+pub struct WoffWriteOptions {
+    force_uncompressed: bool,
+    compression_stats: bool,
+}
+
+impl WoffWriteOptions {
+    /// Sets whether every table is stored uncompressed, which makes the output
+    /// easier to inspect and compare when debugging; disabled by default.
+    pub fn with_force_uncompressed(mut self, force_uncompressed: bool) -> Self {
+        self.force_uncompressed = force_uncompressed;
+        self
+    }
+
+    /// Sets whether statistics on how well the tables compressed are
+    /// collected, to be read back with [`Woff1Font::last_write_stats`];
+    /// disabled by default.
+    pub fn with_compression_stats(mut self, compression_stats: bool) -> Self {
+        self.compression_stats = compression_stats;
+        self
+    }
+}
+
+/// Statistics on how well the tables of a WOFF1 font compressed when it was
+/// written.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CompressionStats {
+    /// The original and compressed lengths of each table, in bytes; the two
+    /// are equal for a table stored uncompressed.
+    pub per_table: BTreeMap<FontTag, (u32, u32)>,
+    /// The total original length of the tables, in bytes.
+    pub total_original: u64,
+    /// The total compressed length of the tables, in bytes.
+    pub total_compressed: u64,
+}
+
+impl CompressionStats {
+    /// Collects the statistics from the lengths in the given directory.
+    fn from_directory(directory: &Woff1Directory) -> Self {
+        let per_table = directory
+            .entries()
+            .iter()
+            .map(|entry| (entry.tag, (entry.origLength, entry.compLength)))
+            .collect::<BTreeMap<_, _>>();
+        Self {
+            total_original: per_table
+                .values()
+                .map(|(original, _)| *original as u64)
+                .sum(),
+            total_compressed: per_table
+                .values()
+                .map(|(_, compressed)| *compressed as u64)
+                .sum(),
+            per_table,
+        }
+    }
+
+    /// Returns the ratio of the compressed to the original length of all the
+    /// tables (e.g., 0.25 when they compressed to a quarter of their size),
+    /// or `None` if there are no table data.
+    pub fn ratio(&self) -> Option<f64> {
+        (self.total_original > 0)
+            .then(|| self.total_compressed as f64 / self.total_original as f64)
+    }
+}
+
+/// Options for converting an SFNT stream to a WOFF1 stream.
+#[derive(Clone, Debug)]
+pub struct SfntToWoffOptions {
+    compression_level: u32,
+    include_c2pa: bool,
+}
+
+impl SfntToWoffOptions {
+    /// Sets the zlib compression level (0-9) used for the tables; defaults to
+    /// 6.
+    pub fn with_compression_level(mut self, compression_level: u32) -> Self {
+        self.compression_level = compression_level.min(9);
+        self
+    }
+
+    /// Sets whether a C2PA table in the SFNT font is carried over to the WOFF1
+    /// font; defaults to `true`.
+    pub fn with_c2pa(mut self, include_c2pa: bool) -> Self {
+        self.include_c2pa = include_c2pa;
+        self
+    }
+}
+
+impl Default for SfntToWoffOptions {
+    fn default() -> Self {
+        Self {
+            compression_level: flate2::Compression::default().level(),
+            include_c2pa: true,
+        }
+    }
+}
+
+/// Reads the SFNT table for the given entry into `data`, replacing its
+/// contents; the 'head' table is read with its checksum adjustment zeroed.
+fn read_table<R: Read + Seek + ?Sized>(
+    reader: &mut R,
+    entry: &SfntDirectoryEntry,
+    data: &mut Vec<u8>,
+) -> Result<(), FontIoError> {
+    reader.seek(SeekFrom::Start(entry.offset as u64))?;
+    data.clear();
+    let length = reader.take(entry.length as u64).read_to_end(data)?;
+    if length != entry.length as usize {
+        return Err(FontIoError::LoadTableTruncated(entry.tag));
+    }
+    if entry.tag == FontTag::HEAD {
+        if let Some(adjustment) = data.get_mut(8..12) {
+            adjustment.fill(0);
+        }
+    }
+    Ok(())
+}
+
+/// Converts the SFNT font in the reader to a WOFF1 font, written to the
+/// writer.
+///
+/// # Remarks
+/// Rather than loading the entire font into memory, the tables are streamed
+/// from the reader twice; first to sum them, as the checksums in the SFNT
+/// directory may be stale and the 'head' table's checksum adjustment must
+/// account for the tables carried over, and then again to compress and write
+/// them out. Only a single table is held in memory at a time, and each table
+/// is compressed once, and only stored compressed if doing so saves space.
+/// The header and directory are written last, by seeking back to where the
+/// font starts in the writer, once the compressed sizes are known.
+///
+/// # Errors
+/// Returns an error if the SFNT font cannot be read, if a table fails to be
+/// compressed, or if the WOFF1 font cannot be written.
+pub fn sfnt_to_woff_stream<R, W>(
+    reader: &mut R,
+    writer: &mut W,
+    options: &SfntToWoffOptions,
+) -> Result<(), FontIoError>
+where
+    R: Read + Seek + ?Sized,
+    W: Write + Seek + ?Sized,
+{
+    let compression = flate2::Compression::new(options.compression_level);
+    let sfnt_header = SfntHeader::from_reader(reader)?;
+    let sfnt_directory = SfntDirectory::from_reader_with_count(
+        reader,
+        sfnt_header.num_tables() as usize,
+    )?;
+    // Keep up with the tables to carry over, with the C2PA table (if any)
+    // placed at the end
+    let mut entries = sfnt_directory
+        .entries()
+        .iter()
+        .filter(|entry| options.include_c2pa || !entry.tag.is_c2pa())
+        .collect::<Vec<_>>();
+    entries.sort_by_key(|entry| (entry.tag.is_c2pa(), entry.tag));
+    if entries.is_empty() {
+        return Err(FontIoError::NoTablesFound);
+    }
+    let num_tables = entries.len() as u16;
+
+    // First pass; sum each table, laying it out as it will be in the SFNT
+    // font the WOFF1 font decodes to (i.e., in the order of its data)
+    let (search_range, entry_selector, range_shift) =
+        SfntHeader::compute_search_params(num_tables);
+    let decoded_header = SfntHeader {
+        sfntVersion: sfnt_header.sfntVersion,
+        numTables: num_tables,
+        searchRange: search_range,
+        entrySelector: entry_selector,
+        rangeShift: range_shift,
+    };
+    let mut decoded_directory = SfntDirectory::new();
+    let mut decoded_offset = SfntHeader::SIZE as u32
+        + num_tables as u32 * SfntDirectoryEntry::SIZE as u32;
+    let mut table_data = Vec::new();
+    let mut checksums = Vec::with_capacity(entries.len());
+    for entry in &entries {
+        read_table(reader, entry, &mut table_data)?;
+        let checksum = crate::utils::checksum(&table_data).0;
+        checksums.push(checksum);
+        decoded_directory.add_entry(SfntDirectoryEntry {
+            tag: entry.tag,
+            offset: decoded_offset,
+            checksum,
+            length: entry.length,
+        });
+        decoded_offset = advance_offset(decoded_offset, entry.length)?;
+    }
+    decoded_directory.sort_entries(|entry| entry.tag);
+    let font_checksum = decoded_header.checksum()
+        + decoded_directory.checksum()
+        + checksums
+            .iter()
+            .fold(Wrapping(0_u32), |sum, checksum| sum + Wrapping(*checksum));
+    let checksum_adjustment =
+        (Wrapping(SFNT_EXPECTED_CHECKSUM) - font_checksum).0;
+
+    // Second pass; leave room for the header and directory, then compress
+    // and write out each table
+    let start = writer.stream_position()?;
+    let mut offset = Woff1Header::SIZE as u32
+        + num_tables as u32 * Woff1DirectoryEntry::SIZE as u32;
+    writer.write_all(&vec![0; offset as usize])?;
+    let mut header = Woff1Header {
+        flavor: sfnt_header.sfntVersion as u32,
+        numTables: num_tables,
+        totalSfntSize: decoded_offset,
+        ..Default::default()
+    };
+    let mut directory = Woff1Directory::new();
+    let mut compressed_data = Vec::new();
+    for (entry, checksum) in entries.iter().zip(checksums) {
+        read_table(reader, entry, &mut table_data)?;
+        if entry.tag == FontTag::HEAD {
+            if let Some(adjustment) = table_data.get_mut(8..12) {
+                adjustment.copy_from_slice(&checksum_adjustment.to_be_bytes());
+            }
+            // Use the font revision as the version of the WOFF1 font
+            if let Some(revision) = table_data.get(4..8) {
+                header.majorVersion =
+                    u16::from_be_bytes([revision[0], revision[1]]);
+                header.minorVersion =
+                    u16::from_be_bytes([revision[2], revision[3]]);
+            }
+        }
+        compressed_data.clear();
+        let mut compressor = CompressingWriter::builder(&mut compressed_data)
+            .with_compression(compression)
+            .build();
+        compressor.write_all(&table_data)?;
+        compressor.finish()?;
+        let stored_data = if compressed_data.len() < table_data.len() {
+            &compressed_data
+        } else {
+            &table_data
+        };
+        let stored_length = stored_data.len() as u32;
+        directory.add_entry(Woff1DirectoryEntry {
+            tag: entry.tag,
+            offset,
+            compLength: stored_length,
+            origLength: entry.length,
+            origChecksum: checksum,
+        });
+        writer.write_all(stored_data)?;
+        let padding = align_to_four(stored_length) - stored_length;
+        writer.write_all(&[0; 3][..padding as usize])?;
+        offset = advance_offset(offset, stored_length)?;
+    }
+    header.length = offset;
+
+    // And go back to fill in the header and directory
+    let end = writer.stream_position()?;
+    writer.seek(SeekFrom::Start(start))?;
+    header.write(writer)?;
+    directory.sort_entries(|entry| entry.tag);
+    directory.write(writer)?;
+    writer.seek(SeekFrom::Start(end))?;
+    Ok(())
+}
+
 #[cfg(test)]
 #[path = "font_test.rs"]
 mod tests;
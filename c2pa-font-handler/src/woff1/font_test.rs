@@ -16,7 +16,7 @@
 
 use std::{
     collections::btree_map::Entry,
-    io::{Cursor, Write},
+    io::{Cursor, Read, Write},
 };
 
 use super::Woff1Font;
@@ -26,12 +26,19 @@ use crate::{
         UpdateContentCredentialRecord,
     },
     chunks::{ChunkReader, ChunkTypeTrait},
+    compression::{CompressingWriter, DecompressingReader},
     data::Data,
     error::FontIoError,
     magic::Magic,
     tag::FontTag,
-    woff1::{font::WoffChunkType, header::Woff1Header, table::NamedTable},
-    Font, FontDataRead, FontDirectory, FontTable, MutFontDataWrite,
+    utils::align_to_four,
+    woff1::{
+        font::{WoffChunkType, WoffReadOptions, WoffWriteOptions},
+        header::Woff1Header,
+        table::NamedTable,
+    },
+    Font, FontDataChecksum, FontDataRead, FontDataWrite, FontDirectory,
+    FontTable, MutFontDataWrite,
 };
 
 #[test]
@@ -239,6 +246,265 @@ fn test_woff1_read_with_metadata() {
     assert_eq!(metadata.len(), 4);
 }
 
+#[test]
+fn test_woff1_read_with_zero_tables() {
+    // Simulate a WOFF font holding only extension metadata
+    let woff_data = vec![
+        0x77, 0x4f, 0x46, 0x46, // Signature
+        0x4f, 0x54, 0x54, 0x4f, // Flavor
+        0x00, 0x00, 0x00, 0x30, // Length
+        0x00, 0x00, 0x00, 0x00, // Number of tables + Reserved
+        0x00, 0x00, 0x00, 0x0c, // Total sfnt size
+        0x00, 0x00, 0x00, 0x00, // Major version + Minor version
+        0x00, 0x00, 0x00, 0x2c, // Metadata Offset
+        0x00, 0x00, 0x00, 0x04, // Metadata Length
+        0x00, 0x00, 0x00, 0x04, // Metadata Original Length
+        0x00, 0x00, 0x00, 0x00, // Private Offset
+        0x00, 0x00, 0x00, 0x00, // Private Length
+        0x77, 0x55, 0x33, 0x58, // Metadata
+    ];
+    let mut woff = Woff1Font::from_reader(&mut Cursor::new(woff_data)).unwrap();
+    assert!(woff.tables.is_empty());
+    assert!(woff.directory().entries().is_empty());
+    assert!(!woff.has_c2pa());
+    assert_eq!(woff.metadata.as_ref().unwrap().len(), 4);
+
+    // Writing the font must not trip over the empty directory
+    let mut writer = Cursor::new(Vec::new());
+    woff.write(&mut writer).unwrap();
+    let woff =
+        Woff1Font::from_reader(&mut Cursor::new(writer.into_inner())).unwrap();
+    assert!(woff.tables.is_empty());
+    let (num_tables, total_sfnt_size) =
+        (woff.header.numTables, woff.header.totalSfntSize);
+    assert_eq!(num_tables, 0);
+    assert_eq!(total_sfnt_size, 12);
+    assert_eq!(woff.metadata.as_ref().unwrap().data, b"wU3X");
+
+    // But there is nothing to convert to an SFNT font
+    let result = crate::sfnt::font::SfntFont::try_from(woff);
+    assert!(matches!(result, Err(FontIoError::NoTablesFound)));
+}
+
+#[test]
+fn test_woff1_write_compression_stats() {
+    let font_data = include_bytes!("../../../.devtools/font.woff");
+    let mut woff = Woff1Font::from_reader(&mut Cursor::new(font_data)).unwrap();
+    // Statistics are only collected when asked for
+    woff.write(&mut Cursor::new(Vec::new())).unwrap();
+    assert!(woff.last_write_stats().is_none());
+
+    let c2pa_record = ContentCredentialRecordBuilder::default()
+        .with_content_credential(vec![0; 256])
+        .build()
+        .unwrap();
+    woff.add_c2pa_record(c2pa_record).unwrap();
+    let options = WoffWriteOptions::default().with_compression_stats(true);
+    woff.write_with_options(&mut Cursor::new(Vec::new()), &options)
+        .unwrap();
+    let stats = woff.last_write_stats().unwrap().clone();
+    // Every table is accounted for, as recorded in the directory
+    assert_eq!(stats.per_table.len(), woff.directory().entries().len());
+    for entry in woff.directory().entries() {
+        assert_eq!(
+            stats.per_table[&entry.tag],
+            (entry.origLength, entry.compLength)
+        );
+    }
+    let (c2pa_original, c2pa_compressed) = stats.per_table[&FontTag::C2PA];
+    assert!(c2pa_compressed < c2pa_original);
+    assert_eq!(
+        stats.total_original,
+        stats
+            .per_table
+            .values()
+            .map(|(o, _)| *o as u64)
+            .sum::<u64>()
+    );
+    assert_eq!(
+        stats.total_compressed,
+        stats
+            .per_table
+            .values()
+            .map(|(_, c)| *c as u64)
+            .sum::<u64>()
+    );
+    assert!(stats.ratio().unwrap() < 1.0);
+
+    // Nothing is saved when the tables are stored uncompressed
+    let options = options.with_force_uncompressed(true);
+    woff.write_with_options(&mut Cursor::new(Vec::new()), &options)
+        .unwrap();
+    let stats = woff.last_write_stats().unwrap();
+    assert_eq!(stats.total_compressed, stats.total_original);
+    assert_eq!(stats.ratio(), Some(1.0));
+}
+
+/// Extension metadata which compresses well.
+const METADATA_XML: &[u8] = b"<?xml version=\"1.0\" encoding=\"UTF-8\"?>\
+    <metadata version=\"1.0\"><description><text lang=\"en\">\
+    A test font. A test font. A test font. A test font.</text>\
+    </description></metadata>";
+
+#[test]
+fn test_woff1_write_compresses_metadata() {
+    let font_data = include_bytes!("../../../.devtools/font.woff");
+    let mut woff = Woff1Font::from_reader(&mut Cursor::new(font_data)).unwrap();
+    woff.metadata = Some(Data::new(METADATA_XML.to_vec()));
+    let computed_size = woff.computed_size().unwrap();
+    let mut writer = Cursor::new(Vec::new());
+    woff.write(&mut writer).unwrap();
+    let written = writer.into_inner();
+    assert_eq!(computed_size, written.len() as u64);
+    // The header records both lengths
+    let (meta_length, meta_orig_length) =
+        (woff.header.metaLength, woff.header.metaOrigLength);
+    assert_eq!(meta_orig_length as usize, METADATA_XML.len());
+    assert!(meta_length < meta_orig_length);
+    // The metadata is zlib-compressed in the file
+    let meta_offset = woff.header.metaOffset as usize;
+    let compressed = &written[meta_offset..meta_offset + meta_length as usize];
+    let mut decompressed = Vec::new();
+    DecompressingReader::builder(&mut Cursor::new(compressed))
+        .build()
+        .read_to_end(&mut decompressed)
+        .unwrap();
+    assert_eq!(decompressed, METADATA_XML);
+    // And decompressed when read back
+    let woff = Woff1Font::from_reader(&mut Cursor::new(written)).unwrap();
+    assert_eq!(woff.metadata.unwrap().data, METADATA_XML);
+}
+
+#[test]
+fn test_woff1_extension_metadata() {
+    let font_data = include_bytes!("../../../.devtools/font.woff");
+    let mut woff = Woff1Font::from_reader(&mut Cursor::new(font_data)).unwrap();
+    assert!(woff.extension_metadata().unwrap().is_none());
+    woff.metadata = Some(Data::new(METADATA_XML.to_vec()));
+    let metadata = woff.extension_metadata().unwrap().unwrap();
+    assert_eq!(
+        metadata.description.as_deref(),
+        Some("A test font. A test font. A test font. A test font.")
+    );
+    woff.metadata = Some(Data::new(vec![0xff, 0xfe]));
+    assert!(matches!(
+        woff.extension_metadata(),
+        Err(FontIoError::StringFromUtf8(_))
+    ));
+}
+
+#[test]
+fn test_woff1_read_compressed_metadata() {
+    let mut compressed = Vec::new();
+    let mut compressed_writer =
+        CompressingWriter::builder(&mut compressed).build();
+    compressed_writer.write_all(METADATA_XML).unwrap();
+    compressed_writer.finish().unwrap();
+    let padded_length = align_to_four(compressed.len() as u32);
+    let mut woff_data = vec![
+        0x77, 0x4f, 0x46, 0x46, // Signature
+        0x4f, 0x54, 0x54, 0x4f, // Flavor
+        0x00, 0x00, 0x00, 0x00, // Length, filled in below
+        0x00, 0x01, 0x00, 0x00, // Number of tables + Reserved
+        0x00, 0x00, 0x00, 0x20, // Total sfnt size
+        0x00, 0x00, 0x00, 0x00, // Major version + Minor version
+        0x00, 0x00, 0x00, 0x44, // Metadata Offset
+        0x00, 0x00, 0x00, 0x00, // Metadata Length, filled in below
+        0x00, 0x00, 0x00,
+        0x00, // Metadata Original Length, filled in below
+        0x00, 0x00, 0x00, 0x00, // Private Offset
+        0x00, 0x00, 0x00, 0x00, // Private Length
+        0x74, 0x65, 0x73, 0x74, // Directory entry - tag (test)
+        0x00, 0x00, 0x00, 0x40, // Directory entry - offset
+        0x00, 0x00, 0x00, 0x04, // Directory entry - comp length
+        0x00, 0x00, 0x00, 0x04, // Directory entry - orig length
+        0x00, 0x00, 0x00, 0x00, // Directory entry - orig checksum
+        0x04, 0x03, 0x02, 0x01, // 'test' table
+    ];
+    let length = woff_data.len() as u32 + padded_length;
+    woff_data[8..12].copy_from_slice(&length.to_be_bytes());
+    woff_data[28..32].copy_from_slice(&(compressed.len() as u32).to_be_bytes());
+    woff_data[32..36]
+        .copy_from_slice(&(METADATA_XML.len() as u32).to_be_bytes());
+    woff_data.extend_from_slice(&compressed);
+    woff_data.resize(length as usize, 0);
+
+    let woff = Woff1Font::from_reader(&mut Cursor::new(&woff_data)).unwrap();
+    assert_eq!(woff.metadata.as_ref().unwrap().data, METADATA_XML);
+
+    // Metadata declaring a size over the limit is refused
+    let options = WoffReadOptions::default()
+        .with_max_decompressed_table_size(METADATA_XML.len() as u32 - 1);
+    let result = Woff1Font::from_reader_with_options(
+        &mut Cursor::new(&woff_data),
+        &options,
+    );
+    assert!(matches!(
+        result,
+        Err(FontIoError::DecompressedSizeExceeded { size, .. })
+            if size as usize == METADATA_XML.len()
+    ));
+}
+
+#[test]
+fn test_woff1_read_metadata_compressed_to_same_length() {
+    // Find some metadata which compresses to exactly its own length
+    let (metadata, compressed) = (1..METADATA_XML.len())
+        .map(|length| {
+            let mut compressed = Vec::new();
+            let mut compressed_writer =
+                CompressingWriter::builder(&mut compressed).build();
+            compressed_writer
+                .write_all(&METADATA_XML[..length])
+                .unwrap();
+            compressed_writer.finish().unwrap();
+            (&METADATA_XML[..length], compressed)
+        })
+        .find(|(metadata, compressed)| metadata.len() == compressed.len())
+        .unwrap();
+    let woff_data = woff_data_with_metadata(&compressed, metadata.len());
+    let woff = Woff1Font::from_reader(&mut Cursor::new(&woff_data)).unwrap();
+    assert_eq!(woff.metadata.as_ref().unwrap().data, metadata);
+
+    // Whereas metadata stored uncompressed is read as is
+    let woff_data = woff_data_with_metadata(metadata, metadata.len());
+    let woff = Woff1Font::from_reader(&mut Cursor::new(&woff_data)).unwrap();
+    assert_eq!(woff.metadata.as_ref().unwrap().data, metadata);
+}
+
+/// Builds a WOFF font with a single table and the given stored metadata,
+/// declared to be `original_length` bytes once decompressed.
+fn woff_data_with_metadata(metadata: &[u8], original_length: usize) -> Vec<u8> {
+    let padded_length = align_to_four(metadata.len() as u32);
+    let mut woff_data = vec![
+        0x77, 0x4f, 0x46, 0x46, // Signature
+        0x4f, 0x54, 0x54, 0x4f, // Flavor
+        0x00, 0x00, 0x00, 0x00, // Length, filled in below
+        0x00, 0x01, 0x00, 0x00, // Number of tables + Reserved
+        0x00, 0x00, 0x00, 0x20, // Total sfnt size
+        0x00, 0x00, 0x00, 0x00, // Major version + Minor version
+        0x00, 0x00, 0x00, 0x44, // Metadata Offset
+        0x00, 0x00, 0x00, 0x00, // Metadata Length, filled in below
+        0x00, 0x00, 0x00,
+        0x00, // Metadata Original Length, filled in below
+        0x00, 0x00, 0x00, 0x00, // Private Offset
+        0x00, 0x00, 0x00, 0x00, // Private Length
+        0x74, 0x65, 0x73, 0x74, // Directory entry - tag (test)
+        0x00, 0x00, 0x00, 0x40, // Directory entry - offset
+        0x00, 0x00, 0x00, 0x04, // Directory entry - comp length
+        0x00, 0x00, 0x00, 0x04, // Directory entry - orig length
+        0x00, 0x00, 0x00, 0x00, // Directory entry - orig checksum
+        0x04, 0x03, 0x02, 0x01, // 'test' table
+    ];
+    let length = woff_data.len() as u32 + padded_length;
+    woff_data[8..12].copy_from_slice(&length.to_be_bytes());
+    woff_data[28..32].copy_from_slice(&(metadata.len() as u32).to_be_bytes());
+    woff_data[32..36].copy_from_slice(&(original_length as u32).to_be_bytes());
+    woff_data.extend_from_slice(metadata);
+    woff_data.resize(length as usize, 0);
+    woff_data
+}
+
 #[test]
 fn test_woff1_write_with_metadata_non_4byte_aligned() {
     // Simulate a WOFF font
@@ -379,7 +645,11 @@ fn test_woff_font_chunk_reader_metadata_private() {
         .find(|p| p.name() == b"\x7F\x7F\x7Fm")
         .unwrap();
     assert_eq!(metadata.offset(), 884);
-    assert_eq!(metadata.length(), 4);
+    // The metadata is written compressed
+    let (meta_length, meta_orig_length) =
+        (font.header.metaLength, font.header.metaOrigLength);
+    assert_eq!(metadata.length(), meta_length as usize);
+    assert_eq!(meta_orig_length, 4);
     assert_eq!(metadata.chunk_type(), &WoffChunkType::Metadata);
     assert!(metadata.chunk_type().should_hash());
     // And should be able to find the private data, which should NOT be hashed??
@@ -387,7 +657,7 @@ fn test_woff_font_chunk_reader_metadata_private() {
         .iter()
         .find(|p| p.name() == b"\x7F\x7F\x7FP")
         .unwrap();
-    assert_eq!(private.offset(), 888);
+    assert_eq!(private.offset(), 884 + align_to_four(meta_length) as usize);
     assert_eq!(private.length(), 4);
     assert_eq!(private.chunk_type(), &WoffChunkType::Private);
     assert!(private.chunk_type().should_hash());
@@ -458,9 +728,7 @@ fn test_woff_add_c2pa_record() {
 #[tracing_test::traced_test]
 fn test_woff_update_c2pa_record_replaces_table() {
     // Load the font data bytes
-    let font_data = include_bytes!("../../../.devtools/font_with_c2pa.woff");
-    let mut reader = std::io::Cursor::new(font_data);
-    let mut woff = Woff1Font::from_reader(&mut reader).unwrap();
+    let mut woff = read_font_with_c2pa();
     // Check that the C2PA record was added successfully
     assert!(woff.has_c2pa());
     // Update the C2PA record
@@ -588,9 +856,7 @@ fn test_add_c2pa_when_one_is_already_present() {
 #[tracing_test::traced_test]
 fn test_get_c2pa_from_woff_font() {
     // Load the signed WOFF font data bytes
-    let font_data = include_bytes!("../../../.devtools/font_with_c2pa.woff");
-    let mut reader = std::io::Cursor::new(font_data);
-    let woff = Woff1Font::from_reader(&mut reader).unwrap();
+    let woff = read_font_with_c2pa();
     // Check that the C2PA record was added successfully
     assert!(woff.has_c2pa());
     let result = woff.get_c2pa();
@@ -602,6 +868,80 @@ fn test_get_c2pa_from_woff_font() {
     );
 }
 
+// This test verifies that the C2PA table is read back the same whether it was
+// stored compressed or uncompressed
+#[test]
+fn test_get_c2pa_from_woff_compressed_and_uncompressed() {
+    let font_data = include_bytes!("../../../.devtools/font.woff");
+    // A content credential which compresses well
+    let c2pa_record = ContentCredentialRecordBuilder::default()
+        .with_active_manifest_uri("https://example.com/manifest.json".into())
+        .with_content_credential(vec![0; 256])
+        .build()
+        .unwrap();
+    let write_and_read = |options: &WoffWriteOptions| {
+        let mut woff =
+            Woff1Font::from_reader(&mut Cursor::new(font_data)).unwrap();
+        woff.add_c2pa_record(c2pa_record.clone()).unwrap();
+        let mut output = Vec::new();
+        woff.write_with_options(&mut output, options).unwrap();
+        Woff1Font::from_reader(&mut Cursor::new(output)).unwrap()
+    };
+    let c2pa_lengths = |woff: &Woff1Font| {
+        let entry = woff
+            .directory()
+            .entries()
+            .iter()
+            .find(|entry| entry.tag == FontTag::C2PA)
+            .unwrap();
+        (entry.compLength, entry.origLength)
+    };
+
+    let compressed = write_and_read(&WoffWriteOptions::default());
+    let (comp_length, orig_length) = c2pa_lengths(&compressed);
+    assert!(comp_length < orig_length);
+    let uncompressed = write_and_read(
+        &WoffWriteOptions::default().with_force_uncompressed(true),
+    );
+    let (comp_length, orig_length) = c2pa_lengths(&uncompressed);
+    assert_eq!(comp_length, orig_length);
+
+    // Either way, the table is read in as a C2PA table
+    for woff in [&compressed, &uncompressed] {
+        assert!(matches!(
+            woff.table(&FontTag::C2PA),
+            Some(NamedTable::C2PA(_))
+        ));
+    }
+    assert_eq!(compressed.get_c2pa().unwrap(), Some(c2pa_record.clone()));
+    assert_eq!(uncompressed.get_c2pa().unwrap(), Some(c2pa_record));
+}
+
+// This test verifies that a C2PA table which does not compress is stored, and
+// read back, uncompressed
+#[test]
+fn test_get_c2pa_from_woff_incompressible() {
+    let font_data = include_bytes!("../../../.devtools/font.woff");
+    let mut woff = Woff1Font::from_reader(&mut Cursor::new(font_data)).unwrap();
+    let c2pa_record = ContentCredentialRecordBuilder::default()
+        .with_active_manifest_uri("http://localhost:3001/c2pa".to_string())
+        .build()
+        .unwrap();
+    woff.add_c2pa_record(c2pa_record.clone()).unwrap();
+    let mut output = Vec::new();
+    woff.write(&mut output).unwrap();
+    let woff = Woff1Font::from_reader(&mut Cursor::new(output)).unwrap();
+    let entry = woff
+        .directory()
+        .entries()
+        .iter()
+        .find(|entry| entry.tag == FontTag::C2PA)
+        .unwrap();
+    let (comp_length, orig_length) = (entry.compLength, entry.origLength);
+    assert_eq!(comp_length, orig_length);
+    assert_eq!(woff.get_c2pa().unwrap(), Some(c2pa_record));
+}
+
 #[test]
 #[tracing_test::traced_test]
 fn test_update_c2pa_record_when_not_present() {
@@ -625,9 +965,7 @@ fn test_update_c2pa_record_when_not_present() {
 #[tracing_test::traced_test]
 fn test_update_c2pa_record() {
     // Load the signed WOFF font data bytes
-    let font_data = include_bytes!("../../../.devtools/font_with_c2pa.woff");
-    let mut reader = std::io::Cursor::new(font_data);
-    let mut woff = Woff1Font::from_reader(&mut reader).unwrap();
+    let mut woff = read_font_with_c2pa();
     // Check that the C2PA record was added successfully
     assert!(woff.has_c2pa());
     // Update the C2PA record
@@ -645,9 +983,7 @@ fn test_update_c2pa_record() {
 #[tracing_test::traced_test]
 fn test_update_c2pa_record_invalid_c2pa_table() {
     // Load the signed WOFF font data bytes
-    let font_data = include_bytes!("../../../.devtools/font_with_c2pa.woff");
-    let mut reader = std::io::Cursor::new(font_data);
-    let mut woff = Woff1Font::from_reader(&mut reader).unwrap();
+    let mut woff = read_font_with_c2pa();
 
     // Replace the C2PA table with an invalid table type, which is just a
     // Generic
@@ -686,7 +1022,7 @@ fn test_woff1_write_with_c2pa_uncompressed() {
     let woff_data = vec![
         0x77, 0x4f, 0x46, 0x46, // Signature
         0x4f, 0x54, 0x54, 0x4f, // Flavor
-        0x00, 0x00, 0x00, 0x50, // Length
+        0x00, 0x00, 0x00, 0x44, // Length
         0x00, 0x01, 0x00, 0x00, // Number of tables (text & C2PA)
         // + Reserved
         0x00, 0x00, 0x00, 0x18, // Total sfnt size
@@ -769,7 +1105,7 @@ fn test_woff1_write_with_c2pa_compressed() {
     let woff_data = vec![
         0x77, 0x4f, 0x46, 0x46, // Signature
         0x4f, 0x54, 0x54, 0x4f, // Flavor
-        0x00, 0x00, 0x00, 0x50, // Length
+        0x00, 0x00, 0x00, 0x44, // Length
         0x00, 0x01, 0x00, 0x00, // Number of tables (text & C2PA)
         // + Reserved
         0x00, 0x00, 0x00, 0x18, // Total sfnt size
@@ -845,7 +1181,7 @@ fn test_woff1_get_decompressed_table_entry_not_found() {
     let woff_data = vec![
         0x77, 0x4f, 0x46, 0x46, // Signature
         0x4f, 0x54, 0x54, 0x4f, // Flavor
-        0x00, 0x00, 0x00, 0x50, // Length
+        0x00, 0x00, 0x00, 0x44, // Length
         0x00, 0x01, 0x00, 0x00, // Number of tables (text & C2PA)
         // + Reserved
         0x00, 0x00, 0x00, 0x18, // Total sfnt size
@@ -880,7 +1216,7 @@ fn test_woff1_get_decompressed_table_table_not_found() {
     let woff_data = vec![
         0x77, 0x4f, 0x46, 0x46, // Signature
         0x4f, 0x54, 0x54, 0x4f, // Flavor
-        0x00, 0x00, 0x00, 0x50, // Length
+        0x00, 0x00, 0x00, 0x44, // Length
         0x00, 0x01, 0x00, 0x00, // Number of tables (text & C2PA)
         // + Reserved
         0x00, 0x00, 0x00, 0x18, // Total sfnt size
@@ -967,3 +1303,486 @@ fn test_woff1_get_decompressed_table() {
         panic!("Expected a Generic table with decompressed data");
     }
 }
+
+#[test]
+fn test_woff1_computed_size() {
+    let font_data = include_bytes!("../../../.devtools/font.woff");
+    let mut woff = Woff1Font::from_reader(&mut Cursor::new(font_data)).unwrap();
+    let computed_size = woff.computed_size().unwrap();
+    let mut writer = Cursor::new(Vec::new());
+    woff.write(&mut writer).unwrap();
+    assert_eq!(computed_size, writer.into_inner().len() as u64);
+}
+
+#[test]
+fn test_woff1_computed_size_with_c2pa() {
+    let font_data = include_bytes!("../../../.devtools/font.woff");
+    let mut woff = Woff1Font::from_reader(&mut Cursor::new(font_data)).unwrap();
+    // Use a manifest store which will compress, so the size accounts for the
+    // compressed C2PA table
+    let c2pa_record = ContentCredentialRecordBuilder::default()
+        .with_active_manifest_uri("https://example.com/manifest".to_string())
+        .with_content_credential(vec![0x42; 256])
+        .build()
+        .unwrap();
+    woff.add_c2pa_record(c2pa_record).unwrap();
+    woff.metadata = Some(Data::new(vec![0x01; 5]));
+    let computed_size = woff.computed_size().unwrap();
+    let mut writer = Cursor::new(Vec::new());
+    woff.write(&mut writer).unwrap();
+    let written = writer.into_inner();
+    assert_eq!(computed_size, written.len() as u64);
+    assert!(computed_size < font_data.len() as u64 + 256);
+}
+
+#[test]
+fn test_woff1_computed_size_force_uncompressed() {
+    let font_data = include_bytes!("../../../.devtools/font.woff");
+    let mut woff = Woff1Font::from_reader(&mut Cursor::new(font_data)).unwrap();
+    let c2pa_record = ContentCredentialRecordBuilder::default()
+        .with_content_credential(vec![0x42; 256])
+        .build()
+        .unwrap();
+    woff.add_c2pa_record(c2pa_record).unwrap();
+    let options = WoffWriteOptions::default().with_force_uncompressed(true);
+    let computed_size = woff.computed_size_with_options(&options).unwrap();
+    assert!(computed_size > woff.computed_size().unwrap());
+    let mut writer = Cursor::new(Vec::new());
+    woff.write_with_options(&mut writer, &options).unwrap();
+    assert_eq!(computed_size, writer.into_inner().len() as u64);
+}
+
+/// Gets the decompressed data of every table in the WOFF1 font.
+fn decompressed_tables(woff: &Woff1Font) -> Vec<(FontTag, Vec<u8>)> {
+    woff.directory()
+        .entries()
+        .iter()
+        .map(|entry| {
+            let mut data = Vec::new();
+            woff.get_decompressed_table(&entry.tag)
+                .unwrap()
+                .write(&mut data)
+                .unwrap();
+            data.truncate(entry.origLength as usize);
+            (entry.tag, data)
+        })
+        .collect()
+}
+
+#[test]
+fn test_sfnt_to_woff_stream() {
+    let font_data = include_bytes!("../../../.devtools/font.otf");
+    let mut writer = Cursor::new(Vec::new());
+    super::sfnt_to_woff_stream(
+        &mut Cursor::new(font_data),
+        &mut writer,
+        &super::SfntToWoffOptions::default(),
+    )
+    .unwrap();
+    let woff_data = writer.into_inner();
+    let woff = Woff1Font::from_reader(&mut Cursor::new(&woff_data)).unwrap();
+    assert_eq!(woff.header.length as usize, woff_data.len());
+    assert_eq!(woff.header.totalSfntSize as usize, font_data.len());
+    assert_eq!(woff.directory().entries().len(), 11);
+    // Every table should round-trip to the same data as the SFNT font
+    let sfnt =
+        crate::sfnt::font::SfntFont::from_reader(&mut Cursor::new(font_data))
+            .unwrap();
+    for (tag, mut data) in decompressed_tables(&woff) {
+        let mut expected = Vec::new();
+        sfnt.table(&tag).unwrap().write(&mut expected).unwrap();
+        expected.truncate(data.len());
+        // Other than the checksum adjustment, recomputed for the decoded font
+        if tag == FontTag::HEAD {
+            data[8..12].fill(0);
+            expected[8..12].fill(0);
+        }
+        assert_eq!(data, expected, "Table {tag} did not round-trip");
+    }
+    // At least some of the tables should have been compressed
+    assert!(woff
+        .directory()
+        .entries()
+        .iter()
+        .any(|entry| entry.compLength < entry.origLength));
+}
+
+#[test]
+fn test_sfnt_to_woff_stream_without_compression() {
+    let font_data = include_bytes!("../../../.devtools/font.otf");
+    let mut writer = Cursor::new(Vec::new());
+    super::sfnt_to_woff_stream(
+        &mut Cursor::new(font_data),
+        &mut writer,
+        &super::SfntToWoffOptions::default().with_compression_level(0),
+    )
+    .unwrap();
+    let woff =
+        Woff1Font::from_reader(&mut Cursor::new(writer.into_inner())).unwrap();
+    assert!(woff
+        .directory()
+        .entries()
+        .iter()
+        .all(|entry| entry.compLength == entry.origLength));
+}
+
+#[test]
+fn test_sfnt_to_woff_stream_c2pa() {
+    // Create an SFNT font with a C2PA table
+    let font_data = include_bytes!("../../../.devtools/font.otf");
+    let mut sfnt =
+        crate::sfnt::font::SfntFont::from_reader(&mut Cursor::new(font_data))
+            .unwrap();
+    let record = ContentCredentialRecordBuilder::default()
+        .with_active_manifest_uri("https://example.com/manifest".to_string())
+        .build()
+        .unwrap();
+    sfnt.add_c2pa_record(record).unwrap();
+    let mut sfnt_data = Cursor::new(Vec::new());
+    sfnt.write(&mut sfnt_data).unwrap();
+    let sfnt_data = sfnt_data.into_inner();
+
+    let convert = |options: &super::SfntToWoffOptions| {
+        let mut writer = Cursor::new(Vec::new());
+        super::sfnt_to_woff_stream(
+            &mut Cursor::new(&sfnt_data),
+            &mut writer,
+            options,
+        )
+        .unwrap();
+        Woff1Font::from_reader(&mut Cursor::new(writer.into_inner())).unwrap()
+    };
+    let woff = convert(&super::SfntToWoffOptions::default());
+    assert!(woff.has_c2pa());
+    assert_eq!(
+        woff.get_c2pa().unwrap().unwrap().active_manifest_uri(),
+        Some("https://example.com/manifest")
+    );
+    let woff = convert(&super::SfntToWoffOptions::default().with_c2pa(false));
+    assert!(!woff.has_c2pa());
+    assert_eq!(woff.directory().entries().len(), 11);
+}
+
+#[test]
+fn test_sfnt_to_woff_stream_checksums() {
+    use crate::sfnt::{font::SfntFont, header::SfntHeader};
+
+    // Create an SFNT font with a C2PA table, and a stale directory checksum
+    let font_data = include_bytes!("../../../.devtools/font.otf");
+    let mut sfnt = SfntFont::from_reader(&mut Cursor::new(font_data)).unwrap();
+    let record = ContentCredentialRecordBuilder::default()
+        .with_active_manifest_uri("https://example.com/manifest".to_string())
+        .build()
+        .unwrap();
+    sfnt.add_c2pa_record(record).unwrap();
+    let mut sfnt_data = Vec::new();
+    sfnt.write(&mut sfnt_data).unwrap();
+    let name_index = sfnt
+        .directory()
+        .entries()
+        .iter()
+        .position(|entry| entry.tag == FontTag::NAME)
+        .unwrap();
+    sfnt_data[SfntHeader::SIZE + name_index * 16 + 4] ^= 0xff;
+
+    for include_c2pa in [true, false] {
+        let mut writer = Cursor::new(Vec::new());
+        super::sfnt_to_woff_stream(
+            &mut Cursor::new(&sfnt_data),
+            &mut writer,
+            &super::SfntToWoffOptions::default().with_c2pa(include_c2pa),
+        )
+        .unwrap();
+        let woff =
+            Woff1Font::from_reader(&mut Cursor::new(writer.into_inner()))
+                .unwrap();
+        let orig_checksums = woff
+            .directory()
+            .entries()
+            .iter()
+            .map(|entry| (entry.tag, entry.origChecksum))
+            .collect::<Vec<_>>();
+        // Decode the WOFF1 font with all of its tables
+        let mut decoded = if include_c2pa {
+            SfntFont::from_woff_carry_c2pa(woff).unwrap()
+        } else {
+            SfntFont::try_from(woff).unwrap()
+        };
+        // The WOFF1 checksums are those of the decoded tables
+        for (tag, orig_checksum) in orig_checksums {
+            let entry = decoded
+                .directory()
+                .entries()
+                .iter()
+                .find(|entry| entry.tag == tag)
+                .unwrap();
+            let checksum = entry.checksum;
+            assert_eq!(orig_checksum, checksum, "Checksum of table {tag}");
+        }
+        // And the checksum adjustment holds for the decoded font as is
+        let mut decoded_data = Vec::new();
+        decoded.write(&mut decoded_data).unwrap();
+        assert_eq!(
+            crate::utils::checksum(&decoded_data).0,
+            crate::sfnt::table::head::SFNT_EXPECTED_CHECKSUM,
+            "Checksum adjustment with the C2PA table included: {include_c2pa}"
+        );
+    }
+}
+
+#[test]
+fn test_woff1_read_with_non_zero_reserved() {
+    let mut woff_data = include_bytes!("../../../.devtools/font.woff").to_vec();
+    woff_data[14..16].copy_from_slice(&[0x00, 0x01]);
+    let result = Woff1Font::from_reader(&mut Cursor::new(&woff_data));
+    assert!(matches!(result, Err(FontIoError::InvalidWoffReserved(1))));
+}
+
+#[test]
+fn test_woff1_read_with_length_mismatch() {
+    let mut woff_data = include_bytes!("../../../.devtools/font.woff").to_vec();
+    // A length past the end of the stream
+    woff_data[8..12].copy_from_slice(&888u32.to_be_bytes());
+    let result = Woff1Font::from_reader(&mut Cursor::new(&woff_data));
+    assert!(matches!(
+        result,
+        Err(FontIoError::InvalidWoffLength {
+            header: 888,
+            actual: 884
+        })
+    ));
+}
+
+#[test]
+fn test_woff1_read_with_length_short_of_data() {
+    // The header of this font declares a length short of its tables' data
+    let font_data = include_bytes!("../../../.devtools/font_with_c2pa.woff");
+    let result = Woff1Font::from_reader(&mut Cursor::new(font_data));
+    assert!(matches!(
+        result,
+        Err(FontIoError::InvalidWoffLength {
+            header: 884,
+            actual: 957
+        })
+    ));
+    let woff = read_font_with_c2pa();
+    assert!(woff.has_c2pa());
+    assert_eq!(woff.tables.len(), 11);
+}
+
+#[test]
+#[tracing_test::traced_test]
+fn test_woff1_read_with_lenient_header() {
+    let mut woff_data = include_bytes!("../../../.devtools/font.woff").to_vec();
+    woff_data[14..16].copy_from_slice(&[0x00, 0x01]);
+    woff_data[8..12].copy_from_slice(&888u32.to_be_bytes());
+    let options = WoffReadOptions::default().with_lenient_header(true);
+    let woff = Woff1Font::from_reader_with_options(
+        &mut Cursor::new(&woff_data),
+        &options,
+    )
+    .unwrap();
+    assert_eq!(woff.tables.len(), 10);
+    assert!(logs_contain(
+        "Ignoring non-zero reserved field in WOFF header: 1"
+    ));
+    assert!(logs_contain(
+        "Ignoring WOFF header length 888, which does not match the data length 884"
+    ));
+}
+
+/// Reads the test font with a C2PA table, whose header declares a length
+/// short of its data, so it is only read with a lenient header.
+fn read_font_with_c2pa() -> Woff1Font {
+    let font_data = include_bytes!("../../../.devtools/font_with_c2pa.woff");
+    let options = WoffReadOptions::default().with_lenient_header(true);
+    Woff1Font::from_reader_with_options(&mut Cursor::new(font_data), &options)
+        .unwrap()
+}
+
+#[test]
+fn test_woff1_read_from_offset_in_stream() {
+    // The length is relative to the start of the font data
+    let mut data = vec![0xff; 16];
+    data.extend_from_slice(include_bytes!("../../../.devtools/font.woff"));
+    let mut reader = Cursor::new(&data);
+    reader.set_position(16);
+    let woff = Woff1Font::from_reader(&mut reader).unwrap();
+    assert_eq!(woff.tables.len(), 10);
+
+    // And may be followed by other data
+    data.extend_from_slice(&[0xff; 16]);
+    let mut reader = Cursor::new(&data);
+    reader.set_position(16);
+    let woff = Woff1Font::from_reader(&mut reader).unwrap();
+    assert_eq!(woff.tables.len(), 10);
+}
+
+#[test]
+fn test_woff1_read_with_decompressed_size_limit() {
+    let font_data = include_bytes!("../../../.devtools/font.woff");
+    let mut woff = Woff1Font::from_reader(&mut Cursor::new(font_data)).unwrap();
+    // A C2PA table large enough to be compressed when written
+    let c2pa_record = ContentCredentialRecordBuilder::default()
+        .with_content_credential(vec![0x00; 1024])
+        .build()
+        .unwrap();
+    woff.add_c2pa_record(c2pa_record).unwrap();
+    let mut writer = Cursor::new(Vec::new());
+    woff.write(&mut writer).unwrap();
+    let woff_data = writer.into_inner();
+
+    let options =
+        WoffReadOptions::default().with_max_decompressed_table_size(512);
+    let result = Woff1Font::from_reader_with_options(
+        &mut Cursor::new(&woff_data),
+        &options,
+    );
+    assert!(matches!(
+        result,
+        Err(FontIoError::DecompressedSizeExceeded {
+            tag: FontTag::C2PA,
+            limit: 512,
+            ..
+        })
+    ));
+    // But the default limit is plenty
+    let woff = Woff1Font::from_reader(&mut Cursor::new(&woff_data)).unwrap();
+    assert!(woff.has_c2pa());
+}
+
+#[test]
+fn test_woff1_get_decompressed_table_with_huge_declared_size() {
+    let mut font_data = include_bytes!("../../../.devtools/font.woff").to_vec();
+    // Declare a huge original length for the compressed 'name' table, the 9th
+    // directory entry
+    let entry = Woff1Header::SIZE + 8 * 20;
+    assert_eq!(&font_data[entry..entry + 4], b"name");
+    font_data[entry + 12..entry + 16].copy_from_slice(&[0xff; 4]);
+    let woff = Woff1Font::from_reader(&mut Cursor::new(&font_data)).unwrap();
+    let result = woff.get_decompressed_table(&FontTag::new(*b"name"));
+    assert!(matches!(
+        result,
+        Err(FontIoError::DecompressedSizeExceeded {
+            size: 0xffffffff,
+            limit: WoffReadOptions::DEFAULT_MAX_DECOMPRESSED_TABLE_SIZE,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn test_woff1_get_decompressed_table_with_read_limit() {
+    let font_data = include_bytes!("../../../.devtools/font.woff");
+    let name = FontTag::new(*b"name");
+    let options =
+        WoffReadOptions::default().with_max_decompressed_table_size(16);
+    // The 'name' table is compressed, and decompresses to more than 16 bytes
+    let woff = Woff1Font::from_reader_with_options(
+        &mut Cursor::new(font_data),
+        &options,
+    )
+    .unwrap();
+    let entry = woff
+        .directory
+        .entries()
+        .iter()
+        .find(|entry| entry.tag == name)
+        .unwrap();
+    assert!(entry.compLength < entry.origLength);
+    let result = woff.get_decompressed_table(&name);
+    assert!(matches!(
+        result,
+        Err(FontIoError::DecompressedSizeExceeded { tag, limit: 16, .. })
+            if tag == name
+    ));
+    // Which also applies when converting the font
+    let result = crate::sfnt::font::SfntFont::try_from(woff);
+    assert!(matches!(
+        result,
+        Err(FontIoError::DecompressedSizeExceeded { limit: 16, .. })
+    ));
+}
+
+#[test]
+fn test_woff_tables_iter() {
+    let font_data = include_bytes!("../../../.devtools/font.woff");
+    let font = Woff1Font::from_reader(&mut Cursor::new(font_data)).unwrap();
+    let tags = font.tables_iter().map(|(tag, _)| *tag).collect::<Vec<_>>();
+    assert_eq!(tags.len(), 10);
+    assert!(tags.is_sorted());
+    for (tag, table) in font.tables_iter() {
+        assert!(std::ptr::eq(table, font.table(tag).unwrap()));
+    }
+}
+
+#[test]
+fn test_woff1_write_force_uncompressed() {
+    let font_data = include_bytes!("../../../.devtools/font.woff");
+    let original = Woff1Font::from_reader(&mut Cursor::new(font_data)).unwrap();
+    assert!(original
+        .directory
+        .entries()
+        .iter()
+        .any(|entry| entry.compLength < entry.origLength));
+    let mut woff = Woff1Font::from_reader(&mut Cursor::new(font_data)).unwrap();
+    let c2pa_record = ContentCredentialRecordBuilder::default()
+        .with_content_credential(vec![0; 256])
+        .build()
+        .unwrap();
+    woff.add_c2pa_record(c2pa_record).unwrap();
+    let mut writer = Cursor::new(Vec::new());
+    woff.write_with_options(
+        &mut writer,
+        &WoffWriteOptions::default().with_force_uncompressed(true),
+    )
+    .unwrap();
+
+    let woff =
+        Woff1Font::from_reader(&mut Cursor::new(writer.into_inner())).unwrap();
+    assert!(woff
+        .directory
+        .entries()
+        .iter()
+        .all(|entry| entry.compLength == entry.origLength));
+    // The tables hold the same data as the original, compressed ones
+    for entry in original.directory.entries() {
+        assert_eq!(
+            woff.get_decompressed_table(&entry.tag).unwrap().len(),
+            original.get_decompressed_table(&entry.tag).unwrap().len()
+        );
+        assert_eq!(
+            woff.get_decompressed_table(&entry.tag).unwrap().checksum(),
+            original
+                .get_decompressed_table(&entry.tag)
+                .unwrap()
+                .checksum()
+        );
+    }
+    assert_eq!(
+        woff.get_c2pa().unwrap().unwrap().content_credential(),
+        Some(&[0; 256][..])
+    );
+}
+
+#[test]
+fn test_woff1_check_total_sfnt_size() {
+    // Declared without the C2PA table
+    let mut woff = read_font_with_c2pa();
+    assert!(woff.check_total_sfnt_size().is_ok());
+    // Declared with it, as written here
+    let mut output = Vec::new();
+    woff.write(&mut output).unwrap();
+    let mut woff = Woff1Font::from_reader(&mut Cursor::new(output)).unwrap();
+    assert!(woff.check_total_sfnt_size().is_ok());
+
+    woff.header.totalSfntSize += 4;
+    let result = woff.check_total_sfnt_size();
+    assert!(matches!(
+        result,
+        Err(FontIoError::SfntSizeMismatch {
+            declared: 1136,
+            computed: 1060,
+        })
+    ));
+}
@@ -0,0 +1,138 @@
+// Copyright 2025 Monotype Imaging Inc.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+//! WOFF1 extension metadata.
+
+use std::collections::BTreeMap;
+
+use crate::error::FontIoError;
+
+/// The namespace of the `xml:lang` attribute.
+const XML_NAMESPACE: &str = "http://www.w3.org/XML/1998/namespace";
+
+/// The parts of a WOFF font's extension metadata which describe where the font
+/// came from and how it may be used.
+///
+/// # Remarks
+/// Elements holding text may hold it in several languages; the untagged or
+/// English text is used when there is any, otherwise the first text is used.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct WoffMetadata {
+    /// The `id` of the `uniqueid` element
+    pub unique_id: Option<String>,
+    /// The `name` of the `vendor` element
+    pub vendor_name: Option<String>,
+    /// The `url` of the `vendor` element
+    pub vendor_url: Option<String>,
+    /// The text of the `description` element
+    pub description: Option<String>,
+    /// The `url` of the `license` element
+    pub license_url: Option<String>,
+    /// The `id` of the `license` element
+    pub license_id: Option<String>,
+    /// The text of the `license` element
+    pub license: Option<String>,
+    /// The text of the `copyright` element
+    pub copyright: Option<String>,
+    /// The text of the `trademark` element
+    pub trademark: Option<String>,
+    /// The `name` of the `licensee` element
+    pub licensee: Option<String>,
+}
+
+impl WoffMetadata {
+    /// Parses the extension metadata from its XML.
+    ///
+    /// # Errors
+    /// Returns [`FontIoError::InvalidWoffMetadata`] if the XML can't be parsed
+    /// or its root element isn't `metadata`.
+    pub fn from_xml(xml: &str) -> Result<Self, FontIoError> {
+        let document = roxmltree::Document::parse(xml)
+            .map_err(|e| FontIoError::InvalidWoffMetadata(e.to_string()))?;
+        let root = document.root_element();
+        if !root.has_tag_name("metadata") {
+            return Err(FontIoError::InvalidWoffMetadata(format!(
+                "expected a 'metadata' root element, found '{}'",
+                root.tag_name().name()
+            )));
+        }
+        let element = |name: &str| {
+            root.children()
+                .find(|node| node.is_element() && node.has_tag_name(name))
+        };
+        let attribute = |name: &str, attribute: &str| {
+            element(name)
+                .and_then(|node| node.attribute(attribute))
+                .map(str::to_string)
+        };
+        let text = |name: &str| element(name).and_then(localized_text);
+        Ok(Self {
+            unique_id: attribute("uniqueid", "id"),
+            vendor_name: attribute("vendor", "name"),
+            vendor_url: attribute("vendor", "url"),
+            description: text("description"),
+            license_url: attribute("license", "url"),
+            license_id: attribute("license", "id"),
+            license: text("license"),
+            copyright: text("copyright"),
+            trademark: text("trademark"),
+            licensee: attribute("licensee", "name"),
+        })
+    }
+
+    /// Gets the fields of the metadata which are relevant to a C2PA
+    /// assertion, keyed by name, leaving out any which are missing.
+    ///
+    /// # Remarks
+    /// The keys are `vendor_name`, `license_url`, `copyright` and
+    /// `unique_id`.
+    pub fn to_assertion_fields(&self) -> BTreeMap<String, String> {
+        [
+            ("vendor_name", &self.vendor_name),
+            ("license_url", &self.license_url),
+            ("copyright", &self.copyright),
+            ("unique_id", &self.unique_id),
+        ]
+        .into_iter()
+        .filter_map(|(key, value)| {
+            value.as_ref().map(|value| (key.to_string(), value.clone()))
+        })
+        .collect()
+    }
+}
+
+/// Gets the text of the element's untagged or English `text` child, falling
+/// back to the first `text` child.
+fn localized_text(node: roxmltree::Node<'_, '_>) -> Option<String> {
+    let texts = node
+        .children()
+        .filter(|child| child.is_element() && child.has_tag_name("text"))
+        .collect::<Vec<_>>();
+    let preferred = texts.iter().find(|text| {
+        text.attribute((XML_NAMESPACE, "lang"))
+            .is_none_or(|lang| lang == "en" || lang.starts_with("en-"))
+    });
+    preferred.or(texts.first()).map(|text| {
+        text.descendants()
+            .filter(|node| node.is_text())
+            .filter_map(|node| node.text())
+            .collect::<String>()
+            .trim()
+            .to_string()
+    })
+}
+
+#[cfg(test)]
+#[path = "metadata_test.rs"]
+mod tests;
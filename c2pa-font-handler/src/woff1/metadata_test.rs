@@ -0,0 +1,103 @@
+// Copyright 2025 Monotype Imaging Inc.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+//! Tests for WOFF1 extension metadata.
+
+use super::*;
+
+/// Extension metadata, as in the example of the WOFF specification.
+const METADATA_XML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<metadata version="1.0">
+    <uniqueid id="org.example.font.test.20251017" />
+    <vendor name="Example Foundry" url="https://example.org" />
+    <credits>
+        <credit name="A Designer" role="Lead" />
+    </credits>
+    <description>
+        <text xml:lang="fr">Une police de test.</text>
+        <text xml:lang="en">A <span>test</span> font.</text>
+    </description>
+    <license url="https://example.org/license" id="EX-1">
+        <text>Do as you please.</text>
+    </license>
+    <copyright>
+        <text xml:lang="de">Urheberrecht 2025 Example Foundry.</text>
+    </copyright>
+    <licensee name="A Licensee" />
+</metadata>"#;
+
+#[test]
+fn test_woff_metadata_from_xml() {
+    let metadata = WoffMetadata::from_xml(METADATA_XML).unwrap();
+    assert_eq!(
+        metadata,
+        WoffMetadata {
+            unique_id: Some("org.example.font.test.20251017".to_string()),
+            vendor_name: Some("Example Foundry".to_string()),
+            vendor_url: Some("https://example.org".to_string()),
+            description: Some("A test font.".to_string()),
+            license_url: Some("https://example.org/license".to_string()),
+            license_id: Some("EX-1".to_string()),
+            license: Some("Do as you please.".to_string()),
+            // Without English text, the first text is used
+            copyright: Some("Urheberrecht 2025 Example Foundry.".to_string()),
+            trademark: None,
+            licensee: Some("A Licensee".to_string()),
+        }
+    );
+}
+
+#[test]
+fn test_woff_metadata_from_xml_empty() {
+    let metadata =
+        WoffMetadata::from_xml("<metadata version=\"1.0\"/>").unwrap();
+    assert_eq!(metadata, WoffMetadata::default());
+    assert!(metadata.to_assertion_fields().is_empty());
+}
+
+#[test]
+fn test_woff_metadata_from_invalid_xml() {
+    let result = WoffMetadata::from_xml("<metadata>");
+    assert!(matches!(result, Err(FontIoError::InvalidWoffMetadata(_))));
+    let result = WoffMetadata::from_xml("<font />");
+    assert!(matches!(
+        result,
+        Err(FontIoError::InvalidWoffMetadata(message))
+            if message == "expected a 'metadata' root element, found 'font'"
+    ));
+}
+
+#[test]
+fn test_woff_metadata_to_assertion_fields() {
+    let metadata = WoffMetadata::from_xml(METADATA_XML).unwrap();
+    let fields = metadata.to_assertion_fields();
+    assert_eq!(
+        fields.into_iter().collect::<Vec<_>>(),
+        vec![
+            (
+                "copyright".to_string(),
+                "Urheberrecht 2025 Example Foundry.".to_string()
+            ),
+            (
+                "license_url".to_string(),
+                "https://example.org/license".to_string()
+            ),
+            (
+                "unique_id".to_string(),
+                "org.example.font.test.20251017".to_string()
+            ),
+            ("vendor_name".to_string(), "Example Foundry".to_string()),
+        ]
+    );
+}
@@ -90,27 +90,27 @@ fn test_named_table_generic_read_exact() {
 
 #[test]
 fn test_named_table_generic_len() {
-    let generic = NamedTable::Generic(Data {
-        data: vec![0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00],
-    });
+    let generic = NamedTable::Generic(Data::new(vec![
+        0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00,
+    ]));
     assert_eq!(generic.len(), 8);
     assert!(!generic.is_empty());
 }
 
 #[test]
 fn test_name_table_generic_checksum() {
-    let generic = NamedTable::Generic(Data {
-        data: vec![0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00],
-    });
+    let generic = NamedTable::Generic(Data::new(vec![
+        0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00,
+    ]));
     let checksum = generic.checksum();
     assert_eq!(checksum.0, 0x00000001);
 }
 
 #[test]
 fn test_named_table_generic_write() {
-    let generic = NamedTable::Generic(Data {
-        data: vec![0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00],
-    });
+    let generic = NamedTable::Generic(Data::new(vec![
+        0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00,
+    ]));
     let mut buffer = Vec::new();
     let mut writer = Cursor::new(&mut buffer);
     generic.write(&mut writer).unwrap();
@@ -121,9 +121,7 @@ fn test_named_table_generic_write() {
 #[test]
 fn test_display_of_named_tables() {
     let c2pa = NamedTable::C2PA(TableC2PA::default());
-    let generic = NamedTable::Generic(Data {
-        data: vec![0x00, 0x00, 0x00, 0x01],
-    });
+    let generic = NamedTable::Generic(Data::new(vec![0x00, 0x00, 0x00, 0x01]));
     assert_eq!(format!("{c2pa}"), "C2PA");
     assert_eq!(format!("{generic}"), "Generic(DATA)");
 }